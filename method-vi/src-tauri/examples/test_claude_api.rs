@@ -51,6 +51,7 @@ async fn main() {
             None,  // Use default model (claude-sonnet-4-20250514)
             Some(20),  // Keep max_tokens low to minimize cost
             None,  // Use default temperature
+            None,  // Use default top_p
         )
         .await
     {
@@ -14,6 +14,7 @@
 
 use method_vi_lib::agents::{Orchestrator, ScopePatternAgent};
 use method_vi_lib::api::AnthropicClient;
+use method_vi_lib::artifacts::GovernanceRole;
 use method_vi_lib::signals::SignalType;
 
 #[tokio::main]
@@ -114,7 +115,7 @@ async fn main() {
     assert!(!intent_summary.hash.is_empty(), "Hash should not be empty");
     assert_eq!(intent_summary.hash.len(), 64, "SHA-256 hash should be 64 chars");
     assert_eq!(intent_summary.author, "scope-pattern-agent", "Author should be scope-pattern-agent");
-    assert_eq!(intent_summary.governance_role, "Observer", "Role should be Observer");
+    assert_eq!(intent_summary.governance_role, GovernanceRole::Observer, "Role should be Observer");
     assert_eq!(intent_summary.is_immutable, false, "Should not be immutable yet");
 
     println!("\n✓ All artifact metadata assertions passed");
@@ -11,6 +11,7 @@
 /// cargo run --example test_e2e_step0_stub
 
 use method_vi_lib::agents::Orchestrator;
+use method_vi_lib::artifacts::GovernanceRole;
 use method_vi_lib::signals::SignalType;
 
 #[tokio::main]
@@ -97,7 +98,7 @@ async fn main() {
     assert!(!intent_summary.hash.is_empty(), "Hash should not be empty");
     assert_eq!(intent_summary.hash.len(), 64, "SHA-256 hash should be 64 chars");
     assert!(intent_summary.author.contains("scope-pattern-agent"), "Author should be scope-pattern-agent");
-    assert_eq!(intent_summary.governance_role, "Observer", "Role should be Observer");
+    assert_eq!(intent_summary.governance_role, GovernanceRole::Observer, "Role should be Observer");
     assert_eq!(intent_summary.is_immutable, false, "Should not be immutable yet");
 
     println!("\n✓ All artifact metadata assertions passed");
@@ -0,0 +1,345 @@
+use log::{debug, info};
+use serde::{Deserialize, Serialize};
+
+use crate::agents::governance_telemetry::{MetricThreshold, ThresholdsConfig};
+
+/// Calibration settings for a single control domain (Step 2)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DomainCalibration {
+    /// What the domain monitors
+    pub objective: String,
+    /// Threshold settings (when to intervene)
+    pub thresholds: Vec<String>,
+    /// Intervention triggers (what causes an alert)
+    pub triggers: Vec<String>,
+    /// Why these settings were chosen
+    pub rationale: String,
+}
+
+impl DomainCalibration {
+    /// True if no field was found in the response
+    pub fn is_empty(&self) -> bool {
+        self.objective.is_empty()
+            && self.thresholds.is_empty()
+            && self.triggers.is_empty()
+            && self.rationale.is_empty()
+    }
+}
+
+/// Structured governance calibration parsed from the Step 2 response
+///
+/// One entry per control domain. Sections missing from the response are left empty.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GovernanceCalibration {
+    pub entropy: DomainCalibration,
+    pub objective: DomainCalibration,
+    pub process: DomainCalibration,
+    pub reflective: DomainCalibration,
+    pub termination: DomainCalibration,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Domain {
+    Entropy,
+    Objective,
+    Process,
+    Reflective,
+    Termination,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Field {
+    Objective,
+    Thresholds,
+    Triggers,
+    Rationale,
+}
+
+impl GovernanceCalibration {
+    /// Parse a governance calibration response into per-domain settings
+    ///
+    /// Domains are found by their "<NAME> CONTROL" headings; within a domain,
+    /// "Objective", "Threshold", "Trigger" and "Rationale" labels start a field and
+    /// following bullet lines are appended to it.
+    pub fn parse(response: &str) -> Self {
+        let mut calibration = GovernanceCalibration::default();
+        let mut domain: Option<Domain> = None;
+        let mut field: Option<Field> = None;
+
+        for raw_line in response.lines() {
+            let line = raw_line.trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Some(next) = detect_domain(line) {
+                domain = Some(next);
+                field = None;
+                continue;
+            }
+
+            let Some(current) = domain else { continue };
+            let text = strip_markers(line);
+            if text.is_empty() {
+                continue;
+            }
+
+            if let Some((label, value)) = split_label(&text) {
+                if let Some(next) = detect_field(&label) {
+                    field = Some(next);
+                    if !value.is_empty() {
+                        calibration.domain_mut(current).push(next, value);
+                    }
+                    continue;
+                }
+            }
+
+            if let Some(current_field) = field {
+                calibration.domain_mut(current).push(current_field, text);
+            }
+        }
+
+        debug!("Parsed governance calibration: {:?}", calibration);
+        calibration
+    }
+
+    fn domain_mut(&mut self, domain: Domain) -> &mut DomainCalibration {
+        match domain {
+            Domain::Entropy => &mut self.entropy,
+            Domain::Objective => &mut self.objective,
+            Domain::Process => &mut self.process,
+            Domain::Reflective => &mut self.reflective,
+            Domain::Termination => &mut self.termination,
+        }
+    }
+
+    /// Override metric thresholds with values found in the calibration
+    ///
+    /// Entropy thresholds map to EV, Objective to IAS, and Process to PCI. Only
+    /// threshold lines naming a level ("pass"/"target", "warning", "halt") with a
+    /// number are applied. Returns the number of threshold values overridden.
+    pub fn apply_thresholds(&self, thresholds: &mut ThresholdsConfig) -> usize {
+        let mut applied = 0;
+        applied += apply_domain_thresholds(&self.entropy.thresholds, &mut thresholds.ev, false);
+        applied += apply_domain_thresholds(&self.objective.thresholds, &mut thresholds.ias, true);
+        applied += apply_domain_thresholds(&self.process.thresholds, &mut thresholds.pci, true);
+
+        if applied > 0 {
+            info!("Applied {} calibrated threshold override(s)", applied);
+        }
+        applied
+    }
+}
+
+impl DomainCalibration {
+    fn push(&mut self, field: Field, value: String) {
+        match field {
+            Field::Objective => append_text(&mut self.objective, &value),
+            Field::Thresholds => self.thresholds.push(value),
+            Field::Triggers => self.triggers.push(value),
+            Field::Rationale => append_text(&mut self.rationale, &value),
+        }
+    }
+}
+
+fn append_text(target: &mut String, value: &str) {
+    if !target.is_empty() {
+        target.push(' ');
+    }
+    target.push_str(value);
+}
+
+/// Detect a domain heading such as "## 1. ENTROPY CONTROL" or "**Reflective Control**"
+fn detect_domain(line: &str) -> Option<Domain> {
+    // "Control objective: ..." is a field, not a heading
+    if let Some((_, rest)) = line.split_once(':') {
+        if !rest.trim().trim_matches('*').is_empty() {
+            return None;
+        }
+    }
+
+    let upper = line.to_uppercase();
+    let is_heading = line.starts_with('#') || line.starts_with("**") || upper.ends_with("CONTROL");
+    if !is_heading || !upper.contains("CONTROL") {
+        return None;
+    }
+
+    if upper.contains("ENTROPY") {
+        Some(Domain::Entropy)
+    } else if upper.contains("OBJECTIVE") {
+        Some(Domain::Objective)
+    } else if upper.contains("PROCESS") {
+        Some(Domain::Process)
+    } else if upper.contains("REFLECTIVE") {
+        Some(Domain::Reflective)
+    } else if upper.contains("TERMINATION") {
+        Some(Domain::Termination)
+    } else {
+        None
+    }
+}
+
+/// Map a label ("Control objective", "Threshold settings", ...) to a field
+fn detect_field(label: &str) -> Option<Field> {
+    let lower = label.to_lowercase();
+    if lower.contains("objective") {
+        Some(Field::Objective)
+    } else if lower.contains("threshold") {
+        Some(Field::Thresholds)
+    } else if lower.contains("trigger") {
+        Some(Field::Triggers)
+    } else if lower.contains("rationale") {
+        Some(Field::Rationale)
+    } else {
+        None
+    }
+}
+
+/// Strip heading, list, numbering and bold markers from a line
+fn strip_markers(line: &str) -> String {
+    let trimmed = line
+        .trim_start_matches('#')
+        .trim_start()
+        .trim_start_matches(['-', '*', '•'])
+        .trim_start();
+    let without_number = match trimmed.split_once(". ") {
+        Some((prefix, rest)) if !prefix.is_empty() && prefix.chars().all(|c| c.is_ascii_digit()) => rest,
+        _ => trimmed,
+    };
+    without_number.replace("**", "").trim().to_string()
+}
+
+/// Split "Label: value" where the label is short enough to be a field name
+fn split_label(text: &str) -> Option<(String, String)> {
+    let (label, value) = text.split_once(':')?;
+    if label.split_whitespace().count() > 4 {
+        return None;
+    }
+    Some((label.trim().to_string(), value.trim().to_string()))
+}
+
+/// Apply "pass/warning/halt" values from threshold lines to a MetricThreshold
+///
+/// `fraction` metrics (IAS, PCI) accept percentages and convert them to 0.0-1.0.
+fn apply_domain_thresholds(lines: &[String], threshold: &mut MetricThreshold, fraction: bool) -> usize {
+    let mut applied = 0;
+
+    for line in lines {
+        let lower = line.to_lowercase();
+        let Some(mut value) = first_number(&lower) else { continue };
+        if fraction && value > 1.0 {
+            value /= 100.0;
+        }
+
+        if lower.contains("halt") {
+            threshold.halt = Some(value);
+        } else if lower.contains("warning") {
+            threshold.warning = Some(value);
+        } else if lower.contains("pass") || lower.contains("target") || lower.contains("acceptable") {
+            threshold.pass = value;
+        } else {
+            continue;
+        }
+        applied += 1;
+    }
+
+    applied
+}
+
+/// First decimal number in the text (sign and % are ignored)
+fn first_number(text: &str) -> Option<f64> {
+    let start = text.find(|c: char| c.is_ascii_digit())?;
+    let number: String = text[start..]
+        .chars()
+        .take_while(|c| c.is_ascii_digit() || *c == '.')
+        .collect();
+    number.trim_end_matches('.').parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FULL_RESPONSE: &str = r#"# Governance Calibration
+
+## 1. ENTROPY CONTROL
+**Control objective:** Keep expansion proportional to the onboarding scope
+**Threshold settings:**
+- Pass: within ±12%
+- Warning (yellow): ±20%
+- HALT (red): ±30%
+**Intervention triggers:**
+- New sections not traceable to the Charter
+**Rationale:** Scope is moderate and well bounded
+
+## 2. OBJECTIVE CONTROL
+Objective: Stay aligned with Charter objectives
+Thresholds:
+- Target IAS ≥ 0.85
+- Warning below 0.70
+Triggers: Findings that do not map to an objective
+Rationale: Objectives are explicit
+
+## 3. PROCESS CONTROL
+Objective: Enforce step sequencing
+Thresholds:
+- Pass: PCI ≥ 92%
+Triggers:
+- Skipped gate
+Rationale: Standard process profile
+
+## 4. REFLECTIVE CONTROL
+Objective: Reflect at each gate
+Triggers:
+- Missed reflection at Step 3
+Rationale: Architecture Map schedules reflection per gate
+
+## 5. TERMINATION CONTROL
+Objective: Close when deliverables are complete
+Thresholds:
+- CI ≥ 0.80 for closure
+Rationale: Good enough is all metrics passing
+"#;
+
+    #[test]
+    fn test_parse_full_calibration() {
+        let calibration = GovernanceCalibration::parse(FULL_RESPONSE);
+
+        assert_eq!(calibration.entropy.objective, "Keep expansion proportional to the onboarding scope");
+        assert_eq!(calibration.entropy.thresholds.len(), 3);
+        assert_eq!(calibration.entropy.triggers, vec!["New sections not traceable to the Charter"]);
+        assert_eq!(calibration.entropy.rationale, "Scope is moderate and well bounded");
+
+        assert_eq!(calibration.objective.objective, "Stay aligned with Charter objectives");
+        assert_eq!(calibration.objective.triggers, vec!["Findings that do not map to an objective"]);
+        assert_eq!(calibration.process.triggers, vec!["Skipped gate"]);
+        assert_eq!(calibration.reflective.rationale, "Architecture Map schedules reflection per gate");
+        assert_eq!(calibration.termination.thresholds, vec!["CI ≥ 0.80 for closure"]);
+
+        let mut thresholds = ThresholdsConfig::default();
+        let applied = calibration.apply_thresholds(&mut thresholds);
+        assert_eq!(applied, 6);
+        assert_eq!(thresholds.ev.pass, 12.0);
+        assert_eq!(thresholds.ev.halt, Some(30.0));
+        assert_eq!(thresholds.ias.pass, 0.85);
+        assert_eq!(thresholds.ias.warning, Some(0.70));
+        assert!((thresholds.pci.pass - 0.92).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_parse_missing_reflective_section() {
+        let response = FULL_RESPONSE
+            .split("## 4. REFLECTIVE CONTROL")
+            .next()
+            .unwrap()
+            .to_string()
+            + "## 5. TERMINATION CONTROL\nRationale: Close when complete\n";
+
+        let calibration = GovernanceCalibration::parse(&response);
+
+        assert!(calibration.reflective.is_empty());
+        assert!(!calibration.process.is_empty());
+        assert_eq!(calibration.termination.rationale, "Close when complete");
+        assert!(calibration.termination.thresholds.is_empty());
+    }
+}
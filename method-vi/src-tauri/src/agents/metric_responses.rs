@@ -0,0 +1,164 @@
+//! Typed shapes of the Governance & Telemetry metric responses
+//!
+//! Metric calls return JSON that used to be read with ad-hoc indexing
+//! (`parsed["score"].as_f64()`), which fails with the same message whether a
+//! field is missing, misspelled or of the wrong type. Deserializing into these
+//! structs instead reports the path of the offending field.
+
+use anyhow::{anyhow, Result};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+
+/// Deserialize a metric response, naming the field that is missing or mistyped
+pub fn parse_metric_response<T: DeserializeOwned>(metric: &str, value: &serde_json::Value) -> Result<T> {
+    serde_path_to_error::deserialize(value).map_err(|e| {
+        let path = e.path().to_string();
+        if path == "." {
+            anyhow!("Invalid {} response: {}", metric, e.inner())
+        } else {
+            anyhow!("Invalid {} response at '{}': {}", metric, path, e.inner())
+        }
+    })
+}
+
+fn no_assessment() -> String {
+    "No assessment provided".to_string()
+}
+
+fn no_reasoning() -> String {
+    "No reasoning provided".to_string()
+}
+
+fn no_rationale() -> String {
+    "No rationale provided".to_string()
+}
+
+/// A CI dimension score, either a bare number or `{ "score": n, ... }`
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(untagged)]
+pub enum DimensionScore {
+    Value(f64),
+    Scored { score: f64 },
+}
+
+impl DimensionScore {
+    pub fn value(self) -> f64 {
+        match self {
+            DimensionScore::Value(score) | DimensionScore::Scored { score } => score,
+        }
+    }
+}
+
+impl Default for DimensionScore {
+    fn default() -> Self {
+        DimensionScore::Value(0.0)
+    }
+}
+
+/// Coherence Index response (standalone and batched)
+#[derive(Debug, Clone, Deserialize)]
+pub struct CiResponse {
+    #[serde(alias = "score")]
+    pub ci_score: f64,
+    #[serde(default)]
+    pub logical_flow: DimensionScore,
+    #[serde(default)]
+    pub term_consistency: DimensionScore,
+    #[serde(default)]
+    pub sentence_clarity: DimensionScore,
+    #[serde(default)]
+    pub structure_consistency: DimensionScore,
+    #[serde(default = "no_assessment")]
+    pub overall_assessment: String,
+}
+
+/// Intent Alignment Score response (standalone and batched)
+#[derive(Debug, Clone, Deserialize)]
+pub struct IasResponse {
+    pub score: f64,
+    #[serde(default = "no_reasoning")]
+    pub reasoning: String,
+}
+
+/// Claim counts behind an EFI score
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct EfiCounts {
+    /// Absent when no claims were scored (instructional/exploratory content)
+    #[serde(default)]
+    pub efi_score: Option<f64>,
+    #[serde(default)]
+    pub total_claims: u64,
+    #[serde(default)]
+    pub scored_claims: u64,
+    #[serde(default)]
+    pub substantiated_scored: u64,
+}
+
+/// Evidence Fidelity Index response, standalone and batched (counts under `summary`)
+#[derive(Debug, Clone, Deserialize)]
+pub struct EfiResponse {
+    #[serde(default)]
+    pub summary: EfiCounts,
+    #[serde(default = "no_reasoning")]
+    pub reasoning: String,
+}
+
+/// Synthesis relevance check response
+#[derive(Debug, Clone, Deserialize)]
+pub struct RelevanceResponse {
+    pub score: f64,
+    #[serde(default = "no_rationale")]
+    pub rationale: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_valid_metric_responses() {
+        let ci: CiResponse = parse_metric_response("CI", &json!({
+            "ci_score": 0.82,
+            "logical_flow": {"score": 0.9, "issues": []},
+            "term_consistency": 0.7,
+            "overall_assessment": "Clear"
+        }))
+        .unwrap();
+        assert_eq!(ci.ci_score, 0.82);
+        assert_eq!(ci.logical_flow.value(), 0.9);
+        assert_eq!(ci.term_consistency.value(), 0.7);
+        assert_eq!(ci.sentence_clarity.value(), 0.0);
+
+        // Older prompts return "score" rather than "ci_score"
+        let ci: CiResponse = parse_metric_response("CI", &json!({"score": 0.6})).unwrap();
+        assert_eq!(ci.ci_score, 0.6);
+        assert_eq!(ci.overall_assessment, "No assessment provided");
+
+        let efi: EfiResponse = parse_metric_response("EFI", &json!({
+            "summary": {"efi_score": 0.5, "total_claims": 6, "scored_claims": 4, "substantiated_scored": 2},
+            "reasoning": "Half substantiated"
+        }))
+        .unwrap();
+        assert_eq!(efi.summary.efi_score, Some(0.5));
+        assert_eq!(efi.summary.scored_claims, 4);
+    }
+
+    #[test]
+    fn test_invalid_metric_responses_name_the_field() {
+        let err = parse_metric_response::<IasResponse>("IAS", &json!({"reasoning": "Aligned"})).unwrap_err();
+        assert_eq!(err.to_string(), "Invalid IAS response: missing field `score`");
+
+        let err = parse_metric_response::<IasResponse>("IAS", &json!({"score": "high"})).unwrap_err();
+        assert!(err.to_string().starts_with("Invalid IAS response at 'score': invalid type: string \"high\""));
+
+        let err = parse_metric_response::<EfiResponse>("EFI", &json!({"summary": {"total_claims": -1}})).unwrap_err();
+        assert!(err.to_string().starts_with("Invalid EFI response at 'summary.total_claims'"));
+
+        let err = parse_metric_response::<EfiResponse>("EFI", &serde_json::Value::Null).unwrap_err();
+        assert!(err.to_string().starts_with("Invalid EFI response: invalid type: null"));
+
+        let err = parse_metric_response::<CiResponse>("CI", &json!("0.8")).unwrap_err();
+        assert!(err.to_string().starts_with("Invalid CI response: invalid type: string \"0.8\""));
+    }
+}
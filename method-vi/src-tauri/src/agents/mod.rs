@@ -1,14 +1,18 @@
 pub mod analysis_synthesis;
+pub mod governance_calibration;
 pub mod governance_telemetry;
+pub mod metric_responses;
 pub mod orchestrator;
 pub mod scope_pattern;
 pub mod structure_redesign;
 pub mod validation_learning;
 
 pub use analysis_synthesis::{
-    AnalysisSynthesisAgent, GlossaryEntry, LensEfficacyReport, LensResult, ModelGeometry,
+    AnalysisSynthesisAgent, CausalEdge, FeedbackLoop, GlossaryEntry, LensEfficacyReport, LensOutputFormat, LensResult, ModelGeometry,
+    Step4Options,
     Step4SynthesisResult, TermConflict,
 };
+pub use governance_calibration::{DomainCalibration, GovernanceCalibration};
 pub use governance_telemetry::{
     CriticalMetrics, EBaseline, GovernanceTelemetryAgent, MetricInput, MetricInputValue,
     MetricResult, MetricStatus, MetricThreshold,
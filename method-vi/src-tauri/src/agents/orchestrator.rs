@@ -1,18 +1,94 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
 use chrono::Utc;
 use log::{debug, info, warn};
 use serde::{Deserialize, Serialize};
 
 use crate::agents::analysis_synthesis::{AnalysisSynthesisAgent, GlossaryEntry, TermConflict};
-use crate::agents::governance_telemetry::{CriticalMetrics, GovernanceTelemetryAgent, IASWarning};
+use crate::agents::governance_calibration::GovernanceCalibration;
+use crate::agents::governance_telemetry::{CriticalMetrics, GovernanceTelemetryAgent, IASWarning, ScopeExpansionCount};
 use crate::agents::scope_pattern::{IntentSummary, ScopePatternAgent, UserDefinedTerm};
 use crate::agents::structure_redesign::StructureRedesignAgent;
 use crate::agents::validation_learning::ValidationLearningAgent;
+use crate::api::ApiUsage;
+use crate::artifacts::GovernanceRole;
+use crate::config::{StepDescriptions, StepRationale};
 use crate::context::{ContextManager, Mode, Role, RunContext, Signal as ContextSignal};
-use crate::governance::{Callout, CalloutManager, ModeDetector, Step, StructureMode};
+use crate::governance::{Callout, CalloutManager, CalloutTier, ModeDetector, Step, StructureMode};
 use crate::ledger::{EntryType, LedgerManager, LedgerPayload, LedgerState};
 use crate::signals::{SignalPayload, SignalRouter, SignalType};
 
+/// Approver identity recorded for gates approved by `with_auto_approval`
+pub const AUTO_APPROVER: &str = "AutoApprover";
+
+/// Intent drift (1 - alignment with the Step 0 primary goal) above which a callout is raised
+pub const DEFAULT_INTENT_DRIFT_THRESHOLD: f64 = 0.4;
+
+/// Analysis targets shorter than this (in characters) need `force` at Step 3
+pub const DEFAULT_MIN_ANALYSIS_CHARS: usize = 200;
+
+/// Step 3 refused to analyze content below the minimum length without `force`
+///
+/// Nothing was analyzed and the run is still at Step 3, so a caller that gets
+/// this can ask the user to confirm and run the step again with `force`.
+#[derive(Debug, Clone)]
+pub struct AnalysisTargetTooShort {
+    pub chars: usize,
+    pub min_chars: usize,
+}
+
+impl std::fmt::Display for AnalysisTargetTooShort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Analysis target is only {} chars (minimum {}) - confirm with force to analyze it anyway",
+            self.chars, self.min_chars
+        )
+    }
+}
+
+impl std::error::Error for AnalysisTargetTooShort {}
+
+/// A step's input artifacts are absent, e.g. a resumed run whose state was
+/// restored without their content
+///
+/// Raised by the prerequisite check before the step starts, so the run keeps
+/// its state; `artifacts` names what has to be restored or regenerated.
+#[derive(Debug, Clone)]
+pub struct MissingArtifact {
+    pub step: u8,
+    /// Display names of the missing artifacts
+    pub artifacts: Vec<&'static str>,
+}
+
+impl std::fmt::Display for MissingArtifact {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Cannot execute Step {} - missing prerequisite artifacts: {}",
+            self.step,
+            self.artifacts.join(", ")
+        )
+    }
+}
+
+impl std::error::Error for MissingArtifact {}
+
+/// Entropy locked as E_baseline in stub mode
+const STUB_E_BASELINE: f64 = 0.5;
+
+/// Timestamp given to stub artifacts in seeded runs, so reruns match exactly
+const STUB_SEEDED_TIMESTAMP: &str = "2025-01-01T00:00:00Z";
+
+/// What Step 2 does when E_baseline was calculated but never locked
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum UnlockedBaselinePolicy {
+    /// Lock it before calibrating and record the late lock in the ledger
+    #[default]
+    Lock,
+    /// Refuse to calibrate against a baseline that is not frozen
+    Reject,
+}
+
 /// Run state for tracking Method-VI session progress
 #[derive(Debug, Clone)]
 pub enum RunState {
@@ -86,7 +162,20 @@ pub enum RunState {
     },
 
     /// Run permanently halted (aborted by user or unrecoverable error)
-    Halted { reason: String },
+    Halted { reason: String, cause: HaltCause },
+}
+
+/// Why a run ended up `Halted`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HaltCause {
+    /// A human rejected a gate
+    GateRejected,
+    /// A human ended a metrics pause at `step` (abort or return) instead of
+    /// proceeding; `override_halt` can reopen the step
+    MetricThreshold { step: u8 },
+    /// The run's circuit breaker opened after repeated Claude API failures at
+    /// `step`; `rollback_to_last_gate` retries the step once the API recovers
+    ApiUnavailable { step: u8 },
 }
 
 impl RunState {
@@ -153,6 +242,15 @@ pub struct Orchestrator {
     /// Signal router for workflow signals
     signal_router: SignalRouter,
 
+    /// Database each step's artifacts are written to (see `with_artifact_persistence`)
+    artifact_db_path: Option<std::path::PathBuf>,
+
+    /// Artifact types locked once created (see `with_immutable_types`)
+    immutable_types: crate::artifacts::ImmutableArtifactTypes,
+
+    /// Step names and ledger rationales (see `with_step_descriptions`)
+    step_descriptions: StepDescriptions,
+
     /// Captured intent summary from Step 0
     pub intent_summary: Option<IntentSummary>,
 
@@ -164,6 +262,7 @@ pub struct Orchestrator {
 
     /// Step 2 artifacts (governance calibration)
     pub governance_summary: Option<String>,
+    pub governance_calibration: Option<GovernanceCalibration>,
     pub domain_snapshots: Option<String>,
 
     /// Step 3 artifacts (six-lens analysis)
@@ -174,6 +273,8 @@ pub struct Orchestrator {
     pub core_thesis: Option<String>,
     pub operating_principles: Option<String>,
     pub model_geometry: Option<String>,
+    /// Text diagram of the model structure, also embedded in `model_geometry`
+    pub geometry_diagram: Option<String>,
     pub causal_spine: Option<String>,
     pub north_star_narrative: Option<String>,
     pub glossary: Option<String>,
@@ -204,9 +305,48 @@ pub struct Orchestrator {
     /// Validation & Learning Agent (optional - if None, Step 6 unavailable)
     validation_agent: Option<ValidationLearningAgent>,
 
+    /// Stub mode - absent agents are replaced by deterministic stubs (see `with_stubs`)
+    stub_mode: bool,
+
+    /// E_baseline locked in stub mode (normally held by the Governance Agent)
+    stub_e_baseline: Option<f64>,
+
+    /// Seed for the stubs' canned variation (see `new_with_seed`)
+    stub_seed: Option<u64>,
+
+    /// How Step 2 treats an E_baseline that is not locked
+    unlocked_baseline_policy: UnlockedBaselinePolicy,
+
+    /// Apply thresholds from the Step 2 calibration to the Governance Agent
+    apply_calibrated_thresholds: bool,
+
+    /// Gates approved automatically after their step completes (see `with_auto_approval`)
+    auto_approve_gates: bool,
+
+    /// Steps whose gates may be auto-approved (None = all gates)
+    auto_approve_steps: Option<Vec<u8>>,
+
+    /// Intent drift above which `check_intent_drift` raises a callout
+    intent_drift_threshold: f64,
+
+    /// Analysis target length below which Step 3 needs `force`
+    min_analysis_chars: usize,
+
+    /// Settings frozen at run start; agents for later steps are built from these
+    pub run_config: Option<crate::config::RunConfig>,
+
+    /// Run this one was forked from (see `fork`)
+    pub forked_from: Option<String>,
+
+    /// E_baseline carried over by `fork`, restored into the next Governance Agent
+    inherited_e_baseline: Option<crate::agents::governance_telemetry::EBaseline>,
+
     /// Latest calculated metrics from Governance Agent
     pub latest_metrics: Option<CriticalMetrics>,
 
+    /// Metrics recorded at each step (step number, metrics), one entry per step
+    pub metrics_history: Vec<(u8, CriticalMetrics)>,
+
     /// Pending IAS Warning requiring acknowledgment (FIX-024)
     ///
     /// When IAS is in warning range (0.30-0.69), this field holds the warning
@@ -242,6 +382,18 @@ pub struct Orchestrator {
 }
 
 impl Orchestrator {
+    /// Create an Orchestrator whose stubs vary reproducibly with `seed`
+    ///
+    /// Stubbed choices (intent category, audience, confidence, model geometry)
+    /// are drawn from the seed and stub timestamps are fixed, so two runs with
+    /// the same label and seed produce identical stub artifacts. Unseeded runs
+    /// keep the first option of each choice.
+    pub fn new_with_seed(label: &str, seed: u64) -> Self {
+        let mut orchestrator = Self::new(label);
+        orchestrator.stub_seed = Some(seed);
+        orchestrator
+    }
+
     /// Set the Scope & Pattern Agent for this orchestrator
     ///
     /// This allows the orchestrator to use the real agent instead of the stub.
@@ -253,7 +405,18 @@ impl Orchestrator {
     /// Set the Governance & Telemetry Agent for this orchestrator
     ///
     /// This enables automatic metrics calculation at step completion.
-    pub fn with_governance_agent(mut self, agent: GovernanceTelemetryAgent) -> Self {
+    pub fn with_governance_agent(mut self, mut agent: GovernanceTelemetryAgent) -> Self {
+        // A forked run keeps the source's frozen baseline and calibrated thresholds
+        if let Some(baseline) = self.inherited_e_baseline.clone() {
+            if agent.get_e_baseline().is_none() {
+                agent.restore_e_baseline(baseline);
+            }
+            if self.apply_calibrated_thresholds {
+                if let Some(ref calibration) = self.governance_calibration {
+                    agent.apply_calibration_thresholds(calibration);
+                }
+            }
+        }
         self.governance_agent = Some(agent);
         self
     }
@@ -282,6 +445,105 @@ impl Orchestrator {
         self
     }
 
+    /// Run in stub mode for offline testing and demos
+    ///
+    /// Any agent that is not set is replaced by a deterministic stub producing
+    /// canned artifacts and metrics, so Steps 0-6 can run without an API key.
+    /// Agents set via the other `with_*` builders are still used.
+    pub fn with_stubs(mut self) -> Self {
+        info!("Stub mode enabled - absent agents will be stubbed");
+        self.stub_mode = true;
+        self
+    }
+
+    /// Let the Step 2 governance calibration override metric thresholds
+    ///
+    /// EV, IAS and PCI thresholds found in the calibration response replace the
+    /// Governance Agent's defaults for the rest of the run.
+    pub fn with_calibrated_thresholds(mut self) -> Self {
+        self.apply_calibrated_thresholds = true;
+        self
+    }
+
+    /// Auto-approve gates for non-interactive (batch) runs
+    ///
+    /// `steps` limits auto-approval to the gates of those steps; None approves
+    /// every gate. Unacknowledged Critical callouts and hard blocks still stop
+    /// auto-approval.
+    pub fn with_auto_approval(mut self, steps: Option<Vec<u8>>) -> Self {
+        info!("Gate auto-approval enabled for steps: {:?}", steps);
+        self.auto_approve_gates = true;
+        self.auto_approve_steps = steps;
+        self
+    }
+
+    /// Choose whether Step 2 locks or rejects an E_baseline that is not locked
+    pub fn with_unlocked_baseline_policy(mut self, policy: UnlockedBaselinePolicy) -> Self {
+        self.unlocked_baseline_policy = policy;
+        self
+    }
+
+    /// Override the intent drift threshold used by `check_intent_drift`
+    pub fn with_intent_drift_threshold(mut self, threshold: f64) -> Self {
+        self.intent_drift_threshold = threshold;
+        self
+    }
+
+    /// Override the analysis target length below which Step 3 needs `force`
+    pub fn with_min_analysis_chars(mut self, min_chars: usize) -> Self {
+        self.min_analysis_chars = min_chars;
+        self
+    }
+
+    /// Freeze the run's settings, applying its drift threshold, analysis minimum,
+    /// metric strictness and calibrated thresholds
+    ///
+    /// Stored with the run row when artifact persistence is configured.
+    pub fn with_run_config(mut self, run_config: crate::config::RunConfig) -> Self {
+        if let Some(threshold) = run_config.intent_drift_threshold {
+            self.intent_drift_threshold = threshold;
+        }
+        self.min_analysis_chars = run_config.min_analysis_chars;
+        self.callout_manager.set_strict(run_config.strict_metrics);
+        if run_config.calibrated_thresholds {
+            self.apply_calibrated_thresholds = true;
+        }
+        self.run_config = Some(run_config);
+        self
+    }
+
+    /// Emit and persist signals under external aliases (see `SignalNaming`)
+    pub fn with_signal_naming(mut self, naming: crate::signals::SignalNaming) -> Self {
+        self.signal_router = self.signal_router.with_naming(naming);
+        self
+    }
+
+    /// Persist emitted signals to the given database so the timeline survives restart
+    pub fn with_signal_persistence(mut self, db_path: std::path::PathBuf) -> Self {
+        self.signal_router = self.signal_router.with_persistence(db_path);
+        self
+    }
+
+    /// Override which artifact types are immutable (Critical Path types always are)
+    pub fn with_immutable_types(mut self, immutable_types: crate::artifacts::ImmutableArtifactTypes) -> Self {
+        self.immutable_types = immutable_types;
+        self
+    }
+
+    /// Override step names and the rationales written to the ledger
+    pub fn with_step_descriptions(mut self, step_descriptions: StepDescriptions) -> Self {
+        self.step_descriptions = step_descriptions;
+        self
+    }
+
+    /// Persist each step's artifacts to the given database when the step completes
+    ///
+    /// Artifact content is written next to the database under `artifacts/{run_id}/`.
+    pub fn with_artifact_persistence(mut self, db_path: std::path::PathBuf) -> Self {
+        self.artifact_db_path = Some(db_path);
+        self
+    }
+
     /// Set user's posture selection (Build/Audit) from Step 0
     ///
     /// This is combined with CI baseline to determine Transformation mode eligibility.
@@ -310,6 +572,450 @@ impl Orchestrator {
         debug!("Posture selection recorded to ledger");
     }
 
+    /// Record an intentional scope change in the ledger
+    ///
+    /// Written as a `scope_expansion` decision carrying the approval flag. SEC
+    /// counts these entries: approved expansions keep it at 100%, unapproved
+    /// ones pull it down from the next metric calculation on.
+    pub fn record_scope_expansion(&mut self, description: &str, approved: bool) -> Result<ScopeExpansionCount> {
+        let description = description.trim();
+        if description.is_empty() {
+            anyhow::bail!("Scope expansion description cannot be empty");
+        }
+
+        let payload = LedgerPayload {
+            action: "scope_expansion".to_string(),
+            inputs: Some(serde_json::json!({
+                "description": description,
+                "approved": approved,
+            })),
+            outputs: None,
+            rationale: Some(if approved {
+                "Scope expansion approved by user".to_string()
+            } else {
+                "Scope expansion recorded without approval".to_string()
+            }),
+        };
+
+        self.ledger.create_entry(
+            &self.run_id,
+            EntryType::Decision,
+            Some(self.state.step_number() as i32),
+            Some("User"),
+            payload,
+        );
+
+        let count = self.scope_expansions();
+        if let Some(agent) = self.governance_agent.as_mut() {
+            agent.set_scope_expansions(count);
+        }
+        info!(
+            "Scope expansion recorded ({}): {} approved, {} unapproved",
+            if approved { "approved" } else { "unapproved" },
+            count.approved,
+            count.unapproved
+        );
+        Ok(count)
+    }
+
+    /// Scope expansions recorded in this run's ledger
+    pub fn scope_expansions(&self) -> ScopeExpansionCount {
+        let mut count = ScopeExpansionCount::default();
+        for entry in self.ledger.get_entries(&self.run_id) {
+            if entry.payload.action != "scope_expansion" {
+                continue;
+            }
+            let approved = entry
+                .payload
+                .inputs
+                .as_ref()
+                .and_then(|inputs| inputs["approved"].as_bool())
+                .unwrap_or(false);
+            if approved {
+                count.approved += 1;
+            } else {
+                count.unapproved += 1;
+            }
+        }
+        count
+    }
+
+    /// Propose a revision of the Charter
+    ///
+    /// The Charter is never edited in place: the frozen baseline and every later
+    /// artifact were derived from its hash. The proposal is recorded in the
+    /// ledger and carries the seed for a follow-on run that starts from the
+    /// revised Charter; this run is left unchanged.
+    pub fn propose_charter_revision(&mut self, new_content: &str) -> Result<CharterRevisionProposal> {
+        let charter = self
+            .charter
+            .as_ref()
+            .context("No Charter to revise - it is created at Step 1")?;
+        if new_content.trim().is_empty() {
+            anyhow::bail!("Revised Charter content cannot be empty");
+        }
+
+        let proposed_hash = crate::hash::sha256_hex(new_content);
+        if proposed_hash == charter.hash {
+            anyhow::bail!("Revised Charter is identical to the current Charter");
+        }
+
+        let refusal_reason = match self.get_e_baseline() {
+            Some(e_baseline) => format!(
+                "The Charter is immutable once the baseline is frozen: E_baseline ({:.2}) and every later \
+                 artifact were derived from Charter {}. Editing it in place would break their lineage, so \
+                 the revision has to start a follow-on run forked from this one.",
+                e_baseline,
+                &charter.hash[..charter.hash.len().min(12)]
+            ),
+            None => format!(
+                "The Charter is immutable: artifacts in this run reference Charter {}. The revision has \
+                 to start a follow-on run forked from this one.",
+                &charter.hash[..charter.hash.len().min(12)]
+            ),
+        };
+
+        let proposal = CharterRevisionProposal {
+            source_run_id: self.run_id.clone(),
+            current_charter_hash: charter.hash.clone(),
+            proposed_charter_hash: proposed_hash,
+            refusal_reason,
+            fork_seed: ForkSeed {
+                forked_from: self.run_id.clone(),
+                user_intent: self
+                    .intent_summary
+                    .as_ref()
+                    .map(|summary| summary.user_request.clone())
+                    .unwrap_or_default(),
+                charter_content: new_content.to_string(),
+            },
+        };
+
+        let payload = LedgerPayload {
+            action: "charter_revision_proposed".to_string(),
+            inputs: Some(serde_json::json!({
+                "current_charter_hash": proposal.current_charter_hash,
+                "proposed_charter_hash": proposal.proposed_charter_hash,
+            })),
+            outputs: Some(serde_json::json!({
+                "resolution": "fork",
+            })),
+            rationale: Some(proposal.refusal_reason.clone()),
+        };
+        self.ledger.create_entry(
+            &self.run_id,
+            EntryType::Decision,
+            Some(self.state.step_number() as i32),
+            Some("User"),
+            payload,
+        );
+
+        info!(
+            "Charter revision proposed for run {} - in-place edit refused, fork required",
+            self.run_id
+        );
+        Ok(proposal)
+    }
+
+    /// Start a new run from this one's results up to and including `up_to_step`
+    ///
+    /// Artifacts, metrics and ledger entries of Steps 0..=`up_to_step` are
+    /// copied under the new run_id: the frontmatter ids, dependencies and links
+    /// are rewritten for the new run (bodies are copied unchanged) and re-hashed,
+    /// `parent_hash` links follow the new hashes, and each artifact records the
+    /// one it was `forked_from`. The Charter, held as parsed data, is re-hashed
+    /// over its display markdown. The ledger is replayed up to the approval of
+    /// the Step `up_to_step` gate. The new run waits at Step `up_to_step + 1`;
+    /// agents are not copied and must be attached again.
+    ///
+    /// Halted or paused runs cannot be forked, and the ledger must record the
+    /// approval of the Step `up_to_step` gate.
+    pub fn fork(&self, label: &str, up_to_step: u8) -> Result<Orchestrator> {
+        if up_to_step > 5 {
+            anyhow::bail!("Runs can be forked after Steps 0-5, not Step {}", up_to_step);
+        }
+        if matches!(
+            self.state,
+            RunState::Halted { .. } | RunState::Paused { .. } | RunState::IASResynthesisPause { .. }
+        ) {
+            anyhow::bail!("Cannot fork run {} while it is halted or paused ({:?})", self.run_id, self.state);
+        }
+        let gate_approved = self.ledger.get_entries(&self.run_id).iter().any(|entry| {
+            matches!(entry.payload.action.as_str(), "gate_approved" | "gate_auto_approved")
+                && entry.step == Some(up_to_step as i32)
+        });
+        let completed = matches!(self.state, RunState::Completed) || self.state.step_number() > up_to_step;
+        if !completed || !gate_approved {
+            anyhow::bail!(
+                "Run {} has not passed the Step {} gate (currently {:?})",
+                self.run_id,
+                up_to_step,
+                self.state
+            );
+        }
+
+        let mut fork = Orchestrator::new(label);
+        if fork.run_id == self.run_id {
+            anyhow::bail!("Fork label must differ from the source run's");
+        }
+        let source_id = self.run_id.clone();
+        let new_id = fork.run_id.clone();
+        info!("Forking run {} after Step {} as {}", source_id, up_to_step, new_id);
+
+        fork.forked_from = Some(source_id.clone());
+        fork.state = match up_to_step {
+            0 => RunState::Step1Active,
+            1 => RunState::Step2Active,
+            2 => RunState::Step3Active,
+            3 => RunState::Step4Active,
+            4 => RunState::Step5Active,
+            _ => RunState::Step6Active,
+        };
+        // The Conductor runs Steps 1-2; from Step 3 on the Observer holds the run
+        fork.active_role = if up_to_step < 2 { Role::Conductor } else { Role::Observer };
+        fork.immutable_types = self.immutable_types.clone();
+        fork.step_descriptions = self.step_descriptions.clone();
+        fork.stub_mode = self.stub_mode;
+        fork.stub_seed = self.stub_seed;
+        fork.unlocked_baseline_policy = self.unlocked_baseline_policy;
+        fork.apply_calibrated_thresholds = self.apply_calibrated_thresholds;
+        fork.auto_approve_gates = self.auto_approve_gates;
+        fork.auto_approve_steps = self.auto_approve_steps.clone();
+        fork.intent_drift_threshold = self.intent_drift_threshold;
+        fork.min_analysis_chars = self.min_analysis_chars;
+        // The fork gets its own circuit breaker
+        fork.run_config = self.run_config.clone().map(|config| crate::config::RunConfig {
+            circuit_breaker: Default::default(),
+            ..config
+        });
+        fork.callout_manager.set_strict(self.callout_manager.is_strict());
+
+        // Old hash -> new hash, so parent_hash links can follow the copies
+        let mut rehashed = std::collections::HashMap::new();
+        let charter = self.charter.clone().filter(|_| up_to_step >= 1).map(|mut charter| {
+            let new_hash = crate::artifacts::calculate_content_hash(&charter.to_display_markdown());
+            rehashed.insert(std::mem::replace(&mut charter.hash, new_hash.clone()), new_hash);
+            charter
+        });
+        let mut copy = |artifact: &Option<String>| {
+            artifact
+                .as_ref()
+                .map(|markdown| Self::fork_artifact(markdown, &source_id, &new_id, &mut rehashed))
+        };
+
+        // Step 0
+        fork.intent_summary = self.intent_summary.clone().map(|mut summary| {
+            summary.run_id = new_id.clone();
+            summary.artifact_id = summary.artifact_id.replace(&source_id, &new_id);
+            summary
+        });
+        fork.user_posture = self.user_posture;
+        fork.raw_input_ci = self.raw_input_ci;
+
+        if up_to_step >= 1 {
+            fork.intent_anchor = copy(&self.intent_anchor);
+            fork.charter = charter;
+            fork.baseline_report = copy(&self.baseline_report);
+            fork.architecture_map = copy(&self.architecture_map);
+            fork.stub_e_baseline = self.stub_e_baseline;
+            fork.inherited_e_baseline = self
+                .governance_agent
+                .as_ref()
+                .and_then(|agent| agent.e_baseline_record().cloned());
+        }
+        if up_to_step >= 2 {
+            fork.governance_summary = copy(&self.governance_summary);
+            fork.governance_calibration = self.governance_calibration.clone();
+            fork.domain_snapshots = copy(&self.domain_snapshots);
+            fork.detected_mode = self.detected_mode;
+            fork.mode_detection_result = self.mode_detection_result.clone();
+            fork.mode_locked = self.mode_locked;
+        }
+        if up_to_step >= 3 {
+            fork.integrated_diagnostic = copy(&self.integrated_diagnostic);
+            fork.lens_efficacy_report = copy(&self.lens_efficacy_report);
+            fork.diagnostic_ci_baseline = self.diagnostic_ci_baseline;
+        }
+        if up_to_step >= 4 {
+            fork.core_thesis = copy(&self.core_thesis);
+            fork.operating_principles = copy(&self.operating_principles);
+            fork.model_geometry = copy(&self.model_geometry);
+            fork.geometry_diagram = self.geometry_diagram.clone();
+            fork.causal_spine = copy(&self.causal_spine);
+            fork.north_star_narrative = copy(&self.north_star_narrative);
+            fork.glossary = copy(&self.glossary);
+            fork.limitations = copy(&self.limitations);
+        }
+        if up_to_step >= 5 {
+            fork.framework_architecture = copy(&self.framework_architecture);
+        }
+
+        // Copies are made in step order, so parents are re-hashed before their children
+        for markdown in [
+            &mut fork.intent_anchor,
+            &mut fork.baseline_report,
+            &mut fork.architecture_map,
+            &mut fork.governance_summary,
+            &mut fork.domain_snapshots,
+            &mut fork.integrated_diagnostic,
+            &mut fork.lens_efficacy_report,
+            &mut fork.core_thesis,
+            &mut fork.operating_principles,
+            &mut fork.model_geometry,
+            &mut fork.causal_spine,
+            &mut fork.north_star_narrative,
+            &mut fork.glossary,
+            &mut fork.limitations,
+            &mut fork.framework_architecture,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            *markdown = Self::relink_hashes(markdown, &rehashed);
+        }
+
+        fork.metrics_history = self
+            .metrics_history
+            .iter()
+            .filter(|(step, _)| *step <= up_to_step)
+            .cloned()
+            .collect();
+        fork.latest_metrics = fork.metrics_history.last().map(|(_, metrics)| metrics.clone());
+
+        // Replay the ledger up to the fork point; the new chain hashes under the new run_id
+        let entries = self.ledger.get_entries(&source_id);
+        let fork_point = entries
+            .iter()
+            .rposition(|entry| {
+                entry.step == Some(up_to_step as i32)
+                    && matches!(entry.payload.action.as_str(), "gate_approved" | "gate_auto_approved")
+            })
+            .map_or(entries.len(), |index| index + 1);
+        for entry in entries.into_iter().take(fork_point) {
+            if entry.step.is_some_and(|step| step > up_to_step as i32) {
+                continue;
+            }
+            fork.ledger.create_entry(&new_id, entry.entry_type, entry.step, entry.role.as_deref(), entry.payload);
+        }
+        let payload = LedgerPayload {
+            action: "run_forked".to_string(),
+            inputs: Some(serde_json::json!({
+                "forked_from": source_id,
+                "up_to_step": up_to_step,
+            })),
+            outputs: Some(serde_json::json!({
+                "artifacts_copied": rehashed.len(),
+            })),
+            rationale: Some(format!(
+                "Forked from {} after Step {}; later steps will be re-executed",
+                source_id, up_to_step
+            )),
+        };
+        fork.ledger.create_entry(&new_id, EntryType::Decision, Some(up_to_step as i32), Some("User"), payload);
+
+        if let Some(ref db_path) = self.artifact_db_path {
+            fork = fork
+                .with_signal_persistence(db_path.clone())
+                .with_artifact_persistence(db_path.clone());
+            for step in 1..=up_to_step {
+                fork.persist_step_artifacts(step);
+            }
+        }
+
+        info!("✓ Forked {} as {} ({} artifacts copied)", source_id, new_id, rehashed.len());
+        Ok(fork)
+    }
+
+    /// Copy an artifact into another run: new ids, new content hash, `forked_from` provenance
+    fn fork_artifact(
+        markdown: &str,
+        source_run_id: &str,
+        new_run_id: &str,
+        rehashed: &mut std::collections::HashMap<String, String>,
+    ) -> String {
+        let source_artifact_id = Self::frontmatter_value(markdown, "artifact_id");
+        let text = Self::rewrite_frontmatter(markdown, |line, field| {
+            let names_run = ["artifact_id:", "run_id:"].iter().any(|key| field.starts_with(key))
+                || field.split(':').next().is_some_and(|key| key.ends_with("_link"));
+            if names_run {
+                line.replace(source_run_id, new_run_id)
+            } else {
+                line.to_string()
+            }
+        });
+        let Some(old_hash) = Self::frontmatter_value(markdown, "hash") else {
+            return text;
+        };
+
+        let new_hash = crate::artifacts::calculate_content_hash(Self::artifact_body(&text));
+        let provenance = source_artifact_id
+            .map(|id| format!("\nforked_from: \"{}\"", id))
+            .unwrap_or_default();
+        let mut lines: Vec<String> = Vec::new();
+        let mut replaced = false;
+        for line in text.lines() {
+            if !replaced && line.starts_with("hash:") {
+                lines.push(format!("hash: \"{}\"{}", new_hash, provenance));
+                replaced = true;
+            } else {
+                lines.push(line.to_string());
+            }
+        }
+        let mut forked = lines.join("\n");
+        if text.ends_with('\n') {
+            forked.push('\n');
+        }
+
+        rehashed.insert(old_hash, new_hash);
+        forked
+    }
+
+    /// The exact body an artifact's hash covers: everything after the frontmatter's blank line
+    fn artifact_body(markdown: &str) -> &str {
+        let body = crate::content::strip_frontmatter(markdown);
+        body.strip_prefix("\r\n").or_else(|| body.strip_prefix('\n')).unwrap_or(body)
+    }
+
+    /// Point a forked artifact's `parent_hash` and dependencies at re-hashed artifacts
+    fn relink_hashes(markdown: &str, rehashed: &std::collections::HashMap<String, String>) -> String {
+        Self::rewrite_frontmatter(markdown, |line, field| {
+            if !(field.starts_with("parent_hash:") || field.starts_with("artifact_id:")) {
+                return line.to_string();
+            }
+            rehashed
+                .iter()
+                .filter(|(old, new)| old != new)
+                .fold(line.to_string(), |line, (old, new)| {
+                    line.replace(&format!("\"{}\"", old), &format!("\"{}\"", new))
+                })
+        })
+    }
+
+    /// Rewrite an artifact's frontmatter line by line, leaving the body untouched
+    ///
+    /// `rewrite` gets each line and its field (`key: value`, with any list or
+    /// flow-mapping prefix such as `- { ` removed).
+    fn rewrite_frontmatter(markdown: &str, mut rewrite: impl FnMut(&str, &str) -> String) -> String {
+        let body = crate::content::strip_frontmatter(markdown);
+        let frontmatter = &markdown[..markdown.len() - body.len()];
+        let mut text: String = frontmatter
+            .split_inclusive('\n')
+            .map(|line| rewrite(line, line.trim_start().trim_start_matches("- ").trim_start_matches("{ ")))
+            .collect();
+        text.push_str(body);
+        text
+    }
+
+    /// Unquoted value of a top-level frontmatter line such as `hash: "..."`
+    fn frontmatter_value(markdown: &str, key: &str) -> Option<String> {
+        let prefix = format!("{}:", key);
+        markdown
+            .lines()
+            .find(|line| line.starts_with(&prefix))
+            .map(|line| line[prefix.len()..].trim().trim_matches('"').to_string())
+            .filter(|value| !value.is_empty())
+    }
+
     /// Create a new Orchestrator for a run
     ///
     /// # Arguments
@@ -327,18 +1033,23 @@ impl Orchestrator {
             mode: Mode::Standard,        // Always Standard for MVP
             ledger: LedgerManager::new(),
             signal_router: SignalRouter::new(),
+            artifact_db_path: None,       // Will be set via with_artifact_persistence()
+            immutable_types: crate::artifacts::ImmutableArtifactTypes::default(),
+            step_descriptions: StepDescriptions::default(),
             intent_summary: None,
             intent_anchor: None,
             charter: None,
             baseline_report: None,
             architecture_map: None,
             governance_summary: None,
+            governance_calibration: None,
             domain_snapshots: None,
             integrated_diagnostic: None,
             lens_efficacy_report: None,
             core_thesis: None,
             operating_principles: None,
             model_geometry: None,
+            geometry_diagram: None,
             causal_spine: None,
             north_star_narrative: None,
             glossary: None,
@@ -354,7 +1065,20 @@ impl Orchestrator {
             structure_agent: None,        // Will be set via with_structure_agent()
             analysis_synthesis_agent: None, // Will be set via with_analysis_synthesis_agent()
             validation_agent: None,       // Will be set via with_validation_agent()
+            stub_mode: false,             // Will be set via with_stubs()
+            stub_e_baseline: None,
+            stub_seed: None,              // Will be set via new_with_seed()
+            unlocked_baseline_policy: UnlockedBaselinePolicy::default(),
+            apply_calibrated_thresholds: false, // Will be set via with_calibrated_thresholds()
+            auto_approve_gates: false,    // Will be set via with_auto_approval()
+            auto_approve_steps: None,
+            intent_drift_threshold: DEFAULT_INTENT_DRIFT_THRESHOLD,
+            min_analysis_chars: DEFAULT_MIN_ANALYSIS_CHARS,
+            run_config: None,             // Will be set via with_run_config()
+            forked_from: None,            // Will be set via fork()
+            inherited_e_baseline: None,
             latest_metrics: None,
+            metrics_history: Vec::new(),
             pending_ias_acknowledgment: None, // FIX-024: IAS soft gate acknowledgment
             detected_mode: None,               // Session 2.2: Set at Step 2 after CI baseline
             mode_detection_result: None,       // Session 2.2: Full detection metadata
@@ -434,6 +1158,8 @@ impl Orchestrator {
             inputs: Some(serde_json::json!({
                 "run_id": self.run_id,
                 "user_intent": user_intent,
+                "strict_metrics": self.callout_manager.is_strict(),
+                "allow_halt_override": self.run_config.as_ref().is_some_and(|config| config.allow_halt_override),
             })),
             outputs: None,
             rationale: Some("Initializing Method-VI run".to_string()),
@@ -487,7 +1213,7 @@ impl Orchestrator {
                 "confidence_score": intent_summary.confidence_score,
                 "intent_category": intent_summary.intent_category,
             })),
-            rationale: Some("Scope & Pattern Agent completed intent analysis".to_string()),
+            rationale: Some(self.step_descriptions.rationale(0, StepRationale::Completed)),
         };
 
         self.ledger.create_entry(
@@ -527,7 +1253,7 @@ impl Orchestrator {
             outputs: Some(serde_json::json!({
                 "signal_hash": signal.hash,
             })),
-            rationale: Some("Step 0 complete, awaiting human approval to proceed".to_string()),
+            rationale: Some(self.step_descriptions.rationale(0, StepRationale::GateRequested)),
         };
 
         self.ledger.create_entry(
@@ -553,9 +1279,56 @@ impl Orchestrator {
     ///
     /// # Returns
     /// True if gate was approved and state transitioned
+    ///
+    /// A role change that breaks the Observer/Conductor state machine is recorded
+    /// to the ledger (see `Role::valid_transition`) without blocking the gate.
     pub fn approve_gate(&mut self, approver: &str) -> Result<bool> {
         info!("Gate approval requested by: {}", approver);
+        require_named("approver", approver)?;
+
+        let previous_role = self.active_role.clone();
+        let approved = self.pass_gate(approver)?;
+        if approved {
+            self.audit_role_transition(&previous_role);
+        }
+        Ok(approved)
+    }
+
+    /// Record a ledger entry if the gate just passed changed roles unexpectedly
+    fn audit_role_transition(&mut self, previous_role: &Role) {
+        let step = match self.state {
+            RunState::Completed => return,
+            ref state => state.step_number(),
+        };
+        if Role::valid_transition(previous_role, &self.active_role, step) {
+            return;
+        }
+
+        warn!(
+            "Unexpected role transition entering Step {}: {:?} → {:?}",
+            step, previous_role, self.active_role
+        );
+        let payload = LedgerPayload {
+            action: "role_transition_invalid".to_string(),
+            inputs: Some(serde_json::json!({
+                "from": previous_role,
+                "to": self.active_role,
+                "step": step,
+            })),
+            outputs: None,
+            rationale: Some("Role transition does not follow the Observer/Conductor state machine".to_string()),
+        };
+        self.ledger.create_entry(
+            &self.run_id,
+            EntryType::Signal,
+            Some(step as i32),
+            Some(ContextManager::get_role_abbreviation(&self.active_role).as_str()),
+            payload,
+        );
+    }
 
+    /// Apply the pending gate's transition (body of `approve_gate`)
+    fn pass_gate(&mut self, approver: &str) -> Result<bool> {
         match &self.state {
             RunState::Step0GatePending => {
                 // Record gate approval in ledger
@@ -566,7 +1339,7 @@ impl Orchestrator {
                         "approver": approver,
                     })),
                     outputs: None,
-                    rationale: Some("Human approved progression to Step 1".to_string()),
+                    rationale: Some(self.step_descriptions.rationale(0, StepRationale::GateApproved)),
                 };
 
                 self.ledger.create_entry(
@@ -595,7 +1368,7 @@ impl Orchestrator {
                         "approver": approver,
                     })),
                     outputs: None,
-                    rationale: Some("Human approved baseline freeze - ready to proceed to Step 2".to_string()),
+                    rationale: Some(self.step_descriptions.rationale(1, StepRationale::GateApproved)),
                 };
 
                 self.ledger.create_entry(
@@ -623,7 +1396,7 @@ impl Orchestrator {
                         "approver": approver,
                     })),
                     outputs: None,
-                    rationale: Some("Human approved governance calibration - ready to proceed to Step 3".to_string()),
+                    rationale: Some(self.step_descriptions.rationale(2, StepRationale::GateApproved)),
                 };
 
                 self.ledger.create_entry(
@@ -652,7 +1425,7 @@ impl Orchestrator {
                         "approver": approver,
                     })),
                     outputs: None,
-                    rationale: Some("Human approved multi-angle analysis - ready to proceed to Step 4".to_string()),
+                    rationale: Some(self.step_descriptions.rationale(3, StepRationale::GateApproved)),
                 };
 
                 self.ledger.create_entry(
@@ -680,7 +1453,7 @@ impl Orchestrator {
                         "approver": approver,
                     })),
                     outputs: None,
-                    rationale: Some("Human approved synthesis lock-in - ready to proceed to Step 5".to_string()),
+                    rationale: Some(self.step_descriptions.rationale(4, StepRationale::GateApproved)),
                 };
 
                 self.ledger.create_entry(
@@ -691,6 +1464,9 @@ impl Orchestrator {
                     payload,
                 );
 
+                // Only an approved glossary becomes a reference for later runs
+                self.persist_glossary();
+
                 // Transition to Step 5
                 self.state = RunState::Step5Active;
 
@@ -756,7 +1532,7 @@ impl Orchestrator {
                         "approver": approver,
                     })),
                     outputs: None,
-                    rationale: Some("Human approved framework architecture - ready to proceed to Step 6".to_string()),
+                    rationale: Some(self.step_descriptions.rationale(5, StepRationale::GateApproved)),
                 };
 
                 self.ledger.create_entry(
@@ -819,7 +1595,7 @@ impl Orchestrator {
                             "exceptional_result": false,
                         })),
                         outputs: None,
-                        rationale: Some("Validation complete - run finished (no Step 6.5)".to_string()),
+                        rationale: Some(self.step_descriptions.rationale(6, StepRationale::GateApproved)),
                     };
 
                     self.ledger.create_entry(
@@ -832,6 +1608,7 @@ impl Orchestrator {
 
                     // Transition to Completed (no learning harvest for non-exceptional results)
                     self.state = RunState::Completed;
+                    self.emit_run_completed();
 
                     info!("✓ Validation gate approved - run completed");
                     info!("Active role: {:?}", self.active_role);
@@ -845,20 +1622,278 @@ impl Orchestrator {
         }
     }
 
-    /// Reject the gate (human decides not to proceed)
-    pub fn reject_gate(&mut self, rejector: &str, reason: &str) -> Result<()> {
-        info!("Gate rejection by: {} - reason: {}", rejector, reason);
+    /// Approve the pending gate automatically, if the auto-approval policy allows it
+    ///
+    /// Returns Ok(false) when auto-approval is disabled, the step is not in the
+    /// allowlist, or Critical callouts still need acknowledgment. The
+    /// `gate_auto_approved` entry is written only once the approval succeeded.
+    pub fn try_auto_approve(&mut self) -> Result<bool> {
+        if !self.auto_approve_gates || !self.state.is_gate_pending() {
+            return Ok(false);
+        }
 
-        if !self.state.is_gate_pending() {
-            anyhow::bail!("No gate pending - current state: {:?}", self.state);
+        let step = self.state.step_number();
+        if let Some(steps) = &self.auto_approve_steps {
+            if !steps.contains(&step) {
+                info!("Step {} gate not in auto-approval allowlist - awaiting human approval", step);
+                return Ok(false);
+            }
+        }
+
+        // Safety: Critical callouts block even in auto mode
+        if !self.callout_manager.can_proceed() {
+            let summary = self.callout_manager.summary();
+            warn!(
+                "Auto-approval blocked at Step {} gate: {} pending acknowledgment(s), {} hard block(s)",
+                step, summary.pending_acknowledgments, summary.hard_blocks
+            );
+            return Ok(false);
+        }
+
+        // Only an approval that went through is recorded
+        let role = ContextManager::get_role_abbreviation(&self.active_role);
+        if !self.approve_gate(AUTO_APPROVER)? {
+            return Ok(false);
         }
 
-        // Record gate rejection in ledger
         let payload = LedgerPayload {
-            action: "gate_rejected".to_string(),
+            action: "gate_auto_approved".to_string(),
             inputs: Some(serde_json::json!({
-                "rejector": rejector,
-                "reason": reason,
+                "step": step,
+                "approver": AUTO_APPROVER,
+                "allowlist": self.auto_approve_steps,
+            })),
+            outputs: None,
+            rationale: Some("Gate approved automatically for a non-interactive run".to_string()),
+        };
+
+        self.ledger.create_entry(
+            &self.run_id,
+            EntryType::Decision,
+            Some(step as i32),
+            Some(role.as_str()),
+            payload,
+        );
+
+        Ok(true)
+    }
+
+    /// Halt the run if `error` came from the run's open circuit breaker
+    ///
+    /// Repeated Claude API failures are not a problem with the step's content,
+    /// so the run stops instead of failing the step over and over. Returns true
+    /// if the run was halted; a run that is already halted is left as it is.
+    pub fn halt_on_circuit_open(&mut self, error: &anyhow::Error) -> bool {
+        let Some(circuit_open) = error.chain().find_map(|e| e.downcast_ref::<crate::api::CircuitOpen>()) else {
+            return false;
+        };
+        if matches!(self.state, RunState::Halted { .. }) {
+            return false;
+        }
+
+        let step = self.state.step_number();
+        let payload = LedgerPayload {
+            action: "circuit_open_halt".to_string(),
+            inputs: Some(serde_json::json!({
+                "consecutive_failures": circuit_open.consecutive_failures,
+                "retry_after_secs": circuit_open.retry_after.as_secs(),
+                "step": step,
+            })),
+            outputs: None,
+            rationale: Some("Claude API unavailable - run halted".to_string()),
+        };
+        self.ledger.create_entry(
+            &self.run_id,
+            EntryType::Decision,
+            Some(step as i32),
+            Some(ContextManager::get_role_abbreviation(&self.active_role).as_str()),
+            payload,
+        );
+
+        self.state = RunState::Halted {
+            reason: circuit_open.to_string(),
+            cause: HaltCause::ApiUnavailable { step },
+        };
+        warn!("Run halted at Step {}: {}", step, circuit_open);
+        true
+    }
+
+    /// Execute the step the run is currently in (Steps 1-6.5)
+    ///
+    /// Step 0 needs the user's intent and is started with `execute_step_0`.
+    /// `force_short_analysis` is passed on to `execute_step_3`. An open circuit
+    /// breaker halts the run (see `halt_on_circuit_open`).
+    pub async fn execute_active_step(&mut self, force_short_analysis: bool) -> Result<()> {
+        let result = self.execute_step_in_state(force_short_analysis).await;
+        if let Err(ref e) = result {
+            self.halt_on_circuit_open(e);
+        }
+        result
+    }
+
+    async fn execute_step_in_state(&mut self, force_short_analysis: bool) -> Result<()> {
+        match self.state {
+            RunState::Step1Active => {
+                self.execute_step_1().await?;
+            }
+            RunState::Step2Active => {
+                self.execute_step_2().await?;
+            }
+            RunState::Step3Active => {
+                self.execute_step_3(force_short_analysis).await?;
+            }
+            RunState::Step4Active => {
+                self.execute_step_4().await?;
+            }
+            RunState::Step5Active => {
+                self.execute_step_5().await?;
+            }
+            RunState::Step6Active => {
+                self.execute_step_6().await?;
+            }
+            RunState::Step6_5Active => {
+                self.execute_step_6_5().await?;
+            }
+            ref state => anyhow::bail!("No step to execute - state: {:?}", state),
+        }
+        Ok(())
+    }
+
+    /// Run every remaining step, auto-approving gates, until the run completes
+    ///
+    /// For batch execution; requires `with_auto_approval`. `user_intent` is used
+    /// if Step 0 has not run yet. Fails if a gate cannot be auto-approved or the
+    /// run pauses or halts. Short analysis targets are forced through Step 3 only
+    /// when its gate is auto-approved too.
+    pub async fn run_to_completion(&mut self, user_intent: &str) -> Result<()> {
+        if !self.auto_approve_gates {
+            anyhow::bail!("run_to_completion requires gate auto-approval (with_auto_approval)");
+        }
+
+        let force_short_analysis = self.auto_approve_steps.as_ref().is_none_or(|steps| steps.contains(&3));
+
+        loop {
+            match &self.state {
+                RunState::Step0Active => {
+                    self.execute_step_0(user_intent).await?;
+                }
+                RunState::Step1Active
+                | RunState::Step2Active
+                | RunState::Step3Active
+                | RunState::Step4Active
+                | RunState::Step5Active
+                | RunState::Step6Active
+                | RunState::Step6_5Active => {
+                    self.execute_active_step(force_short_analysis).await?;
+                }
+                RunState::Completed => {
+                    info!("✓ Batch run completed: {}", self.run_id);
+                    return Ok(());
+                }
+                state if state.is_gate_pending() => {
+                    if !self.try_auto_approve()? {
+                        anyhow::bail!(
+                            "Step {} gate requires human approval",
+                            self.state.step_number()
+                        );
+                    }
+                }
+                state => {
+                    anyhow::bail!("Batch run stopped - state: {:?}", state);
+                }
+            }
+        }
+    }
+
+    /// Total Claude API usage across the agents attached to this run
+    pub fn api_usage(&self) -> ApiUsage {
+        let mut usage = ApiUsage::default();
+        let agents = [
+            self.scope_agent.as_ref().map(|a| a.api_usage()),
+            self.governance_agent.as_ref().map(|a| a.api_usage()),
+            self.structure_agent.as_ref().map(|a| a.api_usage()),
+            self.analysis_synthesis_agent.as_ref().map(|a| a.api_usage()),
+            self.validation_agent.as_ref().map(|a| a.api_usage()),
+        ];
+        for agent_usage in agents.into_iter().flatten() {
+            usage.merge(agent_usage);
+        }
+        usage
+    }
+
+    /// Emit the Run_Completed signal carrying the final scorecard
+    ///
+    /// Payload: final metrics, API usage and cost, callout counts by tier,
+    /// and the Step 6 validation outcome.
+    fn emit_run_completed(&mut self) {
+        let usage = self.api_usage();
+        let callouts = self.callout_manager.summary();
+
+        let scorecard = serde_json::json!({
+            "final_metrics": self.latest_metrics,
+            "total_cost_usd": usage.cost_usd,
+            "api_usage": usage,
+            "callouts_by_tier": callouts.by_tier,
+            "validation": {
+                "outcome": self.validation_outcome,
+                "exceptional": self.exceptional_flag,
+            },
+        });
+
+        let signal_payload = SignalPayload {
+            step_from: 6,
+            step_to: RunState::Completed.step_number() as i32,
+            artifacts_produced: vec![],
+            metrics_snapshot: Some(scorecard),
+            gate_required: false,
+        };
+
+        let signal = self.signal_router.emit_signal(
+            SignalType::RunCompleted,
+            &self.run_id,
+            signal_payload,
+        );
+
+        let payload = LedgerPayload {
+            action: "signal_emitted".to_string(),
+            inputs: Some(serde_json::json!({
+                "signal_type": "Run_Completed",
+                "gate_required": false,
+            })),
+            outputs: Some(serde_json::json!({
+                "signal_hash": signal.hash,
+                "total_cost_usd": usage.cost_usd,
+            })),
+            rationale: Some("Run completed - final scorecard recorded".to_string()),
+        };
+
+        self.ledger.create_entry(
+            &self.run_id,
+            EntryType::Signal,
+            Some(6),
+            Some("Observer"),
+            payload,
+        );
+
+        info!("✓ Run_Completed signal emitted (cost: ${:.4})", usage.cost_usd);
+    }
+
+    /// Reject the gate (human decides not to proceed)
+    pub fn reject_gate(&mut self, rejector: &str, reason: &str) -> Result<()> {
+        info!("Gate rejection by: {} - reason: {}", rejector, reason);
+        require_named("rejector", rejector)?;
+        require_named("rejection reason", reason)?;
+
+        if !self.state.is_gate_pending() {
+            anyhow::bail!("No gate pending - current state: {:?}", self.state);
+        }
+
+        // Record gate rejection in ledger
+        let payload = LedgerPayload {
+            action: "gate_rejected".to_string(),
+            inputs: Some(serde_json::json!({
+                "rejector": rejector,
+                "reason": reason,
             })),
             outputs: None,
             rationale: Some("Human rejected gate - run terminating".to_string()),
@@ -875,6 +1910,7 @@ impl Orchestrator {
         // Transition to halted state
         self.state = RunState::Halted {
             reason: reason.to_string(),
+            cause: HaltCause::GateRejected,
         };
 
         info!("Run halted due to gate rejection");
@@ -1004,6 +2040,7 @@ impl Orchestrator {
                 // Transition to permanently Halted
                 self.state = RunState::Halted {
                     reason: format!("Aborted by {} at Step {}: {}", decider, paused_step, rationale),
+                    cause: HaltCause::MetricThreshold { step: paused_step },
                 };
 
                 info!("Run permanently halted");
@@ -1038,6 +2075,7 @@ impl Orchestrator {
 
                 self.state = RunState::Halted {
                     reason: format!("Return to previous step not supported (requested by {})", decider),
+                    cause: HaltCause::MetricThreshold { step: paused_step },
                 };
 
                 info!("Run halted - return not supported in MVP");
@@ -1050,6 +2088,273 @@ impl Orchestrator {
         }
     }
 
+    /// Accept a metrics warning PAUSE and return to the paused step's active state
+    ///
+    /// Only a PAUSE (see `halt_status`) can be acknowledged this way; a HALT
+    /// needs `handle_halt_decision`. Records a `pause_acknowledged` entry.
+    pub fn acknowledge_pause(&mut self, approver: &str, note: &str) -> Result<()> {
+        let status = self.halt_status();
+        if status.status != HaltLevel::Pause {
+            anyhow::bail!(
+                "Cannot acknowledge - run is not paused on a warning (status {:?}, state {:?})",
+                status.status,
+                self.state
+            );
+        }
+
+        let step = self.state.step_number();
+        let resumed = match step {
+            0 => RunState::Step0Active,
+            1 => RunState::Step1Active,
+            2 => RunState::Step2Active,
+            3 => RunState::Step3Active,
+            4 => RunState::Step4Active,
+            5 => RunState::Step5Active,
+            6 => RunState::Step6Active,
+            _ => anyhow::bail!("Cannot resume from step {}", step),
+        };
+
+        let payload = LedgerPayload {
+            action: "pause_acknowledged".to_string(),
+            inputs: Some(serde_json::json!({
+                "approver": approver,
+                "note": note,
+                "pause_reason": status.reason,
+                "offending_metrics": status.offending_metrics,
+                "step": step,
+            })),
+            outputs: Some(serde_json::json!({
+                "state": format!("{:?}", resumed),
+            })),
+            rationale: Some(format!("Warning accepted by {}: {}", approver, note)),
+        };
+        self.ledger.create_entry(
+            &self.run_id,
+            EntryType::Decision,
+            Some(step as i32),
+            Some("Human"),
+            payload,
+        );
+
+        self.state = resumed;
+        info!("✓ Pause acknowledged by {} - Step {} active again", approver, step);
+        Ok(())
+    }
+
+    /// Reopen the step a metrics HALT stopped, keeping its outputs, at the human's risk
+    ///
+    /// Only for runs halted after a metric threshold (`HaltCause::MetricThreshold`)
+    /// and only when the run's config sets `allow_halt_override`. Unlike
+    /// `rollback_to_last_gate` nothing is discarded. A gate rejection is a human
+    /// decision and cannot be overridden. The justification is recorded in a
+    /// `halt_override` ledger entry.
+    pub fn override_halt(&mut self, approver: &str, justification: &str) -> Result<()> {
+        require_named("approver", approver)?;
+        require_named("justification", justification)?;
+
+        let (halt_reason, step) = match &self.state {
+            RunState::Halted { reason, cause: HaltCause::MetricThreshold { step } } => (reason.clone(), *step),
+            RunState::Halted { cause: HaltCause::GateRejected, .. } => {
+                anyhow::bail!("Cannot override - the run was halted by a gate rejection")
+            }
+            RunState::Halted { cause: HaltCause::ApiUnavailable { .. }, .. } => {
+                anyhow::bail!("Cannot override - the Claude API was unavailable; roll back to retry the step")
+            }
+            state => anyhow::bail!("Cannot override - run is not halted. Current state: {:?}", state),
+        };
+        if !self.run_config.as_ref().is_some_and(|config| config.allow_halt_override) {
+            anyhow::bail!("HALT override is disabled - enable allow_halt_override in the configuration");
+        }
+
+        let resumed = match step {
+            0 => RunState::Step0Active,
+            1 => RunState::Step1Active,
+            2 => RunState::Step2Active,
+            3 => RunState::Step3Active,
+            4 => RunState::Step4Active,
+            5 => RunState::Step5Active,
+            6 => RunState::Step6Active,
+            _ => anyhow::bail!("Cannot resume from step {}", step),
+        };
+
+        let payload = LedgerPayload {
+            action: "halt_override".to_string(),
+            inputs: Some(serde_json::json!({
+                "approver": approver,
+                "justification": justification,
+                "halt_reason": halt_reason,
+                "step": step,
+            })),
+            outputs: Some(serde_json::json!({
+                "state": format!("{:?}", resumed),
+            })),
+            rationale: Some(format!("HALT overridden by {} at their own risk: {}", approver, justification)),
+        };
+        self.ledger.create_entry(
+            &self.run_id,
+            EntryType::Decision,
+            Some(step as i32),
+            Some("Human"),
+            payload,
+        );
+
+        self.state = resumed;
+        warn!("⚠️ HALT overridden by {} - Step {} active again", approver, step);
+        Ok(())
+    }
+
+    /// Roll a halted run back to the last approved gate so the failed step can be retried
+    ///
+    /// Only for runs halted after a metric threshold or an open circuit breaker;
+    /// a gate rejection stays final and a metrics pause is decided with
+    /// `handle_halt_decision` first. The step after the most recent approved
+    /// gate in the ledger becomes active again, artifacts, metrics and callouts
+    /// from that step onward are discarded and a `rollback` entry is recorded.
+    /// Persisted rows and content files of the discarded steps are deleted too,
+    /// so the retried step can store its artifacts again. Refuses if an artifact
+    /// to be discarded is immutable, so the baseline (and any configured
+    /// immutable types) is never rolled back.
+    pub fn rollback_to_last_gate(&mut self) -> Result<()> {
+        let halt_reason = match &self.state {
+            RunState::Halted {
+                reason,
+                cause: HaltCause::MetricThreshold { .. } | HaltCause::ApiUnavailable { .. },
+            } => reason.clone(),
+            RunState::Halted { cause: HaltCause::GateRejected, .. } => {
+                anyhow::bail!("Cannot roll back - the run was halted by a gate rejection")
+            }
+            state => anyhow::bail!("Cannot roll back - run is not halted. Current state: {:?}", state),
+        };
+
+        let approved_step = self
+            .ledger
+            .get_entries(&self.run_id)
+            .iter()
+            .rev()
+            .find(|entry| matches!(entry.payload.action.as_str(), "gate_approved" | "gate_auto_approved"))
+            .and_then(|entry| entry.step)
+            .ok_or_else(|| anyhow::anyhow!("Cannot roll back - no approved gate in the ledger"))?;
+
+        let (target_state, target_role) = match approved_step {
+            0 => (RunState::Step1Active, Role::Conductor),
+            1 => (RunState::Step2Active, Role::Conductor),
+            2 => (RunState::Step3Active, Role::Observer),
+            3 => (RunState::Step4Active, Role::Observer),
+            4 => (RunState::Step5Active, Role::Observer),
+            5 => (RunState::Step6Active, Role::Observer),
+            step => anyhow::bail!("Cannot roll back - no step follows the Step {} gate", step),
+        };
+        let retry_step = approved_step as u8 + 1;
+
+        let discarded: Vec<RunArtifact> = self
+            .collect_artifacts()
+            .into_iter()
+            .filter(|a| crate::artifacts::step_origin_for_type(&a.artifact_type) >= retry_step as i32)
+            .collect();
+        if let Some(locked) = discarded.iter().find(|a| a.is_immutable) {
+            return Err(crate::artifacts::ValidationError::ImmutableModification(locked.artifact_id.clone()).into());
+        }
+        self.delete_persisted_artifacts_from(retry_step)?;
+
+        for step in retry_step..=6 {
+            self.discard_step_outputs(step);
+        }
+        // Callouts raised by the discarded steps would otherwise still block the retried gate
+        self.callout_manager.clear_from_step(retry_step);
+        self.metrics_history.retain(|(step, _)| *step < retry_step);
+        self.latest_metrics = self.metrics_history.last().map(|(_, metrics)| metrics.clone());
+
+        let payload = LedgerPayload {
+            action: "rollback".to_string(),
+            inputs: Some(serde_json::json!({
+                "halt_reason": halt_reason,
+                "last_approved_gate": approved_step,
+            })),
+            outputs: Some(serde_json::json!({
+                "state": format!("{:?}", target_state),
+                "discarded_artifacts": discarded.iter().map(|a| &a.artifact_id).collect::<Vec<_>>(),
+            })),
+            rationale: Some(format!("Rolled back to the Step {} gate to retry Step {}", approved_step, retry_step)),
+        };
+        self.ledger.create_entry(
+            &self.run_id,
+            EntryType::Decision,
+            Some(retry_step as i32),
+            Some("Human"),
+            payload,
+        );
+
+        self.state = target_state;
+        self.active_role = target_role;
+        info!("✓ Rolled back to Step {} gate - retrying Step {}", approved_step, retry_step);
+        Ok(())
+    }
+
+    /// Delete the stored rows and content files of artifacts from `step` onward
+    ///
+    /// No-op unless `with_artifact_persistence` was set. The rows go in one
+    /// transaction; files are removed only once it commits.
+    fn delete_persisted_artifacts_from(&self, step: u8) -> Result<()> {
+        let Some(ref db_path) = self.artifact_db_path else {
+            return Ok(());
+        };
+
+        let mut conn = crate::database::open_connection(db_path)?;
+        let content_paths =
+            crate::database::artifacts::delete_artifacts_from_step(&mut conn, &self.run_id, step as i32)?;
+        for path in &content_paths {
+            let _ = std::fs::remove_file(path);
+        }
+        info!("Deleted {} stored artifacts from Step {} onward", content_paths.len(), step);
+        Ok(())
+    }
+
+    /// Clear the artifacts a step produced (used by `rollback_to_last_gate`)
+    fn discard_step_outputs(&mut self, step: u8) {
+        match step {
+            1 => {
+                self.intent_anchor = None;
+                self.charter = None;
+                self.baseline_report = None;
+                self.architecture_map = None;
+                self.raw_input_ci = None;
+            }
+            2 => {
+                self.governance_summary = None;
+                self.governance_calibration = None;
+                self.domain_snapshots = None;
+                self.detected_mode = None;
+                self.mode_detection_result = None;
+                self.mode_locked = false;
+            }
+            3 => {
+                self.integrated_diagnostic = None;
+                self.lens_efficacy_report = None;
+                self.diagnostic_ci_baseline = None;
+            }
+            4 => {
+                self.core_thesis = None;
+                self.operating_principles = None;
+                self.model_geometry = None;
+                self.geometry_diagram = None;
+                self.causal_spine = None;
+                self.north_star_narrative = None;
+                self.glossary = None;
+                self.limitations = None;
+            }
+            5 => {
+                self.framework_architecture = None;
+            }
+            _ => {
+                self.validation_matrix = None;
+                self.semantic_table = None;
+                self.evidence_report = None;
+                self.validation_outcome = None;
+                self.exceptional_flag = false;
+            }
+        }
+    }
+
     /// Execute Step 1: Baseline Establishment
     ///
     /// Creates the 4 immutable artifacts that define the run baseline:
@@ -1067,6 +2372,7 @@ impl Orchestrator {
         if !matches!(self.state, RunState::Step1Active) {
             anyhow::bail!("Cannot execute Step 1 - current state: {:?}", self.state);
         }
+        self.validate_prerequisites(1)?;
 
         // Ensure we have intent summary from Step 0
         let intent_summary = self.intent_summary.as_ref()
@@ -1081,14 +2387,14 @@ impl Orchestrator {
         self.raw_input_ci = Some(raw_ci);
         info!("Raw input CI calculated: {:.2} (from user_request)", raw_ci);
 
-        // Validate all agents are configured before proceeding
-        if self.scope_agent.is_none() {
+        // Validate all agents are configured before proceeding (stub mode fills the gaps)
+        if self.scope_agent.is_none() && !self.stub_mode {
             anyhow::bail!("Scope & Pattern Agent not configured");
         }
-        if self.governance_agent.is_none() {
+        if self.governance_agent.is_none() && !self.stub_mode {
             anyhow::bail!("Governance & Telemetry Agent not configured");
         }
-        if self.structure_agent.is_none() {
+        if self.structure_agent.is_none() && !self.stub_mode {
             anyhow::bail!("Structure & Redesign Agent not configured");
         }
 
@@ -1097,11 +2403,17 @@ impl Orchestrator {
         let intent_summary_content = intent_summary.generate_content_body();
         let intent_summary_hash = intent_summary.compute_hash();
 
-        let intent_anchor = self.scope_agent.as_ref().unwrap().create_intent_anchor(
-            &self.run_id,
-            &intent_summary_content,
-            &intent_summary_hash,
-        ).await?;
+        let intent_anchor = match self.scope_agent.as_ref() {
+            Some(agent) => agent.create_intent_anchor(
+                &self.run_id,
+                &intent_summary_content,
+                &intent_summary_hash,
+            ).await?,
+            None => self.stub_artifact(1, "intent-anchor", "Intent_Anchor", true, &format!(
+                "# Intent Anchor\n\n{}",
+                intent_summary_content
+            )),
+        };
 
         // Extract Intent_Anchor ID and hash from the artifact
         let intent_anchor_id = format!("{}-intent-anchor", self.run_id);
@@ -1112,14 +2424,17 @@ impl Orchestrator {
 
         // Step 1b: Create Charter (governing document)
         info!("Step 1b: Creating Charter...");
-        let charter = self.scope_agent.as_ref().unwrap().create_charter(
-            &self.run_id,
-            &intent_anchor_content,
-            &intent_anchor_id,
-            &intent_anchor_hash,
-            "Standard",  // Execution mode
-            "Standard",  // Telemetry profile
-        ).await?;
+        let charter = match self.scope_agent.as_ref() {
+            Some(agent) => agent.create_charter(
+                &self.run_id,
+                &intent_anchor_content,
+                &intent_anchor_id,
+                &intent_anchor_hash,
+                "Standard",  // Execution mode
+                "Standard",  // Telemetry profile
+            ).await?,
+            None => self.stub_charter(&intent_summary_for_charter),
+        };
 
         let charter_id = format!("{}-charter", self.run_id);
         let charter_hash = self.extract_hash_from_artifact(&charter)?;
@@ -1134,28 +2449,39 @@ impl Orchestrator {
 
         // Step 1d: Create Baseline_Report
         info!("Step 1d: Creating Baseline_Report...");
-        let baseline_report = self.governance_agent.as_ref().unwrap().create_baseline_report(
-            &self.run_id,
-            &charter_content,
-            &charter_id,
-            &charter_hash,
-            &intent_anchor_id,
-            e_baseline,
-            "Standard",  // Telemetry profile
-        )?;
+        let baseline_report = match self.governance_agent.as_ref() {
+            Some(agent) => agent.create_baseline_report(
+                &self.run_id,
+                &charter_content,
+                &charter_id,
+                &charter_hash,
+                &intent_anchor_id,
+                e_baseline,
+                "Standard",  // Telemetry profile
+            )?,
+            None => self.stub_artifact(1, "baseline-report", "Baseline_Report", true, &format!(
+                "# Baseline Report\n\n- Charter: {} ({})\n- E_baseline: {:.2}\n- Telemetry profile: Standard",
+                charter_id, charter_hash, e_baseline
+            )),
+        };
 
         let baseline_id = format!("{}-baseline-report", self.run_id);
         info!("✓ Baseline_Report created: {}", baseline_id);
 
         // Step 1e: Create Architecture_Map
         info!("Step 1e: Creating Architecture_Map...");
-        let architecture_map = self.structure_agent.as_ref().unwrap().create_architecture_map(
-            &self.run_id,
-            &charter_content,
-            &charter_hash,
-            &intent_anchor_id,
-            "Standard",  // Mode profile
-        ).await?;
+        let architecture_map = match self.structure_agent.as_ref() {
+            Some(agent) => agent.create_architecture_map(
+                &self.run_id,
+                &charter_content,
+                &charter_hash,
+                &intent_anchor_id,
+                "Standard",  // Mode profile
+            ).await?,
+            None => self.stub_artifact(1, "architecture-map", "Architecture_Map", true,
+                "# Architecture Map\n\n## Process Geometry\nLinear\n\n## Steps\n1. Baseline\n2. Governance\n3. Analysis\n4. Synthesis\n5. Redesign\n6. Validation",
+            ),
+        };
 
         let architecture_id = format!("{}-architecture-map", self.run_id);
         info!("✓ Architecture_Map created: {}", architecture_id);
@@ -1198,7 +2524,7 @@ impl Orchestrator {
                 "architecture_id": architecture_id,
                 "e_baseline": e_baseline,
             })),
-            rationale: Some("4 immutable baseline artifacts created and locked".to_string()),
+            rationale: Some(self.step_descriptions.rationale(1, StepRationale::Completed)),
         };
 
         self.ledger.create_entry(
@@ -1243,7 +2569,7 @@ impl Orchestrator {
             outputs: Some(serde_json::json!({
                 "signal_hash": signal.hash,
             })),
-            rationale: Some("Step 1 complete, baseline frozen, awaiting human approval to proceed".to_string()),
+            rationale: Some(self.step_descriptions.rationale(1, StepRationale::GateRequested)),
         };
 
         self.ledger.create_entry(
@@ -1254,6 +2580,8 @@ impl Orchestrator {
             payload,
         );
 
+        self.persist_step_artifacts(1);
+
         // Transition to gate pending state
         self.state = RunState::Step1GatePending;
 
@@ -1281,6 +2609,7 @@ impl Orchestrator {
         if !matches!(self.state, RunState::Step2Active) {
             anyhow::bail!("Cannot execute Step 2 - current state: {:?}", self.state);
         }
+        self.validate_prerequisites(2)?;
 
         // Ensure we have required artifacts from Step 1
         let charter_data = self.charter.as_ref()
@@ -1290,7 +2619,7 @@ impl Orchestrator {
             .ok_or_else(|| anyhow::anyhow!("No Architecture Map available - Step 1 must be completed first"))?;
 
         // Validate governance agent is configured
-        if self.governance_agent.is_none() {
+        if self.governance_agent.is_none() && !self.stub_mode {
             anyhow::bail!("Governance & Telemetry Agent not configured");
         }
 
@@ -1307,22 +2636,26 @@ impl Orchestrator {
         // Get E_baseline
         let e_baseline = self.get_e_baseline()
             .ok_or_else(|| anyhow::anyhow!("E_baseline not available - Step 1 must be completed first"))?;
+        self.ensure_e_baseline_locked()?;
 
         info!("Step 2: Configuring governance controls...");
         info!("  Charter hash: {}", charter_hash);
         info!("  E_baseline: {} words", e_baseline);
 
         // Call Governance & Telemetry Agent to perform calibration
-        let (governance_summary, domain_snapshots) = self.governance_agent.as_ref().unwrap()
-            .perform_governance_calibration(
-                &self.run_id,
-                &charter_content,
-                &charter_hash,
-                &intent_anchor_id,
-                &architecture_map_content,
-                e_baseline,
-            )
-            .await?;
+        let (governance_summary, domain_snapshots, calibration) = match self.governance_agent.as_ref() {
+            Some(agent) => agent
+                .perform_governance_calibration(
+                    &self.run_id,
+                    &charter_content,
+                    &charter_hash,
+                    &intent_anchor_id,
+                    &architecture_map_content,
+                    e_baseline,
+                )
+                .await?,
+            None => self.stub_governance_calibration(&charter_hash, e_baseline),
+        };
 
         let governance_summary_id = format!("{}-governance-summary", self.run_id);
         let domain_snapshots_id = format!("{}-domain-snapshots", self.run_id);
@@ -1330,9 +2663,17 @@ impl Orchestrator {
         info!("✓ Governance_Summary created: {}", governance_summary_id);
         info!("✓ Domain_Snapshots created: {}", domain_snapshots_id);
 
+        if self.apply_calibrated_thresholds {
+            if let Some(agent) = self.governance_agent.as_mut() {
+                let applied = agent.apply_calibration_thresholds(&calibration);
+                info!("✓ {} calibrated threshold(s) applied", applied);
+            }
+        }
+
         // Store artifacts
         self.governance_summary = Some(governance_summary);
         self.domain_snapshots = Some(domain_snapshots);
+        self.governance_calibration = Some(calibration);
 
         info!("Governance calibration artifacts stored");
 
@@ -1444,7 +2785,7 @@ impl Orchestrator {
                     "ias": initial_metrics.ias.as_ref().map(|m| m.value),
                 }
             })),
-            rationale: Some("Governance calibration complete, five control domains configured".to_string()),
+            rationale: Some(self.step_descriptions.rationale(2, StepRationale::Completed)),
         };
 
         self.ledger.create_entry(
@@ -1487,7 +2828,7 @@ impl Orchestrator {
             outputs: Some(serde_json::json!({
                 "signal_hash": signal.hash,
             })),
-            rationale: Some("Step 2 complete, governance calibrated, awaiting human approval to proceed".to_string()),
+            rationale: Some(self.step_descriptions.rationale(2, StepRationale::GateRequested)),
         };
 
         self.ledger.create_entry(
@@ -1498,6 +2839,8 @@ impl Orchestrator {
             payload,
         );
 
+        self.persist_step_artifacts(2);
+
         // Transition to gate pending state
         self.state = RunState::Step2GatePending;
 
@@ -1507,41 +2850,155 @@ impl Orchestrator {
         Ok((governance_summary_id, domain_snapshots_id))
     }
 
-    /// Extract hash from artifact YAML frontmatter
-    fn extract_hash_from_artifact(&self, artifact: &str) -> Result<String> {
-        for line in artifact.lines() {
-            if line.starts_with("hash:") {
-                let hash = line.trim_start_matches("hash:").trim().trim_matches('"');
-                return Ok(hash.to_string());
-            }
-        }
-        anyhow::bail!("No hash found in artifact frontmatter")
-    }
+    /// Regenerate a mutable artifact in place without rerunning its step
+    ///
+    /// Re-invokes the responsible agent while the artifact's gate is still
+    /// pending, replaces the stored artifact and records the regeneration in the
+    /// ledger, re-persisting the step's artifacts when persistence is enabled.
+    /// The Governance_Summary and Domain_Snapshots are supported; both come from
+    /// the Step 2 calibration call, so regenerating either replaces both and,
+    /// when calibrated thresholds are on, re-applies the new calibration's
+    /// thresholds. Immutable types are refused.
+    ///
+    /// # Returns
+    /// The regenerated artifact's new content hash
+    pub async fn regenerate_artifact(&mut self, artifact_type: crate::artifacts::ArtifactType) -> Result<String> {
+        use crate::artifacts::ArtifactType;
 
-    /// Extract content body from artifact (everything after second ---)
-    fn extract_content_from_artifact(&self, artifact: &str) -> Result<String> {
-        let mut in_frontmatter = false;
-        let mut frontmatter_ended = false;
-        let mut content_lines = Vec::new();
+        if self.immutable_types.is_immutable(&artifact_type) {
+            anyhow::bail!("Cannot regenerate immutable artifact type {:?}", artifact_type);
+        }
 
-        for line in artifact.lines() {
-            if line.trim() == "---" {
-                if !in_frontmatter {
-                    in_frontmatter = true;
-                } else if in_frontmatter && !frontmatter_ended {
-                    frontmatter_ended = true;
-                    continue;
+        let (previous_hash, new_artifact, step) = match artifact_type {
+            ArtifactType::GovernanceSummary | ArtifactType::DomainSnapshots => {
+                let name = artifact_type.to_frontmatter_str();
+                if !matches!(self.state, RunState::Step2GatePending) {
+                    anyhow::bail!(
+                        "{} can only be regenerated while the Step 2 gate is pending - current state: {:?}",
+                        name,
+                        self.state
+                    );
                 }
-            } else if frontmatter_ended {
-                content_lines.push(line);
-            }
-        }
 
-        if content_lines.is_empty() {
-            anyhow::bail!("No content found after frontmatter in artifact");
+                let previous = match artifact_type {
+                    ArtifactType::GovernanceSummary => self.governance_summary.as_deref(),
+                    _ => self.domain_snapshots.as_deref(),
+                }
+                .ok_or_else(|| anyhow::anyhow!("No {} to regenerate", name))?;
+                let previous_hash = self.extract_hash_from_artifact(previous)?;
+
+                let charter_data = self.charter.as_ref()
+                    .ok_or_else(|| anyhow::anyhow!("No Charter available"))?;
+                let charter_content = charter_data.to_display_markdown();
+                let charter_hash = charter_data.hash.clone();
+                let architecture_map = self.architecture_map.as_deref()
+                    .ok_or_else(|| anyhow::anyhow!("No Architecture Map available"))?;
+                let architecture_map_content = self.extract_content_from_artifact(architecture_map)?;
+                let intent_anchor_id = format!("{}-intent-anchor", self.run_id);
+                let e_baseline = self.get_e_baseline()
+                    .ok_or_else(|| anyhow::anyhow!("E_baseline not available"))?;
+
+                let (governance_summary, domain_snapshots, calibration) = match self.governance_agent.as_ref() {
+                    Some(agent) => agent
+                        .perform_governance_calibration(
+                            &self.run_id,
+                            &charter_content,
+                            &charter_hash,
+                            &intent_anchor_id,
+                            &architecture_map_content,
+                            e_baseline,
+                        )
+                        .await?,
+                    None if self.stub_mode => {
+                        let revision = self.regeneration_count(&ArtifactType::GovernanceSummary)
+                            + self.regeneration_count(&ArtifactType::DomainSnapshots)
+                            + 1;
+                        self.stub_regenerated_calibration(&charter_hash, e_baseline, revision)
+                    }
+                    None => anyhow::bail!("Governance & Telemetry Agent not configured"),
+                };
+
+                if self.apply_calibrated_thresholds {
+                    if let Some(agent) = self.governance_agent.as_mut() {
+                        let applied = agent.apply_calibration_thresholds(&calibration);
+                        info!("✓ {} calibrated threshold(s) re-applied", applied);
+                    }
+                }
+
+                let new_artifact = match artifact_type {
+                    ArtifactType::GovernanceSummary => governance_summary.clone(),
+                    _ => domain_snapshots.clone(),
+                };
+                self.governance_summary = Some(governance_summary);
+                self.domain_snapshots = Some(domain_snapshots);
+                self.governance_calibration = Some(calibration);
+
+                (previous_hash, new_artifact, 2)
+            }
+            other => anyhow::bail!("Regeneration is not supported for {:?}", other),
+        };
+
+        let new_hash = self.extract_hash_from_artifact(&new_artifact)?;
+
+        // The step's rows were stored before its gate; replace them with the new content
+        self.persist_step_artifacts(step);
+
+        let payload = LedgerPayload {
+            action: "artifact_regenerated".to_string(),
+            inputs: Some(serde_json::json!({
+                "artifact_type": artifact_type,
+                "previous_hash": previous_hash,
+            })),
+            outputs: Some(serde_json::json!({
+                "new_hash": new_hash,
+            })),
+            rationale: Some(format!("{:?} regenerated before gate approval", artifact_type)),
+        };
+
+        self.ledger.create_entry(
+            &self.run_id,
+            EntryType::Intervention,
+            Some(step),
+            Some(ContextManager::get_role_abbreviation(&self.active_role).as_str()),
+            payload,
+        );
+
+        info!("✓ {:?} regenerated: {} -> {}", artifact_type, previous_hash, new_hash);
+
+        Ok(new_hash)
+    }
+
+    /// Number of times an artifact type has been regenerated in this run
+    fn regeneration_count(&self, artifact_type: &crate::artifacts::ArtifactType) -> usize {
+        let type_value = serde_json::json!(artifact_type);
+        self.ledger
+            .get_entries(&self.run_id)
+            .iter()
+            .filter(|e| e.payload.action == "artifact_regenerated")
+            .filter(|e| e.payload.inputs.as_ref().and_then(|i| i.get("artifact_type")) == Some(&type_value))
+            .count()
+    }
+
+    /// Extract hash from artifact YAML frontmatter
+    fn extract_hash_from_artifact(&self, artifact: &str) -> Result<String> {
+        for line in artifact.lines() {
+            if line.starts_with("hash:") {
+                let hash = line.trim_start_matches("hash:").trim().trim_matches('"');
+                return Ok(hash.to_string());
+            }
+        }
+        anyhow::bail!("No hash found in artifact frontmatter")
+    }
+
+    /// Extract content body from artifact (everything after the frontmatter)
+    fn extract_content_from_artifact(&self, artifact: &str) -> Result<String> {
+        let body = crate::content::strip_frontmatter(artifact);
+
+        if body.len() == artifact.len() || body.trim().is_empty() {
+            anyhow::bail!("No content found after frontmatter in artifact");
         }
 
-        Ok(content_lines.join("\n"))
+        Ok(body.to_string())
     }
 
     /// Extract expected artifacts from Charter markdown and IntentSummary
@@ -2108,6 +3565,684 @@ impl Orchestrator {
         summaries
     }
 
+    /// Collect the full content of every artifact produced so far in this run
+    ///
+    /// Only fields that correspond to a spec artifact type are returned; component
+    /// fields (operating principles, model geometry, etc.) are surfaced through
+    /// their parent artifact. Charter is rebuilt from CharterData since the raw
+    /// markdown is not retained after Step 1.
+    pub fn collect_artifacts(&self) -> Vec<RunArtifact> {
+        use crate::artifacts::ArtifactType;
+
+        let mut artifacts = Vec::new();
+
+        // Step 1 artifacts
+        if let Some(ref anchor) = self.intent_anchor {
+            artifacts.push(self.run_artifact_from_markdown(anchor, ArtifactType::IntentAnchor, "intent-anchor"));
+        }
+        if let Some(ref charter) = self.charter {
+            artifacts.push(RunArtifact {
+                artifact_id: format!("{}-charter", self.run_id),
+                is_immutable: self.immutable_types.is_immutable(&ArtifactType::Charter),
+                artifact_type: ArtifactType::Charter,
+                content: charter.to_display_markdown(),
+                hash: charter.hash.clone(),
+                display: charter.to_display_markdown(),
+            });
+        }
+        if let Some(ref baseline) = self.baseline_report {
+            artifacts.push(self.run_artifact_from_markdown(baseline, ArtifactType::BaselineReport, "baseline-report"));
+        }
+        if let Some(ref architecture) = self.architecture_map {
+            artifacts.push(self.run_artifact_from_markdown(architecture, ArtifactType::ArchitectureMap, "architecture-map"));
+        }
+
+        // Step 2 artifacts
+        if let Some(ref summary) = self.governance_summary {
+            artifacts.push(self.run_artifact_from_markdown(summary, ArtifactType::GovernanceSummary, "governance-summary"));
+        }
+
+        // Step 3 artifacts
+        if let Some(ref diagnostic) = self.integrated_diagnostic {
+            artifacts.push(self.run_artifact_from_markdown(diagnostic, ArtifactType::DiagnosticSummary, "diagnostic-summary"));
+        }
+        if let Some(ref report) = self.lens_efficacy_report {
+            artifacts.push(self.run_artifact_from_markdown(report, ArtifactType::LensEfficacyReport, "lens-efficacy-report"));
+        }
+
+        // Step 4 artifacts
+        if let Some(ref thesis) = self.core_thesis {
+            artifacts.push(self.run_artifact_from_markdown(thesis, ArtifactType::CoreThesis, "core-thesis"));
+        }
+        if let Some(ref spine) = self.causal_spine {
+            artifacts.push(self.run_artifact_from_markdown(spine, ArtifactType::CausalSpineDraft, "causal-spine"));
+        }
+        if let Some(ref glossary) = self.glossary {
+            artifacts.push(self.run_artifact_from_markdown(glossary, ArtifactType::Glossary, "glossary"));
+        }
+
+        // Step 5 artifacts
+        if let Some(ref framework) = self.framework_architecture {
+            artifacts.push(self.run_artifact_from_markdown(framework, ArtifactType::FrameworkDraft, "framework-draft"));
+        }
+
+        // Step 6 artifacts
+        if let Some(ref matrix) = self.validation_matrix {
+            artifacts.push(self.run_artifact_from_markdown(matrix, ArtifactType::ValidationReport, "validation-report"));
+        }
+
+        artifacts
+    }
+
+    /// Write the artifacts produced by a step to the database in one transaction
+    ///
+    /// No-op unless `with_artifact_persistence` was set. Failures are logged rather
+    /// than failing the step, as with signal persistence. Rows from an earlier
+    /// execution of the step are replaced; a failed batch leaves the stored rows
+    /// and content files as they were.
+    fn persist_step_artifacts(&self, step: u8) {
+        let Some(ref db_path) = self.artifact_db_path else {
+            return;
+        };
+
+        let artifacts: Vec<RunArtifact> = self
+            .collect_artifacts()
+            .into_iter()
+            .filter(|a| crate::artifacts::step_origin_for_type(&a.artifact_type) == step as i32)
+            .collect();
+        if artifacts.is_empty() {
+            return;
+        }
+
+        let content_dir = db_path
+            .parent()
+            .unwrap_or_else(|| std::path::Path::new("."))
+            .join("artifacts")
+            .join(&self.run_id);
+
+        let mut staged = Vec::new();
+        let result = self.write_step_artifacts(db_path, &content_dir, step, &artifacts, &mut staged);
+        match result {
+            Ok(()) => info!("✓ Persisted {} Step {} artifacts", artifacts.len(), step),
+            Err(e) => {
+                warn!("Failed to persist Step {} artifacts for run {}: {:#}", step, self.run_id, e);
+                for path in staged {
+                    let _ = std::fs::remove_file(path);
+                }
+            }
+        }
+    }
+
+    /// Record cross-lens contradictions as persistent flaws
+    ///
+    /// No-op unless `with_artifact_persistence` was set; failures are logged.
+    fn persist_contradictions(&self, contradictions: &[String]) {
+        let Some(ref db_path) = self.artifact_db_path else {
+            return;
+        };
+
+        let result = crate::database::open_connection(db_path)
+            .and_then(|conn| crate::database::flaws::record_contradictions(&conn, &self.run_id, contradictions));
+        match result {
+            Ok(ids) => info!("✓ Recorded {} contradiction flaws", ids.len()),
+            Err(e) => warn!("Failed to record contradictions for run {}: {:#}", self.run_id, e),
+        }
+    }
+
+    /// Add this run's Step 4 glossary to the store shared across runs
+    ///
+    /// No-op unless `with_artifact_persistence` was set; failures are logged.
+    fn persist_glossary(&self) {
+        let (Some(db_path), Some(glossary)) = (&self.artifact_db_path, &self.glossary) else {
+            return;
+        };
+
+        let result = serde_json::from_str::<Vec<crate::agents::GlossaryEntry>>(glossary)
+            .context("Glossary is not a list of entries")
+            .and_then(|glossary| {
+                let conn = crate::database::open_connection(db_path)?;
+                let added = crate::database::glossary::record_glossary(&conn, &self.run_id, &glossary)?;
+                Ok((added, glossary.len()))
+            });
+        match result {
+            Ok((added, total)) => info!("✓ Glossary store: {} new terms of {}", added, total),
+            Err(e) => warn!("Failed to record glossary for run {}: {:#}", self.run_id, e),
+        }
+    }
+
+    /// Store a run's pattern cards in the patterns table
+    ///
+    /// Each card keeps the run's model geometry and its rationale alongside it,
+    /// so runs can be compared by geometry. No-op unless
+    /// `with_artifact_persistence` was set; failures are logged.
+    fn persist_pattern_cards(&self, cards: &[crate::agents::validation_learning::PatternCard]) {
+        let Some(ref db_path) = self.artifact_db_path else {
+            return;
+        };
+
+        // Stored as "{geometry:?}: {rationale}", optionally followed by the diagram
+        let geometry = self.model_geometry.as_deref().unwrap_or_default();
+        let geometry = geometry.split("\n\nMODEL DIAGRAM:").next().unwrap_or_default();
+        let (geometry, rationale) = geometry.split_once(": ").unwrap_or((geometry, ""));
+        let architecture = serde_json::json!({ "geometry": geometry, "rationale": rationale }).to_string();
+
+        let result = crate::database::open_connection(db_path).and_then(|conn| {
+            for card in cards {
+                crate::database::patterns::create_pattern(&conn, &crate::database::models::Pattern {
+                    id: format!("{}-{}", self.run_id, card.pattern_id),
+                    intent_category: self.get_intent_category(),
+                    ci_achievement: None,
+                    ev_stability: None,
+                    architecture_pattern: Some(architecture.clone()),
+                    analysis_pattern: None,
+                    synthesis_pattern: None,
+                    structure_pattern: None,
+                    validation_pattern: Some(serde_json::to_string(card)?),
+                    applicability: None,
+                    vitality_freshness: 1.0,
+                    vitality_relevance: 1.0,
+                    application_count: 0,
+                    success_count: 0,
+                    created_at: Utc::now(),
+                    last_applied: None,
+                    source_run_id: Some(self.run_id.clone()),
+                    is_starter: false,
+                })?;
+            }
+            Ok(())
+        });
+        match result {
+            Ok(()) => info!("✓ Stored {} pattern cards", cards.len()),
+            Err(e) => warn!("Failed to store pattern cards for run {}: {:#}", self.run_id, e),
+        }
+    }
+
+    /// Stage content files, replace the step's rows in one transaction, then move
+    /// the staged files into place
+    ///
+    /// Content is written beside its final path and only renamed over it once
+    /// the rows commit, so a failed batch never clobbers files that stored rows
+    /// still point at.
+    fn write_step_artifacts(
+        &self,
+        db_path: &std::path::Path,
+        content_dir: &std::path::Path,
+        step: u8,
+        artifacts: &[RunArtifact],
+        staged: &mut Vec<std::path::PathBuf>,
+    ) -> Result<()> {
+        use crate::database::{artifacts as db_artifacts, models, runs};
+
+        std::fs::create_dir_all(content_dir).context("Failed to create artifact content directory")?;
+
+        let now = Utc::now();
+        let mut records = Vec::with_capacity(artifacts.len());
+        for artifact in artifacts {
+            let path = content_dir.join(format!("{}.md", artifact.artifact_id));
+            let staging = content_dir.join(format!("{}.md.partial", artifact.artifact_id));
+            std::fs::write(&staging, &artifact.content)
+                .with_context(|| format!("Failed to write content for artifact {}", artifact.artifact_id))?;
+            staged.push(staging);
+
+            let artifact_type = serde_json::to_value(&artifact.artifact_type)?
+                .as_str()
+                .unwrap_or_default()
+                .to_string();
+            records.push(models::Artifact {
+                id: artifact.artifact_id.clone(),
+                run_id: self.run_id.clone(),
+                r#type: artifact_type,
+                step_origin: crate::artifacts::step_origin_for_type(&artifact.artifact_type),
+                hash: artifact.hash.clone(),
+                is_immutable: artifact.is_immutable,
+                content_path: Some(path.to_string_lossy().to_string()),
+                created_at: now,
+                parent_hash: None,
+            });
+        }
+
+        let mut conn = crate::database::open_connection(db_path)?;
+
+        // Artifacts reference their run, so make sure the run row exists first
+        if runs::get_run(&conn, &self.run_id)?.is_none() {
+            let intent_anchor_hash = self
+                .intent_anchor
+                .as_deref()
+                .and_then(|anchor| self.extract_hash_from_artifact(anchor).ok())
+                .unwrap_or_default();
+            runs::create_run(
+                &conn,
+                &models::Run {
+                    id: self.run_id.clone(),
+                    intent_anchor_hash,
+                    created_at: now,
+                    completed_at: None,
+                    final_ci: None,
+                    final_ev: None,
+                    status: Some("active".to_string()),
+                },
+            )?;
+            if let Some(ref run_config) = self.run_config {
+                runs::set_run_config(&conn, &self.run_id, run_config)?;
+            }
+        }
+
+        // Frontmatter dependencies must already be stored (or be part of this batch)
+        for artifact in artifacts {
+            let Some(mut parsed) = self
+                .artifact_text(&artifact.artifact_id)
+                .and_then(|text| crate::artifacts::parse_artifact(&text).ok())
+            else {
+                continue;
+            };
+            parsed
+                .frontmatter
+                .dependencies
+                .retain(|dep| !artifacts.iter().any(|a| a.artifact_id == dep.artifact_id));
+            db_artifacts::validate_dependencies_exist(&conn, &parsed)?;
+        }
+
+        let replaced = db_artifacts::replace_step_artifacts_tx(&mut conn, &self.run_id, step as i32, &records)?;
+
+        let kept: Vec<&str> = records.iter().filter_map(|r| r.content_path.as_deref()).collect();
+        for (staging, record) in staged.iter().zip(&records) {
+            let path = record.content_path.as_deref().unwrap_or_default();
+            std::fs::rename(staging, path)
+                .with_context(|| format!("Failed to move content into place for artifact {}", record.id))?;
+        }
+        staged.clear();
+        for path in replaced.iter().filter(|path| !kept.contains(&path.as_str())) {
+            let _ = std::fs::remove_file(path);
+        }
+
+        Ok(())
+    }
+
+    /// Build a RunArtifact from stored markdown
+    ///
+    /// Uses parse_artifact when the frontmatter matches the spec schema. Agent output
+    /// uses a looser frontmatter (e.g. "Baseline_Report", structured dependencies), so
+    /// fall back to line-based extraction of artifact_id/hash and the content body.
+    fn run_artifact_from_markdown(
+        &self,
+        markdown: &str,
+        artifact_type: crate::artifacts::ArtifactType,
+        id_suffix: &str,
+    ) -> RunArtifact {
+        let is_immutable = self.immutable_types.is_immutable(&artifact_type);
+        let display = crate::artifacts::render_display(markdown);
+
+        if let Ok(parsed) = crate::artifacts::parse_artifact(markdown) {
+            return RunArtifact {
+                artifact_id: parsed.frontmatter.artifact_id,
+                artifact_type,
+                is_immutable,
+                content: parsed.content,
+                hash: parsed.frontmatter.hash,
+                display,
+            };
+        }
+
+        let content = self
+            .extract_content_from_artifact(markdown)
+            .map(|body| body.trim().to_string())
+            .unwrap_or_else(|_| markdown.to_string());
+        let hash = self
+            .extract_hash_from_artifact(markdown)
+            .unwrap_or_else(|_| crate::artifacts::calculate_content_hash(&content));
+        let artifact_id = markdown
+            .lines()
+            .find(|line| line.starts_with("artifact_id:"))
+            .map(|line| line.trim_start_matches("artifact_id:").trim().trim_matches('"').to_string())
+            .unwrap_or_else(|| format!("{}-{}", self.run_id, id_suffix));
+
+        RunArtifact {
+            artifact_id,
+            artifact_type,
+            is_immutable,
+            content,
+            hash,
+            display,
+        }
+    }
+
+    /// Full text (frontmatter + body) of an artifact produced in this run
+    ///
+    /// Returns the stored markdown when it carries frontmatter; otherwise (e.g. the
+    /// rebuilt Charter or JSON-backed artifacts) frontmatter is rendered from the
+    /// RunArtifact. Returns None if the run has not produced the artifact.
+    pub fn artifact_text(&self, artifact_id: &str) -> Option<String> {
+        let artifact = self
+            .collect_artifacts()
+            .into_iter()
+            .find(|a| a.artifact_id == artifact_id)?;
+
+        match self.stored_artifact_markdown(&artifact.artifact_type) {
+            Some(markdown) if markdown.trim_start().starts_with("---") => Some(markdown.clone()),
+            _ => Some(artifact.to_markdown(&self.run_id)),
+        }
+    }
+
+    /// Markdown held for an artifact type, for types stored as markdown
+    fn stored_artifact_markdown(&self, artifact_type: &crate::artifacts::ArtifactType) -> Option<&String> {
+        use crate::artifacts::ArtifactType;
+
+        match artifact_type {
+            ArtifactType::IntentAnchor => self.intent_anchor.as_ref(),
+            ArtifactType::BaselineReport => self.baseline_report.as_ref(),
+            ArtifactType::ArchitectureMap => self.architecture_map.as_ref(),
+            ArtifactType::GovernanceSummary => self.governance_summary.as_ref(),
+            ArtifactType::DiagnosticSummary => self.integrated_diagnostic.as_ref(),
+            ArtifactType::LensEfficacyReport => self.lens_efficacy_report.as_ref(),
+            ArtifactType::CoreThesis => self.core_thesis.as_ref(),
+            ArtifactType::CausalSpineDraft => self.causal_spine.as_ref(),
+            ArtifactType::Glossary => self.glossary.as_ref(),
+            ArtifactType::FrameworkDraft => self.framework_architecture.as_ref(),
+            ArtifactType::ValidationReport => self.validation_matrix.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Check every artifact's content hash and that its declared parent is one of its ancestors
+    ///
+    /// Walks all produced artifacts (plus the Intent Summary, the root of the
+    /// chain). Stored artifact bodies are re-hashed and compared with their
+    /// `hash`, so content edited after hashing is caught. Each `parent_hash` must
+    /// be the hash of an artifact reached through the artifact's `dependencies`
+    /// (directly or through theirs); dependencies may name an artifact id or a
+    /// hash. Artifacts without a `parent_hash` are listed as unchecked rather
+    /// than broken. Fails only if an artifact's frontmatter cannot be read.
+    pub fn verify_lineage(&self) -> Result<LineageReport> {
+        use std::collections::{HashMap, HashSet};
+
+        // Every artifact a dependency or parent can point at: id -> hash, hash -> id
+        let mut hash_by_id: HashMap<String, String> = HashMap::new();
+        let mut id_by_hash: HashMap<String, String> = HashMap::new();
+        if let Some(ref summary) = self.intent_summary {
+            hash_by_id.insert(summary.artifact_id.clone(), summary.hash.clone());
+            id_by_hash.insert(summary.hash.clone(), summary.artifact_id.clone());
+        }
+
+        let mut declared = Vec::new();
+        let mut dependencies_by_id: HashMap<String, Vec<String>> = HashMap::new();
+        for artifact in self.collect_artifacts() {
+            hash_by_id.insert(artifact.artifact_id.clone(), artifact.hash.clone());
+            id_by_hash.insert(artifact.hash.clone(), artifact.artifact_id.clone());
+
+            let text = self.artifact_text(&artifact.artifact_id).unwrap_or_default();
+            let frontmatter = text
+                .trim_start()
+                .strip_prefix("---")
+                .and_then(|rest| rest.split_once("\n---"))
+                .map(|(yaml, _)| yaml)
+                .unwrap_or_default();
+            let yaml: serde_yaml::Value = serde_yaml::from_str(frontmatter)
+                .with_context(|| format!("Failed to read frontmatter of {}", artifact.artifact_id))?;
+            let parent_hash = yaml
+                .get("parent_hash")
+                .and_then(|value| value.as_str())
+                .filter(|hash| !hash.is_empty())
+                .map(str::to_string);
+            let dependencies = yaml
+                .get("dependencies")
+                .and_then(|value| value.as_sequence())
+                .map(|entries| {
+                    entries
+                        .iter()
+                        .filter_map(|entry| entry.as_str().or_else(|| entry.get("artifact_id").and_then(|id| id.as_str())))
+                        .map(str::to_string)
+                        .collect()
+                })
+                .unwrap_or_default();
+            dependencies_by_id.insert(artifact.artifact_id.clone(), dependencies);
+
+            // Only stored markdown carries the exact body its hash was taken over
+            let content_intact = self
+                .stored_artifact_markdown(&artifact.artifact_type)
+                .filter(|markdown| markdown.trim_start().starts_with("---"))
+                .map(|markdown| crate::artifacts::calculate_content_hash(Self::artifact_body(markdown)) == artifact.hash);
+            declared.push((artifact, parent_hash, content_intact));
+        }
+
+        // Hashes of the artifacts reached by following dependencies from `artifact_id`
+        let ancestor_hashes = |artifact_id: &str| -> HashSet<String> {
+            let mut hashes = HashSet::new();
+            let mut visited = HashSet::new();
+            let mut pending = vec![artifact_id.to_string()];
+            while let Some(id) = pending.pop() {
+                if !visited.insert(id.clone()) {
+                    continue;
+                }
+                for dependency in dependencies_by_id.get(&id).into_iter().flatten() {
+                    let resolved = if let Some(hash) = hash_by_id.get(dependency) {
+                        Some((dependency.clone(), hash.clone()))
+                    } else {
+                        id_by_hash.get(dependency).map(|id| (id.clone(), dependency.clone()))
+                    };
+                    if let Some((dependency_id, hash)) = resolved {
+                        hashes.insert(hash);
+                        pending.push(dependency_id);
+                    }
+                }
+            }
+            hashes
+        };
+
+        let mut report = LineageReport::default();
+        for (artifact, parent_hash, content_intact) in declared {
+            if let Some(intact) = content_intact {
+                report.content_checked += 1;
+                if !intact {
+                    report.breaks.push(LineageBreak {
+                        artifact_id: artifact.artifact_id.clone(),
+                        artifact_type: artifact.artifact_type.clone(),
+                        parent_hash: parent_hash.clone().unwrap_or_default(),
+                        reason: "content does not match its stored hash".to_string(),
+                    });
+                }
+            }
+
+            let Some(parent_hash) = parent_hash else {
+                report.unchecked.push(artifact.artifact_id);
+                continue;
+            };
+            report.checked += 1;
+            let reason = if parent_hash == artifact.hash {
+                "parent_hash is the artifact's own hash"
+            } else if !id_by_hash.contains_key(&parent_hash) {
+                "parent_hash matches no artifact in this run"
+            } else if !ancestor_hashes(&artifact.artifact_id).contains(&parent_hash) {
+                "parent_hash is not an artifact reached through its dependencies"
+            } else {
+                continue;
+            };
+            report.breaks.push(LineageBreak {
+                artifact_id: artifact.artifact_id,
+                artifact_type: artifact.artifact_type,
+                parent_hash,
+                reason: reason.to_string(),
+            });
+        }
+
+        if report.breaks.is_empty() {
+            info!(
+                "✓ Lineage intact: {} parent links and {} content hashes verified",
+                report.checked, report.content_checked
+            );
+        } else {
+            warn!(
+                "Lineage has {} break(s) in {} parent links and {} content hashes",
+                report.breaks.len(),
+                report.checked,
+                report.content_checked
+            );
+        }
+        Ok(report)
+    }
+
+    /// Build the Coherence Spine from the artifacts held in memory
+    ///
+    /// Edges come from each artifact's frontmatter dependencies; dependencies on
+    /// artifacts this run does not hold are left out.
+    pub fn spine_graph(&self) -> crate::spine::SpineGraph {
+        use crate::spine::{Dependency, DependencyType, SpineManager};
+
+        let artifacts = self.collect_artifacts();
+        let mut manager = SpineManager::new();
+        for artifact in &artifacts {
+            let artifact_type = serde_json::to_value(&artifact.artifact_type)
+                .ok()
+                .and_then(|v| v.as_str().map(str::to_string))
+                .unwrap_or_default();
+            let node = crate::spine::Artifact {
+                id: artifact.artifact_id.clone(),
+                artifact_type: crate::spine::ArtifactType::from_db_type(&artifact_type),
+                step_origin: crate::artifacts::step_origin_for_type(&artifact.artifact_type),
+                hash: artifact.hash.clone(),
+                is_immutable: artifact.is_immutable,
+                created_at: Utc::now(),
+                parent_hash: None,
+            };
+            if let Err(e) = manager.add_artifact(node) {
+                warn!("Skipping artifact in spine graph: {}", e);
+            }
+        }
+
+        for artifact in &artifacts {
+            let Some(parsed) = self
+                .artifact_text(&artifact.artifact_id)
+                .and_then(|text| crate::artifacts::parse_artifact(&text).ok())
+            else {
+                continue;
+            };
+            for dep in parsed.frontmatter.dependencies {
+                let dependency_type = DependencyType::from_db_type(&dep.relationship.to_string())
+                    .unwrap_or(DependencyType::References);
+                // Missing targets and cycles are reported by the spine health check
+                let _ = manager.add_dependency(Dependency {
+                    source_id: artifact.artifact_id.clone(),
+                    target_id: dep.artifact_id,
+                    dependency_type,
+                    created_at: Utc::now(),
+                });
+            }
+        }
+
+        manager.graph()
+    }
+
+    /// Render the run report as one self-contained HTML page
+    ///
+    /// Covers the intent, latest metrics, model geometry, core thesis, operating
+    /// principles and framework, followed by the spine as a Mermaid diagram.
+    /// Sections the run has not produced are omitted. Styles are inline and no
+    /// scripts are loaded, so the page opens offline; the diagram source sits in
+    /// a `<div class="mermaid">` for viewers that render Mermaid.
+    pub fn to_html_report(&self) -> String {
+        use crate::artifacts::ArtifactType;
+
+        fn escape(text: &str) -> String {
+            text.replace('&', "&amp;")
+                .replace('<', "&lt;")
+                .replace('>', "&gt;")
+                .replace('"', "&quot;")
+        }
+
+        // Prefer the artifact body so frontmatter is not shown
+        let artifacts = self.collect_artifacts();
+        let body_of = |artifact_type: ArtifactType, raw: &Option<String>| -> Option<String> {
+            artifacts
+                .iter()
+                .find(|a| a.artifact_type == artifact_type)
+                .map(|a| a.content.clone())
+                .or_else(|| raw.clone())
+                .filter(|text| !text.trim().is_empty())
+        };
+
+        let mut sections = Vec::new();
+        if let Some(ref summary) = self.intent_summary {
+            sections.push(("Intent", escape(&summary.primary_goal)));
+        }
+        if let Some(ref metrics) = self.latest_metrics {
+            let rows: String = [
+                ("CI", &metrics.ci),
+                ("EV", &metrics.ev),
+                ("IAS", &metrics.ias),
+                ("EFI", &metrics.efi),
+                ("SEC", &metrics.sec),
+                ("PCI", &metrics.pci),
+            ]
+            .into_iter()
+            .filter_map(|(name, result)| {
+                result.as_ref().map(|r| {
+                    format!(
+                        "<tr><td>{}</td><td>{:.2}</td><td>{:?}</td><td>{}</td></tr>\n",
+                        name,
+                        r.value,
+                        r.status,
+                        escape(&r.interpretation)
+                    )
+                })
+            })
+            .collect();
+            if !rows.is_empty() {
+                sections.push((
+                    "Metrics",
+                    format!(
+                        "<table>\n<tr><th>Metric</th><th>Value</th><th>Status</th><th>Interpretation</th></tr>\n{}</table>",
+                        rows
+                    ),
+                ));
+            }
+        }
+        for (title, text) in [
+            ("Model Geometry", self.model_geometry.clone().filter(|t| !t.trim().is_empty())),
+            ("Core Thesis", body_of(ArtifactType::CoreThesis, &self.core_thesis)),
+            ("Operating Principles", self.operating_principles.clone().filter(|t| !t.trim().is_empty())),
+            ("Framework", body_of(ArtifactType::FrameworkDraft, &self.framework_architecture)),
+        ] {
+            if let Some(text) = text {
+                sections.push((title, format!("<div class=\"text\">{}</div>", escape(text.trim()))));
+            }
+        }
+
+        let graph = self.spine_graph();
+        if !graph.nodes.is_empty() {
+            sections.push((
+                "Coherence Spine",
+                format!("<div class=\"mermaid\">\n{}</div>", escape(&graph.to_mermaid())),
+            ));
+        }
+
+        let mut html = String::new();
+        html.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+        html.push_str(&format!("<title>Method-VI Run Report - {}</title>\n", escape(&self.run_id)));
+        html.push_str(
+            "<style>\n\
+             body { font-family: -apple-system, 'Segoe UI', sans-serif; max-width: 860px; margin: 2em auto; color: #222; line-height: 1.5; }\n\
+             h1 { border-bottom: 2px solid #444; padding-bottom: 0.3em; }\n\
+             h2 { margin-top: 1.8em; color: #333; }\n\
+             table { border-collapse: collapse; width: 100%; }\n\
+             th, td { border: 1px solid #ccc; padding: 0.4em 0.6em; text-align: left; }\n\
+             th { background: #f3f3f3; }\n\
+             .text, .mermaid { white-space: pre-wrap; background: #fafafa; border: 1px solid #e3e3e3; padding: 0.8em; }\n\
+             .meta { color: #666; }\n\
+             </style>\n",
+        );
+        html.push_str("</head>\n<body>\n<h1>Method-VI Run Report</h1>\n");
+        html.push_str(&format!(
+            "<p class=\"meta\">Run ID: {} · State: {:?} · Generated: {}</p>\n",
+            escape(&self.run_id),
+            self.state,
+            Utc::now().to_rfc3339()
+        ));
+        for (title, content) in sections {
+            html.push_str(&format!("<h2>{}</h2>\n{}\n", title, content));
+        }
+        html.push_str("<p class=\"meta\"><em>Generated by Method-VI</em></p>\n</body>\n</html>\n");
+        html
+    }
+
     /// Check required deliverables before Step 6 transition
     ///
     /// Validates that all required artifacts defined in CharterData.expected_artifacts
@@ -2168,83 +4303,56 @@ impl Orchestrator {
         }
     }
 
-    /// Extract objectives from Charter content for relevance checking
+    /// Check that the artifacts `step` reads from earlier steps are present
     ///
-    /// Parses the Charter markdown to find Primary and Secondary Objectives.
-    /// Returns a list of objective statements.
-    fn extract_objectives_from_charter(&self, charter_content: &str) -> Result<Vec<String>> {
-        let mut objectives = Vec::new();
-        let mut in_objectives_section = false;
-
-        for line in charter_content.lines() {
-            let trimmed = line.trim();
-
-            // Check if we're entering an objectives section
-            if trimmed.contains("Objective") && (trimmed.starts_with("##") || trimmed.starts_with("**")) {
-                in_objectives_section = true;
-
-                // If the objective is on the same line (e.g., "**Primary Objective:** Description")
-                if let Some(colon_pos) = trimmed.find(':') {
-                    let objective = trimmed[colon_pos + 1..].trim();
-                    if !objective.is_empty() {
-                        objectives.push(objective.to_string());
-                    }
-                }
-                continue;
-            }
-
-            // If we're in objectives section, collect bullet points or text
-            if in_objectives_section {
-                // Stop if we hit a new section header (## Something else)
-                if trimmed.starts_with("##") && !trimmed.contains("Objective") {
-                    in_objectives_section = false;
-                    continue;
-                }
-
-                // Collect bullet points
-                if trimmed.starts_with("- ") || trimmed.starts_with("* ") {
-                    let objective = trimmed.trim_start_matches("- ").trim_start_matches("* ").trim();
-                    if !objective.is_empty() {
-                        objectives.push(objective.to_string());
-                    }
-                }
-                // Collect numbered lists
-                else if trimmed.len() > 2 && trimmed.chars().next().unwrap().is_numeric() && trimmed.chars().nth(1) == Some('.') {
-                    let objective = trimmed.splitn(2, '.').nth(1).unwrap_or("").trim();
-                    if !objective.is_empty() {
-                        objectives.push(objective.to_string());
-                    }
-                }
-                // Collect plain text lines (non-empty, non-header)
-                else if !trimmed.is_empty() && !trimmed.starts_with('#') {
-                    objectives.push(trimmed.to_string());
-                }
-            }
-        }
+    /// Called at the start of each `execute_step_N` so a resumed run with
+    /// unrestored content fails with `MissingArtifact` instead of panicking.
+    pub fn validate_prerequisites(&self, step: u8) -> Result<()> {
+        let required: &[(&'static str, bool)] = match step {
+            1 => &[("Intent Summary", self.intent_summary.is_some())],
+            2 => &[
+                ("Charter", self.charter.is_some()),
+                ("Architecture Map", self.architecture_map.is_some()),
+            ],
+            3 => &[
+                ("Intent Summary", self.intent_summary.is_some()),
+                ("Charter", self.charter.is_some()),
+            ],
+            4 => &[
+                ("Charter", self.charter.is_some()),
+                ("Integrated Diagnostic", self.integrated_diagnostic.is_some()),
+            ],
+            5 => &[
+                ("Charter", self.charter.is_some()),
+                ("Core Thesis", self.core_thesis.is_some()),
+            ],
+            6 => &[
+                ("Charter", self.charter.is_some()),
+                ("Architecture Map", self.architecture_map.is_some()),
+                ("Core Thesis", self.core_thesis.is_some()),
+                ("Glossary", self.glossary.is_some()),
+                ("Framework Architecture", self.framework_architecture.is_some()),
+            ],
+            _ => &[],
+        };
 
-        if objectives.is_empty() {
-            warn!("No objectives found in Charter - using fallback");
-            objectives.push("Analyze and synthesize user-provided content according to Method-VI framework".to_string());
+        let artifacts: Vec<&'static str> = required
+            .iter()
+            .filter(|(_, present)| !present)
+            .map(|(name, _)| *name)
+            .collect();
+        if artifacts.is_empty() {
+            Ok(())
+        } else {
+            Err(MissingArtifact { step, artifacts }.into())
         }
-
-        info!("Extracted {} objectives from Charter", objectives.len());
-        Ok(objectives)
     }
 
     /// Validate an action is allowed in the current state
     ///
     /// Uses the Ledger Manager to validate state transitions
     pub fn validate_action(&self, action: &str) -> Result<bool> {
-        let ledger_state = match &self.state {
-            RunState::Step0Active => LedgerState::Step0Active,
-            RunState::Step0GatePending | RunState::Step1GatePending => LedgerState::GatePending,
-            RunState::Step1Active => LedgerState::Normal,
-            RunState::Paused { .. } => LedgerState::HaltActive, // Paused requires decision
-            RunState::Halted { .. } => LedgerState::HaltActive,
-            _ => LedgerState::Normal,
-        };
-
-        let validation = self.ledger.validate_action(&ledger_state, action);
+        let validation = self.ledger.validate_action(&self.action_ledger_state(), action);
 
         if !validation.allowed {
             if let Some(reason) = validation.reason {
@@ -2255,12 +4363,49 @@ impl Orchestrator {
         Ok(validation.allowed)
     }
 
-    /// Get the current ledger state
-    pub fn get_ledger_state(&self) -> LedgerState {
+    /// Ledger state `validate_action` checks actions against
+    fn action_ledger_state(&self) -> LedgerState {
         match &self.state {
             RunState::Step0Active => LedgerState::Step0Active,
-            RunState::Step0GatePending | RunState::Step1GatePending | RunState::Step2GatePending => LedgerState::GatePending,
-            RunState::Step1Active => LedgerState::BaselineFrozen, // After baseline is frozen
+            state if state.is_gate_pending() => LedgerState::GatePending,
+            RunState::Step1Active => LedgerState::Normal,
+            RunState::Paused { .. } => LedgerState::HaltActive, // Paused requires decision
+            RunState::Halted { .. } => LedgerState::HaltActive,
+            _ => LedgerState::Normal,
+        }
+    }
+
+    /// The active role and the governed actions `validate_action` allows right now
+    pub fn permitted_actions(&self) -> PermittedActions {
+        let ledger_state = self.action_ledger_state();
+        PermittedActions {
+            run_id: self.run_id.clone(),
+            active_role: self.active_role.clone(),
+            permitted: self.ledger.permitted_actions(&ledger_state),
+            ledger_state,
+        }
+    }
+
+    /// Ledger entries, signals and callouts of this run in time order
+    pub fn timeline(&self) -> Vec<crate::timeline::TimelineEvent> {
+        crate::timeline::build_timeline(
+            &self.ledger.get_entries(&self.run_id),
+            &self.signal_router.get_signal_chain(&self.run_id),
+            self.callout_manager.all(),
+        )
+    }
+
+    /// Total, per-step and gate-wait durations of this run
+    pub fn timing(&self) -> crate::timeline::RunTiming {
+        crate::timeline::compute_run_timing(&self.ledger.get_entries(&self.run_id))
+    }
+
+    /// Get the current ledger state
+    pub fn get_ledger_state(&self) -> LedgerState {
+        match &self.state {
+            RunState::Step0Active => LedgerState::Step0Active,
+            state if state.is_gate_pending() => LedgerState::GatePending,
+            RunState::Step1Active => LedgerState::BaselineFrozen, // After baseline is frozen
             RunState::Step2Active => LedgerState::Normal,
             RunState::Paused { .. } => LedgerState::HaltActive, // Paused awaits human decision
             RunState::Halted { .. } => LedgerState::HaltActive,
@@ -2268,6 +4413,215 @@ impl Orchestrator {
         }
     }
 
+    /// Plan of a step with its configured display name
+    fn planned_step(&self, step: u8) -> StepDescription {
+        StepDescription {
+            name: self.step_descriptions.name(step),
+            ..planned_step(step)
+        }
+    }
+
+    /// What the next step will do and which gate it ends at, without executing anything
+    ///
+    /// While a gate is pending this describes the step after approval; paused,
+    /// halted and completed runs say why no step can start.
+    pub fn describe_next_step(&self) -> StepDescription {
+        let blocked = |name: &str, reason: String| StepDescription {
+            step: None,
+            name: name.to_string(),
+            artifacts: Vec::new(),
+            gate_signal: None,
+            required_agents: Vec::new(),
+            blocked_reason: Some(reason),
+        };
+
+        let (plan, blocked_reason) = match &self.state {
+            RunState::Step0Active => (self.planned_step(0), None),
+            RunState::Step1Active => (self.planned_step(1), None),
+            RunState::Step2Active => (self.planned_step(2), None),
+            RunState::Step3Active => (self.planned_step(3), None),
+            RunState::Step4Active => (self.planned_step(4), None),
+            RunState::Step5Active => (self.planned_step(5), None),
+            RunState::Step6Active => (self.planned_step(6), None),
+            RunState::Step6_5Active => (planned_learning_harvest(), None),
+            RunState::Step6GatePending if !self.exceptional_flag => {
+                return blocked(
+                    "Run completion",
+                    "Approve the Validation_Complete gate to finish the run".to_string(),
+                );
+            }
+            RunState::Step6GatePending => (
+                planned_learning_harvest(),
+                Some("Waiting for approval of the Validation_Complete gate".to_string()),
+            ),
+            RunState::Step0GatePending
+            | RunState::Step1GatePending
+            | RunState::Step2GatePending
+            | RunState::Step3GatePending
+            | RunState::Step4GatePending
+            | RunState::Step5GatePending => {
+                let step = self.state.step_number();
+                let gate = planned_step(step).gate_signal.unwrap_or_default();
+                (
+                    self.planned_step(step + 1),
+                    Some(format!("Waiting for approval of the {} gate", gate)),
+                )
+            }
+            RunState::Paused { reason, step, .. } => (
+                self.planned_step(*step),
+                Some(format!("Run paused at Step {}: {}", step, reason)),
+            ),
+            RunState::IASResynthesisPause { message, step, .. } => (
+                self.planned_step(*step),
+                Some(format!("IAS re-synthesis review at Step {}: {}", step, message)),
+            ),
+            RunState::Completed => {
+                return blocked("Run completed", "Run completed - no steps remain".to_string());
+            }
+            RunState::Halted { reason, .. } => {
+                return blocked("Run halted", format!("Run halted: {}", reason));
+            }
+            RunState::FutureStep(n) => {
+                return blocked("Not implemented", format!("Step {} is not implemented", n));
+            }
+        };
+
+        StepDescription { blocked_reason, ..plan }
+    }
+
+    /// The gate this run is waiting on, if it is in a `*GatePending` state
+    ///
+    /// Artifacts are the ones listed by the gate signal that opened the gate.
+    pub fn pending_gate(&self) -> Option<PendingGate> {
+        if !self.state.is_gate_pending() {
+            return None;
+        }
+
+        let step = self.state.step_number();
+        let plan = self.planned_step(step);
+        let signal = self
+            .signal_router
+            .get_signal_chain(&self.run_id)
+            .into_iter()
+            .rev()
+            .find(|signal| SignalRouter::is_gate_signal(&signal.signal_type));
+
+        Some(PendingGate {
+            run_id: self.run_id.clone(),
+            step,
+            step_name: plan.name,
+            gate: plan.gate_signal.unwrap_or_default(),
+            artifacts: signal
+                .as_ref()
+                .map(|signal| signal.payload.artifacts_produced.clone())
+                .unwrap_or_default(),
+            requested_at: signal.map(|signal| signal.timestamp.to_rfc3339()),
+        })
+    }
+
+    /// Current HALT/PAUSE status with its cause, for a blocking banner in the UI
+    ///
+    /// Only a halted or paused run reports a Halt, with the state's own reason.
+    /// A pending IAS acknowledgment is a Pause. Once a HALT was overridden with
+    /// "proceed", failing metrics at that step are reported as a non-blocking
+    /// `halt_overridden` note.
+    pub fn halt_status(&self) -> HaltStatusReport {
+        use crate::agents::governance_telemetry::MetricStatus;
+
+        let failing: Vec<&crate::agents::governance_telemetry::MetricResult> = self
+            .latest_metrics
+            .as_ref()
+            .map(|m| {
+                [&m.ci, &m.ev, &m.ias, &m.efi, &m.sec, &m.pci]
+                    .into_iter()
+                    .flatten()
+                    .filter(|r| r.status == MetricStatus::Fail)
+                    .collect()
+            })
+            .unwrap_or_default();
+        let failing_names: Vec<String> = failing.iter().map(|r| r.metric_name.clone()).collect();
+
+        match &self.state {
+            RunState::Halted { reason, .. } => HaltStatusReport {
+                status: HaltLevel::Halt,
+                cause: Some("run_halted".to_string()),
+                reason: Some(reason.clone()),
+                offending_metrics: failing_names,
+            },
+            RunState::Paused { reason, triggered_metrics, .. } => {
+                let is_resynthesis = triggered_metrics
+                    .as_ref()
+                    .and_then(|t| t.get("check_type"))
+                    .and_then(|c| c.as_str())
+                    == Some("re_synthesis_pause");
+                let mut offending: Vec<String> = triggered_metrics
+                    .as_ref()
+                    .and_then(|t| t.as_object())
+                    .map(|t| {
+                        ["ci", "ev", "ias", "efi", "sec", "pci"]
+                            .iter()
+                            .filter(|name| t.contains_key(**name))
+                            .map(|name| name.to_uppercase())
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                for name in failing_names {
+                    if !offending.contains(&name) {
+                        offending.push(name);
+                    }
+                }
+
+                HaltStatusReport {
+                    status: if is_resynthesis { HaltLevel::Pause } else { HaltLevel::Halt },
+                    cause: Some(if is_resynthesis { "re_synthesis_pause" } else { "halt_awaiting_decision" }.to_string()),
+                    reason: Some(reason.clone()),
+                    offending_metrics: offending,
+                }
+            }
+            RunState::IASResynthesisPause { message, .. } => HaltStatusReport {
+                status: HaltLevel::Pause,
+                cause: Some("re_synthesis_pause".to_string()),
+                reason: Some(message.clone()),
+                offending_metrics: vec!["IAS".to_string()],
+            },
+            _ if !failing.is_empty() && self.halt_overridden_at_current_step() => HaltStatusReport {
+                status: HaltLevel::Continue,
+                cause: Some("halt_overridden".to_string()),
+                reason: Some(
+                    failing
+                        .iter()
+                        .map(|r| format!("{} at {:.2}: {}", r.metric_name, r.value, r.interpretation))
+                        .collect::<Vec<_>>()
+                        .join("; "),
+                ),
+                offending_metrics: failing_names,
+            },
+            _ => match self.pending_ias_acknowledgment {
+                Some(ref warning) => HaltStatusReport {
+                    status: HaltLevel::Pause,
+                    cause: Some("ias_acknowledgment_required".to_string()),
+                    reason: Some(warning.message.clone()),
+                    offending_metrics: vec!["IAS".to_string()],
+                },
+                None => HaltStatusReport {
+                    status: HaltLevel::Continue,
+                    cause: None,
+                    reason: None,
+                    offending_metrics: Vec::new(),
+                },
+            },
+        }
+    }
+
+    /// Whether a HALT at the current step was overridden with "proceed"
+    fn halt_overridden_at_current_step(&self) -> bool {
+        let step = self.state.step_number() as i32;
+        self.ledger
+            .get_entries(&self.run_id)
+            .iter()
+            .any(|entry| entry.payload.action == "halt_override_proceed" && entry.step == Some(step))
+    }
+
     /// Get reference to the signal router (for testing/inspection)
     pub fn get_signal_router(&self) -> &SignalRouter {
         &self.signal_router
@@ -2278,6 +4632,22 @@ impl Orchestrator {
         &self.ledger
     }
 
+    /// STUB: Pick one of `options` for a named choice (the first when unseeded)
+    fn stub_choice<'a, T>(&self, salt: &str, options: &'a [T]) -> &'a T {
+        match self.stub_seed {
+            Some(seed) => &options[crate::api::echo::seeded_index(seed, salt, options.len())],
+            None => &options[0],
+        }
+    }
+
+    /// STUB: Creation time for stub artifacts (fixed in seeded runs)
+    fn stub_timestamp(&self) -> chrono::DateTime<Utc> {
+        match self.stub_seed {
+            Some(_) => STUB_SEEDED_TIMESTAMP.parse().expect("valid RFC 3339 timestamp"),
+            None => Utc::now(),
+        }
+    }
+
     /// STUB: Scope & Pattern Agent
     ///
     /// This is a placeholder that returns a mock intent summary.
@@ -2290,9 +4660,10 @@ impl Orchestrator {
         debug!("Steno-Ledger: {}", steno_ledger);
 
         // Create a mock IntentSummary artifact
-        let timestamp = Utc::now().format("%Y%m%d%H%M%S").to_string();
+        let now = self.stub_timestamp();
+        let timestamp = now.format("%Y%m%d%H%M%S").to_string();
         let artifact_id = format!("{}-intent-summary-{}", self.run_id, timestamp);
-        let created_at = Utc::now().to_rfc3339();
+        let created_at = now.to_rfc3339();
 
         let mut summary = IntentSummary {
             artifact_id,
@@ -2306,13 +4677,13 @@ impl Orchestrator {
             intent_anchor_link: None,
             is_immutable: false,
             author: "scope-pattern-agent-stub".to_string(),
-            governance_role: "Observer".to_string(),
+            governance_role: GovernanceRole::Observer,
             user_request: user_intent.to_string(),
             primary_goal: format!("Accomplish: {}", user_intent),
-            audience: "General users".to_string(),
+            audience: self.stub_choice("audience", &["General users", "Team leads", "Executives"]).to_string(),
             expected_outcome: format!("Successfully implement: {}", user_intent),
-            intent_category: "Operational".to_string(),
-            confidence_score: 75,
+            intent_category: self.stub_choice("intent_category", &["Operational", "Analytical", "Exploratory"]).to_string(),
+            confidence_score: *self.stub_choice("confidence_score", &[75, 65, 85]),
             confidence_explanation: "Moderate confidence - based on stub analysis".to_string(),
             request_specificity: "Medium".to_string(),
             scope_definition_clarity: "Partial".to_string(),
@@ -2336,6 +4707,239 @@ impl Orchestrator {
         Ok(summary)
     }
 
+    /// STUB: Wrap a canned body in artifact frontmatter
+    ///
+    /// The hash is computed from the body so stub artifacts are deterministic.
+    fn stub_artifact(
+        &self,
+        step: u8,
+        id_suffix: &str,
+        artifact_type: &str,
+        is_immutable: bool,
+        body: &str,
+    ) -> String {
+        format!(
+            "---\nartifact_id: \"{}-{}\"\nartifact_type: \"{}\"\nrun_id: \"{}\"\nstep_origin: {}\nhash: \"{}\"\nis_immutable: {}\nauthor: \"orchestrator-stub\"\ntemplate_version: {}\n---\n\n{}",
+            self.run_id,
+            id_suffix,
+            artifact_type,
+            self.run_id,
+            step,
+            crate::artifacts::calculate_content_hash(body),
+            is_immutable,
+            crate::artifacts::ARTIFACT_TEMPLATE_VERSION,
+            body
+        )
+    }
+
+    /// STUB: Scope & Pattern Agent charter creation (Step 1)
+    fn stub_charter(&self, intent_summary: &IntentSummary) -> String {
+        let mut body = format!(
+            "# Charter\n\n## Primary Goal\n{}\n\n## Objectives\n",
+            intent_summary.primary_goal
+        );
+        for item in &intent_summary.likely_in_scope {
+            body.push_str(&format!("- {}\n", item));
+        }
+        body.push_str("\n## Out of Scope\n");
+        for item in &intent_summary.likely_out_of_scope {
+            body.push_str(&format!("- {}\n", item));
+        }
+        body.push_str("\n## Deliverables\n- Framework definition\n");
+
+        self.stub_artifact(1, "charter", "Charter", true, &body)
+    }
+
+    /// STUB: Governance & Telemetry Agent calibration (Step 2)
+    ///
+    /// Returns (governance_summary, domain_snapshots, calibration).
+    fn stub_governance_calibration(&self, charter_hash: &str, e_baseline: f64) -> (String, String, GovernanceCalibration) {
+        let governance_summary = self.stub_artifact(2, "governance-summary", "Governance_Summary", false, &format!(
+            "# Governance Summary\n\n- Charter hash: {}\n- E_baseline: {:.2}\n- Control domains: Entropy, Objective, Process, Reflective, Termination",
+            charter_hash, e_baseline
+        ));
+        let domain_snapshots = self.stub_artifact(2, "domain-snapshots", "Domain_Snapshots", false,
+            "# Domain Snapshots\n\n| Domain | Status |\n|---|---|\n| Entropy | Nominal |\n| Objective | Nominal |\n| Process | Nominal |\n| Reflective | Nominal |\n| Termination | Nominal |",
+        );
+
+        (governance_summary, domain_snapshots, GovernanceCalibration::default())
+    }
+
+    /// STUB: Governance calibration rerun for `regenerate_artifact`
+    ///
+    /// Adds the revision number so each regeneration yields a new hash.
+    fn stub_regenerated_calibration(&self, charter_hash: &str, e_baseline: f64, revision: usize) -> (String, String, GovernanceCalibration) {
+        let governance_summary = self.stub_artifact(2, "governance-summary", "Governance_Summary", false, &format!(
+            "# Governance Summary\n\n- Charter hash: {}\n- E_baseline: {:.2}\n- Control domains: Entropy, Objective, Process, Reflective, Termination\n- Revision: {}",
+            charter_hash, e_baseline, revision
+        ));
+        let domain_snapshots = self.stub_artifact(2, "domain-snapshots", "Domain_Snapshots", false, &format!(
+            "# Domain Snapshots\n\n| Domain | Status |\n|---|---|\n| Entropy | Nominal |\n| Objective | Nominal |\n| Process | Nominal |\n| Reflective | Nominal |\n| Termination | Nominal |\n\nRevision: {}",
+            revision
+        ));
+
+        (governance_summary, domain_snapshots, GovernanceCalibration::default())
+    }
+
+    /// STUB: Analysis & Synthesis Agent six-lens analysis (Step 3)
+    fn stub_six_lens_analysis(
+        analysis_target: &str,
+        intent_category: &str,
+    ) -> (String, crate::agents::analysis_synthesis::LensEfficacyReport) {
+        use crate::agents::analysis_synthesis::{LensEfficacyReport, LensResult};
+
+        let lens_results: Vec<LensResult> = ["Structural", "Intent", "Logic", "Evidence", "Thematic", "Expression"]
+            .iter()
+            .map(|lens| LensResult {
+                lens_name: lens.to_string(),
+                analysis: format!("{} lens (stub) applied to {} chars of {} content", lens, analysis_target.len(), intent_category),
+                key_findings: vec![format!("{} finding (stub)", lens)],
+                findings_parsed_cleanly: true,
+                efficacy_score: 0.8,
+                tokens_used: 0,
+            })
+            .collect();
+
+        let mut integrated_diagnostic = String::from("# Integrated Diagnostic\n\n");
+        for result in &lens_results {
+            integrated_diagnostic.push_str(&format!("## {} Lens\n- {}\n\n", result.lens_name, result.key_findings[0]));
+        }
+
+        let report = LensEfficacyReport {
+            total_insights: lens_results.len(),
+            high_value_combinations: lens_results.len(),
+            lens_results,
+            estimated_cost: 0.0,
+            actual_cost: 0.0,
+            contradictions: vec![],
+        };
+
+        (integrated_diagnostic, report)
+    }
+
+    /// STUB: Analysis & Synthesis Agent synthesis lock-in (Step 4)
+    fn stub_step4_synthesis(&self, charter_objectives: &[String]) -> crate::agents::analysis_synthesis::Step4SynthesisResult {
+        use crate::agents::analysis_synthesis::{ModelGeometry, Step4SynthesisResult};
+
+        let (model_geometry, shape) = self
+            .stub_choice(
+                "model_geometry",
+                &[(ModelGeometry::Linear, "sequential"), (ModelGeometry::Cyclic, "iterative"), (ModelGeometry::Branching, "branching")],
+            )
+            .clone();
+
+        let objectives = if charter_objectives.is_empty() {
+            "the Charter objectives".to_string()
+        } else {
+            charter_objectives.join("; ")
+        };
+
+        let north_star_narrative = format!("The framework delivers {} through a clear, staged path.", objectives);
+
+        Step4SynthesisResult {
+            core_thesis: format!("A staged framework delivers: {}", objectives),
+            operating_principles: vec![
+                "Anchor every decision to the Charter".to_string(),
+                "Prefer sequential, reviewable increments".to_string(),
+                "Make assumptions explicit".to_string(),
+            ],
+            operating_principles_fallback: false,
+            model_geometry,
+            geometry_rationale: format!("Stub synthesis uses a {} model", shape),
+            geometry_diagram: Some("Foundations → Operating Model → Adoption Path".to_string()),
+            causal_spine: "Foundations → Operating Model → Adoption Path".to_string(),
+            causal_edges: Vec::new(),
+            feedback_loops: Vec::new(),
+            north_star_word_count: crate::content::word_count(&north_star_narrative),
+            north_star_narrative,
+            glossary: vec![GlossaryEntry {
+                term: "Framework".to_string(),
+                definition: "The structured deliverable produced by this run".to_string(),
+            }],
+            limitations: vec!["Stub synthesis - content is canned, not analyzed".to_string()],
+            novel_geometry_flag: false,
+            omitted: Vec::new(),
+        }
+    }
+
+    /// STUB: Validation & Learning Agent validation (Step 6)
+    ///
+    /// Passes every dimension without reaching the exceptional (Step 6.5) threshold.
+    fn stub_validation_result() -> crate::agents::validation_learning::ValidationResult {
+        use crate::agents::validation_learning::{
+            Critical6Scores, ValidationDimensionResult, ValidationResult, ValidationStatus,
+        };
+
+        let dimension_results = ["Logic", "Semantic", "Clarity", "Evidence", "Scope", "Process"]
+            .iter()
+            .map(|dimension| ValidationDimensionResult {
+                dimension_name: dimension.to_string(),
+                status: ValidationStatus::Pass,
+                score: 0.9,
+                findings: vec![format!("{} validation passed (stub)", dimension)],
+                failures: vec![],
+                evidence: "Stub validation".to_string(),
+            })
+            .collect();
+
+        ValidationResult {
+            overall_status: ValidationStatus::Pass,
+            dimension_results,
+            critical_6_scores: Critical6Scores {
+                ci: 0.82,
+                ev: 0.0,
+                ias: 0.85,
+                efi: 0.96,
+                sec: 1.0,
+                pci: 0.95,
+            },
+            exceptional_flag: false,
+            validation_matrix: "# Logic Validation Matrix\n\nAll reasoning chains pass (stub)".to_string(),
+            semantic_table: "# Semantic Consistency Table\n\nAll glossary terms used consistently (stub)".to_string(),
+            evidence_report: "# Evidence Audit Report\n\nAll claims substantiated (stub)".to_string(),
+        }
+    }
+
+    /// STUB: Governance & Telemetry Agent metrics
+    ///
+    /// Returns passing Critical 6 metrics; EV is 0% against the stub E_baseline.
+    fn stub_metrics(&self, step: u8) -> CriticalMetrics {
+        use crate::agents::governance_telemetry::{MetricInput, MetricInputValue, MetricResult, MetricStatus, ThresholdsConfig};
+
+        let thresholds = ThresholdsConfig::default();
+        let e_baseline = self.stub_e_baseline.unwrap_or(STUB_E_BASELINE);
+        let metric = |name: &str, value: f64, threshold: &crate::agents::governance_telemetry::MetricThreshold, inputs_used: Vec<MetricInput>| MetricResult {
+            metric_name: name.to_string(),
+            value,
+            threshold: threshold.clone(),
+            status: MetricStatus::Pass,
+            inputs_used,
+            calculation_method: "Stub (canned value)".to_string(),
+            interpretation: format!("Stub {} for step {}", name, step),
+            recommendation: None,
+        };
+
+        CriticalMetrics {
+            ci: Some(metric("CI", 0.82, &thresholds.ci, vec![])),
+            ev: Some(metric("EV", 0.0, &thresholds.ev, vec![
+                MetricInput {
+                    name: "E_current".to_string(),
+                    value: MetricInputValue::Number(e_baseline),
+                    source: "Stub".to_string(),
+                },
+                MetricInput {
+                    name: "E_baseline".to_string(),
+                    value: MetricInputValue::Number(e_baseline),
+                    source: "Stub".to_string(),
+                },
+            ])),
+            ias: Some(metric("IAS", 0.85, &thresholds.ias, vec![])),
+            efi: Some(metric("EFI", 0.96, &thresholds.efi, vec![])),
+            sec: Some(metric("SEC", 100.0, &thresholds.sec, vec![])),
+            pci: Some(metric("PCI", 0.95, &thresholds.pci, vec![])),
+        }
+    }
+
 
     /// Calculate metrics for the current step
     ///
@@ -2353,6 +4957,11 @@ impl Orchestrator {
         content: &str,
         charter_objectives: &str,
     ) -> Result<(Option<CriticalMetrics>, bool)> {
+        let scope_expansions = self.scope_expansions();
+        if let Some(agent) = self.governance_agent.as_mut() {
+            agent.set_scope_expansions(scope_expansions);
+        }
+
         if let Some(ref agent) = self.governance_agent {
             info!("Calculating metrics for step {}", self.state.step_number());
 
@@ -2437,13 +5046,119 @@ impl Orchestrator {
             // Store latest metrics
             self.latest_metrics = Some(metrics.clone());
 
+            // Record in history, replacing any earlier calculation for this step
+            self.metrics_history.retain(|(step, _)| *step != current_step);
+            self.metrics_history.push((current_step, metrics.clone()));
+
             Ok((Some(metrics), halt_triggered))
+        } else if self.stub_mode {
+            let current_step = self.state.step_number();
+            debug!("STUB: Calculating metrics for step {}", current_step);
+
+            let metrics = self.stub_metrics(current_step);
+            self.latest_metrics = Some(metrics.clone());
+            self.metrics_history.retain(|(step, _)| *step != current_step);
+            self.metrics_history.push((current_step, metrics.clone()));
+
+            Ok((Some(metrics), false))
         } else {
             debug!("Governance agent not available - skipping metrics calculation");
             Ok((None, false))
         }
     }
 
+    /// Primary output of the most advanced step completed so far
+    ///
+    /// Framework Architecture (Step 5), Core Thesis (Step 4), Integrated
+    /// Diagnostic (Step 3), Governance Summary (Step 2) or Charter (Step 1).
+    fn current_primary_output(&self) -> Option<(u8, String)> {
+        if let Some(content) = &self.framework_architecture {
+            Some((5, content.clone()))
+        } else if let Some(content) = &self.core_thesis {
+            Some((4, content.clone()))
+        } else if let Some(content) = &self.integrated_diagnostic {
+            Some((3, content.clone()))
+        } else if let Some(content) = &self.governance_summary {
+            Some((2, content.clone()))
+        } else {
+            self.charter.as_ref().map(|charter| (1, charter.to_display_markdown()))
+        }
+    }
+
+    /// Stub alignment: share of the goal's keywords that appear in the content
+    fn stub_intent_alignment(content: &str, primary_goal: &str) -> f64 {
+        let content = content.to_lowercase();
+        let keywords: Vec<String> = primary_goal
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|word| word.len() > 3)
+            .map(str::to_lowercase)
+            .collect();
+
+        if keywords.is_empty() {
+            return 1.0;
+        }
+
+        let found = keywords.iter().filter(|word| content.contains(word.as_str())).count();
+        found as f64 / keywords.len() as f64
+    }
+
+    /// Measure drift between the current step's output and the Step 0 intent
+    ///
+    /// Compares the primary output of the latest completed step against
+    /// `IntentSummary.primary_goal` using the Governance Agent's alignment
+    /// scoring, and returns drift = 1 - alignment. Drift above the configured
+    /// threshold raises a Warning callout.
+    pub async fn check_intent_drift(&mut self) -> Result<f64> {
+        let primary_goal = self
+            .intent_summary
+            .as_ref()
+            .map(|summary| summary.primary_goal.clone())
+            .ok_or_else(|| anyhow::anyhow!("No intent summary - Step 0 must be completed first"))?;
+
+        let (step, content) = self
+            .current_primary_output()
+            .ok_or_else(|| anyhow::anyhow!("No step output to compare - Step 1 must be completed first"))?;
+
+        let alignment = if let Some(ref agent) = self.governance_agent {
+            agent.score_intent_alignment(&content, &primary_goal).await?
+        } else if self.stub_mode {
+            Self::stub_intent_alignment(&content, &primary_goal)
+        } else {
+            anyhow::bail!("Governance agent not available - cannot score intent alignment");
+        };
+
+        let drift = (1.0 - alignment).clamp(0.0, 1.0);
+        info!("Intent drift at Step {}: {:.2} (threshold {:.2})", step, drift, self.intent_drift_threshold);
+
+        if drift > self.intent_drift_threshold {
+            warn!("⚠️ Intent drift {:.2} exceeds threshold {:.2}", drift, self.intent_drift_threshold);
+
+            let callout_step = Step::from_u8(step).unwrap_or(Step::Step0_Intent);
+            let mode = self
+                .mode_detection_result
+                .as_ref()
+                .map(|r| r.mode)
+                .unwrap_or(StructureMode::Refining);
+
+            self.callout_manager.add(Callout::new(
+                CalloutTier::Warning,
+                "Intent Drift",
+                drift,
+                None,
+                format!("Drift above {:.2}", self.intent_drift_threshold),
+                format!(
+                    "Step {} output has drifted from the original goal: \"{}\"",
+                    step, primary_goal
+                ),
+                "Compare the current output with the Intent Summary and refocus on the original ask",
+                callout_step,
+                mode,
+            ));
+        }
+
+        Ok(drift)
+    }
+
     /// Calculate and lock E_baseline (Step 1)
     ///
     /// This should be called after the Baseline Report is generated.
@@ -2453,6 +5168,10 @@ impl Orchestrator {
             agent.lock_e_baseline(1)?;
             info!("E_baseline calculated and locked: {}", baseline);
             Ok(baseline)
+        } else if self.stub_mode {
+            self.stub_e_baseline = Some(STUB_E_BASELINE);
+            info!("STUB: E_baseline locked: {}", STUB_E_BASELINE);
+            Ok(STUB_E_BASELINE)
         } else {
             Err(anyhow::anyhow!(
                 "Governance agent not available - cannot calculate E_baseline"
@@ -2465,6 +5184,141 @@ impl Orchestrator {
         self.governance_agent
             .as_ref()
             .and_then(|agent| agent.get_e_baseline())
+            .or(self.stub_e_baseline)
+    }
+
+    /// Guard Step 3 against trivially short analysis targets
+    ///
+    /// Below `min_analysis_chars` an Attention callout is raised, and the six
+    /// lenses only run when the caller confirms with `force`; otherwise the step
+    /// fails with `AnalysisTargetTooShort` and the run stays at Step 3.
+    fn check_analysis_target_length(&mut self, analysis_target: &str, force: bool) -> Result<()> {
+        let chars = analysis_target.trim().chars().count();
+        if chars >= self.min_analysis_chars {
+            return Ok(());
+        }
+
+        warn!(
+            "Analysis target is {} chars, below the {}-char minimum (force: {})",
+            chars, self.min_analysis_chars, force
+        );
+        let mode = self
+            .mode_detection_result
+            .as_ref()
+            .map(|r| r.mode)
+            .unwrap_or(StructureMode::Refining);
+        self.callout_manager.add(Callout::new(
+            CalloutTier::Attention,
+            "Content Length",
+            chars as f64,
+            None,
+            format!("Minimum {} chars for analysis", self.min_analysis_chars),
+            format!(
+                "The analysis target is only {} characters - six-lens analysis of it is likely to be noise",
+                chars
+            ),
+            "Add the full content to analyze, or confirm to analyze it as is",
+            Step::Step3_Diagnostic,
+            mode,
+        ));
+
+        if force {
+            return Ok(());
+        }
+        Err(AnalysisTargetTooShort { chars, min_chars: self.min_analysis_chars }.into())
+    }
+
+    /// Enforce the Baseline_Frozen contract before governance calibration
+    ///
+    /// Step 2 thresholds are set against an immutable E_baseline. One that was
+    /// calculated but not locked is locked now or rejected, per
+    /// `with_unlocked_baseline_policy`. The stub baseline is always frozen.
+    fn ensure_e_baseline_locked(&mut self) -> Result<()> {
+        let Some(agent) = self.governance_agent.as_mut() else {
+            return Ok(());
+        };
+        if agent.is_e_baseline_locked() {
+            return Ok(());
+        }
+
+        match self.unlocked_baseline_policy {
+            UnlockedBaselinePolicy::Reject => anyhow::bail!(
+                "E_baseline is not locked - Step 2 requires the baseline frozen at Step 1"
+            ),
+            UnlockedBaselinePolicy::Lock => {
+                agent.lock_e_baseline(2)?;
+                warn!("E_baseline was not locked entering Step 2 - locked now");
+
+                let payload = LedgerPayload {
+                    action: "e_baseline_locked".to_string(),
+                    inputs: Some(serde_json::json!({
+                        "e_baseline": agent.get_e_baseline(),
+                        "policy": "Lock",
+                    })),
+                    outputs: None,
+                    rationale: Some("E_baseline was unlocked entering Step 2 - locked before governance calibration".to_string()),
+                };
+                self.ledger.create_entry(
+                    &self.run_id,
+                    EntryType::Decision,
+                    Some(2),
+                    Some("Conductor"),
+                    payload,
+                );
+                Ok(())
+            }
+        }
+    }
+
+    /// Compare the metrics calculated at two steps
+    pub fn diff_metrics(&self, step_a: u8, step_b: u8) -> Result<Vec<crate::agents::governance_telemetry::MetricDelta>> {
+        let snapshot = |step: u8| {
+            self.metrics_history
+                .iter()
+                .find(|(s, _)| *s == step)
+                .map(|(_, metrics)| metrics)
+                .ok_or_else(|| anyhow::anyhow!("No metrics snapshot for Step {} in run {}", step, self.run_id))
+        };
+
+        Ok(snapshot(step_a)?.diff(snapshot(step_b)?))
+    }
+
+    /// EV (expansion variance) at each step, in step order
+    ///
+    /// Steps where EV was not computed (e.g. Step 0/1 before E_baseline lock) are omitted.
+    pub fn ev_trend(&self) -> Vec<(u8, f64)> {
+        self.ev_trend_points()
+            .into_iter()
+            .map(|point| (point.step, point.ev))
+            .collect()
+    }
+
+    /// EV trend with the E_current/E_baseline values behind each point
+    pub fn ev_trend_points(&self) -> Vec<EvTrendPoint> {
+        use crate::agents::governance_telemetry::MetricInputValue;
+
+        let mut points: Vec<EvTrendPoint> = self
+            .metrics_history
+            .iter()
+            .filter_map(|(step, metrics)| {
+                let ev = metrics.ev.as_ref()?;
+                let input = |name: &str| {
+                    ev.inputs_used.iter().find(|i| i.name == name).and_then(|i| match i.value {
+                        MetricInputValue::Number(n) => Some(n),
+                        _ => None,
+                    })
+                };
+                Some(EvTrendPoint {
+                    step: *step,
+                    ev: ev.value,
+                    e_current: input("E_current"),
+                    e_baseline: input("E_baseline"),
+                })
+            })
+            .collect();
+
+        points.sort_by_key(|point| point.step);
+        points
     }
 
     /// Acknowledge IAS Warning (FIX-024)
@@ -2533,13 +5387,14 @@ impl Orchestrator {
     ///
     /// # Returns
     /// A tuple of (integrated_diagnostic_id, lens_efficacy_report_id)
-    pub async fn execute_step_3(&mut self) -> Result<(String, String)> {
+    pub async fn execute_step_3(&mut self, force: bool) -> Result<(String, String)> {
         info!("=== Executing Step 3: Multi-Angle Analysis ===");
 
         // Validate state
         if !matches!(self.state, RunState::Step3Active) {
             anyhow::bail!("Cannot execute Step 3 - current state: {:?}", self.state);
         }
+        self.validate_prerequisites(3)?;
 
         // Ensure we have required artifacts from Steps 0 and 1
         let charter_data = self.charter.as_ref()
@@ -2549,7 +5404,7 @@ impl Orchestrator {
             .ok_or_else(|| anyhow::anyhow!("No Intent Summary available - Step 0 must be completed first"))?;
 
         // Validate analysis_synthesis_agent is configured
-        if self.analysis_synthesis_agent.is_none() {
+        if self.analysis_synthesis_agent.is_none() && !self.stub_mode {
             anyhow::bail!("Analysis & Synthesis Agent not configured");
         }
 
@@ -2570,16 +5425,20 @@ impl Orchestrator {
         // Get intent category (clone to avoid borrow issues)
         let intent_category = intent_summary.intent_category.clone();
 
+        self.check_analysis_target_length(&analysis_target, force)?;
+
         info!("Step 3: Performing six-lens analysis...");
         info!("  Intent category: {}", intent_category);
         info!("  Analysis target size: {} chars", analysis_target.len());
         info!("  Governance context size: {} chars", governance_context.len());
 
         // Perform six-lens analysis with BOTH inputs
-        let agent = self.analysis_synthesis_agent.as_mut().unwrap();
-        let (integrated_diagnostic, lens_efficacy) = agent
-            .perform_six_lens_analysis(&analysis_target, &governance_context, &intent_category)
-            .await?;
+        let (integrated_diagnostic, lens_efficacy) = match self.analysis_synthesis_agent.as_mut() {
+            Some(agent) => agent
+                .perform_six_lens_analysis(&analysis_target, &governance_context, &intent_category)
+                .await?,
+            None => Self::stub_six_lens_analysis(&analysis_target, &intent_category),
+        };
 
         let integrated_diagnostic_id = format!("{}-integrated-diagnostic", self.run_id);
         let lens_efficacy_report_id = format!("{}-lens-efficacy-report", self.run_id);
@@ -2595,6 +5454,11 @@ impl Orchestrator {
 
         info!("Six-lens analysis artifacts stored");
 
+        if !lens_efficacy.contradictions.is_empty() {
+            info!("  Contradictions across lenses: {}", lens_efficacy.contradictions.len());
+            self.persist_contradictions(&lens_efficacy.contradictions);
+        }
+
         // Calculate metrics
         info!("Step 3: Calculating metrics...");
         let (metrics, halt_triggered) = self.calculate_metrics(&analysis_target, &governance_context).await?;
@@ -2639,7 +5503,7 @@ impl Orchestrator {
                 "total_insights": lens_efficacy.total_insights,
                 "high_value_combinations": lens_efficacy.high_value_combinations,
             })),
-            rationale: Some("Six-lens analysis complete, integrated diagnostic created".to_string()),
+            rationale: Some(self.step_descriptions.rationale(3, StepRationale::Completed)),
         };
 
         self.ledger.create_entry(
@@ -2682,7 +5546,7 @@ impl Orchestrator {
             outputs: Some(serde_json::json!({
                 "signal_hash": signal.hash,
             })),
-            rationale: Some("Step 3 complete, six-lens analysis complete, awaiting human approval to proceed".to_string()),
+            rationale: Some(self.step_descriptions.rationale(3, StepRationale::GateRequested)),
         };
 
         self.ledger.create_entry(
@@ -2693,6 +5557,8 @@ impl Orchestrator {
             payload,
         );
 
+        self.persist_step_artifacts(3);
+
         // Transition to gate pending state
         self.state = RunState::Step3GatePending;
 
@@ -2723,13 +5589,14 @@ impl Orchestrator {
         if !matches!(self.state, RunState::Step4Active) {
             anyhow::bail!("Cannot execute Step 4 - current state: {:?}", self.state);
         }
+        self.validate_prerequisites(4)?;
 
         // Ensure we have required artifacts from Step 3
         let _integrated_diagnostic = self.integrated_diagnostic.as_ref()
             .ok_or_else(|| anyhow::anyhow!("No Integrated Diagnostic available - Step 3 must be completed first"))?;
 
         // Validate analysis_synthesis_agent is configured
-        if self.analysis_synthesis_agent.is_none() {
+        if self.analysis_synthesis_agent.is_none() && !self.stub_mode {
             anyhow::bail!("Analysis & Synthesis Agent not configured");
         }
 
@@ -2741,8 +5608,11 @@ impl Orchestrator {
             .ok_or_else(|| anyhow::anyhow!("No Charter available"))?;
         let charter_content = charter_data.to_display_markdown();
 
-        // Get objectives directly from CharterData
-        let charter_objectives = charter_data.objectives.clone();
+        // Parse objectives the same way IAS and get_charter_objectives do
+        let mut charter_objectives = crate::governance::parse_charter_objectives(&charter_content);
+        if charter_objectives.is_empty() {
+            charter_objectives = charter_data.objectives.clone();
+        }
 
         // Check that analysis findings relate to Charter objectives
         // This prevents synthesizing based on wrong analysis target (e.g., Charter itself)
@@ -2836,10 +5706,12 @@ impl Orchestrator {
         info!("Step 4: Performing synthesis lock-in...");
 
         // Perform Step 4 synthesis (agent already has integrated diagnostic from Step 3)
-        let agent = self.analysis_synthesis_agent.as_mut().unwrap();
-        let synthesis_result = agent
-            .perform_step4_synthesis()
-            .await?;
+        let synthesis_result = match self.analysis_synthesis_agent.as_mut() {
+            Some(agent) => agent
+                .perform_step4_synthesis()
+                .await?,
+            None => self.stub_step4_synthesis(&charter_objectives),
+        };
 
         let core_thesis_id = format!("{}-core-thesis", self.run_id);
         let operating_principles_id = format!("{}-operating-principles", self.run_id);
@@ -2860,11 +5732,21 @@ impl Orchestrator {
         if synthesis_result.novel_geometry_flag {
             info!("⚠ Novel geometry flagged for Learning Harvest");
         }
+        if !synthesis_result.omitted.is_empty() {
+            info!("Intentionally omitted: {}", synthesis_result.omitted.join(", "));
+        }
 
         // Store artifacts
         self.core_thesis = Some(synthesis_result.core_thesis.clone());
         self.operating_principles = Some(synthesis_result.operating_principles.join("\n"));
-        self.model_geometry = Some(format!("{:?}: {}", synthesis_result.model_geometry, synthesis_result.geometry_rationale));
+        self.model_geometry = Some(match synthesis_result.geometry_diagram {
+            Some(ref diagram) => format!(
+                "{:?}: {}\n\nMODEL DIAGRAM:\n{}",
+                synthesis_result.model_geometry, synthesis_result.geometry_rationale, diagram
+            ),
+            None => format!("{:?}: {}", synthesis_result.model_geometry, synthesis_result.geometry_rationale),
+        });
+        self.geometry_diagram = synthesis_result.geometry_diagram.clone();
         self.causal_spine = Some(synthesis_result.causal_spine.clone());
         self.north_star_narrative = Some(synthesis_result.north_star_narrative.clone());
         self.glossary = Some(serde_json::to_string_pretty(&synthesis_result.glossary)?);
@@ -2910,7 +5792,8 @@ impl Orchestrator {
 
         // Calculate metrics
         info!("Step 4: Calculating metrics...");
-        let charter_data = self.charter.as_ref().unwrap();
+        let charter_data = self.charter.as_ref()
+            .ok_or_else(|| anyhow::anyhow!("No Charter available"))?;
         let charter_content = charter_data.to_display_markdown();
 
         // Use the north star narrative as the output for metrics
@@ -3087,8 +5970,9 @@ impl Orchestrator {
                 "glossary_count": synthesis_result.glossary.len(),
                 "limitations_count": synthesis_result.limitations.len(),
                 "novel_geometry": synthesis_result.novel_geometry_flag,
+                "omitted": synthesis_result.omitted,
             })),
-            rationale: Some("Synthesis complete, model locked, ready for redesign".to_string()),
+            rationale: Some(self.step_descriptions.rationale(4, StepRationale::Completed)),
         };
 
         self.ledger.create_entry(
@@ -3136,7 +6020,7 @@ impl Orchestrator {
             outputs: Some(serde_json::json!({
                 "signal_hash": signal.hash,
             })),
-            rationale: Some("Step 4 complete, synthesis locked, awaiting human approval to proceed".to_string()),
+            rationale: Some(self.step_descriptions.rationale(4, StepRationale::GateRequested)),
         };
 
         self.ledger.create_entry(
@@ -3147,6 +6031,8 @@ impl Orchestrator {
             payload,
         );
 
+        self.persist_step_artifacts(4);
+
         // Transition to gate pending state
         self.state = RunState::Step4GatePending;
 
@@ -3173,13 +6059,14 @@ impl Orchestrator {
         if !matches!(self.state, RunState::Step5Active) {
             anyhow::bail!("Cannot execute Step 5 - current state: {:?}", self.state);
         }
+        self.validate_prerequisites(5)?;
 
         // Ensure we have required artifacts from Step 4
         let core_thesis = self.core_thesis.as_ref()
             .ok_or_else(|| anyhow::anyhow!("No Core Thesis available - Step 4 must be completed first"))?;
 
         // Validate structure_agent is configured
-        if self.structure_agent.is_none() {
+        if self.structure_agent.is_none() && !self.stub_mode {
             anyhow::bail!("Structure & Redesign Agent not configured");
         }
 
@@ -3205,10 +6092,15 @@ impl Orchestrator {
         );
 
         // Call Structure & Redesign Agent (reuse from Step 1)
-        let agent = self.structure_agent.as_mut().unwrap();
-        let framework_architecture = agent
-            .create_framework_architecture(&self.run_id, core_thesis, &synthesis)
-            .await?;
+        let framework_architecture = match self.structure_agent.as_mut() {
+            Some(agent) => agent
+                .create_framework_architecture(&self.run_id, core_thesis, &synthesis)
+                .await?,
+            None => self.stub_artifact(5, "framework-architecture", "Framework_Architecture", false, &format!(
+                "# Framework Architecture\n\n## Core Thesis\n{}\n\n## Section Map\n1. Foundations\n2. Operating Model\n3. Adoption Path",
+                core_thesis
+            )),
+        };
 
         let framework_architecture_id = format!("{}-framework-architecture", self.run_id);
 
@@ -3221,7 +6113,8 @@ impl Orchestrator {
 
         // Calculate metrics
         info!("Step 5: Calculating metrics...");
-        let charter_data = self.charter.as_ref().unwrap();
+        let charter_data = self.charter.as_ref()
+            .ok_or_else(|| anyhow::anyhow!("No Charter available"))?;
         let charter_content = charter_data.to_display_markdown();
 
         // Use the framework architecture as the output for metrics
@@ -3321,7 +6214,7 @@ impl Orchestrator {
             outputs: Some(serde_json::json!({
                 "framework_architecture_id": framework_architecture_id,
             })),
-            rationale: Some("Framework architecture complete, ready for implementation".to_string()),
+            rationale: Some(self.step_descriptions.rationale(5, StepRationale::Completed)),
         };
 
         self.ledger.create_entry(
@@ -3363,7 +6256,7 @@ impl Orchestrator {
             outputs: Some(serde_json::json!({
                 "signal_hash": signal.hash,
             })),
-            rationale: Some("Step 5 complete, framework ready, awaiting human approval to proceed to validation".to_string()),
+            rationale: Some(self.step_descriptions.rationale(5, StepRationale::GateRequested)),
         };
 
         self.ledger.create_entry(
@@ -3374,6 +6267,8 @@ impl Orchestrator {
             payload,
         );
 
+        self.persist_step_artifacts(5);
+
         // Transition to gate pending state
         self.state = RunState::Step5GatePending;
 
@@ -3404,6 +6299,7 @@ impl Orchestrator {
         if !matches!(self.state, RunState::Step6Active) {
             anyhow::bail!("Cannot execute Step 6 - current state: {:?}", self.state);
         }
+        self.validate_prerequisites(6)?;
 
         // Ensure we have required artifacts from Step 5
         let framework_content = self.framework_architecture.as_ref()
@@ -3423,7 +6319,7 @@ impl Orchestrator {
             .ok_or_else(|| anyhow::anyhow!("No Architecture Map available - Step 1 must be completed first"))?;
 
         // Validate validation_agent is configured
-        if self.validation_agent.is_none() {
+        if self.validation_agent.is_none() && !self.stub_mode {
             anyhow::bail!("Validation & Learning Agent not configured");
         }
 
@@ -3436,18 +6332,20 @@ impl Orchestrator {
         let steno_ledger = self.generate_steno_ledger();
 
         // Call Validation & Learning Agent
-        let agent = self.validation_agent.as_mut().unwrap();
-        let mut validation_result = agent
-            .validate_framework(
-                &self.run_id,
-                framework_content,
-                &charter_objectives_content,
-                core_thesis,
-                glossary,
-                architecture_map,
-                &steno_ledger,
-            )
-            .await?;
+        let mut validation_result = match self.validation_agent.as_mut() {
+            Some(agent) => agent
+                .validate_framework(
+                    &self.run_id,
+                    framework_content,
+                    &charter_objectives_content,
+                    core_thesis,
+                    glossary,
+                    architecture_map,
+                    &steno_ledger,
+                )
+                .await?,
+            None => Self::stub_validation_result(),
+        };
 
         info!("✓ Validation complete");
 
@@ -3455,10 +6353,13 @@ impl Orchestrator {
         // The validation agent's evidence_audit uses weak regex parsing and can produce
         // false positives. Use the same strict JSON-based calculation as Steps 2-5.
         info!("Calculating EFI using consistent governance method...");
-        let governance_metrics = self.governance_agent.as_ref()
-            .ok_or_else(|| anyhow::anyhow!("Governance agent not available"))?
-            .calculate_metrics(framework_content, &charter_objectives_content, 6)
-            .await?;
+        let governance_metrics = match self.governance_agent.as_ref() {
+            Some(agent) => agent
+                .calculate_metrics(framework_content, &charter_objectives_content, 6)
+                .await?,
+            None if self.stub_mode => self.stub_metrics(6),
+            None => anyhow::bail!("Governance agent not available"),
+        };
 
         let governance_efi = governance_metrics.efi.as_ref()
             .ok_or_else(|| anyhow::anyhow!("EFI not calculated in governance metrics"))?;
@@ -3586,7 +6487,8 @@ impl Orchestrator {
                 "exceptional_flag": validation_result.exceptional_flag,
             })),
             rationale: Some(format!(
-                "Validation complete. Outcome: {}. Critical 6 all pass: {}",
+                "{}. Outcome: {}. Critical 6 all pass: {}",
+                self.step_descriptions.rationale(6, StepRationale::Completed),
                 outcome,
                 scores.all_pass()
             )),
@@ -3640,7 +6542,7 @@ impl Orchestrator {
             outputs: Some(serde_json::json!({
                 "signal_hash": signal.hash,
             })),
-            rationale: Some("Step 6 complete, validation results ready, awaiting human approval".to_string()),
+            rationale: Some(self.step_descriptions.rationale(6, StepRationale::GateRequested)),
         };
 
         self.ledger.create_entry(
@@ -3651,6 +6553,8 @@ impl Orchestrator {
             payload,
         );
 
+        self.persist_step_artifacts(6);
+
         // Transition to gate pending state
         self.state = RunState::Step6GatePending;
 
@@ -3693,11 +6597,10 @@ impl Orchestrator {
         info!("  Failure patterns: {}", harvest_result.failure_count);
         info!("  Optimization patterns: {}", harvest_result.optimization_count);
 
-        // TODO: Update knowledge repository with pattern cards
-        // For MVP, we'll log the patterns
         for card in &harvest_result.pattern_cards {
             info!("  Pattern: {} ({})", card.pattern_name, card.category);
         }
+        self.persist_pattern_cards(&harvest_result.pattern_cards);
 
         // Record Step 6.5 completion in ledger
         let payload = LedgerPayload {
@@ -3762,6 +6665,7 @@ impl Orchestrator {
 
         // Transition directly to Completed (no gate for Step 6.5)
         self.state = RunState::Completed;
+        self.emit_run_completed();
 
         info!("Step 6.5 complete - run finished");
         info!("State: {:?}", self.state);
@@ -3863,9 +6767,10 @@ impl Orchestrator {
             trail.push(AuditEntry {
                 timestamp: signal.timestamp.to_rfc3339(),
                 entry_type: "Signal".to_string(),
-                description: format!("{:?}", signal.signal_type),
+                description: signal.external_name.clone(),
                 metadata: serde_json::json!({
                     "signal_type": format!("{:?}", signal.signal_type),
+                    "external_name": signal.external_name,
                     "hash": signal.hash,
                 }),
             });
@@ -3967,7 +6872,10 @@ impl Orchestrator {
             total_gates: 0, // Simplified - would count gate signals
             steps_completed: self.state.step_number() as usize,
             exceptional_run: self.exceptional_flag,
-            halt_count: 0, // Would need to track HALT conditions
+            halt_count: self
+                .signal_router
+                .signals_of_type(&self.run_id, &SignalType::Halt)
+                .len(),
         }
     }
 
@@ -4126,49 +7034,250 @@ pub struct ArchivedArtifact {
     pub size_bytes: usize,
 }
 
-/// Final run statistics
+/// An artifact whose declared parent or content hash could not be verified
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct RunStatistics {
-    pub total_signals: usize,
-    pub total_gates: usize,
-    pub steps_completed: usize,
-    pub exceptional_run: bool,
-    pub halt_count: usize,
+pub struct LineageBreak {
+    pub artifact_id: String,
+    pub artifact_type: crate::artifacts::ArtifactType,
+    /// Declared parent hash (empty if the artifact declares none)
+    pub parent_hash: String,
+    pub reason: String,
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_orchestrator_creation() {
-        let orch = Orchestrator::new("test-run");
+/// Result of `Orchestrator::verify_lineage`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LineageReport {
+    /// Artifacts whose parent_hash was checked
+    pub checked: usize,
+    /// Artifacts whose content was re-hashed against their stored hash
+    pub content_checked: usize,
+    /// Artifacts that declare no parent_hash
+    pub unchecked: Vec<String>,
+    pub breaks: Vec<LineageBreak>,
+}
 
-        assert!(orch.run_id.contains("test-run"));
-        assert!(matches!(orch.state, RunState::Step0Active));
-        assert!(matches!(orch.active_role, Role::Observer));
-        assert!(matches!(orch.mode, Mode::Standard));
+impl LineageReport {
+    pub fn is_intact(&self) -> bool {
+        self.breaks.is_empty()
     }
+}
 
-    #[test]
-    fn test_run_id_format() {
-        let orch = Orchestrator::new("Analysis");
-        let date = Utc::now().format("%Y-%m-%d").to_string();
-        let expected = format!("{}-Analysis", date);
+/// Result of `Orchestrator::permitted_actions`, for enabling UI controls
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PermittedActions {
+    pub run_id: String,
+    pub active_role: Role,
+    pub ledger_state: LedgerState,
+    /// Governed actions (see `GOVERNED_ACTIONS`) currently allowed
+    pub permitted: Vec<String>,
+}
 
-        assert_eq!(orch.run_id, expected);
-    }
+/// Data needed to start a run that follows on from another
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ForkSeed {
+    /// Run the new run follows on from
+    pub forked_from: String,
+    /// The source run's original request, to replay at Step 0
+    pub user_intent: String,
+    /// Charter the new run starts from
+    pub charter_content: String,
+}
 
-    #[test]
-    fn test_steno_ledger_generation() {
-        let orch = Orchestrator::new("test");
-        let steno = orch.generate_steno_ledger();
+/// Result of `Orchestrator::propose_charter_revision`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CharterRevisionProposal {
+    pub source_run_id: String,
+    pub current_charter_hash: String,
+    pub proposed_charter_hash: String,
+    /// Why the Charter was not edited in place
+    pub refusal_reason: String,
+    pub fork_seed: ForkSeed,
+}
 
-        assert!(steno.contains("RUN:"));
-        assert!(steno.contains("S:0"));
-        assert!(steno.contains("R:OBS"));
-        assert!(steno.contains("M:STD"));
-    }
+/// Full artifact content for the UI, as returned by get_run_artifacts
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunArtifact {
+    pub artifact_id: String,
+    pub artifact_type: crate::artifacts::ArtifactType,
+    /// Immutable artifacts (Critical Path, Architecture and any configured types) are locked for editing
+    pub is_immutable: bool,
+    pub content: String,
+    pub hash: String,
+    /// Content for the UI: frontmatter shown as a header line (see `render_display`)
+    #[serde(default)]
+    pub display: String,
+}
+
+impl RunArtifact {
+    /// Render the artifact as markdown with YAML frontmatter
+    pub fn to_markdown(&self, run_id: &str) -> String {
+        format!(
+            "---\nartifact_id: \"{}\"\nartifact_type: \"{}\"\nrun_id: \"{}\"\nhash: \"{}\"\nis_immutable: {}\n---\n\n{}\n",
+            self.artifact_id, self.artifact_type.to_frontmatter_str(), run_id, self.hash, self.is_immutable, self.content
+        )
+    }
+}
+
+/// Preview of a step: what it produces, the gate it emits and the agents it needs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StepDescription {
+    /// Step number (6 for Step 6.5); None when no step can follow
+    pub step: Option<u8>,
+    pub name: String,
+    pub artifacts: Vec<crate::artifacts::ArtifactType>,
+    /// Gate signal emitted when the step completes
+    pub gate_signal: Option<String>,
+    pub required_agents: Vec<String>,
+    /// Why the step cannot start yet (pending gate, pause, halt)
+    pub blocked_reason: Option<String>,
+}
+
+/// Reject an empty or whitespace-only value before it reaches the ledger
+///
+/// Gate decisions must name who made them (and why, for rejections).
+fn require_named(field: &str, value: &str) -> Result<()> {
+    if value.trim().is_empty() {
+        anyhow::bail!("Gate decision needs a non-empty {}", field);
+    }
+    Ok(())
+}
+
+/// Static plan for Steps 0-6, as executed by the `execute_step_*` methods
+fn planned_step(step: u8) -> StepDescription {
+    use crate::artifacts::ArtifactType::*;
+
+    let (artifacts, gate, agents) = match step {
+        0 => (vec![IntentSummary], "Ready_for_Step_1", vec!["Scope & Pattern"]),
+        1 => (
+            vec![IntentAnchor, Charter, BaselineReport, ArchitectureMap],
+            "Baseline_Frozen",
+            vec!["Scope & Pattern", "Governance & Telemetry", "Structure & Redesign"],
+        ),
+        2 => (vec![GovernanceSummary], "Ready_for_Analysis", vec!["Governance & Telemetry"]),
+        3 => (
+            vec![DiagnosticSummary, LensEfficacyReport],
+            "Ready_for_Synthesis",
+            vec!["Analysis & Synthesis"],
+        ),
+        4 => (
+            vec![CoreThesis, CausalSpineDraft, Glossary],
+            "Ready_for_Redesign",
+            vec!["Analysis & Synthesis"],
+        ),
+        5 => (vec![FrameworkDraft], "Ready_for_Validation", vec!["Structure & Redesign"]),
+        _ => (vec![ValidationReport], "Validation_Complete", vec!["Validation & Learning"]),
+    };
+
+    StepDescription {
+        step: Some(step.min(6)),
+        name: StepDescriptions::default().name(step),
+        artifacts,
+        gate_signal: Some(gate.to_string()),
+        required_agents: agents.into_iter().map(String::from).collect(),
+        blocked_reason: None,
+    }
+}
+
+fn planned_learning_harvest() -> StepDescription {
+    StepDescription {
+        step: Some(6),
+        name: "Step 6.5: Learning Harvest".to_string(),
+        artifacts: vec![crate::artifacts::ArtifactType::PatternCard],
+        gate_signal: Some("Learning_Harvested".to_string()),
+        required_agents: vec!["Validation & Learning".to_string()],
+        blocked_reason: None,
+    }
+}
+
+/// Whether a run may continue, is paused for review, or is halted
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum HaltLevel {
+    Continue,
+    Pause,
+    Halt,
+}
+
+/// A gate awaiting human approval, as listed in the review queue
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PendingGate {
+    pub run_id: String,
+    pub step: u8,
+    pub step_name: String,
+    /// Gate signal awaiting approval (e.g. "Baseline_Frozen")
+    pub gate: String,
+    /// Artifacts the step produced for review
+    pub artifacts: Vec<String>,
+    /// When the gate signal was emitted (RFC 3339)
+    pub requested_at: Option<String>,
+}
+
+/// HALT/PAUSE status of a run and what caused it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HaltStatusReport {
+    pub status: HaltLevel,
+    /// Machine-readable cause (e.g. "run_halted", "halt_overridden")
+    pub cause: Option<String>,
+    /// Human-readable explanation for the banner
+    pub reason: Option<String>,
+    /// Metric names that triggered the status (e.g. "CI")
+    pub offending_metrics: Vec<String>,
+}
+
+/// Single point on the EV (expansion-over-time) trend
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EvTrendPoint {
+    pub step: u8,
+    /// EV variance percentage at this step
+    pub ev: f64,
+    /// Content entropy at this step (E_current), for absolute growth
+    pub e_current: Option<f64>,
+    /// Locked E_baseline the variance was measured against
+    pub e_baseline: Option<f64>,
+}
+
+/// Final run statistics
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunStatistics {
+    pub total_signals: usize,
+    pub total_gates: usize,
+    pub steps_completed: usize,
+    pub exceptional_run: bool,
+    pub halt_count: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_orchestrator_creation() {
+        let orch = Orchestrator::new("test-run");
+
+        assert!(orch.run_id.contains("test-run"));
+        assert!(matches!(orch.state, RunState::Step0Active));
+        assert!(matches!(orch.active_role, Role::Observer));
+        assert!(matches!(orch.mode, Mode::Standard));
+    }
+
+    #[test]
+    fn test_run_id_format() {
+        let orch = Orchestrator::new("Analysis");
+        let date = Utc::now().format("%Y-%m-%d").to_string();
+        let expected = format!("{}-Analysis", date);
+
+        assert_eq!(orch.run_id, expected);
+    }
+
+    #[test]
+    fn test_steno_ledger_generation() {
+        let orch = Orchestrator::new("test");
+        let steno = orch.generate_steno_ledger();
+
+        assert!(steno.contains("RUN:"));
+        assert!(steno.contains("S:0"));
+        assert!(steno.contains("R:OBS"));
+        assert!(steno.contains("M:STD"));
+    }
 
     #[tokio::test]
     async fn test_step_0_execution() {
@@ -4205,6 +7314,93 @@ mod tests {
         assert!(matches!(orch.active_role, Role::Conductor));
     }
 
+    #[tokio::test]
+    async fn test_unexpected_role_transition_is_recorded() {
+        let mut orch = Orchestrator::new("test");
+        orch.execute_step_0("Test intent").await.unwrap();
+
+        // A correct Step 0 gate records nothing
+        orch.approve_gate("Human Reviewer").unwrap();
+        let violations = |orch: &Orchestrator| {
+            orch.ledger
+                .get_entries(&orch.run_id)
+                .iter()
+                .filter(|e| e.payload.action == "role_transition_invalid")
+                .count()
+        };
+        assert_eq!(violations(&orch), 0);
+
+        // Entering Step 3 hands over from the Conductor; simulate a refactor that
+        // left another role active before the Step 2 gate
+        orch.state = RunState::Step2GatePending;
+        orch.active_role = Role::Patcher;
+        assert!(orch.approve_gate("Human Reviewer").unwrap());
+        assert_eq!(violations(&orch), 1);
+        assert!(matches!(orch.state, RunState::Step3Active));
+    }
+
+    #[test]
+    fn test_describe_next_step() {
+        use crate::artifacts::ArtifactType;
+
+        let mut orch = Orchestrator::new("test");
+        orch.state = RunState::Step2Active;
+
+        let next = orch.describe_next_step();
+        assert_eq!(next.step, Some(2));
+        assert_eq!(next.name, "Step 2: Governance Calibration");
+        assert_eq!(next.artifacts, vec![ArtifactType::GovernanceSummary]);
+        assert_eq!(next.gate_signal.as_deref(), Some("Ready_for_Analysis"));
+        assert_eq!(next.required_agents, vec!["Governance & Telemetry"]);
+        assert!(next.blocked_reason.is_none());
+
+        orch.state = RunState::Step2GatePending;
+        let next = orch.describe_next_step();
+        assert_eq!(next.step, Some(3));
+        assert!(next.blocked_reason.unwrap().contains("Ready_for_Analysis"));
+
+        orch.state = RunState::Completed;
+        let next = orch.describe_next_step();
+        assert_eq!(next.step, None);
+        assert!(next.blocked_reason.unwrap().contains("completed"));
+    }
+
+    #[tokio::test]
+    async fn test_custom_step_descriptions_in_ledger() {
+        use crate::config::StepLabel;
+
+        let descriptions = StepDescriptions::default().with_step(2, StepLabel {
+            name: Some("Schritt 2: Governance-Kalibrierung".to_string()),
+            gate_approved: Some("Kalibrierung freigegeben".to_string()),
+            ..StepLabel::default()
+        });
+        let mut orch = Orchestrator::new("custom-text")
+            .with_stubs()
+            .with_auto_approval(None)
+            .with_step_descriptions(descriptions);
+
+        orch.run_to_completion("Design a customer onboarding framework").await.unwrap();
+
+        let entries = orch.get_ledger().get_entries(&orch.run_id);
+        let rationale = |step: i32, action: &str| {
+            entries
+                .iter()
+                .find(|e| e.step == Some(step) && e.payload.action == action)
+                .and_then(|e| e.payload.rationale.clone())
+                .unwrap()
+        };
+        assert_eq!(rationale(2, "gate_approved"), "Kalibrierung freigegeben");
+        // Fields and steps without an override keep the English text
+        assert_eq!(
+            rationale(2, "gate_signal_emitted"),
+            "Step 2 complete, governance calibrated, awaiting human approval to proceed"
+        );
+        assert_eq!(rationale(1, "gate_approved"), "Human approved baseline freeze - ready to proceed to Step 2");
+
+        orch.state = RunState::Step2Active;
+        assert_eq!(orch.describe_next_step().name, "Schritt 2: Governance-Kalibrierung");
+    }
+
     #[tokio::test]
     async fn test_gate_rejection() {
         let mut orch = Orchestrator::new("test");
@@ -4262,6 +7458,36 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[tokio::test]
+    async fn test_permitted_actions_at_pending_gate() {
+        let mut orch = Orchestrator::new("test").with_stubs();
+        orch.execute_step_0("Design a customer onboarding framework").await.unwrap();
+
+        let actions = orch.permitted_actions();
+        assert_eq!(actions.ledger_state, LedgerState::GatePending);
+        assert_eq!(actions.active_role, Role::Observer);
+        assert!(actions.permitted.contains(&"human_approve".to_string()));
+        assert!(actions.permitted.contains(&"human_reject".to_string()));
+        assert!(!actions.permitted.contains(&"agent_progression".to_string()));
+        for action in &actions.permitted {
+            assert!(orch.validate_action(action).unwrap());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_permitted_actions_at_step_3_gate() {
+        let mut orch = drive_stub_workflow(Orchestrator::new("test").with_stubs(), 3).await;
+        orch.execute_step_3(true).await.unwrap();
+        assert!(matches!(orch.state, RunState::Step3GatePending));
+
+        let actions = orch.permitted_actions();
+        assert_eq!(actions.ledger_state, LedgerState::GatePending);
+        assert_eq!(orch.get_ledger_state(), LedgerState::GatePending);
+        assert!(actions.permitted.contains(&"human_approve".to_string()));
+        assert!(!actions.permitted.contains(&"agent_progression".to_string()));
+        assert!(orch.validate_action("agent_progression").is_err());
+    }
+
     #[tokio::test]
     async fn test_ledger_recording() {
         let mut orch = Orchestrator::new("test");
@@ -4292,4 +7518,1269 @@ mod tests {
         assert!(matches!(signal.signal_type, SignalType::ReadyForStep1));
         assert!(signal.payload.gate_required);
     }
+
+    fn stub_artifact(run_id: &str, id_suffix: &str, artifact_type: &str, body: &str) -> String {
+        format!(
+            "---\nartifact_id: \"{}-{}\"\nartifact_type: \"{}\"\nrun_id: \"{}\"\nstep_origin: 1\nhash: \"{}\"\nis_immutable: true\n---\n\n{}",
+            run_id,
+            id_suffix,
+            artifact_type,
+            run_id,
+            crate::artifacts::calculate_content_hash(body),
+            body
+        )
+    }
+
+    #[tokio::test]
+    async fn test_collect_artifacts_after_step_1() {
+        use crate::artifacts::ArtifactType;
+
+        let mut orch = Orchestrator::new("test");
+        orch.execute_step_0("Test intent").await.unwrap();
+        orch.approve_gate("Human Reviewer").unwrap();
+
+        // Stub Step 1 completion (no API calls)
+        let run_id = orch.run_id.clone();
+        orch.intent_anchor = Some(stub_artifact(&run_id, "intent-anchor", "Intent_Anchor", "# Intent Anchor"));
+        orch.charter = Some(crate::governance::CharterData {
+            hash: "charter-hash".to_string(),
+            primary_goal: "Test goal".to_string(),
+            objectives: vec!["Objective one".to_string()],
+            expected_artifacts: vec![],
+            success_criteria_state: "Defined".to_string(),
+            created_at: Utc::now(),
+        });
+        orch.baseline_report = Some(stub_artifact(&run_id, "baseline-report", "Baseline_Report", "# Baseline"));
+        orch.architecture_map = Some(stub_artifact(&run_id, "architecture-map", "Architecture_Map", "# Architecture"));
+        orch.state = RunState::Step1GatePending;
+
+        let artifacts = orch.collect_artifacts();
+        assert_eq!(artifacts.len(), 4);
+
+        let types: Vec<ArtifactType> = artifacts.iter().map(|a| a.artifact_type.clone()).collect();
+        assert_eq!(
+            types,
+            vec![
+                ArtifactType::IntentAnchor,
+                ArtifactType::Charter,
+                ArtifactType::BaselineReport,
+                ArtifactType::ArchitectureMap,
+            ]
+        );
+        assert!(artifacts.iter().all(|a| a.is_immutable));
+
+        let anchor = &artifacts[0];
+        assert_eq!(anchor.artifact_id, format!("{}-intent-anchor", run_id));
+        assert_eq!(anchor.content, "# Intent Anchor");
+        assert_eq!(anchor.hash, crate::artifacts::calculate_content_hash("# Intent Anchor"));
+        assert_eq!(artifacts[1].hash, "charter-hash");
+    }
+
+    fn ev_metrics(e_current: f64, e_baseline: f64) -> CriticalMetrics {
+        use crate::agents::governance_telemetry::{MetricInput, MetricInputValue, MetricResult, MetricStatus, MetricThreshold};
+
+        CriticalMetrics {
+            ci: None,
+            ev: Some(MetricResult {
+                metric_name: "EV".to_string(),
+                value: ((e_current - e_baseline).abs() / e_baseline) * 100.0,
+                threshold: MetricThreshold { pass: 10.0, warning: Some(20.0), halt: Some(30.0) },
+                status: MetricStatus::Pass,
+                inputs_used: vec![
+                    MetricInput {
+                        name: "E_current".to_string(),
+                        value: MetricInputValue::Number(e_current),
+                        source: "Current Content".to_string(),
+                    },
+                    MetricInput {
+                        name: "E_baseline".to_string(),
+                        value: MetricInputValue::Number(e_baseline),
+                        source: "Baseline Report".to_string(),
+                    },
+                ],
+                calculation_method: String::new(),
+                interpretation: String::new(),
+                recommendation: None,
+            }),
+            ias: None,
+            efi: None,
+            sec: None,
+            pci: None,
+        }
+    }
+
+    #[test]
+    fn test_diff_metrics_requires_both_snapshots() {
+        let mut orch = Orchestrator::new("test");
+        orch.metrics_history.push((3, ev_metrics(105.0, 100.0)));
+        orch.metrics_history.push((5, ev_metrics(120.0, 100.0)));
+
+        let diff = orch.diff_metrics(3, 5).unwrap();
+        assert_eq!(diff.len(), 1);
+        assert_eq!(diff[0].metric, "EV");
+
+        let err = orch.diff_metrics(3, 4).unwrap_err();
+        assert!(err.to_string().contains("No metrics snapshot for Step 4"));
+    }
+
+    #[tokio::test]
+    async fn test_unapproved_scope_expansion_lowers_sec() {
+        let content = "# Onboarding Guide\n\nNew hires shadow a mentor for a week.";
+        let mut agent = GovernanceTelemetryAgent::from_client(crate::api::AnthropicClient::echo().unwrap());
+        agent.calculate_e_baseline(content, 1).await.unwrap();
+        let mut orch = Orchestrator::new("scope-expansion").with_governance_agent(agent);
+
+        let count = orch.record_scope_expansion("Add contractor onboarding", true).unwrap();
+        assert_eq!(count, ScopeExpansionCount { approved: 1, unapproved: 0 });
+        let (metrics, _) = orch.calculate_metrics(content, "Objectives: shorten onboarding").await.unwrap();
+        assert_eq!(metrics.unwrap().sec.unwrap().value, 100.0);
+
+        orch.record_scope_expansion("Cover offboarding too", false).unwrap();
+        assert_eq!(orch.scope_expansions(), ScopeExpansionCount { approved: 1, unapproved: 1 });
+        let (metrics, _) = orch.calculate_metrics(content, "Objectives: shorten onboarding").await.unwrap();
+        assert_eq!(metrics.unwrap().sec.unwrap().value, 50.0);
+
+        assert!(orch.record_scope_expansion("  ", true).is_err());
+    }
+
+    #[test]
+    fn test_ev_trend_from_metrics_history() {
+        let mut orch = Orchestrator::new("test");
+
+        // Step 1 metrics without EV (before E_baseline lock) are omitted
+        let mut step_1 = ev_metrics(100.0, 100.0);
+        step_1.ev = None;
+        orch.metrics_history.push((1, step_1));
+        orch.metrics_history.push((3, ev_metrics(150.0, 100.0)));
+        orch.metrics_history.push((2, ev_metrics(110.0, 100.0)));
+        orch.metrics_history.push((4, ev_metrics(200.0, 100.0)));
+
+        let trend = orch.ev_trend();
+        assert_eq!(trend.len(), 3);
+        assert_eq!(trend.iter().map(|(step, _)| *step).collect::<Vec<_>>(), vec![2, 3, 4]);
+        assert!((trend[0].1 - 10.0).abs() < 1e-9);
+        assert!((trend[2].1 - 100.0).abs() < 1e-9);
+
+        let points = orch.ev_trend_points();
+        assert_eq!(points[1].e_current, Some(150.0));
+        assert_eq!(points[1].e_baseline, Some(100.0));
+    }
+
+    #[tokio::test]
+    async fn test_open_circuit_breaker_halts_run() {
+        use crate::api::test_server::TestServer;
+        use crate::config::{AppConfig, RunConfig};
+        use base64::Engine;
+
+        let config = AppConfig {
+            anthropic_api_key: Some(base64::prelude::BASE64_STANDARD.encode("test-key")),
+            circuit_failure_threshold: 1,
+            ..AppConfig::default()
+        };
+        let orch = Orchestrator::new("circuit-open")
+            .with_stubs()
+            .with_run_config(RunConfig::from(&config));
+        let mut orch = drive_stub_workflow(orch, 3).await;
+
+        // Earlier failures opened the run's breaker
+        let server = TestServer::echo(std::time::Duration::ZERO).await;
+        let client = config
+            .run_api_client(orch.run_config.as_ref().unwrap())
+            .unwrap()
+            .with_api_url(server.messages_url());
+        client.circuit_breaker().record_failure();
+        orch = orch.with_analysis_synthesis_agent(AnalysisSynthesisAgent::from_client(client));
+
+        let err = orch.execute_active_step(true).await.unwrap_err();
+        assert!(err.chain().any(|e| e.is::<crate::api::CircuitOpen>()), "unexpected error: {:#}", err);
+        assert!(server.log().requests.is_empty());
+        assert!(matches!(orch.state, RunState::Halted { cause: HaltCause::ApiUnavailable { step: 3 }, .. }));
+        assert!(orch.ledger.get_entries(&orch.run_id).iter().any(|e| e.payload.action == "circuit_open_halt"));
+        assert!(orch.override_halt("Reviewer", "API is back").is_err());
+
+        // Once the API recovers the step is retried from the last gate
+        orch.rollback_to_last_gate().unwrap();
+        assert!(matches!(orch.state, RunState::Step3Active));
+    }
+
+    #[test]
+    fn test_calibrated_thresholds_flag_applies_to_new_runs() {
+        use crate::config::AppConfig;
+
+        let default_run = crate::commands::step0::new_run(&AppConfig::default(), "2025-01-01-Default", None);
+        assert!(!default_run.apply_calibrated_thresholds);
+
+        let config = AppConfig {
+            calibrated_thresholds: true,
+            ..AppConfig::default()
+        };
+        let calibrated_run = crate::commands::step0::new_run(&config, "2025-01-01-Calibrated", None);
+        assert!(calibrated_run.apply_calibrated_thresholds);
+        assert!(calibrated_run.run_config.as_ref().unwrap().calibrated_thresholds);
+    }
+
+    #[tokio::test]
+    async fn test_run_config_is_frozen_at_step_0() {
+        use crate::api::test_server::TestServer;
+        use crate::config::{AppConfig, RunConfig};
+        use base64::Engine;
+
+        let (dir, db_path) = temp_db("run-config");
+
+        let mut config = AppConfig {
+            anthropic_api_key: Some(base64::prelude::BASE64_STANDARD.encode("test-key")),
+            default_model: "claude-model-a".to_string(),
+            ..AppConfig::default()
+        };
+        config.metric_enforcement.efi = vec![3, 6];
+        let frozen = RunConfig::from(&config);
+        let orch = Orchestrator::new("run-config")
+            .with_stubs()
+            .with_run_config(frozen.clone())
+            .with_artifact_persistence(db_path.clone());
+        let mut orch = drive_stub_workflow(orch, 3).await;
+        assert!(matches!(orch.state, RunState::Step3Active));
+
+        // Settings change globally before Step 3
+        config.default_model = "claude-model-b".to_string();
+        config.metric_enforcement = crate::governance::MetricEnforcementSchedule::default();
+        config.metric_thresholds.ci.pass = 0.95;
+
+        // Step 3's agents are built the way execute_step_3 builds them
+        let server = TestServer::echo(std::time::Duration::ZERO).await;
+        let run_config = orch.run_config.clone().unwrap();
+        let client = config.run_api_client(&run_config).unwrap().with_api_url(server.messages_url());
+        let governance = crate::commands::step0::governance_agent(crate::api::AnthropicClient::echo().unwrap(), &run_config);
+        assert_eq!(governance.get_thresholds().ci.pass, frozen.metric_thresholds.ci.pass);
+        assert_eq!(run_config.metric_enforcement.efi, vec![3, 6]);
+        orch = orch.with_analysis_synthesis_agent(AnalysisSynthesisAgent::from_client(client));
+        orch.execute_active_step(true).await.unwrap();
+
+        let requests = &server.log().requests;
+        assert!(!requests.is_empty(), "Step 3 made no calls");
+        assert!(requests.iter().all(|request| request["model"] == "claude-model-a"), "Step 3 used the global model");
+
+        // Stored with the run once its artifacts are persisted
+        let conn = crate::database::open_connection(&db_path).unwrap();
+        assert_eq!(crate::database::runs::get_run_config(&conn, &orch.run_id).unwrap(), Some(frozen));
+
+        drop(conn);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[tokio::test]
+    async fn test_glossary_is_stored_when_step_4_gate_is_approved() {
+        let (dir, db_path) = temp_db("glossary");
+        let stored_terms = |run_id: &str| {
+            let conn = crate::database::open_connection(&db_path).unwrap();
+            conn.query_row("SELECT COUNT(*) FROM glossary_terms WHERE source_run_id = ?1", [run_id], |row| {
+                row.get::<_, i64>(0)
+            })
+            .unwrap()
+        };
+
+        let orch = Orchestrator::new("glossary-gate")
+            .with_stubs()
+            .with_artifact_persistence(db_path.clone());
+        let mut orch = drive_stub_workflow(orch, 4).await;
+        orch.execute_step_4().await.unwrap();
+        assert!(matches!(orch.state, RunState::Step4GatePending));
+
+        // Generated but not yet approved
+        let glossary: Vec<crate::agents::GlossaryEntry> =
+            serde_json::from_str(orch.glossary.as_ref().unwrap()).unwrap();
+        assert!(!glossary.is_empty());
+        assert_eq!(stored_terms(&orch.run_id), 0);
+
+        orch.approve_gate("Human Reviewer").unwrap();
+        assert_eq!(stored_terms(&orch.run_id), glossary.len() as i64);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[tokio::test]
+    async fn test_verify_lineage_of_echo_run_is_intact() {
+        let config = crate::config::AppConfig { provider: "echo".to_string(), ..crate::config::AppConfig::default() };
+        let run_config = crate::config::RunConfig::from(&config);
+        let client = || config.run_api_client(&run_config).unwrap();
+        let orch = Orchestrator::new("echo-lineage")
+            .with_stubs()
+            .with_scope_agent(ScopePatternAgent::new(client()))
+            .with_governance_agent(GovernanceTelemetryAgent::from_client(client()))
+            .with_structure_agent(StructureRedesignAgent::from_client(client()))
+            .with_analysis_synthesis_agent(AnalysisSynthesisAgent::from_client(client()));
+
+        let orch = drive_stub_workflow(orch, 5).await;
+
+        // Agent-written artifacts declare parents through their dependencies
+        let report = orch.verify_lineage().unwrap();
+        assert!(report.is_intact(), "unexpected breaks: {:?}", report.breaks);
+        assert!(report.checked >= 3);
+        assert!(report.content_checked >= 3);
+
+        // A fork re-hashes the Charter and relinks the artifacts derived from it
+        let fork = orch.fork("echo-lineage-fork", 2).unwrap();
+        let charter_hash = &fork.charter.as_ref().unwrap().hash;
+        assert_ne!(charter_hash, &orch.charter.as_ref().unwrap().hash);
+        let baseline = fork.baseline_report.as_ref().unwrap();
+        assert_eq!(Orchestrator::frontmatter_value(baseline, "parent_hash").as_ref(), Some(charter_hash));
+        let report = fork.verify_lineage().unwrap();
+        assert!(report.is_intact(), "unexpected breaks: {:?}", report.breaks);
+    }
+
+    #[tokio::test]
+    async fn test_verify_lineage_localizes_tampered_parent() {
+        fn with_parent(text: &str, parent_hash: &str, parent_id: &str) -> String {
+            text.replacen(
+                "\nhash: ",
+                &format!(
+                    "\nparent_hash: \"{}\"\ndependencies:\n- {{ artifact_id: \"{}\", relationship: \"derived_from\" }}\nhash: ",
+                    parent_hash, parent_id
+                ),
+                1,
+            )
+        }
+
+        let mut orch = run_stub_workflow("lineage").await;
+        let id_of = |orch: &Orchestrator, artifact_type: crate::artifacts::ArtifactType| {
+            orch.collect_artifacts()
+                .into_iter()
+                .find(|a| a.artifact_type == artifact_type)
+                .map(|a| (a.artifact_id, a.hash))
+                .unwrap()
+        };
+        let (charter_id, charter_hash) = id_of(&orch, crate::artifacts::ArtifactType::Charter);
+        let (baseline_id, baseline_hash) = id_of(&orch, crate::artifacts::ArtifactType::BaselineReport);
+        let (_, thesis_hash) = id_of(&orch, crate::artifacts::ArtifactType::CoreThesis);
+
+        // Stub artifacts declare no parents; link Step 1 and 2 artifacts as the agents do
+        orch.baseline_report = Some(with_parent(orch.baseline_report.as_ref().unwrap(), &charter_hash, &charter_id));
+        orch.architecture_map = Some(with_parent(orch.architecture_map.as_ref().unwrap(), &charter_hash, &charter_id));
+        orch.governance_summary = Some(with_parent(orch.governance_summary.as_ref().unwrap(), &baseline_hash, &baseline_id));
+        let linked = orch.governance_summary.clone();
+
+        let report = orch.verify_lineage().unwrap();
+        assert!(report.is_intact(), "unexpected breaks: {:?}", report.breaks);
+        assert_eq!(report.checked, 3);
+        assert!(report.unchecked.iter().any(|id| id.ends_with("-core-thesis")));
+
+        let single_break = |orch: &Orchestrator| {
+            let report = orch.verify_lineage().unwrap();
+            assert_eq!(report.breaks.len(), 1, "breaks: {:?}", report.breaks);
+            report.breaks.into_iter().next().unwrap()
+        };
+        let repoint = |orch: &mut Orchestrator, parent_hash: &str| {
+            orch.governance_summary = Some(linked.as_ref().unwrap().replacen(
+                &format!("parent_hash: \"{}\"", baseline_hash),
+                &format!("parent_hash: \"{}\"", parent_hash),
+                1,
+            ));
+        };
+
+        // A parent hash that names nothing in the run
+        let tampered = "f".repeat(64);
+        repoint(&mut orch, &tampered);
+        let broken = single_break(&orch);
+        assert_eq!(broken.artifact_type, crate::artifacts::ArtifactType::GovernanceSummary);
+        assert_eq!(broken.parent_hash, tampered);
+        assert_eq!(broken.reason, "parent_hash matches no artifact in this run");
+
+        // A parent re-pointed at a real artifact the summary does not derive from
+        repoint(&mut orch, &thesis_hash);
+        let broken = single_break(&orch);
+        assert_eq!(broken.artifact_type, crate::artifacts::ArtifactType::GovernanceSummary);
+        assert_eq!(broken.reason, "parent_hash is not an artifact reached through its dependencies");
+
+        // Content edited after hashing, with the stale hash left in place
+        orch.governance_summary = linked;
+        let baseline = orch.baseline_report.clone().unwrap();
+        let (frontmatter, body) = baseline.rsplit_once("\n---").unwrap();
+        orch.baseline_report = Some(format!("{}\n---{}\nEdited after hashing.\n", frontmatter, body));
+        let broken = single_break(&orch);
+        assert_eq!(broken.artifact_type, crate::artifacts::ArtifactType::BaselineReport);
+        assert_eq!(broken.reason, "content does not match its stored hash");
+    }
+
+    #[tokio::test]
+    async fn test_html_report_for_completed_stub_run() {
+        let orch = run_stub_workflow("html-report").await;
+        assert!(matches!(orch.state, RunState::Completed));
+
+        let html = orch.to_html_report();
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(!html.contains("<script"));
+
+        let thesis = orch
+            .collect_artifacts()
+            .into_iter()
+            .find(|a| a.artifact_type == crate::artifacts::ArtifactType::CoreThesis)
+            .unwrap();
+        let first_line = thesis.content.lines().find(|l| !l.trim().is_empty()).unwrap().trim();
+        assert!(html.contains("<h2>Core Thesis</h2>"));
+        assert!(html.contains(&first_line.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")));
+
+        assert!(html.contains("<div class=\"mermaid\">"));
+        assert!(html.contains("flowchart TD"));
+    }
+
+    async fn run_stub_workflow(label: &str) -> Orchestrator {
+        drive_stub_workflow(Orchestrator::new(label).with_stubs(), 7).await
+    }
+
+    /// Drive a stub-mode orchestrator from Step 0, approving each gate, until Step `stop_at` is active
+    ///
+    /// `stop_at` counts as `RunState::step_number` does, so 7 runs through to Completed.
+    async fn drive_stub_workflow(mut orch: Orchestrator, stop_at: u8) -> Orchestrator {
+        if stop_at == 0 {
+            return orch;
+        }
+
+        orch.execute_step_0("Design a customer onboarding framework").await.unwrap();
+        assert!(matches!(orch.state, RunState::Step0GatePending));
+        orch.approve_gate("Human Reviewer").unwrap();
+
+        while orch.state.step_number() < stop_at {
+            let step = orch.state.step_number();
+            orch.execute_active_step(true).await.unwrap();
+            assert!(
+                orch.state.is_gate_pending() && orch.state.step_number() == step,
+                "Step {} did not reach its gate: {:?}",
+                step,
+                orch.state
+            );
+            orch.approve_gate("Human Reviewer").unwrap();
+        }
+
+        orch
+    }
+
+    /// Create a temp directory holding a fresh database for persistence tests
+    ///
+    /// Returns the directory, which the test removes, and the database path inside it.
+    fn temp_db(label: &str) -> (std::path::PathBuf, std::path::PathBuf) {
+        let dir = std::env::temp_dir().join(format!("method-vi-{}-{}", label, uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let db_path = dir.join("method-vi.db");
+        let conn = rusqlite::Connection::open(&db_path).unwrap();
+        crate::database::schema::create_schema(&conn).unwrap();
+        (dir, db_path)
+    }
+
+    #[tokio::test]
+    async fn test_stub_mode_end_to_end() {
+        let orch = run_stub_workflow("stub-e2e").await;
+
+        assert!(matches!(orch.state, RunState::Completed));
+        assert!(!orch.exceptional_flag);
+        assert_eq!(orch.get_e_baseline(), Some(STUB_E_BASELINE));
+        assert!(orch.check_required_deliverables().is_ok());
+
+        // Metrics recorded at Steps 2-5
+        let steps: Vec<u8> = orch.metrics_history.iter().map(|(step, _)| *step).collect();
+        assert_eq!(steps, vec![2, 3, 4, 5]);
+        assert!(orch.ev_trend().iter().all(|(_, ev)| *ev == 0.0));
+
+        let artifacts = orch.collect_artifacts();
+        assert!(artifacts.iter().any(|a| a.artifact_id == format!("{}-framework-architecture", orch.run_id)));
+
+        // Stub output is deterministic for the same input
+        let again = run_stub_workflow("stub-e2e").await;
+        assert_eq!(orch.charter.as_ref().unwrap().hash, again.charter.as_ref().unwrap().hash);
+        assert_eq!(orch.core_thesis, again.core_thesis);
+        assert_eq!(orch.framework_architecture, again.framework_architecture);
+    }
+
+    #[tokio::test]
+    async fn test_run_completed_signal_carries_final_metrics() {
+        let (dir, db_path) = temp_db("run-completed");
+
+        let orch = Orchestrator::new("run-completed")
+            .with_stubs()
+            .with_signal_persistence(db_path.clone());
+        let orch = drive_stub_workflow(orch, 7).await;
+        assert!(matches!(orch.state, RunState::Completed));
+
+        let chain = orch.get_signal_router().get_signal_chain(&orch.run_id);
+        let completed = chain.last().unwrap();
+        assert_eq!(completed.signal_type, SignalType::RunCompleted);
+        assert!(!completed.payload.gate_required);
+
+        let scorecard = completed.payload.metrics_snapshot.as_ref().unwrap();
+        assert_eq!(
+            scorecard["final_metrics"],
+            serde_json::to_value(&orch.latest_metrics).unwrap()
+        );
+        assert_eq!(scorecard["final_metrics"]["ci"]["metric_name"], "CI");
+        assert_eq!(scorecard["validation"]["outcome"], "PASS");
+        assert_eq!(scorecard["total_cost_usd"], 0.0);
+        assert!(scorecard["callouts_by_tier"].is_object());
+
+        // Persisted with the rest of the chain
+        let conn = rusqlite::Connection::open(&db_path).unwrap();
+        let persisted = SignalRouter::new().load_chain(&conn, &orch.run_id).unwrap();
+        assert_eq!(persisted.last().unwrap().signal_type, SignalType::RunCompleted);
+        assert_eq!(persisted.last().unwrap().hash, completed.hash);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[tokio::test]
+    async fn test_step_artifacts_persisted_per_step() {
+        let (dir, db_path) = temp_db("artifacts");
+
+        let orch = Orchestrator::new("run-artifacts")
+            .with_stubs()
+            .with_artifact_persistence(db_path.clone());
+        let orch = drive_stub_workflow(orch, 7).await;
+
+        let conn = rusqlite::Connection::open(&db_path).unwrap();
+        let persisted = crate::database::artifacts::list_artifacts_by_run(&conn, &orch.run_id).unwrap();
+        assert_eq!(persisted.len(), orch.collect_artifacts().len());
+        assert_eq!(persisted[0].step_origin, 1);
+        assert!(persisted.iter().any(|a| a.r#type == "CoreThesis" && a.step_origin == 4));
+
+        let charter = persisted.iter().find(|a| a.r#type == "Charter").unwrap();
+        assert!(charter.is_immutable);
+        let content = std::fs::read_to_string(charter.content_path.as_ref().unwrap()).unwrap();
+        assert!(!content.is_empty());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[tokio::test]
+    async fn test_regenerate_governance_summary_in_step_2() {
+        use crate::artifacts::ArtifactType;
+
+        let (dir, db_path) = temp_db("regenerate");
+        let stored_hash = |orch: &Orchestrator| {
+            let conn = crate::database::open_connection(&db_path).unwrap();
+            let row = crate::database::artifacts::list_artifacts_by_run(&conn, &orch.run_id)
+                .unwrap()
+                .into_iter()
+                .find(|a| a.r#type == "GovernanceSummary")
+                .unwrap();
+            let in_memory = orch.collect_artifacts().into_iter().find(|a| a.artifact_id == row.id).unwrap();
+            let content = std::fs::read_to_string(row.content_path.as_ref().unwrap()).unwrap();
+            assert_eq!(content, in_memory.content);
+            row.hash
+        };
+
+        let orch = Orchestrator::new("regenerate")
+            .with_stubs()
+            .with_artifact_persistence(db_path.clone());
+        let mut orch = drive_stub_workflow(orch, 2).await;
+        orch.execute_step_2().await.unwrap();
+        assert!(matches!(orch.state, RunState::Step2GatePending));
+
+        let original = orch.extract_hash_from_artifact(orch.governance_summary.as_ref().unwrap()).unwrap();
+        let regenerated = orch.regenerate_artifact(ArtifactType::GovernanceSummary).await.unwrap();
+        assert_ne!(regenerated, original);
+        assert_eq!(
+            orch.extract_hash_from_artifact(orch.governance_summary.as_ref().unwrap()).unwrap(),
+            regenerated
+        );
+        assert_eq!(stored_hash(&orch), regenerated);
+
+        let again = orch.regenerate_artifact(ArtifactType::GovernanceSummary).await.unwrap();
+        assert_ne!(again, regenerated);
+        assert_eq!(orch.regeneration_count(&ArtifactType::GovernanceSummary), 2);
+
+        let entry = orch.get_ledger().get_entries(&orch.run_id).into_iter()
+            .rfind(|e| e.payload.action == "artifact_regenerated")
+            .unwrap();
+        assert_eq!(entry.payload.inputs.as_ref().unwrap()["previous_hash"], regenerated.as_str());
+
+        // Immutable types are refused
+        let err = orch.regenerate_artifact(ArtifactType::Charter).await.unwrap_err();
+        assert!(err.to_string().contains("immutable"));
+        assert!(matches!(orch.state, RunState::Step2GatePending));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[tokio::test]
+    async fn test_regenerate_domain_snapshots_in_step_2() {
+        use crate::artifacts::ArtifactType;
+
+        let (dir, db_path) = temp_db("regenerate-snapshots");
+        let orch = Orchestrator::new("regenerate-snapshots")
+            .with_stubs()
+            .with_artifact_persistence(db_path.clone());
+        let mut orch = drive_stub_workflow(orch, 2).await;
+        orch.execute_step_2().await.unwrap();
+
+        let original = orch.extract_hash_from_artifact(orch.domain_snapshots.as_ref().unwrap()).unwrap();
+        let regenerated = orch.regenerate_artifact(ArtifactType::DomainSnapshots).await.unwrap();
+        assert_ne!(regenerated, original);
+        assert_eq!(
+            orch.extract_hash_from_artifact(orch.domain_snapshots.as_ref().unwrap()).unwrap(),
+            regenerated
+        );
+        assert_eq!(orch.regeneration_count(&ArtifactType::DomainSnapshots), 1);
+
+        let conn = crate::database::open_connection(&db_path).unwrap();
+        let row = crate::database::artifacts::list_artifacts_by_run(&conn, &orch.run_id)
+            .unwrap()
+            .into_iter()
+            .find(|a| a.r#type == "DomainSnapshots")
+            .unwrap();
+        assert_eq!(row.hash, regenerated);
+
+        // Only while the Step 2 gate is pending
+        orch.approve_gate("Reviewer").unwrap();
+        let err = orch.regenerate_artifact(ArtifactType::DomainSnapshots).await.unwrap_err();
+        assert!(err.to_string().contains("Domain_Snapshots can only be regenerated"));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[tokio::test]
+    async fn test_regenerate_reapplies_calibrated_thresholds() {
+        use crate::api::test_server::TestServer;
+        use crate::artifacts::ArtifactType;
+
+        let orch = Orchestrator::new("regenerate-thresholds")
+            .with_stubs()
+            .with_calibrated_thresholds();
+        let mut orch = drive_stub_workflow(orch, 2).await;
+        orch.execute_step_2().await.unwrap();
+
+        let calibration = "## 2. OBJECTIVE CONTROL\nObjective: Stay aligned\nThresholds:\n- Target IAS ≥ 0.91\n";
+        let server = TestServer::fixed(
+            serde_json::json!({
+                "id": "msg_test",
+                "type": "message",
+                "role": "assistant",
+                "content": [{"type": "text", "text": calibration}],
+                "model": "test-model",
+                "stop_reason": "end_turn",
+                "usage": {"input_tokens": 1, "output_tokens": 1},
+            })
+            .to_string(),
+        )
+        .await;
+        orch = orch.with_governance_agent(GovernanceTelemetryAgent::from_client(server.client()));
+        assert_ne!(orch.governance_agent.as_ref().unwrap().get_thresholds().ias.pass, 0.91);
+
+        orch.regenerate_artifact(ArtifactType::GovernanceSummary).await.unwrap();
+        assert_eq!(orch.governance_agent.as_ref().unwrap().get_thresholds().ias.pass, 0.91);
+        assert_eq!(orch.governance_calibration.as_ref().unwrap().objective.objective, "Stay aligned");
+    }
+
+    #[test]
+    fn test_halt_status_reports_ci_failure() {
+        use crate::agents::governance_telemetry::MetricStatus;
+
+        let mut orch = Orchestrator::new("halt-status").with_stubs();
+        assert_eq!(orch.halt_status().status, HaltLevel::Continue);
+
+        let mut metrics = orch.stub_metrics(3);
+        let ci = metrics.ci.as_mut().unwrap();
+        ci.value = 0.42;
+        ci.status = MetricStatus::Fail;
+        ci.interpretation = "Content lacks logical flow".to_string();
+        orch.latest_metrics = Some(metrics);
+
+        // Failing metrics alone do not block: only a halted or paused run does
+        assert_eq!(orch.halt_status().status, HaltLevel::Continue);
+
+        orch.state = RunState::Paused {
+            reason: "CI below threshold".to_string(),
+            step: 3,
+            triggered_metrics: Some(serde_json::json!({ "ci": 0.42 })),
+            all_metrics_snapshot: None,
+        };
+        let report = orch.halt_status();
+        assert_eq!(report.status, HaltLevel::Halt);
+        assert_eq!(report.cause.as_deref(), Some("halt_awaiting_decision"));
+        assert_eq!(report.offending_metrics, vec!["CI"]);
+
+        // After "proceed" the gate is pending again and the banner is only a note
+        orch.handle_halt_decision("proceed", "Human Reviewer", "Flow is acceptable for a draft").unwrap();
+        assert!(matches!(orch.state, RunState::Step3GatePending));
+        let report = orch.halt_status();
+        assert_eq!(report.status, HaltLevel::Continue);
+        assert_eq!(report.cause.as_deref(), Some("halt_overridden"));
+        assert!(report.reason.as_ref().unwrap().starts_with("CI at 0.42"));
+        assert_eq!(report.offending_metrics, vec!["CI"]);
+
+        orch.state = RunState::Halted {
+            reason: "Aborted by reviewer".to_string(),
+            cause: HaltCause::MetricThreshold { step: 3 },
+        };
+        let report = orch.halt_status();
+        assert_eq!(report.cause.as_deref(), Some("run_halted"));
+        assert_eq!(report.reason.as_deref(), Some("Aborted by reviewer"));
+        assert_eq!(report.offending_metrics, vec!["CI"]);
+    }
+
+    #[tokio::test]
+    async fn test_rollback_to_last_gate_after_step_3_halt() {
+        use crate::agents::governance_telemetry::MetricStatus;
+
+        let mut orch = Orchestrator::new("rollback").with_stubs();
+        assert!(orch.rollback_to_last_gate().is_err());
+
+        let mut orch = drive_stub_workflow(orch, 3).await;
+        orch.execute_step_3(true).await.unwrap();
+        assert!(orch.integrated_diagnostic.is_some());
+
+        // CI fails at Step 3, the reviewer halts the run
+        let mut metrics = orch.stub_metrics(3);
+        let ci = metrics.ci.as_mut().unwrap();
+        ci.value = 0.42;
+        ci.status = MetricStatus::Fail;
+        orch.metrics_history.push((3, metrics.clone()));
+        orch.latest_metrics = Some(metrics);
+        orch.state = RunState::Paused {
+            reason: "CI below threshold".to_string(),
+            step: 3,
+            triggered_metrics: Some(serde_json::json!({"ci": 0.42})),
+            all_metrics_snapshot: None,
+        };
+        // A pause is decided first
+        assert!(orch.rollback_to_last_gate().is_err());
+        orch.handle_halt_decision("abort", "Human", "Analysis incoherent").unwrap();
+        assert!(matches!(orch.state, RunState::Halted { .. }));
+        orch.callout_manager.add(Callout::new(
+            CalloutTier::Critical, "CI", 0.42, None, "", "", "",
+            Step::Step3_Diagnostic, StructureMode::Builder,
+        ));
+        assert!(!orch.callout_manager.can_proceed());
+
+        orch.rollback_to_last_gate().unwrap();
+        assert!(matches!(orch.state, RunState::Step3Active));
+        assert!(orch.callout_manager.can_proceed());
+        assert_eq!(orch.active_role, Role::Observer);
+        assert!(orch.integrated_diagnostic.is_none());
+        assert!(orch.lens_efficacy_report.is_none());
+        assert!(orch.charter.is_some() && orch.governance_summary.is_some());
+        assert!(orch.metrics_history.iter().all(|(step, _)| *step < 3));
+
+        let entries = orch.ledger.get_entries(&orch.run_id);
+        let rollback = entries.last().unwrap();
+        assert_eq!(rollback.payload.action, "rollback");
+        assert_eq!(rollback.payload.inputs.as_ref().unwrap()["last_approved_gate"], 2);
+
+        // The retried step runs normally
+        orch.execute_step_3(true).await.unwrap();
+        assert!(matches!(orch.state, RunState::Step3GatePending));
+
+        // A gate rejection stays final
+        orch.reject_gate("Human", "Wrong framing").unwrap();
+        let err = orch.rollback_to_last_gate().unwrap_err();
+        assert!(err.to_string().contains("gate rejection"));
+    }
+
+    #[tokio::test]
+    async fn test_rollback_and_override_keep_persisted_artifacts_consistent() {
+        use crate::config::{AppConfig, RunConfig};
+
+        fn assert_store_matches(db_path: &std::path::Path, orch: &Orchestrator) {
+            let conn = crate::database::open_connection(db_path).unwrap();
+            let rows = crate::database::artifacts::list_artifacts_by_run(&conn, &orch.run_id).unwrap();
+            let in_memory = orch.collect_artifacts();
+            assert_eq!(rows.len(), in_memory.len());
+            for row in &rows {
+                let artifact = in_memory.iter().find(|a| a.artifact_id == row.id).unwrap();
+                let content = std::fs::read_to_string(row.content_path.as_ref().unwrap()).unwrap();
+                assert_eq!(content, artifact.content, "{}", row.id);
+                assert_eq!(row.hash, artifact.hash, "{}", row.id);
+            }
+            let dir = std::path::Path::new(rows[0].content_path.as_ref().unwrap()).parent().unwrap();
+            assert_eq!(std::fs::read_dir(dir).unwrap().count(), rows.len());
+        }
+
+        let (dir, db_path) = temp_db("rollback");
+
+        let config = RunConfig {
+            allow_halt_override: true,
+            ..RunConfig::from(&AppConfig::default())
+        };
+        let orch = Orchestrator::new("rollback-persisted")
+            .with_stubs()
+            .with_run_config(config)
+            .with_artifact_persistence(db_path.clone());
+        let mut orch = drive_stub_workflow(orch, 3).await;
+        orch.execute_step_3(true).await.unwrap();
+        assert_store_matches(&db_path, &orch);
+
+        // Halt → rollback drops the Step 3 rows and files
+        orch.state = RunState::Halted {
+            reason: "CI below threshold".to_string(),
+            cause: HaltCause::MetricThreshold { step: 3 },
+        };
+        orch.rollback_to_last_gate().unwrap();
+        assert_store_matches(&db_path, &orch);
+
+        // The re-run stores Step 3 again
+        orch.execute_step_3(true).await.unwrap();
+        assert_store_matches(&db_path, &orch);
+
+        // Overriding a HALT reopens Step 3 with its rows still stored; re-running replaces them
+        orch.state = RunState::Halted {
+            reason: "CI below threshold".to_string(),
+            cause: HaltCause::MetricThreshold { step: 3 },
+        };
+        orch.override_halt("Lead Reviewer", "Accepting the lower CI for this draft").unwrap();
+        orch.execute_step_3(true).await.unwrap();
+        assert_store_matches(&db_path, &orch);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[tokio::test]
+    async fn test_acknowledge_pause_resumes_step() {
+        let mut orch = drive_stub_workflow(Orchestrator::new("ack-pause").with_stubs(), 4).await;
+
+        // A HALT is not a warning and cannot be acknowledged away
+        orch.state = RunState::Paused {
+            reason: "Relevance too low".to_string(),
+            step: 4,
+            triggered_metrics: Some(serde_json::json!({"pre_synthesis_relevance": 0.3})),
+            all_metrics_snapshot: None,
+        };
+        assert!(orch.acknowledge_pause("Human", "Looks fine").is_err());
+
+        // IAS in the warning range pauses Step 4 for review
+        orch.state = RunState::Paused {
+            reason: "Re-synthesis Pause".to_string(),
+            step: 4,
+            triggered_metrics: Some(serde_json::json!({"ias": 0.65, "check_type": "re_synthesis_pause"})),
+            all_metrics_snapshot: None,
+        };
+        orch.acknowledge_pause("Human", "Pivot is intentional").unwrap();
+        assert!(matches!(orch.state, RunState::Step4Active));
+
+        let entries = orch.ledger.get_entries(&orch.run_id);
+        let ack = entries.last().unwrap();
+        assert_eq!(ack.payload.action, "pause_acknowledged");
+        assert_eq!(ack.payload.inputs.as_ref().unwrap()["note"], "Pivot is intentional");
+
+        orch.execute_step_4().await.unwrap();
+        assert!(matches!(orch.state, RunState::Step4GatePending));
+    }
+
+    #[tokio::test]
+    async fn test_short_analysis_target_needs_force() {
+        async fn at_step_3(intent: &str) -> Orchestrator {
+            let mut orch = Orchestrator::new("short-target").with_stubs();
+            orch.execute_step_0(intent).await.unwrap();
+            orch.approve_gate("Human").unwrap();
+            orch.execute_step_1().await.unwrap();
+            orch.approve_gate("Human").unwrap();
+            orch.execute_step_2().await.unwrap();
+            orch.approve_gate("Human").unwrap();
+            orch
+        }
+        let short_callouts = |orch: &Orchestrator| {
+            orch.callout_manager
+                .all()
+                .iter()
+                .filter(|c| c.metric_name == "Content Length" && c.tier == CalloutTier::Attention)
+                .count()
+        };
+
+        // Short content without force is blocked and the run stays at Step 3
+        let mut orch = at_step_3("Onboarding framework").await;
+        let err = orch.execute_step_3(false).await.unwrap_err();
+        assert_eq!(err.downcast_ref::<AnalysisTargetTooShort>().unwrap().min_chars, DEFAULT_MIN_ANALYSIS_CHARS);
+        assert!(matches!(orch.state, RunState::Step3Active));
+        assert_eq!(short_callouts(&orch), 1);
+
+        // Confirming with force analyzes it anyway
+        orch.execute_step_3(true).await.unwrap();
+        assert!(matches!(orch.state, RunState::Step3GatePending));
+
+        // Sufficient content needs no confirmation
+        let mut orch = at_step_3(&"Design a customer onboarding framework covering mentoring. ".repeat(5)).await;
+        orch.execute_step_3(false).await.unwrap();
+        assert!(matches!(orch.state, RunState::Step3GatePending));
+        assert_eq!(short_callouts(&orch), 0);
+    }
+
+    #[tokio::test]
+    async fn test_unlocked_baseline_policy_at_step_2() {
+        async fn at_step_2(policy: UnlockedBaselinePolicy) -> Orchestrator {
+            let orch = Orchestrator::new("unlocked-baseline")
+                .with_stubs()
+                .with_unlocked_baseline_policy(policy);
+            let mut orch = drive_stub_workflow(orch, 2).await;
+
+            // Baseline calculated but never locked
+            let mut agent = GovernanceTelemetryAgent::from_client(crate::api::AnthropicClient::echo().unwrap());
+            agent.calculate_e_baseline("# Onboarding Guide\n\nNew hires shadow a mentor.", 1).await.unwrap();
+            orch.governance_agent = Some(agent);
+            orch
+        }
+
+        let mut orch = at_step_2(UnlockedBaselinePolicy::Reject).await;
+        let err = orch.execute_step_2().await.unwrap_err();
+        assert!(err.to_string().contains("E_baseline is not locked"));
+        assert!(matches!(orch.state, RunState::Step2Active));
+
+        let mut orch = at_step_2(UnlockedBaselinePolicy::Lock).await;
+        orch.execute_step_2().await.unwrap();
+        assert!(orch.governance_agent.as_ref().unwrap().is_e_baseline_locked());
+        assert!(orch
+            .get_ledger()
+            .get_entries(&orch.run_id)
+            .iter()
+            .any(|e| e.payload.action == "e_baseline_locked"));
+    }
+
+    #[tokio::test]
+    async fn test_same_seed_gives_identical_stub_output() {
+        let intent = "Design a customer onboarding framework";
+        let mut first = Orchestrator::new_with_seed("seeded", 42).with_stubs();
+        let mut second = Orchestrator::new_with_seed("seeded", 42).with_stubs();
+
+        let a = first.execute_step_0(intent).await.unwrap();
+        let b = second.execute_step_0(intent).await.unwrap();
+        assert_eq!(serde_json::to_value(&a).unwrap(), serde_json::to_value(&b).unwrap());
+        assert_eq!(a.created_at, STUB_SEEDED_TIMESTAMP.replace('Z', "+00:00"));
+
+        let geometry = |orch: &Orchestrator| format!("{:?}", orch.stub_step4_synthesis(&[]).model_geometry);
+        assert_eq!(geometry(&first), geometry(&second));
+
+        // Unseeded stubs keep their fixed choices
+        let unseeded = Orchestrator::new("unseeded").with_stubs();
+        let summary = unseeded.stub_scope_and_pattern_agent(intent).unwrap();
+        assert_eq!(summary.intent_category, "Operational");
+        assert_eq!(summary.confidence_score, 75);
+        assert_eq!(geometry(&unseeded), "Linear");
+    }
+
+    #[tokio::test]
+    async fn test_run_to_completion_with_auto_approval() {
+        let mut orch = Orchestrator::new("batch-run").with_stubs().with_auto_approval(None);
+
+        orch.run_to_completion("Design a customer onboarding framework").await.unwrap();
+        assert!(matches!(orch.state, RunState::Completed));
+
+        let auto_approvals = orch
+            .get_ledger()
+            .get_entries(&orch.run_id)
+            .iter()
+            .filter(|e| e.payload.action == "gate_auto_approved")
+            .count();
+        assert_eq!(auto_approvals, 7);
+
+        // Gates outside the allowlist wait for a human
+        let mut orch = Orchestrator::new("batch-allowlist").with_stubs().with_auto_approval(Some(vec![0]));
+        let err = orch.run_to_completion("Design a customer onboarding framework").await.unwrap_err();
+        assert!(err.to_string().contains("Step 1 gate requires human approval"));
+        assert!(matches!(orch.state, RunState::Step1GatePending));
+    }
+
+    #[tokio::test]
+    async fn test_auto_approval_blocked_by_critical_callout() {
+        let mut orch = Orchestrator::new("batch-critical").with_stubs().with_auto_approval(None);
+        orch.execute_step_0("Design a customer onboarding framework").await.unwrap();
+
+        orch.callout_manager.add(Callout::new(
+            CalloutTier::Critical,
+            "CI",
+            0.4,
+            None,
+            "Halt threshold 0.5",
+            "Coherence collapsed",
+            "Review the intent summary",
+            Step::Step0_Intent,
+            StructureMode::Refining,
+        ));
+
+        assert!(!orch.try_auto_approve().unwrap());
+        assert!(matches!(orch.state, RunState::Step0GatePending));
+    }
+
+    #[test]
+    fn test_failed_auto_approval_is_not_recorded() {
+        // At the Step 5 gate without the required deliverables the approval fails
+        let mut orch = Orchestrator::new("batch-blocked").with_stubs().with_auto_approval(None);
+        orch.state = RunState::Step5GatePending;
+
+        assert!(orch.try_auto_approve().is_err());
+        assert!(matches!(orch.state, RunState::Step5GatePending));
+        let entries = orch.get_ledger().get_entries(&orch.run_id);
+        assert!(entries.iter().any(|e| e.payload.action == "gate_blocked"));
+        assert!(!entries.iter().any(|e| e.payload.action == "gate_auto_approved"));
+    }
+
+    #[tokio::test]
+    async fn test_gate_decisions_require_named_party() {
+        let mut orch = Orchestrator::new("named-approver").with_stubs();
+        orch.execute_step_0("Design a customer onboarding framework").await.unwrap();
+        let entries_before = orch.ledger.get_entries(&orch.run_id).len();
+
+        for approver in ["", "   "] {
+            let err = orch.approve_gate(approver).unwrap_err();
+            assert!(err.to_string().contains("non-empty approver"), "{}", err);
+        }
+        assert!(orch.reject_gate(" ", "Scope unclear").is_err());
+        assert!(orch.reject_gate("Human Reviewer", "").is_err());
+
+        // Nothing was written and the gate is still pending
+        assert_eq!(orch.ledger.get_entries(&orch.run_id).len(), entries_before);
+        assert!(matches!(orch.state, RunState::Step0GatePending));
+
+        assert!(orch.approve_gate("Human Reviewer").unwrap());
+        assert!(matches!(orch.state, RunState::Step1Active));
+    }
+
+    #[tokio::test]
+    async fn test_strict_metrics_blocks_on_warning_callout() {
+        use crate::config::{AppConfig, RunConfig};
+
+        for strict in [false, true] {
+            let run_config = RunConfig {
+                strict_metrics: strict,
+                ..RunConfig::from(&AppConfig::default())
+            };
+            let mut orch = Orchestrator::new("strict-metrics")
+                .with_stubs()
+                .with_run_config(run_config)
+                .with_auto_approval(None);
+            orch.execute_step_0("Design a customer onboarding framework").await.unwrap();
+
+            // The policy in force is on record from the start of the run
+            let entries = orch.ledger.get_entries(&orch.run_id);
+            let run_start = entries.iter().find(|e| e.payload.action == "run_start").unwrap();
+            assert_eq!(run_start.payload.inputs.as_ref().unwrap()["strict_metrics"], strict);
+
+            orch.callout_manager.add(Callout::new(
+                CalloutTier::Warning,
+                "CI",
+                0.7,
+                None,
+                "Pass threshold 0.8",
+                "Coherence below target",
+                "Tighten the intent summary",
+                Step::Step0_Intent,
+                StructureMode::Refining,
+            ));
+
+            assert_eq!(orch.callout_manager.can_proceed(), !strict);
+            assert_eq!(orch.try_auto_approve().unwrap(), !strict);
+
+            if strict {
+                let pending = orch.callout_manager.get_pending_acknowledgments()[0].id.clone();
+                orch.callout_manager.acknowledge(&pending).unwrap();
+                assert!(orch.try_auto_approve().unwrap());
+            }
+            assert!(matches!(orch.state, RunState::Step1Active));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_intent_drift_raises_callout() {
+        let mut orch = drive_stub_workflow(Orchestrator::new("intent-drift").with_stubs(), 1).await;
+        orch.execute_step_1().await.unwrap();
+
+        // The stub Charter restates the goal - no drift
+        let drift = orch.check_intent_drift().await.unwrap();
+        assert!(drift <= DEFAULT_INTENT_DRIFT_THRESHOLD, "unexpected drift {}", drift);
+        assert_eq!(orch.callout_manager.all().len(), 0);
+
+        // A later output about something else entirely scores low alignment
+        orch.core_thesis = Some("# Core Thesis\n\nQuarterly tax filing for freelancers".to_string());
+        let drift = orch.check_intent_drift().await.unwrap();
+        assert!(drift > DEFAULT_INTENT_DRIFT_THRESHOLD);
+
+        let callouts = orch.callout_manager.all();
+        assert_eq!(callouts.len(), 1);
+        assert_eq!(callouts[0].metric_name, "Intent Drift");
+        assert_eq!(callouts[0].tier, CalloutTier::Warning);
+        assert_eq!(callouts[0].step, Step::Step4_Synthesis);
+    }
+
+    #[tokio::test]
+    async fn test_step_1_without_agents_requires_stub_mode() {
+        let mut orch = Orchestrator::new("test");
+        orch.execute_step_0("Test intent").await.unwrap();
+        orch.approve_gate("Human Reviewer").unwrap();
+
+        let err = orch.execute_step_1().await.unwrap_err();
+        assert!(err.to_string().contains("not configured"));
+    }
+
+    #[tokio::test]
+    async fn test_charter_revision_after_baseline_freeze_proposes_fork() {
+        let mut orch = Orchestrator::new("test").with_stubs();
+        assert!(orch.propose_charter_revision("# Charter\n\nRevised").is_err());
+
+        let mut orch = drive_stub_workflow(orch, 2).await;
+        let charter_hash = orch.charter.as_ref().unwrap().hash.clone();
+
+        let revised = "# Charter\n\nDesign a customer onboarding framework for enterprise accounts";
+        let proposal = orch.propose_charter_revision(revised).unwrap();
+        assert_eq!(proposal.source_run_id, orch.run_id);
+        assert_eq!(proposal.current_charter_hash, charter_hash);
+        assert_ne!(proposal.proposed_charter_hash, charter_hash);
+        assert!(proposal.refusal_reason.contains("baseline is frozen"));
+        assert_eq!(proposal.fork_seed.forked_from, orch.run_id);
+        assert_eq!(proposal.fork_seed.user_intent, "Design a customer onboarding framework");
+        assert_eq!(proposal.fork_seed.charter_content, revised);
+
+        // The run itself is not mutated
+        assert_eq!(orch.charter.as_ref().unwrap().hash, charter_hash);
+        assert!(matches!(orch.state, RunState::Step2Active));
+
+        let entry = orch.ledger.get_entries(&orch.run_id).into_iter()
+            .find(|e| e.payload.action == "charter_revision_proposed")
+            .expect("proposal recorded in the ledger");
+        assert_eq!(entry.payload.outputs.unwrap()["resolution"], "fork");
+    }
+
+    #[tokio::test]
+    async fn test_fork_after_step_2_copies_first_three_steps() {
+        let source = drive_stub_workflow(Orchestrator::new("source").with_stubs(), 4).await;
+
+        assert!(source.fork("source", 2).is_err(), "fork needs its own run_id");
+        assert!(source.fork("too-far", 4).is_err(), "Step 4 has not run yet");
+
+        let mut fork = source.fork("variant", 2).unwrap();
+        assert_eq!(fork.forked_from.as_deref(), Some(source.run_id.as_str()));
+        assert!(matches!(fork.state, RunState::Step3Active));
+        assert_eq!(fork.active_role, Role::Observer);
+        assert_eq!(fork.intent_summary.as_ref().unwrap().run_id, fork.run_id);
+
+        // Steps 0-2 are copied under the new run, Step 3 is left to re-execute
+        let source_artifacts = source.collect_artifacts();
+        let artifacts = fork.collect_artifacts();
+        let types: Vec<_> = artifacts.iter().map(|a| a.artifact_type.clone()).collect();
+        let expected: Vec<_> = source_artifacts
+            .iter()
+            .filter(|a| crate::artifacts::step_origin_for_type(&a.artifact_type) <= 2)
+            .map(|a| a.artifact_type.clone())
+            .collect();
+        assert_eq!(types, expected);
+        assert!(fork.integrated_diagnostic.is_none());
+        assert!(fork.lens_efficacy_report.is_none());
+
+        for artifact in &artifacts {
+            assert!(artifact.artifact_id.starts_with(&fork.run_id), "{}", artifact.artifact_id);
+            // Bodies are copied as written, even where their prose names the source run
+            let source_artifact = source_artifacts.iter().find(|a| a.artifact_type == artifact.artifact_type).unwrap();
+            assert_eq!(artifact.content, source_artifact.content);
+        }
+        assert_ne!(fork.charter.as_ref().unwrap().hash, source.charter.as_ref().unwrap().hash);
+        assert!(fork.verify_lineage().unwrap().is_intact());
+        let baseline = fork.baseline_report.as_ref().unwrap();
+        assert!(baseline.contains(&format!("run_id: \"{}\"", fork.run_id)));
+        assert!(baseline.contains(&format!("Charter: {}-charter", source.run_id)));
+        assert!(baseline.contains(&format!("forked_from: \"{}-baseline", source.run_id)));
+        assert_eq!(
+            Orchestrator::frontmatter_value(baseline, "hash").unwrap(),
+            crate::artifacts::calculate_content_hash(Orchestrator::artifact_body(baseline))
+        );
+        assert_eq!(fork.get_e_baseline(), source.get_e_baseline());
+
+        // The ledger is replayed up to the fork point under a fresh chain
+        let entries = fork.get_ledger().get_entries(&fork.run_id);
+        assert!(entries.iter().all(|e| e.step.unwrap_or(0) <= 2));
+        assert_eq!(entries.last().unwrap().payload.action, "run_forked");
+        assert_eq!(entries[entries.len() - 2].payload.action, "gate_approved");
+        assert_eq!(entries[entries.len() - 2].step, Some(2));
+        assert!(fork.get_ledger().verify_chain_integrity(&fork.run_id));
+
+        // Step 3 can be re-executed on the fork
+        fork.execute_step_3(true).await.unwrap();
+        assert!(matches!(fork.state, RunState::Step3GatePending));
+    }
+
+    #[tokio::test]
+    async fn test_fork_of_halted_run_fails() {
+        let mut source = drive_stub_workflow(Orchestrator::new("halted-source").with_stubs(), 2).await;
+        assert_eq!(source.fork("early", 0).unwrap().active_role, Role::Conductor);
+
+        source.state = RunState::Halted {
+            reason: "Aborted by reviewer".to_string(),
+            cause: HaltCause::GateRejected,
+        };
+        for up_to_step in [1, 5] {
+            let err = source.fork("halted-fork", up_to_step).unwrap_err();
+            assert!(err.to_string().contains("halted or paused"), "{}", err);
+        }
+
+        // Without the gate approval on record a fork is refused even from a later state
+        source.state = RunState::Step4Active;
+        assert!(source.fork("unapproved", 3).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_resumed_step_without_prerequisites_errors_cleanly() {
+        let mut orch = drive_stub_workflow(Orchestrator::new("resume-missing").with_stubs(), 1).await;
+        orch.execute_step_1().await.unwrap();
+
+        // Restored into Step 5 with the charter but no Step 4 content
+        orch.state = RunState::Step5Active;
+        assert!(orch.core_thesis.is_none());
+
+        let err = orch.execute_step_5().await.unwrap_err();
+        let missing = err.downcast_ref::<MissingArtifact>().expect("expected a MissingArtifact error");
+        assert_eq!(missing.step, 5);
+        assert_eq!(missing.artifacts, vec!["Core Thesis"]);
+        assert!(err.to_string().contains("Core Thesis"));
+        assert!(matches!(orch.state, RunState::Step5Active));
+
+        // Nothing restored at all
+        let mut empty = Orchestrator::new("resume-empty").with_stubs();
+        empty.state = RunState::Step6Active;
+        let err = empty.execute_step_6().await.unwrap_err();
+        assert_eq!(err.downcast_ref::<MissingArtifact>().unwrap().artifacts.len(), 5);
+    }
+
+    #[tokio::test]
+    async fn test_override_metric_halt_resumes_step() {
+        use crate::config::{AppConfig, RunConfig};
+
+        let run_config = RunConfig {
+            allow_halt_override: true,
+            ..RunConfig::from(&AppConfig::default())
+        };
+        let orch = Orchestrator::new("override-halt").with_stubs().with_run_config(run_config);
+        let mut orch = drive_stub_workflow(orch, 3).await;
+        orch.execute_step_3(true).await.unwrap();
+        let diagnostic = orch.integrated_diagnostic.clone();
+
+        // A metrics HALT at Step 3 that the reviewer first chose to abort
+        orch.state = RunState::Paused {
+            reason: "CI below threshold".to_string(),
+            step: 3,
+            triggered_metrics: Some(serde_json::json!({"ci": 0.42})),
+            all_metrics_snapshot: None,
+        };
+        orch.handle_halt_decision("abort", "Reviewer", "Too incoherent").unwrap();
+        assert!(matches!(orch.state, RunState::Halted { cause: HaltCause::MetricThreshold { step: 3 }, .. }));
+
+        assert!(orch.override_halt("Lead", "").is_err());
+        orch.override_halt("Lead", "Source is a draft; coherence is expected to be low").unwrap();
+
+        assert!(matches!(orch.state, RunState::Step3Active));
+        // Nothing discarded, unlike a rollback
+        assert_eq!(orch.integrated_diagnostic, diagnostic);
+        let entry = orch.ledger.get_entries(&orch.run_id).last().unwrap().clone();
+        assert_eq!(entry.payload.action, "halt_override");
+        let inputs = entry.payload.inputs.unwrap();
+        assert_eq!(inputs["approver"], "Lead");
+        assert_eq!(inputs["justification"], "Source is a draft; coherence is expected to be low");
+        assert_eq!(inputs["step"], 3);
+
+        // Gate rejections stay final
+        orch.state = RunState::Halted {
+            reason: "Scope is wrong".to_string(),
+            cause: HaltCause::GateRejected,
+        };
+        assert!(orch.override_halt("Lead", "Disagree with the rejection").is_err());
+
+        // Disabled unless the config allows it
+        let mut locked = Orchestrator::new("override-disabled").with_stubs();
+        locked.state = RunState::Halted {
+            reason: "CI below threshold".to_string(),
+            cause: HaltCause::MetricThreshold { step: 3 },
+        };
+        assert!(locked.override_halt("Lead", "Accepting the risk").is_err());
+        assert!(matches!(locked.state, RunState::Halted { .. }));
+    }
 }
@@ -2,9 +2,19 @@ use anyhow::{Context as AnyhropicContext, Result};
 use chrono::Utc;
 use log::{debug, info};
 use serde::{Deserialize, Serialize};
-use sha2::{Digest, Sha256};
 
-use crate::api::AnthropicClient;
+use crate::api::{AnthropicClient, ApiUsage};
+use crate::artifacts::{GovernanceRole, ARTIFACT_TEMPLATE_VERSION};
+use crate::hash::sha256_hex;
+
+/// Sampling temperature for interpreting the user's intent (Step 0)
+const INTENT_TEMPERATURE: f32 = 0.2;
+
+/// Sampling temperature for the Intent Anchor, which must restate intent verbatim
+const ANCHOR_TEMPERATURE: f32 = 0.0;
+
+/// Sampling temperature for drafting the Charter
+const CHARTER_TEMPERATURE: f32 = 0.3;
 
 /// A term defined by the user in their original request
 /// Extracted during Step 0/1 and protected during glossary generation
@@ -36,7 +46,7 @@ pub struct IntentSummary {
     pub intent_anchor_link: Option<String>,
     pub is_immutable: bool,
     pub author: String,
-    pub governance_role: String,
+    pub governance_role: GovernanceRole,
 
     // Intent extraction
     pub user_request: String,
@@ -137,10 +147,35 @@ impl IntentSummary {
 
     /// Generate SHA-256 hash of the content body
     pub fn compute_hash(&self) -> String {
-        let content = self.generate_content_body();
-        let mut hasher = Sha256::new();
-        hasher.update(content.as_bytes());
-        format!("{:x}", hasher.finalize())
+        sha256_hex(self.generate_content_body())
+    }
+
+    /// Apply a human answer to one clarification question without an API call
+    ///
+    /// Scope items named in the answer (or, failing that, in the question) move to
+    /// `likely_out_of_scope` when the answer is negative ("no", "exclude",
+    /// "out of scope"...) and to `likely_in_scope` when it is affirmative. The
+    /// question is removed and the hash recomputed.
+    pub fn apply_clarification(&mut self, question: &str, answer: &str) -> Result<()> {
+        let position = self
+            .questions_for_clarification
+            .iter()
+            .position(|q| q.trim().eq_ignore_ascii_case(question.trim()))
+            .ok_or_else(|| anyhow::anyhow!("No clarification question matching '{}'", question))?;
+        self.questions_for_clarification.remove(position);
+
+        match answer_direction(answer) {
+            Some(ScopeDirection::Out) => {
+                move_mentioned(&mut self.likely_in_scope, &mut self.likely_out_of_scope, question, answer)
+            }
+            Some(ScopeDirection::In) => {
+                move_mentioned(&mut self.likely_out_of_scope, &mut self.likely_in_scope, question, answer)
+            }
+            None => debug!("Clarification answer names no scope change: {}", answer),
+        }
+
+        self.hash = self.compute_hash();
+        Ok(())
     }
 
     /// Generate the complete artifact as markdown with YAML frontmatter
@@ -159,6 +194,7 @@ intent_anchor_link: {}
 is_immutable: {}
 author: "{}"
 governance_role: "{}"
+template_version: {}
 ---
 
 {}
@@ -175,6 +211,7 @@ governance_role: "{}"
             self.is_immutable,
             self.author,
             self.governance_role,
+            ARTIFACT_TEMPLATE_VERSION,
             self.generate_content_body()
         )
     }
@@ -233,6 +270,11 @@ impl ScopePatternAgent {
         ScopePatternAgent { claude_client }
     }
 
+    /// Token usage and estimated cost of this agent's Claude calls
+    pub fn api_usage(&self) -> ApiUsage {
+        self.claude_client.usage()
+    }
+
     /// Interpret user intent and create Intent_Summary artifact
     ///
     /// This is invoked during Step 0 of the Method-VI process.
@@ -306,7 +348,7 @@ Respond in the EXACT format above, preserving the section headers."#,
         info!("Calling Claude API for intent interpretation...");
         let response = self
             .claude_client
-            .call_claude(&system_prompt, &user_prompt, None, Some(2000), None)
+            .call_claude(&system_prompt, &user_prompt, None, Some(2000), Some(INTENT_TEMPERATURE), None)
             .await
             .context("Failed to call Claude API for intent interpretation")?;
 
@@ -388,7 +430,7 @@ Respond in the EXACT format above, preserving the section headers."#,
             intent_anchor_link: None,
             is_immutable: false,
             author: "scope-pattern-agent".to_string(),
-            governance_role: "Observer".to_string(),
+            governance_role: GovernanceRole::Observer,
             user_request: user_request.to_string(),
             primary_goal,
             audience,
@@ -452,14 +494,15 @@ Respond in the EXACT format above, preserving the section headers."#,
         );
 
         let anchor_content = self.claude_client
-            .call_claude(&system_prompt, &user_message, None, Some(4096), None)
+            .call_claude(&system_prompt, &user_message, None, Some(4096), Some(ANCHOR_TEMPERATURE), None)
             .await
             .context("Failed to generate Intent_Anchor content")?;
 
         // Build complete artifact with frontmatter
         let artifact_id = format!("{}-intent-anchor", run_id);
         let created_at = Utc::now().to_rfc3339();
-        let content_hash = self.compute_content_hash(&anchor_content);
+        let anchor_content = anchor_content.trim();
+        let content_hash = self.compute_content_hash(anchor_content);
 
         let artifact = format!(
             "---\n\
@@ -471,12 +514,12 @@ Respond in the EXACT format above, preserving the section headers."#,
             hash: \"{}\"\n\
             parent_hash: \"{}\"\n\
             dependencies:\n\
-              - artifact_id: \"{}\"\n\
-                relationship: \"derived_from\"\n\
+            - {{ artifact_id: \"{}\", relationship: \"derived_from\" }}\n\
             intent_anchor_link: \"{}\"\n\
             is_immutable: true\n\
             author: \"scope-pattern-agent\"\n\
-            governance_role: \"Observer\"\n\
+            governance_role: \"{}\"\n\
+            template_version: {}\n\
             ---\n\n\
             {}",
             artifact_id,
@@ -486,6 +529,8 @@ Respond in the EXACT format above, preserving the section headers."#,
             intent_summary_hash,
             intent_summary_hash,
             artifact_id, // Self-referencing: this IS the anchor
+            GovernanceRole::Observer,
+            ARTIFACT_TEMPLATE_VERSION,
             anchor_content.trim()
         );
 
@@ -544,7 +589,7 @@ Respond in the EXACT format above, preserving the section headers."#,
         );
 
         let charter_content = self.claude_client
-            .call_claude(&system_prompt, &user_message, None, Some(4096), None)
+            .call_claude(&system_prompt, &user_message, None, Some(4096), Some(CHARTER_TEMPERATURE), None)
             .await
             .context("Failed to generate Charter content")?;
 
@@ -563,12 +608,12 @@ Respond in the EXACT format above, preserving the section headers."#,
             hash: \"{}\"\n\
             parent_hash: \"{}\"\n\
             dependencies:\n\
-              - artifact_id: \"{}\"\n\
-                relationship: \"derived_from\"\n\
+            - {{ artifact_id: \"{}\", relationship: \"derived_from\" }}\n\
             intent_anchor_link: \"{}\"\n\
             is_immutable: true\n\
             author: \"scope-pattern-agent\"\n\
-            governance_role: \"Observer\"\n\
+            governance_role: \"{}\"\n\
+            template_version: {}\n\
             ---\n\n\
             {}",
             artifact_id,
@@ -578,6 +623,8 @@ Respond in the EXACT format above, preserving the section headers."#,
             intent_anchor_hash,
             intent_anchor_hash,
             intent_anchor_id,
+            GovernanceRole::Observer,
+            ARTIFACT_TEMPLATE_VERSION,
             charter_content.trim()
         );
 
@@ -587,9 +634,81 @@ Respond in the EXACT format above, preserving the section headers."#,
 
     /// Compute SHA-256 hash of content
     fn compute_content_hash(&self, content: &str) -> String {
-        let mut hasher = Sha256::new();
-        hasher.update(content.as_bytes());
-        format!("{:x}", hasher.finalize())
+        sha256_hex(content)
+    }
+}
+
+/// Which scope list a clarification answer points to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ScopeDirection {
+    In,
+    Out,
+}
+
+/// Read an answer as excluding or including what it refers to
+///
+/// Explicit scope wording decides first: "exclude", "leave it out", "don't
+/// include" exclude; "include", "keep it in", "in scope" include. An answer
+/// with both is ambiguous and reads as neither. Only without such wording does
+/// a bare "no"/"not" exclude or "yes" include, so "No problem, include it"
+/// includes.
+fn answer_direction(answer: &str) -> Option<ScopeDirection> {
+    const EXCLUDE: [&str; 9] = ["exclude", "excluded", "skip", "drop", "defer", "out", "without", "later", "remove"];
+    const INCLUDE: [&str; 5] = ["include", "included", "keep", "add", "in-scope"];
+    const NEGATIONS: [&str; 3] = ["no", "not", "never"];
+
+    let lower = answer.to_lowercase();
+    let words: Vec<&str> = lower
+        .split(|c: char| !(c.is_alphanumeric() || c == '-' || c == '\''))
+        .filter(|w| !w.is_empty())
+        .collect();
+    let is_negation = |w: &str| NEGATIONS.contains(&w) || w.ends_with("n't");
+
+    // A scope word right after a negation ("don't include") points the other way
+    let (mut include, mut exclude) = (false, false);
+    for (i, word) in words.iter().enumerate() {
+        let includes = INCLUDE.contains(word) || (*word == "in" && words.get(i + 1) == Some(&"scope"));
+        if !includes && !EXCLUDE.contains(word) {
+            continue;
+        }
+        let negated = i > 0 && is_negation(words[i - 1]);
+        if includes != negated {
+            include = true;
+        } else {
+            exclude = true;
+        }
+    }
+
+    match (include, exclude) {
+        (true, false) => Some(ScopeDirection::In),
+        (false, true) => Some(ScopeDirection::Out),
+        (true, true) => None,
+        (false, false) if words.iter().any(|w| is_negation(w)) => Some(ScopeDirection::Out),
+        (false, false) if words.contains(&"yes") => Some(ScopeDirection::In),
+        (false, false) => None,
+    }
+}
+
+/// Move items named in the answer (or else the question) from one list to another
+fn move_mentioned(from: &mut Vec<String>, to: &mut Vec<String>, question: &str, answer: &str) {
+    let mentioned_in = |text: &str| {
+        let text = text.to_lowercase();
+        from.iter()
+            .filter(|item| text.contains(&item.to_lowercase()))
+            .cloned()
+            .collect::<Vec<_>>()
+    };
+
+    let mut moving = mentioned_in(answer);
+    if moving.is_empty() {
+        moving = mentioned_in(question);
+    }
+
+    for item in moving {
+        from.retain(|i| *i != item);
+        if !to.contains(&item) {
+            to.push(item);
+        }
     }
 }
 
@@ -741,7 +860,7 @@ OUT OF SCOPE:
             intent_anchor_link: None,
             is_immutable: false,
             author: "scope-pattern-agent".to_string(),
-            governance_role: "Observer".to_string(),
+            governance_role: GovernanceRole::Observer,
             user_request: "Test request".to_string(),
             primary_goal: "Test goal".to_string(),
             audience: "Test audience".to_string(),
@@ -756,6 +875,7 @@ OUT OF SCOPE:
             likely_in_scope: vec!["Item 1".to_string()],
             likely_out_of_scope: vec!["Item 2".to_string()],
             edge_cases: vec![],
+            user_defined_terms: vec![],
         };
 
         let hash = summary.compute_hash();
@@ -777,7 +897,7 @@ OUT OF SCOPE:
             intent_anchor_link: None,
             is_immutable: false,
             author: "scope-pattern-agent".to_string(),
-            governance_role: "Observer".to_string(),
+            governance_role: GovernanceRole::Observer,
             user_request: "Test request".to_string(),
             primary_goal: "Test goal".to_string(),
             audience: "Test audience".to_string(),
@@ -792,6 +912,7 @@ OUT OF SCOPE:
             likely_in_scope: vec!["Item 1".to_string()],
             likely_out_of_scope: vec!["Item 2".to_string()],
             edge_cases: vec![],
+            user_defined_terms: vec![],
         };
 
         let markdown = summary.to_markdown();
@@ -800,4 +921,81 @@ OUT OF SCOPE:
         assert!(markdown.contains("# Intent Summary"));
         assert!(markdown.contains("Test request"));
     }
+
+    #[test]
+    fn test_apply_clarification_moves_item_out_of_scope() {
+        let mut summary = IntentSummary {
+            artifact_id: "test-id".to_string(),
+            artifact_type: "Intent_Summary".to_string(),
+            run_id: "2025-12-17-Test".to_string(),
+            step_origin: 0,
+            created_at: "2025-12-17T10:00:00Z".to_string(),
+            hash: String::new(),
+            parent_hash: None,
+            dependencies: vec![],
+            intent_anchor_link: None,
+            is_immutable: false,
+            author: "scope-pattern-agent".to_string(),
+            governance_role: GovernanceRole::Observer,
+            user_request: "Build a reporting tool".to_string(),
+            primary_goal: "Reporting".to_string(),
+            audience: "Analysts".to_string(),
+            expected_outcome: "Weekly reports".to_string(),
+            intent_category: "Operational".to_string(),
+            confidence_score: 70,
+            confidence_explanation: "Scope unclear".to_string(),
+            request_specificity: "Medium".to_string(),
+            scope_definition_clarity: "Partial".to_string(),
+            success_criteria_state: "Implied".to_string(),
+            questions_for_clarification: vec![
+                "Should the mobile app be part of this project?".to_string(),
+                "Who approves the reports?".to_string(),
+            ],
+            likely_in_scope: vec!["Web dashboard".to_string(), "Mobile app".to_string()],
+            likely_out_of_scope: vec!["Billing".to_string()],
+            edge_cases: vec![],
+            user_defined_terms: vec![],
+        };
+        summary.hash = summary.compute_hash();
+        let original_hash = summary.hash.clone();
+
+        summary
+            .apply_clarification("Should the mobile app be part of this project?", "No, leave it out for now.")
+            .unwrap();
+
+        assert_eq!(summary.likely_in_scope, vec!["Web dashboard"]);
+        assert_eq!(summary.likely_out_of_scope, vec!["Billing", "Mobile app"]);
+        assert_eq!(summary.questions_for_clarification, vec!["Who approves the reports?"]);
+        assert_ne!(summary.hash, original_hash);
+        assert_eq!(summary.hash, summary.compute_hash());
+
+        // An affirmative answer naming an item brings it back in scope
+        summary
+            .apply_clarification("Who approves the reports?", "Finance approves them; include billing too.")
+            .unwrap();
+        assert_eq!(summary.likely_in_scope, vec!["Web dashboard", "Billing"]);
+        assert!(summary.questions_for_clarification.is_empty());
+
+        assert!(summary.apply_clarification("Unknown question?", "Yes").is_err());
+    }
+
+    #[test]
+    fn test_answer_direction_prefers_explicit_scope_wording() {
+        // Explicit include wording wins over an unrelated "no"/"not"
+        assert_eq!(answer_direction("No problem, include it"), Some(ScopeDirection::In));
+        assert_eq!(answer_direction("not sure, keep it in"), Some(ScopeDirection::In));
+        assert_eq!(answer_direction("Yes, it's in scope"), Some(ScopeDirection::In));
+
+        assert_eq!(answer_direction("Yes, leave it out"), Some(ScopeDirection::Out));
+        assert_eq!(answer_direction("Don't include it"), Some(ScopeDirection::Out));
+        assert_eq!(answer_direction("It is not in scope"), Some(ScopeDirection::Out));
+
+        // Both directions named: ambiguous
+        assert_eq!(answer_direction("Include reporting but exclude billing"), None);
+
+        // No scope wording: fall back to yes/no
+        assert_eq!(answer_direction("No."), Some(ScopeDirection::Out));
+        assert_eq!(answer_direction("Yes please"), Some(ScopeDirection::In));
+        assert_eq!(answer_direction("Finance approves them"), None);
+    }
 }
@@ -2,9 +2,17 @@ use anyhow::{Context, Result};
 use chrono::Utc;
 use log::{debug, info};
 use serde::{Deserialize, Serialize};
-use sha2::{Digest, Sha256};
 
 use crate::api::anthropic::AnthropicClient;
+use crate::api::ApiUsage;
+use crate::artifacts::{GovernanceRole, ARTIFACT_TEMPLATE_VERSION};
+use crate::hash::sha256_hex;
+
+/// Sampling temperature for the Architecture Map (Step 1)
+const ARCHITECTURE_TEMPERATURE: f32 = 0.3;
+
+/// Sampling temperature for framework design (Step 5)
+const FRAMEWORK_TEMPERATURE: f32 = 0.5;
 
 /// Structure & Redesign Agent
 ///
@@ -24,7 +32,17 @@ impl StructureRedesignAgent {
         let api_client = AnthropicClient::new(api_key)
             .context("Failed to create Anthropic API client")?;
 
-        Ok(Self { api_client })
+        Ok(Self::from_client(api_client))
+    }
+
+    /// Create the agent around an existing client (e.g. the echo provider)
+    pub fn from_client(api_client: AnthropicClient) -> Self {
+        Self { api_client }
+    }
+
+    /// Token usage and estimated cost of this agent's Claude calls
+    pub fn api_usage(&self) -> ApiUsage {
+        self.api_client.usage()
     }
 
     /// Create Architecture Map artifact (Step 1)
@@ -69,7 +87,7 @@ impl StructureRedesignAgent {
         );
 
         let architecture_content = self.api_client
-            .call_claude(system_prompt, &user_message, None, Some(4096), None)
+            .call_claude(system_prompt, &user_message, None, Some(4096), Some(ARCHITECTURE_TEMPERATURE), None)
             .await
             .context("Failed to generate Architecture Map content")?;
 
@@ -90,14 +108,13 @@ impl StructureRedesignAgent {
             hash: \"{}\"\n\
             parent_hash: \"{}\"\n\
             dependencies:\n\
-              - artifact_id: \"{}\"\n\
-                relationship: \"derived_from\"\n\
-              - artifact_id: \"{}\"\n\
-                relationship: \"constrained_by\"\n\
+            - {{ artifact_id: \"{}\", relationship: \"derived_from\" }}\n\
+            - {{ artifact_id: \"{}\", relationship: \"constrained_by\" }}\n\
             intent_anchor_link: \"{}\"\n\
             is_immutable: true\n\
             author: \"structure-redesign-agent\"\n\
-            governance_role: \"Observer\"\n\
+            governance_role: \"{}\"\n\
+            template_version: {}\n\
             ---\n\n\
             {}",
             artifact_id,
@@ -108,6 +125,8 @@ impl StructureRedesignAgent {
             charter_hash,
             intent_anchor_id,
             intent_anchor_id,
+            GovernanceRole::Observer,
+            ARTIFACT_TEMPLATE_VERSION,
             content_body
         );
 
@@ -132,9 +151,7 @@ impl StructureRedesignAgent {
 
     /// Calculate SHA-256 hash of content
     fn calculate_hash(&self, content: &str) -> String {
-        let mut hasher = Sha256::new();
-        hasher.update(content.as_bytes());
-        format!("{:x}", hasher.finalize())
+        sha256_hex(content)
     }
 
     /// Create Framework Draft Architecture Outline (Step 5)
@@ -220,7 +237,7 @@ impl StructureRedesignAgent {
         );
 
         let architecture = self.api_client
-            .call_claude(system_prompt, &user_message, None, Some(8192), None)
+            .call_claude(system_prompt, &user_message, None, Some(8192), Some(FRAMEWORK_TEMPERATURE), None)
             .await
             .context("Failed to generate framework architecture")?;
 
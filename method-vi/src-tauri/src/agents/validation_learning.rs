@@ -3,6 +3,10 @@ use log::{info, debug};
 use serde::{Deserialize, Serialize};
 
 use crate::api::anthropic::AnthropicClient;
+use crate::api::ApiUsage;
+
+/// Sampling temperature for the Step 6 validation checks - verdicts must be repeatable
+const VALIDATION_TEMPERATURE: f32 = 0.0;
 
 /// Validation & Learning Agent - Quality Assurance and Pattern Extraction Specialist
 ///
@@ -144,7 +148,12 @@ impl ValidationLearningAgent {
         let api_client = AnthropicClient::new(api_key)
             .context("Failed to create Anthropic API client")?;
 
-        Ok(Self {
+        Ok(Self::from_client(api_client))
+    }
+
+    /// Create the agent around an existing client (e.g. the echo provider)
+    pub fn from_client(api_client: AnthropicClient) -> Self {
+        Self {
             api_client,
             logic_validation: None,
             semantic_validation: None,
@@ -156,7 +165,12 @@ impl ValidationLearningAgent {
             exceptional_flag: false,
             performance_highlights: Vec::new(),
             failure_points: Vec::new(),
-        })
+        }
+    }
+
+    /// Token usage and estimated cost of this agent's Claude calls
+    pub fn api_usage(&self) -> ApiUsage {
+        self.api_client.usage()
     }
 
     /// Execute comprehensive validation (Step 6)
@@ -273,7 +287,7 @@ impl ValidationLearningAgent {
         );
 
         let response = self.api_client
-            .call_claude(system_prompt, &user_message, None, Some(4096), None)
+            .call_claude(system_prompt, &user_message, None, Some(4096), Some(VALIDATION_TEMPERATURE), None)
             .await?;
 
         // Parse response (simplified - in production would use structured output)
@@ -335,7 +349,7 @@ impl ValidationLearningAgent {
         );
 
         let response = self.api_client
-            .call_claude(system_prompt, &user_message, None, Some(4096), None)
+            .call_claude(system_prompt, &user_message, None, Some(4096), Some(VALIDATION_TEMPERATURE), None)
             .await?;
 
         let status = if response.contains("PASS") {
@@ -394,7 +408,7 @@ impl ValidationLearningAgent {
         );
 
         let response = self.api_client
-            .call_claude(system_prompt, &user_message, None, Some(4096), None)
+            .call_claude(system_prompt, &user_message, None, Some(4096), Some(VALIDATION_TEMPERATURE), None)
             .await?;
 
         let status = if response.contains("PASS") {
@@ -452,7 +466,7 @@ impl ValidationLearningAgent {
         );
 
         let response = self.api_client
-            .call_claude(system_prompt, &user_message, None, Some(4096), None)
+            .call_claude(system_prompt, &user_message, None, Some(4096), Some(VALIDATION_TEMPERATURE), None)
             .await?;
 
         let status = if response.contains("PASS") {
@@ -513,7 +527,7 @@ impl ValidationLearningAgent {
         );
 
         let response = self.api_client
-            .call_claude(system_prompt, &user_message, None, Some(4096), None)
+            .call_claude(system_prompt, &user_message, None, Some(4096), Some(VALIDATION_TEMPERATURE), None)
             .await?;
 
         let status = if response.contains("PASS") {
@@ -574,7 +588,7 @@ impl ValidationLearningAgent {
         );
 
         let response = self.api_client
-            .call_claude(system_prompt, &user_message, None, Some(4096), None)
+            .call_claude(system_prompt, &user_message, None, Some(4096), Some(VALIDATION_TEMPERATURE), None)
             .await?;
 
         let status = if response.contains("PASS") {
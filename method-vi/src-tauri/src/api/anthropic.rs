@@ -2,18 +2,82 @@ use anyhow::{Context, Result};
 use log::{debug, error, info, warn};
 use reqwest;
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 use std::time::Duration;
 
+use super::circuit_breaker::CircuitBreaker;
+use super::echo::EchoClient;
+use super::rate_limiter::RateLimiter;
+use super::tokenizer::estimate_tokens;
+use super::usage::{estimate_cost, ApiUsage, UsageTracker};
+
 const ANTHROPIC_API_URL: &str = "https://api.anthropic.com/v1/messages";
 const ANTHROPIC_API_VERSION: &str = "2023-06-01";
 const DEFAULT_MODEL: &str = "claude-sonnet-4-20250514";
+/// Cheaper model for short JSON judgment calls (relevance checks, classification)
+pub const DEFAULT_UTILITY_MODEL: &str = "claude-3-5-haiku-20241022";
 const DEFAULT_MAX_TOKENS: u32 = 4096;
-const REQUEST_TIMEOUT_SECS: u64 = 120;
+/// Time allowed for one Claude call, from sending the request to reading the body
+pub const DEFAULT_REQUEST_TIMEOUT_SECS: u64 = 120;
+/// Extra attempts made after a call times out
+const TIMEOUT_RETRIES: u32 = 1;
+/// System prompts estimated at this many tokens or more are marked for prompt caching
+///
+/// The API does not cache shorter prefixes, so marking them only adds overhead.
+const MIN_CACHEABLE_TOKENS: u32 = 1024;
+/// Model IDs offered when the models endpoint can't be queried
+pub const KNOWN_MODELS: &[&str] = &[
+    "claude-opus-4-1-20250805",
+    "claude-opus-4-20250514",
+    DEFAULT_MODEL,
+    "claude-3-7-sonnet-20250219",
+    DEFAULT_UTILITY_MODEL,
+];
+
+/// Error returned when a Claude call gets no complete response in time
+///
+/// Timeouts are retryable: `call_claude` retries a timed-out call before giving
+/// up, so seeing this means every attempt ran out of time.
+#[derive(Debug, Clone)]
+pub struct RequestTimeout {
+    pub timeout: Duration,
+}
+
+impl std::fmt::Display for RequestTimeout {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Timeout: Claude API did not respond within {}s",
+            self.timeout.as_secs_f64()
+        )
+    }
+}
+
+impl std::error::Error for RequestTimeout {}
 
 /// Anthropic API client for calling Claude
 pub struct AnthropicClient {
     api_key: String,
     client: reqwest::Client,
+    /// Messages endpoint (overridable to point tests at a local server)
+    api_url: String,
+    /// Per-call timeout covering the request and the response body
+    request_timeout: Duration,
+    circuit_breaker: Arc<CircuitBreaker>,
+    /// Throttle applied to every call (requests per minute and concurrency)
+    rate_limiter: Arc<RateLimiter>,
+    /// Token usage of successful calls
+    usage: Arc<UsageTracker>,
+    /// Model used when a call doesn't specify one
+    default_model: String,
+    /// Max tokens used when a call doesn't specify a limit
+    default_max_tokens: u32,
+    /// Temperature used when a call doesn't specify one (None = API default)
+    default_temperature: Option<f32>,
+    /// top_p used when a call doesn't specify one (None = API default)
+    default_top_p: Option<f32>,
+    /// Offline provider answering in place of the API (development only)
+    echo: Option<EchoClient>,
 }
 
 /// Message content for Claude API
@@ -33,14 +97,41 @@ struct Message {
 
 /// Request body for Claude API
 #[derive(Debug, Serialize)]
-struct ClaudeRequest {
+pub(crate) struct ClaudeRequest {
     model: String,
     max_tokens: u32,
     messages: Vec<Message>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    system: Option<String>,
+    system: Option<SystemPrompt>,
     #[serde(skip_serializing_if = "Option::is_none")]
     temperature: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    top_p: Option<f32>,
+}
+
+/// System prompt: plain text, or text blocks when the prompt is cached
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+enum SystemPrompt {
+    Text(String),
+    Blocks(Vec<SystemBlock>),
+}
+
+/// System prompt text block
+#[derive(Debug, Serialize)]
+struct SystemBlock {
+    #[serde(rename = "type")]
+    block_type: String,
+    text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cache_control: Option<CacheControl>,
+}
+
+/// Marks a prompt prefix for caching
+#[derive(Debug, Serialize)]
+struct CacheControl {
+    #[serde(rename = "type")]
+    control_type: String,
 }
 
 /// Usage information from API response
@@ -48,6 +139,11 @@ struct ClaudeRequest {
 struct Usage {
     input_tokens: u32,
     output_tokens: u32,
+    /// Absent or null when the call used no prompt caching
+    #[serde(default)]
+    cache_creation_input_tokens: Option<u32>,
+    #[serde(default)]
+    cache_read_input_tokens: Option<u32>,
 }
 
 /// API response content
@@ -71,6 +167,17 @@ struct ClaudeResponse {
     usage: Usage,
 }
 
+/// One page of the models endpoint
+#[derive(Debug, Deserialize)]
+struct ModelsResponse {
+    data: Vec<ModelInfo>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ModelInfo {
+    id: String,
+}
+
 /// Error response from Claude API
 #[derive(Debug, Deserialize)]
 struct ErrorResponse {
@@ -83,11 +190,101 @@ impl AnthropicClient {
     /// Create a new Anthropic API client
     pub fn new(api_key: String) -> Result<Self> {
         let client = reqwest::Client::builder()
-            .timeout(Duration::from_secs(REQUEST_TIMEOUT_SECS))
             .build()
             .context("Failed to create HTTP client")?;
 
-        Ok(AnthropicClient { api_key, client })
+        Ok(AnthropicClient {
+            api_key,
+            client,
+            api_url: ANTHROPIC_API_URL.to_string(),
+            request_timeout: Duration::from_secs(DEFAULT_REQUEST_TIMEOUT_SECS),
+            circuit_breaker: Arc::new(CircuitBreaker::default()),
+            rate_limiter: Arc::new(RateLimiter::default()),
+            usage: Arc::new(UsageTracker::default()),
+            default_model: DEFAULT_MODEL.to_string(),
+            default_max_tokens: DEFAULT_MAX_TOKENS,
+            default_temperature: None,
+            default_top_p: None,
+            echo: None,
+        })
+    }
+
+    /// Create a client backed by the offline echo provider (no API key, no network)
+    pub fn echo() -> Result<Self> {
+        let mut client = Self::new(String::new())?;
+        client.echo = Some(EchoClient::default());
+        Ok(client)
+    }
+
+    /// Echo provider client whose canned scores vary reproducibly with `seed`
+    pub fn echo_with_seed(seed: u64) -> Result<Self> {
+        let mut client = Self::new(String::new())?;
+        client.echo = Some(EchoClient::seeded(seed));
+        Ok(client)
+    }
+
+    /// Set the model and max tokens used when a call passes None
+    pub fn with_model_defaults(mut self, model: &str, max_tokens: u32) -> Self {
+        self.default_model = model.to_string();
+        self.default_max_tokens = max_tokens;
+        self
+    }
+
+    /// Model used for calls that don't name one
+    pub fn default_model(&self) -> &str {
+        &self.default_model
+    }
+
+    /// Set client-level sampling defaults, used when a call passes None
+    pub fn with_sampling_defaults(mut self, temperature: Option<f32>, top_p: Option<f32>) -> Self {
+        self.default_temperature = temperature;
+        self.default_top_p = top_p;
+        self
+    }
+
+    /// Set the per-call timeout (default 120s)
+    pub fn with_request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = timeout;
+        self
+    }
+
+    /// Send requests to another messages endpoint (e.g. a local test server)
+    pub fn with_api_url(mut self, api_url: impl Into<String>) -> Self {
+        self.api_url = api_url.into();
+        self
+    }
+
+    /// Use a shared circuit breaker (e.g. one per run across all agents)
+    pub fn with_circuit_breaker(mut self, circuit_breaker: Arc<CircuitBreaker>) -> Self {
+        self.circuit_breaker = circuit_breaker;
+        self
+    }
+
+    /// Get the circuit breaker guarding this client's calls
+    pub fn circuit_breaker(&self) -> Arc<CircuitBreaker> {
+        Arc::clone(&self.circuit_breaker)
+    }
+
+    /// Use a shared rate limiter (e.g. one per account across all agents)
+    pub fn with_rate_limiter(mut self, rate_limiter: Arc<RateLimiter>) -> Self {
+        self.rate_limiter = rate_limiter;
+        self
+    }
+
+    /// Get the rate limiter throttling this client's calls
+    pub fn rate_limiter(&self) -> Arc<RateLimiter> {
+        Arc::clone(&self.rate_limiter)
+    }
+
+    /// Report usage into a shared tracker (e.g. one per run across all agents)
+    pub fn with_usage_tracker(mut self, usage: Arc<UsageTracker>) -> Self {
+        self.usage = usage;
+        self
+    }
+
+    /// Token usage and estimated cost of this client's successful calls
+    pub fn usage(&self) -> ApiUsage {
+        self.usage.snapshot()
     }
 
     /// Call Claude API with system prompt and user message
@@ -95,8 +292,10 @@ impl AnthropicClient {
     /// # Arguments
     /// * `system_prompt` - System prompt to set Claude's behavior
     /// * `user_message` - User message to send to Claude
-    /// * `model` - Model to use (default: claude-sonnet-4-20250514)
-    /// * `max_tokens` - Maximum tokens to generate (default: 4096)
+    /// * `model` - Model to use (default: client default, else claude-sonnet-4-20250514)
+    /// * `max_tokens` - Maximum tokens to generate (default: client default, else 4096)
+    /// * `temperature` - Sampling temperature (default: client default, else API default)
+    /// * `top_p` - Nucleus sampling cutoff (default: client default, else API default)
     ///
     /// # Returns
     /// The text response from Claude
@@ -107,6 +306,12 @@ impl AnthropicClient {
     /// - Network request fails
     /// - API returns an error (rate limiting, invalid request, etc.)
     /// - Response cannot be parsed
+    /// - The circuit breaker is open after repeated failures (`CircuitOpen`)
+    /// - No response arrived within the request timeout, after retrying (`RequestTimeout`)
+    ///
+    /// Calls wait on the rate limiter before they are sent. Each attempt counts
+    /// towards the circuit breaker. An echo client answers locally and records
+    /// no usage.
     pub async fn call_claude(
         &self,
         system_prompt: &str,
@@ -114,35 +319,132 @@ impl AnthropicClient {
         model: Option<&str>,
         max_tokens: Option<u32>,
         temperature: Option<f32>,
+        top_p: Option<f32>,
     ) -> Result<String> {
-        let model = model.unwrap_or(DEFAULT_MODEL);
-        let max_tokens = max_tokens.unwrap_or(DEFAULT_MAX_TOKENS);
+        if let Some(echo) = &self.echo {
+            return Ok(echo.respond(system_prompt, user_message));
+        }
 
-        debug!("Calling Claude API with model: {}, max_tokens: {}, temperature: {:?}",
-               model, max_tokens, temperature);
-        debug!("System prompt length: {} chars", system_prompt.len());
-        debug!("User message length: {} chars", user_message.len());
+        let mut attempt = 0;
+        loop {
+            let result = self
+                .circuit_breaker
+                .call(|| {
+                    self.rate_limiter.call(|| async {
+                        tokio::time::timeout(
+                            self.request_timeout,
+                            self.send_request(system_prompt, user_message, model, max_tokens, temperature, top_p),
+                        )
+                        .await
+                        .unwrap_or_else(|_| Err(RequestTimeout { timeout: self.request_timeout }.into()))
+                    })
+                })
+                .await;
+
+            match result {
+                Err(e) if e.is::<RequestTimeout>() && attempt < TIMEOUT_RETRIES => {
+                    attempt += 1;
+                    warn!("{} - retrying (attempt {} of {})", e, attempt + 1, TIMEOUT_RETRIES + 1);
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// List the model IDs available to this API key
+    ///
+    /// Queries the models endpoint next to the messages endpoint (`.../v1/models`).
+    /// Echo clients have no models endpoint and return an error.
+    pub async fn list_models(&self) -> Result<Vec<String>> {
+        if self.echo.is_some() {
+            anyhow::bail!("The echo provider has no models endpoint");
+        }
+
+        let models_url = format!("{}models", self.api_url.trim_end_matches("messages"));
+        let response = self
+            .client
+            .get(&models_url)
+            .query(&[("limit", "1000")])
+            .header("x-api-key", &self.api_key)
+            .header("anthropic-version", ANTHROPIC_API_VERSION)
+            .timeout(self.request_timeout)
+            .send()
+            .await
+            .context("Failed to send request to Anthropic models endpoint")?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            anyhow::bail!("Models endpoint returned {}: {}", status, body);
+        }
 
-        // Build request
-        let request_body = ClaudeRequest {
-            model: model.to_string(),
-            max_tokens,
+        let models: ModelsResponse = response
+            .json()
+            .await
+            .context("Failed to parse models response")?;
+        Ok(models.data.into_iter().map(|model| model.id).collect())
+    }
+
+    /// Build the request body, applying model/token/sampling defaults
+    ///
+    /// A system prompt long enough to be cached (by `estimate_tokens`) is sent
+    /// as a block marked for prompt caching, so repeated calls sharing it are
+    /// billed at the cache read price.
+    pub(crate) fn build_request(
+        &self,
+        system_prompt: &str,
+        user_message: &str,
+        model: Option<&str>,
+        max_tokens: Option<u32>,
+        temperature: Option<f32>,
+        top_p: Option<f32>,
+    ) -> ClaudeRequest {
+        ClaudeRequest {
+            model: model.unwrap_or(&self.default_model).to_string(),
+            max_tokens: max_tokens.unwrap_or(self.default_max_tokens),
             messages: vec![Message {
                 role: "user".to_string(),
                 content: user_message.to_string(),
             }],
             system: if system_prompt.is_empty() {
                 None
+            } else if estimate_tokens(system_prompt) >= MIN_CACHEABLE_TOKENS {
+                Some(SystemPrompt::Blocks(vec![SystemBlock {
+                    block_type: "text".to_string(),
+                    text: system_prompt.to_string(),
+                    cache_control: Some(CacheControl {
+                        control_type: "ephemeral".to_string(),
+                    }),
+                }]))
             } else {
-                Some(system_prompt.to_string())
+                Some(SystemPrompt::Text(system_prompt.to_string()))
             },
-            temperature,
-        };
+            temperature: temperature.or(self.default_temperature),
+            top_p: top_p.or(self.default_top_p),
+        }
+    }
+
+    /// Send a single request to the Claude API (no circuit breaker)
+    async fn send_request(
+        &self,
+        system_prompt: &str,
+        user_message: &str,
+        model: Option<&str>,
+        max_tokens: Option<u32>,
+        temperature: Option<f32>,
+        top_p: Option<f32>,
+    ) -> Result<String> {
+        let request_body = self.build_request(system_prompt, user_message, model, max_tokens, temperature, top_p);
+
+        debug!("Calling Claude API with model: {}, max_tokens: {}, temperature: {:?}, top_p: {:?}",
+               request_body.model, request_body.max_tokens, request_body.temperature, request_body.top_p);
+        debug!("System prompt length: {} chars", system_prompt.len());
+        debug!("User message length: {} chars", user_message.len());
 
         // Make API request
         let response = self
             .client
-            .post(ANTHROPIC_API_URL)
+            .post(&self.api_url)
             .header("x-api-key", &self.api_key)
             .header("anthropic-version", ANTHROPIC_API_VERSION)
             .header("content-type", "application/json")
@@ -191,6 +493,18 @@ impl AnthropicClient {
         status: reqwest::StatusCode,
         response: reqwest::Response,
     ) -> Result<String> {
+        // Honour retry-after on rate limiting so queued calls back off too
+        if status.as_u16() == 429 {
+            let retry_after = response
+                .headers()
+                .get(reqwest::header::RETRY_AFTER)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.trim().parse::<u64>().ok());
+            if let Some(secs) = retry_after {
+                self.rate_limiter.pause_for(Duration::from_secs(secs));
+            }
+        }
+
         let error_body = response
             .text()
             .await
@@ -215,7 +529,7 @@ impl AnthropicClient {
                 403 => anyhow::bail!("Access forbidden: {}", error_response.message),
                 404 => anyhow::bail!("API endpoint not found: {}", error_response.message),
                 429 => {
-                    warn!("Rate limit exceeded - consider implementing retry logic");
+                    warn!("Rate limit exceeded (429)");
                     anyhow::bail!("Rate limit exceeded: {}", error_response.message)
                 }
                 500..=599 => anyhow::bail!(
@@ -241,10 +555,15 @@ impl AnthropicClient {
         let output_tokens = response.usage.output_tokens;
         let total_tokens = input_tokens + output_tokens;
 
+        self.usage.record(input_tokens as u64, output_tokens as u64);
+        self.usage.record_cache(
+            response.usage.cache_read_input_tokens.unwrap_or(0) as u64,
+            response.usage.cache_creation_input_tokens.unwrap_or(0) as u64,
+        );
+
         // Approximate costs (as of 2025)
-        // Claude Sonnet 4: $3 per 1M input tokens, $15 per 1M output tokens
-        let input_cost = (input_tokens as f64 / 1_000_000.0) * 3.0;
-        let output_cost = (output_tokens as f64 / 1_000_000.0) * 15.0;
+        let input_cost = estimate_cost(input_tokens as u64, 0);
+        let output_cost = estimate_cost(0, output_tokens as u64);
         let total_cost = input_cost + output_cost;
 
         info!(
@@ -264,6 +583,7 @@ impl AnthropicClient {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::api::test_server::TestServer;
 
     #[test]
     fn test_request_serialization() {
@@ -274,8 +594,9 @@ mod tests {
                 role: "user".to_string(),
                 content: "Hello".to_string(),
             }],
-            system: Some("You are a helpful assistant".to_string()),
+            system: Some(SystemPrompt::Text("You are a helpful assistant".to_string())),
             temperature: Some(0.0),
+            top_p: None,
         };
 
         let json = serde_json::to_string(&request).unwrap();
@@ -296,11 +617,75 @@ mod tests {
             }],
             system: None,
             temperature: None,
+            top_p: None,
         };
 
         let json = serde_json::to_string(&request).unwrap();
         assert!(!json.contains("system"));
         assert!(!json.contains("temperature"));  // Should not be included when None
+        assert!(!json.contains("top_p"));
+    }
+
+    #[test]
+    fn test_sampling_defaults_and_overrides() {
+        let client = AnthropicClient::new("test-key".to_string())
+            .unwrap()
+            .with_sampling_defaults(Some(0.7), Some(0.9));
+
+        // Per-call values win over client defaults
+        let request = client.build_request("", "Test", None, None, Some(0.0), None);
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["temperature"], 0.0);
+        assert!((json["top_p"].as_f64().unwrap() - 0.9).abs() < 1e-6);
+
+        let request = client.build_request("", "Test", None, None, None, None);
+        let json = serde_json::to_value(&request).unwrap();
+        assert!((json["temperature"].as_f64().unwrap() - 0.7).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_long_system_prompt_is_marked_for_caching() {
+        let client = AnthropicClient::new("test-key".to_string()).unwrap();
+
+        let request = client.build_request("You are a helpful assistant", "Test", None, None, None, None);
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["system"], "You are a helpful assistant");
+
+        let long_prompt = "Apply the governance rules below to every answer. ".repeat(200);
+        assert!(estimate_tokens(&long_prompt) >= MIN_CACHEABLE_TOKENS);
+        let request = client.build_request(&long_prompt, "Test", None, None, None, None);
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["system"][0]["type"], "text");
+        assert_eq!(json["system"][0]["text"], long_prompt.as_str());
+        assert_eq!(json["system"][0]["cache_control"]["type"], "ephemeral");
+    }
+
+    #[tokio::test]
+    async fn test_null_cache_usage_counts_as_zero() {
+        let server = TestServer::fixed(
+            serde_json::json!({
+                "id": "msg_test",
+                "type": "message",
+                "role": "assistant",
+                "content": [{ "type": "text", "text": "Hi" }],
+                "model": "test-model",
+                "stop_reason": "end_turn",
+                "usage": {
+                    "input_tokens": 10,
+                    "output_tokens": 5,
+                    "cache_creation_input_tokens": null,
+                    "cache_read_input_tokens": 40,
+                },
+            })
+            .to_string(),
+        )
+        .await;
+        let client = server.client();
+
+        assert_eq!(client.call_claude("", "Hello", None, None, None, None).await.unwrap(), "Hi");
+        let usage = client.usage();
+        assert_eq!(usage.cache_creation_tokens, 0);
+        assert_eq!(usage.cache_read_tokens, 40);
     }
 
     #[test]
@@ -309,6 +694,29 @@ mod tests {
         assert!(client.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_unresponsive_api_times_out() {
+        // Accepts connections but never answers
+        let server = TestServer::unresponsive().await;
+
+        let timeout = Duration::from_millis(200);
+        let client = server.client().with_request_timeout(timeout);
+
+        let start = std::time::Instant::now();
+        let err = client
+            .call_claude("", "Hello", None, None, None, None)
+            .await
+            .unwrap_err();
+        let elapsed = start.elapsed();
+
+        let timeout_err = err.downcast_ref::<RequestTimeout>().expect("expected a Timeout error");
+        assert_eq!(timeout_err.timeout, timeout);
+        // One attempt plus the retry, each cut off at the timeout
+        let attempts = TIMEOUT_RETRIES + 1;
+        assert!(elapsed >= timeout * attempts);
+        assert!(elapsed < timeout * attempts + Duration::from_secs(2));
+    }
+
     // Note: Actual API tests would require a valid API key and should be integration tests
     // They are commented out to avoid hitting the API during unit tests
 
@@ -326,6 +734,7 @@ mod tests {
                     None,
                     Some(10),
                     None,
+                    None,
                 )
                 .await;
 
@@ -0,0 +1,185 @@
+use anyhow::Result;
+use log::{info, warn};
+use std::future::Future;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Consecutive failures before the breaker opens
+pub const DEFAULT_FAILURE_THRESHOLD: u32 = 5;
+
+/// How long the breaker stays open before allowing a trial call
+pub const DEFAULT_COOLDOWN_SECS: u64 = 60;
+
+/// Error returned when a call is rejected because the breaker is open
+///
+/// No request was sent. `Orchestrator::halt_on_circuit_open` looks for this
+/// in a failed step's error chain and halts the run rather than failing the
+/// step again; `retry_after` says when a trial call will be let through.
+#[derive(Debug, Clone)]
+pub struct CircuitOpen {
+    pub consecutive_failures: u32,
+    pub retry_after: Duration,
+}
+
+impl std::fmt::Display for CircuitOpen {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Circuit open: Claude API failed {} consecutive times - retry in {}s",
+            self.consecutive_failures,
+            self.retry_after.as_secs()
+        )
+    }
+}
+
+impl std::error::Error for CircuitOpen {}
+
+#[derive(Debug, Default)]
+struct BreakerState {
+    consecutive_failures: u32,
+    opened_at: Option<Instant>,
+}
+
+/// Circuit breaker for Claude API calls
+///
+/// After `failure_threshold` consecutive failures the breaker opens and calls
+/// fail fast with `CircuitOpen` instead of hitting a degraded API. It resets on
+/// a successful call; once the cooldown elapses a single trial call is allowed,
+/// and another failure re-opens it immediately.
+#[derive(Debug)]
+pub struct CircuitBreaker {
+    failure_threshold: u32,
+    cooldown: Duration,
+    state: Mutex<BreakerState>,
+}
+
+impl CircuitBreaker {
+    /// Create a breaker with the given failure threshold and cooldown
+    pub fn new(failure_threshold: u32, cooldown: Duration) -> Self {
+        CircuitBreaker {
+            failure_threshold: failure_threshold.max(1),
+            cooldown,
+            state: Mutex::new(BreakerState::default()),
+        }
+    }
+
+    /// Check whether a call may proceed
+    pub fn check(&self) -> std::result::Result<(), CircuitOpen> {
+        let mut state = self.state.lock().unwrap();
+
+        if let Some(opened_at) = state.opened_at {
+            let elapsed = opened_at.elapsed();
+            if elapsed < self.cooldown {
+                return Err(CircuitOpen {
+                    consecutive_failures: state.consecutive_failures,
+                    retry_after: self.cooldown - elapsed,
+                });
+            }
+
+            // Cooldown elapsed - allow one trial call (half-open)
+            info!("Circuit breaker cooldown elapsed - allowing trial call");
+            state.opened_at = None;
+            state.consecutive_failures = self.failure_threshold - 1;
+        }
+
+        Ok(())
+    }
+
+    /// Record a successful call, closing the breaker
+    pub fn record_success(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.consecutive_failures = 0;
+        state.opened_at = None;
+    }
+
+    /// Record a failed call, opening the breaker once the threshold is reached
+    pub fn record_failure(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.consecutive_failures += 1;
+
+        if state.consecutive_failures >= self.failure_threshold && state.opened_at.is_none() {
+            warn!(
+                "Circuit breaker OPEN after {} consecutive Claude API failures",
+                state.consecutive_failures
+            );
+            state.opened_at = Some(Instant::now());
+        }
+    }
+
+    /// True if the breaker is open and still cooling down
+    pub fn is_open(&self) -> bool {
+        let state = self.state.lock().unwrap();
+        matches!(state.opened_at, Some(opened_at) if opened_at.elapsed() < self.cooldown)
+    }
+
+    /// Run an operation through the breaker, recording its outcome
+    pub async fn call<T, F, Fut>(&self, operation: F) -> Result<T>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        self.check()?;
+
+        match operation().await {
+            Ok(value) => {
+                self.record_success();
+                Ok(value)
+            }
+            Err(e) => {
+                self.record_failure();
+                Err(e)
+            }
+        }
+    }
+}
+
+impl Default for CircuitBreaker {
+    fn default() -> Self {
+        Self::new(DEFAULT_FAILURE_THRESHOLD, Duration::from_secs(DEFAULT_COOLDOWN_SECS))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    async fn always_fails(calls: &AtomicU32) -> Result<String> {
+        calls.fetch_add(1, Ordering::SeqCst);
+        anyhow::bail!("Anthropic API server error: overloaded")
+    }
+
+    #[tokio::test]
+    async fn test_breaker_opens_after_threshold() {
+        let breaker = CircuitBreaker::new(3, Duration::from_secs(60));
+        let calls = AtomicU32::new(0);
+
+        for _ in 0..3 {
+            let err = breaker.call(|| always_fails(&calls)).await.unwrap_err();
+            assert!(err.downcast_ref::<CircuitOpen>().is_none());
+        }
+        assert!(breaker.is_open());
+
+        // Further calls fail fast without reaching the API
+        let err = breaker.call(|| always_fails(&calls)).await.unwrap_err();
+        assert!(err.downcast_ref::<CircuitOpen>().is_some());
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_breaker_resets_on_success_and_cooldown() {
+        let breaker = CircuitBreaker::new(2, Duration::from_millis(0));
+        let calls = AtomicU32::new(0);
+
+        let _ = breaker.call(|| always_fails(&calls)).await;
+        breaker.record_success();
+        let _ = breaker.call(|| always_fails(&calls)).await;
+        assert!(!breaker.is_open(), "success should reset the failure count");
+
+        let _ = breaker.call(|| always_fails(&calls)).await;
+        // Zero cooldown: trial call is allowed straight away
+        let result = breaker.call(|| async { Ok::<_, anyhow::Error>("ok") }).await;
+        assert_eq!(result.unwrap(), "ok");
+        assert!(!breaker.is_open());
+    }
+}
@@ -0,0 +1,277 @@
+use serde_json::json;
+
+/// Lines that introduce the response template in agent prompts
+const FORMAT_MARKERS: [&str; 7] = [
+    "Format your response as",
+    "Format as:",
+    "Format:",
+    "Format each entry as:",
+    "Format each principle as:",
+    "Please extract:",
+    "Provide:",
+];
+
+/// Score the echo provider reports for LLM-judged metrics when unseeded
+const ECHO_SCORE: f64 = 0.85;
+
+/// Deterministic index into `len` options for a seed and a named choice
+///
+/// Each `salt` draws independently, so adding a choice elsewhere does not
+/// shift existing ones. Used by the echo provider and the orchestrator stubs.
+pub fn seeded_index(seed: u64, salt: &str, len: usize) -> usize {
+    // FNV-1a over the salt, mixed with the seed by SplitMix64
+    let salt_hash = salt
+        .bytes()
+        .fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3));
+    let mut z = (seed ^ salt_hash).wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^= z >> 31;
+    (z % len.max(1) as u64) as usize
+}
+
+/// Offline provider that answers prompts with deterministic, template-filled text
+///
+/// For UI development without API tokens. Unlike the stub agents, responses
+/// still go through the real agent parsers: metric prompts get JSON of the
+/// requested shape, and Markdown prompts get their own response template back
+/// with each `[placeholder]` filled in. A seed varies the judged scores
+/// (0.75-0.95) reproducibly.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EchoClient {
+    seed: Option<u64>,
+}
+
+impl EchoClient {
+    /// Echo provider whose scores vary with the seed
+    pub fn seeded(seed: u64) -> Self {
+        EchoClient { seed: Some(seed) }
+    }
+
+    /// Score reported for LLM-judged metrics
+    fn score(&self) -> f64 {
+        match self.seed {
+            Some(seed) => 0.75 + seeded_index(seed, "echo-score", 21) as f64 / 100.0,
+            None => ECHO_SCORE,
+        }
+    }
+
+    /// Build the response to one Claude call
+    pub fn respond(&self, system_prompt: &str, user_message: &str) -> String {
+        if system_prompt.contains("JSON") || user_message.contains("Respond in JSON") || user_message.contains("JSON object") {
+            return json_response(&format!("{}\n{}", system_prompt, user_message), self.score());
+        }
+        if system_prompt.contains("NORTH-STAR NARRATIVE") {
+            return NORTH_STAR.to_string();
+        }
+        if user_message.contains("Format each principle as:") {
+            return PRINCIPLES.to_string();
+        }
+        if user_message.starts_with("GLOSSARY CREATION") {
+            return glossary();
+        }
+
+        match template(user_message) {
+            Some(template) => fill_template(template),
+            None => fallback(user_message),
+        }
+    }
+}
+
+/// JSON shaped like the metric prompt asks for, with `score` for judged values
+fn json_response(prompt: &str, score: f64) -> String {
+    let value = if prompt.contains("\"ci\":") && prompt.contains("\"efi\":") {
+        json!({
+            "ci": {
+                "logical_flow": score,
+                "term_consistency": score,
+                "sentence_clarity": score,
+                "structure_consistency": score,
+                "ci_score": score,
+                "overall_assessment": "Echo provider: content reads clearly."
+            },
+            "ias": {"score": score, "reasoning": "Echo provider: content follows the objectives."},
+            "efi": {
+                "claims": [],
+                "summary": {"total_claims": 4, "scored_claims": 2, "substantiated_scored": 2, "efi_score": 1.0},
+                "reasoning": "Echo provider: scored claims are substantiated."
+            }
+        })
+    } else if prompt.contains("ci_score") {
+        json!({
+            "logical_flow": {"score": score, "rationale": "Echo provider"},
+            "term_consistency": {"score": score, "rationale": "Echo provider"},
+            "sentence_clarity": {"score": score, "rationale": "Echo provider"},
+            "structure_consistency": {"score": score, "rationale": "Echo provider"},
+            "ci_score": score,
+            "overall_assessment": "Echo provider: content reads clearly."
+        })
+    } else if prompt.contains("unique_concepts") {
+        json!({"unique_concepts": 6, "relationships": 4, "decision_points": 2, "content_units": 8})
+    } else if prompt.contains("substantiated_scored") {
+        json!({
+            "claims": [],
+            "summary": {"total_claims": 4, "scored_claims": 2, "substantiated_scored": 2, "efi_score": 1.0},
+            "reasoning": "Echo provider: scored claims are substantiated."
+        })
+    } else if prompt.contains("\"rationale\"") {
+        json!({"score": score, "rationale": "Echo provider: findings address the objectives."})
+    } else {
+        json!({"score": score, "reasoning": "Echo provider: content follows the objectives."})
+    };
+
+    value.to_string()
+}
+
+/// Lines after the last format marker line, if the prompt has one
+fn template(user_message: &str) -> Option<&str> {
+    let marker = FORMAT_MARKERS
+        .iter()
+        .filter_map(|marker| user_message.rfind(marker))
+        .max()?;
+    let start = user_message[marker..].find('\n').map_or(user_message.len(), |idx| marker + idx);
+    Some(&user_message[start..])
+}
+
+/// Replace each `[placeholder]` with deterministic text and drop `...` lines
+fn fill_template(template: &str) -> String {
+    template
+        .lines()
+        .filter(|line| line.trim() != "...")
+        .map(fill_line)
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim()
+        .to_string()
+}
+
+fn fill_line(line: &str) -> String {
+    let mut filled = String::new();
+    let mut rest = line;
+
+    while let Some(open) = rest.find('[') {
+        let Some(close) = rest[open..].find(']').map(|idx| open + idx) else {
+            break;
+        };
+        filled.push_str(&rest[..open]);
+        filled.push_str(&fill_placeholder(&rest[open + 1..close]));
+        rest = &rest[close + 1..];
+    }
+
+    filled.push_str(rest);
+    filled
+}
+
+/// `[A | B]` and `[A / B]` pick the first option, `[0-100]` a value 80% of the way
+/// up the range, anything else becomes its own description
+fn fill_placeholder(placeholder: &str) -> String {
+    for separator in ['|', '/'] {
+        if placeholder.contains(separator) {
+            if let Some(first) = placeholder.split(separator).map(str::trim).find(|s| !s.is_empty()) {
+                return first.to_string();
+            }
+        }
+    }
+
+    if let Some((low, high)) = placeholder.split_once('-') {
+        if let (Ok(low), Ok(high)) = (low.trim().parse::<f64>(), high.trim().parse::<f64>()) {
+            let value = low + (high - low) * 0.8;
+            return if high > 1.0 {
+                format!("{}", value.round())
+            } else {
+                format!("{:.2}", value)
+            };
+        }
+    }
+
+    placeholder.trim().to_string()
+}
+
+/// Plain Markdown for prompts without a response template
+fn fallback(user_message: &str) -> String {
+    let heading = user_message
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .unwrap_or("Response");
+
+    format!(
+        "# {}\n\n## Overview\n\nDeterministic response from the echo provider.\n\n\
+        ## Details\n\n- First point\n- Second point\n- Third point\n",
+        heading.trim_start_matches('#').trim()
+    )
+}
+
+fn glossary() -> String {
+    ["Framework", "Objective", "Constraint", "Stakeholder", "Outcome"]
+        .iter()
+        .map(|term| format!("TERM: {}\nDEFINITION: Echo provider definition of {}.", term, term.to_lowercase()))
+        .collect::<Vec<_>>()
+        .join("\n\n---\n\n")
+}
+
+const PRINCIPLES: &str = "\
+1. Clarity First: State each element plainly before elaborating on it.
+2. Traceable Reasoning: Link every conclusion back to the analysis that supports it.
+3. Proportionate Scope: Spend effort where the objectives place their weight.";
+
+const NORTH_STAR: &str = "This framework exists to turn scattered observations into a clear, \
+shared direction that every later decision can be measured against. It starts from the core \
+thesis, keeps the operating principles in view, and follows the causal structure from root \
+drivers to visible outcomes so that no recommendation floats free of its reasons. Work guided \
+by it should favour clarity over volume, trace each claim to its evidence, and invest effort \
+in proportion to what the objectives actually require. When trade-offs appear, the framework \
+asks which option keeps the reasoning traceable and the scope honest, and chooses that one. \
+Used this way it becomes a steady reference point rather than a document to be filed away.";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agents::analysis_synthesis::AnalysisSynthesisAgent;
+    use crate::api::AnthropicClient;
+
+    #[test]
+    fn test_seeded_scores_are_reproducible() {
+        assert_eq!(EchoClient::default().score(), ECHO_SCORE);
+        assert_eq!(EchoClient::seeded(7).score(), EchoClient::seeded(7).score());
+        assert!((0.75..=0.95).contains(&EchoClient::seeded(7).score()));
+        assert_eq!(seeded_index(42, "geometry", 3), seeded_index(42, "geometry", 3));
+        assert!(seeded_index(42, "geometry", 3) < 3);
+    }
+
+    #[test]
+    fn test_fill_placeholder() {
+        assert_eq!(fill_placeholder("Exploratory | Analytical | Operational"), "Exploratory");
+        assert_eq!(fill_placeholder("PASS / FAIL / WARNING"), "PASS");
+        assert_eq!(fill_placeholder("0-100"), "80");
+        assert_eq!(fill_placeholder("0.0-1.0"), "0.80");
+        assert_eq!(fill_placeholder("Finding 1"), "Finding 1");
+        assert_eq!(fill_line("1. [Theme 1] - [Description]"), "1. Theme 1 - Description");
+    }
+
+    #[tokio::test]
+    async fn test_step_3_lenses_parse_echo_responses() {
+        let mut agent = AnalysisSynthesisAgent::from_client(AnthropicClient::echo().unwrap());
+
+        let (diagnostic, report) = agent
+            .perform_six_lens_analysis(
+                "# Onboarding Guide\n\nNew hires read the handbook, then shadow a mentor for a week.",
+                "Objectives: shorten onboarding",
+                "Operational",
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(report.lens_results.len(), 6);
+        for lens in &report.lens_results {
+            assert_eq!(
+                lens.key_findings,
+                vec!["Finding 1", "Finding 2", "Finding 3"],
+                "{} lens findings",
+                lens.lens_name
+            );
+        }
+        assert!(diagnostic.contains("**Overall Diagnostic Summary:**"));
+        assert_eq!(agent.api_usage().calls, 0);
+    }
+}
@@ -1,3 +1,17 @@
 pub mod anthropic;
+pub mod circuit_breaker;
+pub mod echo;
+pub mod rate_limiter;
+pub mod shared;
+#[cfg(test)]
+pub(crate) mod test_server;
+pub mod tokenizer;
+pub mod usage;
 
-pub use anthropic::AnthropicClient;
+pub use anthropic::{AnthropicClient, RequestTimeout, DEFAULT_REQUEST_TIMEOUT_SECS, DEFAULT_UTILITY_MODEL, KNOWN_MODELS};
+pub use circuit_breaker::{CircuitBreaker, CircuitOpen};
+pub use echo::EchoClient;
+pub use rate_limiter::RateLimiter;
+pub use shared::SharedSlot;
+pub use tokenizer::estimate_tokens;
+pub use usage::{ApiUsage, UsageTracker};
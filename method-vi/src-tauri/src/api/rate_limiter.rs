@@ -0,0 +1,183 @@
+use log::{debug, warn};
+use std::future::Future;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tokio::sync::Semaphore;
+
+/// Requests per minute allowed by default
+pub const DEFAULT_REQUESTS_PER_MINUTE: u32 = 50;
+
+/// Calls allowed in flight at once by default
+pub const DEFAULT_MAX_CONCURRENT: usize = 4;
+
+#[derive(Debug)]
+struct TokenBucket {
+    tokens: f64,
+    last_refill: Instant,
+    /// Set from a 429 retry-after; no call starts before this
+    paused_until: Option<Instant>,
+}
+
+/// Request throttle for Claude API calls
+///
+/// A semaphore caps the calls in flight and a token bucket paces call starts to
+/// `requests_per_minute`, allowing bursts of up to `max_concurrent` calls. A
+/// 429 pauses every caller until its retry-after has passed. Every client built
+/// from the app config gets the same limiter, so all runs and agents draw on
+/// one request budget.
+#[derive(Debug)]
+pub struct RateLimiter {
+    requests_per_minute: u32,
+    max_concurrent: usize,
+    permits: Semaphore,
+    bucket: Mutex<TokenBucket>,
+}
+
+impl RateLimiter {
+    /// Create a limiter; `requests_per_minute` of 0 only limits concurrency
+    pub fn new(requests_per_minute: u32, max_concurrent: usize) -> Self {
+        let max_concurrent = max_concurrent.max(1);
+        RateLimiter {
+            requests_per_minute,
+            max_concurrent,
+            permits: Semaphore::new(max_concurrent),
+            bucket: Mutex::new(TokenBucket {
+                tokens: max_concurrent as f64,
+                last_refill: Instant::now(),
+                paused_until: None,
+            }),
+        }
+    }
+
+    /// Call starts allowed per minute (0 = unpaced)
+    pub fn requests_per_minute(&self) -> u32 {
+        self.requests_per_minute
+    }
+
+    /// Calls allowed in flight at once
+    pub fn max_concurrent(&self) -> usize {
+        self.max_concurrent
+    }
+
+    /// Number of calls currently holding a concurrency permit
+    pub fn in_flight(&self) -> usize {
+        self.max_concurrent - self.permits.available_permits()
+    }
+
+    /// Hold back new calls for the given time (e.g. a 429 retry-after)
+    pub fn pause_for(&self, duration: Duration) {
+        let mut bucket = self.bucket.lock().unwrap();
+        let until = Instant::now() + duration;
+        if bucket.paused_until.is_none_or(|current| current < until) {
+            warn!("Rate limiter paused for {}s", duration.as_secs());
+            bucket.paused_until = Some(until);
+        }
+    }
+
+    /// Time to wait before the next call may start, taking a token if none
+    fn reserve(&self) -> Option<Duration> {
+        let mut bucket = self.bucket.lock().unwrap();
+        let now = Instant::now();
+
+        if let Some(until) = bucket.paused_until {
+            if now < until {
+                return Some(until - now);
+            }
+            bucket.paused_until = None;
+        }
+
+        if self.requests_per_minute == 0 {
+            return None;
+        }
+
+        let per_second = self.requests_per_minute as f64 / 60.0;
+        let elapsed = now.duration_since(bucket.last_refill).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * per_second).min(self.max_concurrent as f64);
+        bucket.last_refill = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            None
+        } else {
+            Some(Duration::from_secs_f64((1.0 - bucket.tokens) / per_second))
+        }
+    }
+
+    /// Run an operation once a concurrency permit and a rate token are available
+    pub async fn call<T, F, Fut>(&self, operation: F) -> T
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = T>,
+    {
+        let _permit = self
+            .permits
+            .acquire()
+            .await
+            .expect("rate limiter semaphore is never closed");
+
+        while let Some(wait) = self.reserve() {
+            debug!("Rate limiter: waiting {}ms before next Claude call", wait.as_millis());
+            tokio::time::sleep(wait).await;
+        }
+
+        operation().await
+    }
+}
+
+impl Default for RateLimiter {
+    fn default() -> Self {
+        Self::new(DEFAULT_REQUESTS_PER_MINUTE, DEFAULT_MAX_CONCURRENT)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_concurrency_limit() {
+        let limiter = Arc::new(RateLimiter::new(0, 2));
+        let current = Arc::new(AtomicUsize::new(0));
+        let peak = Arc::new(AtomicUsize::new(0));
+
+        let handles: Vec<_> = (0..6)
+            .map(|_| {
+                let limiter = Arc::clone(&limiter);
+                let current = Arc::clone(&current);
+                let peak = Arc::clone(&peak);
+                tokio::spawn(async move {
+                    limiter
+                        .call(|| async {
+                            let now = current.fetch_add(1, Ordering::SeqCst) + 1;
+                            peak.fetch_max(now, Ordering::SeqCst);
+                            tokio::time::sleep(Duration::from_millis(20)).await;
+                            current.fetch_sub(1, Ordering::SeqCst);
+                        })
+                        .await
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            handle.await.unwrap();
+        }
+
+        assert_eq!(peak.load(Ordering::SeqCst), 2);
+        assert_eq!(limiter.in_flight(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_requests_per_minute_paces_calls() {
+        // 600 RPM = one call per 100ms after a burst of max_concurrent
+        let limiter = RateLimiter::new(600, 1);
+        let start = Instant::now();
+
+        for _ in 0..3 {
+            limiter.call(|| async {}).await;
+        }
+
+        assert!(start.elapsed() >= Duration::from_millis(190));
+    }
+}
@@ -0,0 +1,34 @@
+use std::sync::{Arc, OnceLock};
+
+/// Slot holding a value shared by every client that reads it
+///
+/// Clones share the slot, and the value is created by the first client that
+/// asks for it. Used for the per-run circuit breaker and the per-app rate
+/// limiter. It is runtime state, not a setting, so slots always compare equal.
+#[derive(Debug)]
+pub struct SharedSlot<T>(Arc<OnceLock<Arc<T>>>);
+
+impl<T> SharedSlot<T> {
+    /// The shared value, created with `init` if this is the first use
+    pub fn get_or_init(&self, init: impl FnOnce() -> T) -> Arc<T> {
+        Arc::clone(self.0.get_or_init(|| Arc::new(init())))
+    }
+}
+
+impl<T> Clone for SharedSlot<T> {
+    fn clone(&self) -> Self {
+        SharedSlot(Arc::clone(&self.0))
+    }
+}
+
+impl<T> Default for SharedSlot<T> {
+    fn default() -> Self {
+        SharedSlot(Arc::new(OnceLock::new()))
+    }
+}
+
+impl<T> PartialEq for SharedSlot<T> {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
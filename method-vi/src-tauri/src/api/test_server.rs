@@ -0,0 +1,179 @@
+//! Local HTTP server standing in for the Claude API in tests
+//!
+//! Clients are pointed at it with `with_api_url`. It can answer like the echo
+//! provider, return one canned body to every request, or never answer at all.
+//! Every request body it receives is logged for assertions.
+
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+use super::{AnthropicClient, EchoClient};
+
+/// Requests seen by a `TestServer`
+#[derive(Default)]
+pub(crate) struct RequestLog {
+    /// JSON bodies of the requests, in arrival order (GETs are not logged)
+    pub requests: Vec<serde_json::Value>,
+    /// First line of each request's user message
+    pub prompts: Vec<String>,
+    in_flight: Vec<String>,
+    /// (already in flight, newly arrived) prompt pairs
+    pub overlaps: Vec<(String, String)>,
+}
+
+#[derive(Clone)]
+enum Reply {
+    /// Answer like the echo provider, holding each response for the delay
+    Echo(Duration),
+    /// Return this body to every request
+    Fixed(String),
+    /// Accept connections but never answer
+    Never,
+}
+
+/// Claude API stand-in listening on a local port
+pub(crate) struct TestServer {
+    base_url: String,
+    log: Arc<Mutex<RequestLog>>,
+}
+
+impl TestServer {
+    /// Answer every message like the echo provider, after `delay`
+    ///
+    /// A non-zero delay makes concurrent requests overlap.
+    pub async fn echo(delay: Duration) -> Self {
+        Self::start(Reply::Echo(delay)).await
+    }
+
+    /// Answer every request with `body` as JSON
+    pub async fn fixed(body: impl Into<String>) -> Self {
+        Self::start(Reply::Fixed(body.into())).await
+    }
+
+    /// Accept connections but never answer them
+    pub async fn unresponsive() -> Self {
+        Self::start(Reply::Never).await
+    }
+
+    async fn start(reply: Reply) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let log = Arc::new(Mutex::new(RequestLog::default()));
+
+        let server_log = log.clone();
+        tokio::spawn(async move {
+            let mut held = Vec::new();
+            while let Ok((socket, _)) = listener.accept().await {
+                if matches!(reply, Reply::Never) {
+                    held.push(socket);
+                    continue;
+                }
+                tokio::spawn(respond(socket, reply.clone(), server_log.clone()));
+            }
+        });
+
+        TestServer { base_url, log }
+    }
+
+    /// Messages endpoint, for `AnthropicClient::with_api_url`
+    pub fn messages_url(&self) -> String {
+        format!("{}/v1/messages", self.base_url)
+    }
+
+    /// Client sending its calls to this server
+    pub fn client(&self) -> AnthropicClient {
+        AnthropicClient::new("test-key".to_string())
+            .unwrap()
+            .with_api_url(self.messages_url())
+    }
+
+    /// Requests received so far
+    pub fn log(&self) -> MutexGuard<'_, RequestLog> {
+        self.log.lock().unwrap()
+    }
+}
+
+async fn respond(mut socket: TcpStream, reply: Reply, log: Arc<Mutex<RequestLog>>) {
+    let Some(body) = read_body(&mut socket).await else {
+        return;
+    };
+
+    let request: Option<serde_json::Value> = serde_json::from_slice(&body).ok();
+    let (system, user) = request
+        .as_ref()
+        .map(|request| {
+            (
+                // Cached system prompts arrive as a list of text blocks
+                request["system"]
+                    .as_str()
+                    .or_else(|| request["system"][0]["text"].as_str())
+                    .unwrap_or_default()
+                    .to_string(),
+                request["messages"][0]["content"].as_str().unwrap_or_default().to_string(),
+            )
+        })
+        .unwrap_or_default();
+    let prompt = user.lines().next().unwrap_or_default().to_string();
+
+    if let Some(request) = request {
+        let mut log = log.lock().unwrap();
+        let overlaps: Vec<_> = log.in_flight.iter().map(|p| (p.clone(), prompt.clone())).collect();
+        log.overlaps.extend(overlaps);
+        log.in_flight.push(prompt.clone());
+        log.prompts.push(prompt.clone());
+        log.requests.push(request);
+    }
+
+    let reply = match reply {
+        Reply::Echo(delay) => {
+            tokio::time::sleep(delay).await;
+            serde_json::json!({
+                "id": "msg_test",
+                "type": "message",
+                "role": "assistant",
+                "content": [{ "type": "text", "text": EchoClient::default().respond(&system, &user) }],
+                "model": "test-model",
+                "stop_reason": "end_turn",
+                "usage": { "input_tokens": 1, "output_tokens": 1 },
+            })
+            .to_string()
+        }
+        Reply::Fixed(body) => body,
+        Reply::Never => unreachable!("unanswered connections are held, not served"),
+    };
+
+    let response = format!(
+        "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+        reply.len(),
+        reply
+    );
+    log.lock().unwrap().in_flight.retain(|p| *p != prompt);
+    let _ = socket.write_all(response.as_bytes()).await;
+}
+
+/// Read one HTTP request, returning its body (empty for a GET)
+async fn read_body(socket: &mut TcpStream) -> Option<Vec<u8>> {
+    let mut request = Vec::new();
+    let mut buf = [0u8; 8192];
+    loop {
+        let n = socket.read(&mut buf).await.ok()?;
+        if n == 0 {
+            return None;
+        }
+        request.extend_from_slice(&buf[..n]);
+
+        let text = String::from_utf8_lossy(&request);
+        if let Some(split) = text.find("\r\n\r\n") {
+            let length = text[..split]
+                .lines()
+                .find_map(|line| line.to_ascii_lowercase().strip_prefix("content-length:").map(|v| v.trim().parse::<usize>().unwrap()))
+                .unwrap_or(0);
+            if request.len() >= split + 4 + length {
+                return Some(request[split + 4..split + 4 + length].to_vec());
+            }
+        }
+    }
+}
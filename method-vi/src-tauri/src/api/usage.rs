@@ -0,0 +1,137 @@
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Claude Sonnet 4 pricing: $3 per 1M input tokens
+pub const INPUT_COST_PER_MTOK: f64 = 3.0;
+
+/// Claude Sonnet 4 pricing: $15 per 1M output tokens
+pub const OUTPUT_COST_PER_MTOK: f64 = 15.0;
+
+/// Prompt caching: writing a cache entry costs 1.25x the input price
+pub const CACHE_WRITE_MULTIPLIER: f64 = 1.25;
+
+/// Prompt caching: reading a cached prefix costs 0.1x the input price
+pub const CACHE_READ_MULTIPLIER: f64 = 0.1;
+
+/// Approximate cost in USD for a number of input and output tokens
+pub fn estimate_cost(input_tokens: u64, output_tokens: u64) -> f64 {
+    (input_tokens as f64 / 1_000_000.0) * INPUT_COST_PER_MTOK
+        + (output_tokens as f64 / 1_000_000.0) * OUTPUT_COST_PER_MTOK
+}
+
+/// Approximate cost in USD of cache reads and cache writes
+pub fn estimate_cache_cost(cache_read_tokens: u64, cache_creation_tokens: u64) -> f64 {
+    (cache_read_tokens as f64 * CACHE_READ_MULTIPLIER + cache_creation_tokens as f64 * CACHE_WRITE_MULTIPLIER)
+        / 1_000_000.0
+        * INPUT_COST_PER_MTOK
+}
+
+/// Dollars saved by caching versus sending the same tokens as plain input
+///
+/// Negative when cache writes were never read back often enough to pay off.
+pub fn estimate_cache_savings(cache_read_tokens: u64, cache_creation_tokens: u64) -> f64 {
+    estimate_cost(cache_read_tokens + cache_creation_tokens, 0)
+        - estimate_cache_cost(cache_read_tokens, cache_creation_tokens)
+}
+
+/// Snapshot of API usage for a client, agent or run
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct ApiUsage {
+    pub calls: u64,
+    pub input_tokens: u64,
+    pub output_tokens: u64,
+    /// Includes cache reads and writes
+    pub cost_usd: f64,
+    #[serde(default)]
+    pub cache_read_tokens: u64,
+    #[serde(default)]
+    pub cache_creation_tokens: u64,
+    /// Estimated savings from prompt caching versus uncached input
+    #[serde(default)]
+    pub cache_savings_usd: f64,
+}
+
+impl ApiUsage {
+    /// Add another usage snapshot to this one
+    pub fn merge(&mut self, other: ApiUsage) {
+        self.calls += other.calls;
+        self.input_tokens += other.input_tokens;
+        self.output_tokens += other.output_tokens;
+        self.cost_usd += other.cost_usd;
+        self.cache_read_tokens += other.cache_read_tokens;
+        self.cache_creation_tokens += other.cache_creation_tokens;
+        self.cache_savings_usd += other.cache_savings_usd;
+    }
+}
+
+/// Running totals of successful Claude API calls
+///
+/// Counters are atomics, so concurrent calls record without a lock. Each client
+/// starts with its own tracker; `AnthropicClient::with_usage_tracker` points
+/// several clients at one to get a combined total.
+#[derive(Debug, Default)]
+pub struct UsageTracker {
+    calls: AtomicU64,
+    input_tokens: AtomicU64,
+    output_tokens: AtomicU64,
+    cache_read_tokens: AtomicU64,
+    cache_creation_tokens: AtomicU64,
+}
+
+impl UsageTracker {
+    /// Record one call's token usage
+    pub fn record(&self, input_tokens: u64, output_tokens: u64) {
+        self.calls.fetch_add(1, Ordering::Relaxed);
+        self.input_tokens.fetch_add(input_tokens, Ordering::Relaxed);
+        self.output_tokens.fetch_add(output_tokens, Ordering::Relaxed);
+    }
+
+    /// Record one call's prompt cache reads and writes
+    pub fn record_cache(&self, cache_read_tokens: u64, cache_creation_tokens: u64) {
+        self.cache_read_tokens.fetch_add(cache_read_tokens, Ordering::Relaxed);
+        self.cache_creation_tokens.fetch_add(cache_creation_tokens, Ordering::Relaxed);
+    }
+
+    /// Current totals with estimated cost
+    pub fn snapshot(&self) -> ApiUsage {
+        let input_tokens = self.input_tokens.load(Ordering::Relaxed);
+        let output_tokens = self.output_tokens.load(Ordering::Relaxed);
+        let cache_read_tokens = self.cache_read_tokens.load(Ordering::Relaxed);
+        let cache_creation_tokens = self.cache_creation_tokens.load(Ordering::Relaxed);
+        ApiUsage {
+            calls: self.calls.load(Ordering::Relaxed),
+            input_tokens,
+            output_tokens,
+            cost_usd: estimate_cost(input_tokens, output_tokens)
+                + estimate_cache_cost(cache_read_tokens, cache_creation_tokens),
+            cache_read_tokens,
+            cache_creation_tokens,
+            cache_savings_usd: estimate_cache_savings(cache_read_tokens, cache_creation_tokens),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cache_savings_from_usage() {
+        let uncached = UsageTracker::default();
+        uncached.record(1_000, 500);
+        let usage = uncached.snapshot();
+        assert_eq!(usage.cache_read_tokens, 0);
+        assert_eq!(usage.cache_creation_tokens, 0);
+        assert_eq!(usage.cache_savings_usd, 0.0);
+
+        let cached = UsageTracker::default();
+        cached.record(1_000, 500);
+        cached.record_cache(200_000, 50_000);
+        let usage = cached.snapshot();
+
+        // 250k tokens at $3/MTok uncached = $0.75; cached: 200k * 0.1 + 50k * 1.25 = 82.5k -> $0.2475
+        assert!(usage.cache_savings_usd > 0.0);
+        assert!((usage.cache_savings_usd - 0.5025).abs() < 1e-9, "{}", usage.cache_savings_usd);
+        assert!((usage.cost_usd - (estimate_cost(1_000, 500) + 0.2475)).abs() < 1e-9);
+    }
+}
@@ -3,8 +3,11 @@
 pub mod validation;
 
 pub use validation::{
-    Artifact, ArtifactFrontmatter, ArtifactType, GovernanceRole, ValidationError,
-    calculate_content_hash, detect_circular_dependency, is_immutable_type, parse_artifact,
-    validate_artifact, validate_dependencies, validate_frontmatter, validate_hash,
-    validate_immutability, validate_parent, validate_uniqueness,
+    Artifact, ArtifactDependency, ArtifactFrontmatter, ARTIFACT_TEMPLATE_VERSION, ArtifactType, DependencyRule,
+    DependencyRules, GovernanceRole, ImmutableArtifactTypes, Relationship, ValidationError,
+    CRITICAL_PATH_TYPES, DEPENDENCY_RULES,
+    calculate_content_hash, dependency_rules, detect_circular_dependency, is_immutable_type,
+    parse_artifact, render_display, step_origin_for_type, validate_artifact, validate_artifact_type, validate_dependencies, validate_frontmatter,
+    validate_hash, validate_immutability, validate_immutability_with, validate_parent, validate_relationships,
+    validate_uniqueness, validate_update,
 };
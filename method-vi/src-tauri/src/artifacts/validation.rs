@@ -1,7 +1,20 @@
 use anyhow::{anyhow, Context, Result};
 use serde::{Deserialize, Serialize};
-use sha2::{Digest, Sha256};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+
+use crate::hash::sha256_hex;
+
+/// Version of the artifact templates the builders write
+///
+/// Bump when a template's layout changes, so a hash audit can tell an older
+/// template from tampered content. Version 1 is every artifact written before
+/// frontmatter carried `template_version`.
+pub const ARTIFACT_TEMPLATE_VERSION: u32 = 2;
+
+/// Template version assumed for frontmatter without `template_version`
+fn legacy_template_version() -> u32 {
+    1
+}
 
 /// Artifact frontmatter structure (YAML at top of markdown file)
 /// From specs/Method-VI_Artifact_Templates.md (line 35-45)
@@ -14,11 +27,14 @@ pub struct ArtifactFrontmatter {
     pub created_at: String, // ISO-8601
     pub hash: String,       // SHA-256 of content body
     pub parent_hash: Option<String>,
-    pub dependencies: Vec<String>, // artifact_ids this depends on
+    pub dependencies: Vec<ArtifactDependency>,
     pub intent_anchor_link: Option<String>,
     pub is_immutable: bool,
     pub author: String,
     pub governance_role: GovernanceRole,
+    /// Template version that produced the artifact (1 if absent)
+    #[serde(default = "legacy_template_version")]
+    pub template_version: u32,
 }
 
 /// Valid artifact types from specs/Method-VI_Artifact_Templates.md (line 48-68)
@@ -42,6 +58,358 @@ pub enum ArtifactType {
     ValidationReport,
     FinalOutput,
     PatternCard,
+    DomainSnapshots,
+}
+
+impl ArtifactType {
+    pub const ALL: [ArtifactType; 18] = [
+        ArtifactType::IntentSummary,
+        ArtifactType::PatternSuggestions,
+        ArtifactType::IntentAnchor,
+        ArtifactType::Charter,
+        ArtifactType::BaselineReport,
+        ArtifactType::ArchitectureMap,
+        ArtifactType::GovernanceSummary,
+        ArtifactType::DiagnosticSummary,
+        ArtifactType::LensEfficacyReport,
+        ArtifactType::CoreThesis,
+        ArtifactType::CausalSpineDraft,
+        ArtifactType::Glossary,
+        ArtifactType::FrameworkDraft,
+        ArtifactType::InnovationNotes,
+        ArtifactType::ValidationReport,
+        ArtifactType::FinalOutput,
+        ArtifactType::PatternCard,
+        ArtifactType::DomainSnapshots,
+    ];
+
+    /// Name as written in the `artifact_type` frontmatter field, spelled as in
+    /// the spec's artifact index ("Baseline_Report")
+    pub fn to_frontmatter_str(&self) -> &'static str {
+        match self {
+            ArtifactType::IntentSummary => "Intent_Summary",
+            ArtifactType::PatternSuggestions => "Pattern_Suggestions",
+            ArtifactType::IntentAnchor => "Intent_Anchor",
+            ArtifactType::Charter => "Charter",
+            ArtifactType::BaselineReport => "Baseline_Report",
+            ArtifactType::ArchitectureMap => "Architecture_Map",
+            ArtifactType::GovernanceSummary => "Governance_Summary",
+            ArtifactType::DiagnosticSummary => "Diagnostic_Summary",
+            ArtifactType::LensEfficacyReport => "Lens_Efficacy_Report",
+            ArtifactType::CoreThesis => "Core_Thesis",
+            ArtifactType::CausalSpineDraft => "Causal_Spine_Draft",
+            ArtifactType::Glossary => "Glossary",
+            ArtifactType::FrameworkDraft => "Framework_Draft",
+            ArtifactType::InnovationNotes => "Innovation_Notes",
+            ArtifactType::ValidationReport => "Validation_Report",
+            ArtifactType::FinalOutput => "Final_Output",
+            ArtifactType::PatternCard => "Pattern_Card",
+            ArtifactType::DomainSnapshots => "Domain_Snapshots",
+        }
+    }
+
+    /// Parse a frontmatter type name, ignoring case and underscores
+    /// ("Baseline_Report" and "BaselineReport" are the same type)
+    pub fn parse(s: &str) -> Option<Self> {
+        let key = |name: &str| -> String { name.chars().filter(|c| *c != '_').flat_map(char::to_lowercase).collect() };
+        let declared = key(s);
+        Self::ALL
+            .into_iter()
+            .find(|artifact_type| key(artifact_type.to_frontmatter_str()) == declared)
+    }
+}
+
+impl std::fmt::Display for ArtifactType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.to_frontmatter_str())
+    }
+}
+
+/// Relationship between an artifact and one of its dependencies
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Relationship {
+    DerivedFrom,
+    ConstrainedBy,
+    References,
+}
+
+impl std::fmt::Display for Relationship {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Relationship::DerivedFrom => "derived_from",
+            Relationship::ConstrainedBy => "constrained_by",
+            Relationship::References => "references",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Dependency entry in artifact frontmatter
+///
+/// Templates write `{artifact_id, relationship}`; a bare artifact ID is also
+/// accepted and treated as a `references` relationship.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(from = "RawDependency")]
+pub struct ArtifactDependency {
+    pub artifact_id: String,
+    pub relationship: Relationship,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawDependency {
+    Id(String),
+    Full {
+        artifact_id: String,
+        relationship: Relationship,
+    },
+}
+
+impl From<RawDependency> for ArtifactDependency {
+    fn from(raw: RawDependency) -> Self {
+        match raw {
+            RawDependency::Id(artifact_id) => ArtifactDependency {
+                artifact_id,
+                relationship: Relationship::References,
+            },
+            RawDependency::Full { artifact_id, relationship } => ArtifactDependency {
+                artifact_id,
+                relationship,
+            },
+        }
+    }
+}
+
+/// A relationship to an artifact of a given type
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DependencyRule {
+    pub relationship: Relationship,
+    pub target: ArtifactType,
+}
+
+/// Relationships required and allowed for one artifact type
+///
+/// Any dependency not matching a required or allowed rule is rejected.
+/// Artifact types without an entry in `DEPENDENCY_RULES` are not checked.
+#[derive(Debug, Clone)]
+pub struct DependencyRules {
+    pub artifact_type: ArtifactType,
+    pub required: &'static [DependencyRule],
+    pub allowed: &'static [DependencyRule],
+}
+
+/// Dependency rules matching the artifact templates
+/// From specs/Method-VI_Artifact_Templates.md
+///
+/// `references` dependencies are pointers rather than lineage, so they are
+/// allowed but not required (the glossary can be omitted in Step 4, and Pattern
+/// Cards may be harvested without Innovation Notes).
+pub const DEPENDENCY_RULES: &[DependencyRules] = &[
+    DependencyRules {
+        artifact_type: ArtifactType::IntentSummary,
+        required: &[],
+        allowed: &[],
+    },
+    DependencyRules {
+        artifact_type: ArtifactType::PatternSuggestions,
+        required: &[DependencyRule {
+            relationship: Relationship::DerivedFrom,
+            target: ArtifactType::IntentSummary,
+        }],
+        allowed: &[],
+    },
+    DependencyRules {
+        artifact_type: ArtifactType::IntentAnchor,
+        required: &[],
+        allowed: &[DependencyRule {
+            relationship: Relationship::DerivedFrom,
+            target: ArtifactType::IntentSummary,
+        }],
+    },
+    DependencyRules {
+        artifact_type: ArtifactType::Charter,
+        required: &[DependencyRule {
+            relationship: Relationship::DerivedFrom,
+            target: ArtifactType::IntentAnchor,
+        }],
+        allowed: &[],
+    },
+    DependencyRules {
+        artifact_type: ArtifactType::BaselineReport,
+        required: &[
+            DependencyRule {
+                relationship: Relationship::DerivedFrom,
+                target: ArtifactType::Charter,
+            },
+            DependencyRule {
+                relationship: Relationship::ConstrainedBy,
+                target: ArtifactType::IntentAnchor,
+            },
+        ],
+        allowed: &[],
+    },
+    DependencyRules {
+        artifact_type: ArtifactType::ArchitectureMap,
+        required: &[
+            DependencyRule {
+                relationship: Relationship::DerivedFrom,
+                target: ArtifactType::Charter,
+            },
+            DependencyRule {
+                relationship: Relationship::ConstrainedBy,
+                target: ArtifactType::IntentAnchor,
+            },
+        ],
+        allowed: &[],
+    },
+    DependencyRules {
+        artifact_type: ArtifactType::GovernanceSummary,
+        required: &[
+            DependencyRule {
+                relationship: Relationship::DerivedFrom,
+                target: ArtifactType::BaselineReport,
+            },
+            DependencyRule {
+                relationship: Relationship::ConstrainedBy,
+                target: ArtifactType::ArchitectureMap,
+            },
+        ],
+        allowed: &[],
+    },
+    DependencyRules {
+        artifact_type: ArtifactType::DiagnosticSummary,
+        required: &[
+            DependencyRule {
+                relationship: Relationship::DerivedFrom,
+                target: ArtifactType::GovernanceSummary,
+            },
+            DependencyRule {
+                relationship: Relationship::ConstrainedBy,
+                target: ArtifactType::Charter,
+            },
+        ],
+        allowed: &[],
+    },
+    DependencyRules {
+        artifact_type: ArtifactType::LensEfficacyReport,
+        required: &[DependencyRule {
+            relationship: Relationship::DerivedFrom,
+            target: ArtifactType::DiagnosticSummary,
+        }],
+        allowed: &[],
+    },
+    DependencyRules {
+        artifact_type: ArtifactType::CoreThesis,
+        required: &[
+            DependencyRule {
+                relationship: Relationship::DerivedFrom,
+                target: ArtifactType::DiagnosticSummary,
+            },
+            DependencyRule {
+                relationship: Relationship::ConstrainedBy,
+                target: ArtifactType::Charter,
+            },
+        ],
+        allowed: &[],
+    },
+    DependencyRules {
+        artifact_type: ArtifactType::CausalSpineDraft,
+        required: &[DependencyRule {
+            relationship: Relationship::DerivedFrom,
+            target: ArtifactType::CoreThesis,
+        }],
+        allowed: &[],
+    },
+    DependencyRules {
+        artifact_type: ArtifactType::Glossary,
+        required: &[DependencyRule {
+            relationship: Relationship::DerivedFrom,
+            target: ArtifactType::CoreThesis,
+        }],
+        allowed: &[],
+    },
+    DependencyRules {
+        artifact_type: ArtifactType::FrameworkDraft,
+        required: &[
+            DependencyRule {
+                relationship: Relationship::DerivedFrom,
+                target: ArtifactType::CoreThesis,
+            },
+            DependencyRule {
+                relationship: Relationship::DerivedFrom,
+                target: ArtifactType::CausalSpineDraft,
+            },
+            DependencyRule {
+                relationship: Relationship::ConstrainedBy,
+                target: ArtifactType::Charter,
+            },
+        ],
+        allowed: &[DependencyRule {
+            relationship: Relationship::References,
+            target: ArtifactType::Glossary,
+        }],
+    },
+    DependencyRules {
+        artifact_type: ArtifactType::InnovationNotes,
+        required: &[DependencyRule {
+            relationship: Relationship::DerivedFrom,
+            target: ArtifactType::FrameworkDraft,
+        }],
+        allowed: &[],
+    },
+    DependencyRules {
+        artifact_type: ArtifactType::ValidationReport,
+        required: &[
+            DependencyRule {
+                relationship: Relationship::DerivedFrom,
+                target: ArtifactType::FrameworkDraft,
+            },
+            DependencyRule {
+                relationship: Relationship::ConstrainedBy,
+                target: ArtifactType::Charter,
+            },
+        ],
+        allowed: &[],
+    },
+    DependencyRules {
+        artifact_type: ArtifactType::FinalOutput,
+        required: &[
+            DependencyRule {
+                relationship: Relationship::DerivedFrom,
+                target: ArtifactType::FrameworkDraft,
+            },
+            DependencyRule {
+                relationship: Relationship::DerivedFrom,
+                target: ArtifactType::ValidationReport,
+            },
+        ],
+        allowed: &[],
+    },
+    DependencyRules {
+        artifact_type: ArtifactType::PatternCard,
+        required: &[DependencyRule {
+            relationship: Relationship::DerivedFrom,
+            target: ArtifactType::ValidationReport,
+        }],
+        allowed: &[
+            DependencyRule {
+                relationship: Relationship::References,
+                target: ArtifactType::LensEfficacyReport,
+            },
+            DependencyRule {
+                relationship: Relationship::References,
+                target: ArtifactType::InnovationNotes,
+            },
+        ],
+    },
+];
+
+/// Look up the dependency rules for an artifact type
+pub fn dependency_rules(artifact_type: &ArtifactType) -> Option<&'static DependencyRules> {
+    DEPENDENCY_RULES
+        .iter()
+        .find(|rules| &rules.artifact_type == artifact_type)
 }
 
 /// Valid governance roles from specs/module-plan-method-vi.md (line 2945-2958)
@@ -58,6 +426,44 @@ pub enum GovernanceRole {
     Archivist,
 }
 
+impl GovernanceRole {
+    pub const ALL: [GovernanceRole; 8] = [
+        GovernanceRole::Observer,
+        GovernanceRole::Conductor,
+        GovernanceRole::Auditor,
+        GovernanceRole::Patcher,
+        GovernanceRole::Fabricator,
+        GovernanceRole::Examiner,
+        GovernanceRole::Curator,
+        GovernanceRole::Archivist,
+    ];
+
+    /// Role name as written in the `governance_role` frontmatter field
+    pub fn to_frontmatter_str(&self) -> &'static str {
+        match self {
+            GovernanceRole::Observer => "Observer",
+            GovernanceRole::Conductor => "Conductor",
+            GovernanceRole::Auditor => "Auditor",
+            GovernanceRole::Patcher => "Patcher",
+            GovernanceRole::Fabricator => "Fabricator",
+            GovernanceRole::Examiner => "Examiner",
+            GovernanceRole::Curator => "Curator",
+            GovernanceRole::Archivist => "Archivist",
+        }
+    }
+
+    /// Parse a frontmatter role name (exact match)
+    pub fn parse(s: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|role| role.to_frontmatter_str() == s)
+    }
+}
+
+impl std::fmt::Display for GovernanceRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.to_frontmatter_str())
+    }
+}
+
 /// Complete artifact with frontmatter and content
 #[derive(Debug, Clone)]
 pub struct Artifact {
@@ -72,10 +478,24 @@ pub enum ValidationError {
     InvalidFieldValue { field: String, reason: String },
     HashMismatch { expected: String, actual: String },
     DependencyNotFound(String),
+    /// Dependencies of an artifact that are not stored for its run
+    MissingDependencies { artifact_id: String, missing: Vec<String> },
     CircularDependency(Vec<String>),
     OrphanArtifact(String),
     ImmutableModification(String),
     UniquenessViolation(String),
+    /// Artifact ID already persisted in the database (any run)
+    DuplicateId(String),
+    MissingRelationship {
+        artifact_id: String,
+        relationship: Relationship,
+        target_type: ArtifactType,
+    },
+    DisallowedRelationship {
+        artifact_id: String,
+        dependency_id: String,
+        relationship: Relationship,
+    },
 }
 
 impl std::fmt::Display for ValidationError {
@@ -93,6 +513,9 @@ impl std::fmt::Display for ValidationError {
             ValidationError::DependencyNotFound(dep_id) => {
                 write!(f, "Dependency not found: {}", dep_id)
             }
+            ValidationError::MissingDependencies { artifact_id, missing } => {
+                write!(f, "Artifact {} depends on artifacts not stored for its run: {}", artifact_id, missing.join(", "))
+            }
             ValidationError::CircularDependency(path) => {
                 write!(f, "Circular dependency detected: {:?}", path)
             }
@@ -105,6 +528,23 @@ impl std::fmt::Display for ValidationError {
             ValidationError::UniquenessViolation(artifact_id) => {
                 write!(f, "Artifact ID already exists in run: {}", artifact_id)
             }
+            ValidationError::DuplicateId(artifact_id) => {
+                write!(f, "Artifact ID already exists in database: {}", artifact_id)
+            }
+            ValidationError::MissingRelationship { artifact_id, relationship, target_type } => {
+                write!(
+                    f,
+                    "Artifact {} is missing required {} dependency on {:?}",
+                    artifact_id, relationship, target_type
+                )
+            }
+            ValidationError::DisallowedRelationship { artifact_id, dependency_id, relationship } => {
+                write!(
+                    f,
+                    "Artifact {} has disallowed {} dependency on {}",
+                    artifact_id, relationship, dependency_id
+                )
+            }
         }
     }
 }
@@ -121,7 +561,22 @@ pub fn parse_artifact(markdown: &str) -> Result<Artifact> {
     }
 
     // Parse frontmatter (parts[1] is the YAML between ---)
-    let frontmatter: ArtifactFrontmatter = serde_yaml::from_str(parts[1])
+    let yaml: serde_yaml::Value = serde_yaml::from_str(parts[1])
+        .context("Failed to parse frontmatter YAML")?;
+
+    // Report a misspelled role as a validation error rather than a YAML error
+    if let Some(role) = yaml.get("governance_role").and_then(|value| value.as_str()) {
+        validate_governance_role(role)?;
+    }
+
+    // Same for the type; any spelling `ArtifactType::parse` accepts is read as the type
+    let mut yaml = yaml;
+    if let Some(declared) = yaml.get("artifact_type").and_then(|value| value.as_str()) {
+        let artifact_type = validate_artifact_type(declared)?;
+        yaml["artifact_type"] = serde_yaml::to_value(&artifact_type)?;
+    }
+
+    let frontmatter: ArtifactFrontmatter = serde_yaml::from_value(yaml)
         .context("Failed to parse frontmatter YAML")?;
 
     // Content is everything after the second ---
@@ -133,15 +588,59 @@ pub fn parse_artifact(markdown: &str) -> Result<Artifact> {
     })
 }
 
+/// Render an artifact for display: frontmatter replaced by a one-line header
+///
+/// The header shows the artifact type, creation time and an immutability
+/// badge above the unchanged body. Text whose frontmatter does not parse
+/// (or that has none) is returned as-is.
+pub fn render_display(artifact_text: &str) -> String {
+    match parse_artifact(artifact_text) {
+        Ok(artifact) => format!(
+            "> **{:?}** · Created {} · {}\n\n{}",
+            artifact.frontmatter.artifact_type,
+            artifact.frontmatter.created_at,
+            if artifact.frontmatter.is_immutable { "🔒 Immutable" } else { "Mutable" },
+            artifact.content
+        ),
+        Err(_) => artifact_text.to_string(),
+    }
+}
+
 /// Calculate SHA-256 hash of content
 pub fn calculate_content_hash(content: &str) -> String {
-    let mut hasher = Sha256::new();
-    hasher.update(content.as_bytes());
-    format!("{:x}", hasher.finalize())
+    sha256_hex(content)
+}
+
+/// Validate a `governance_role` frontmatter value against the known roles
+pub fn validate_governance_role(role: &str) -> Result<GovernanceRole, ValidationError> {
+    GovernanceRole::parse(role).ok_or_else(|| ValidationError::InvalidFieldValue {
+        field: "governance_role".to_string(),
+        reason: format!(
+            "Unknown governance role '{}' (expected one of: {})",
+            role,
+            GovernanceRole::ALL.map(|role| role.to_frontmatter_str()).join(", ")
+        ),
+    })
+}
+
+/// Validate an `artifact_type` frontmatter value against the known types
+pub fn validate_artifact_type(declared: &str) -> Result<ArtifactType, ValidationError> {
+    ArtifactType::parse(declared).ok_or_else(|| ValidationError::InvalidFieldValue {
+        field: "artifact_type".to_string(),
+        reason: format!(
+            "Unknown artifact type '{}' (expected one of: {})",
+            declared,
+            ArtifactType::ALL.map(|t| t.to_frontmatter_str()).join(", ")
+        ),
+    })
 }
 
 /// Validate artifact frontmatter completeness
 /// From specs/Method-VI_Artifact_Templates.md (line 39)
+///
+/// `governance_role` and `artifact_type` are typed, so unknown roles and types
+/// are rejected when the frontmatter is parsed (see `validate_governance_role`
+/// and `validate_artifact_type`).
 pub fn validate_frontmatter(frontmatter: &ArtifactFrontmatter) -> Result<(), ValidationError> {
     // All required fields are enforced by the struct definition
     // Additional validation for field values
@@ -170,6 +669,16 @@ pub fn validate_frontmatter(frontmatter: &ArtifactFrontmatter) -> Result<(), Val
         });
     }
 
+    if frontmatter.template_version == 0 || frontmatter.template_version > ARTIFACT_TEMPLATE_VERSION {
+        return Err(ValidationError::InvalidFieldValue {
+            field: "template_version".to_string(),
+            reason: format!(
+                "Must be 1-{}, got {}",
+                ARTIFACT_TEMPLATE_VERSION, frontmatter.template_version
+            ),
+        });
+    }
+
     Ok(())
 }
 
@@ -231,14 +740,58 @@ pub fn validate_dependencies(
     artifact: &ArtifactFrontmatter,
     existing_artifacts: &HashSet<String>, // Set of existing artifact IDs
 ) -> Result<(), ValidationError> {
-    for dep_id in &artifact.dependencies {
-        if !existing_artifacts.contains(dep_id) {
-            return Err(ValidationError::DependencyNotFound(dep_id.clone()));
+    for dep in &artifact.dependencies {
+        if !existing_artifacts.contains(&dep.artifact_id) {
+            return Err(ValidationError::DependencyNotFound(dep.artifact_id.clone()));
         }
     }
     Ok(())
 }
 
+/// Validate dependency relationships against `DEPENDENCY_RULES`
+///
+/// Dependencies whose target is unknown are skipped here; `validate_dependencies`
+/// reports them as not found.
+pub fn validate_relationships(
+    artifact: &ArtifactFrontmatter,
+    existing_artifact_types: &HashMap<String, ArtifactType>, // artifact_id -> type
+) -> Result<(), ValidationError> {
+    let Some(rules) = dependency_rules(&artifact.artifact_type) else {
+        return Ok(());
+    };
+
+    let matches = |rule: &DependencyRule, dep: &ArtifactDependency| {
+        dep.relationship == rule.relationship
+            && existing_artifact_types.get(&dep.artifact_id) == Some(&rule.target)
+    };
+
+    for rule in rules.required {
+        if !artifact.dependencies.iter().any(|dep| matches(rule, dep)) {
+            return Err(ValidationError::MissingRelationship {
+                artifact_id: artifact.artifact_id.clone(),
+                relationship: rule.relationship,
+                target_type: rule.target.clone(),
+            });
+        }
+    }
+
+    for dep in &artifact.dependencies {
+        if !existing_artifact_types.contains_key(&dep.artifact_id) {
+            continue;
+        }
+        let permitted = rules.required.iter().chain(rules.allowed).any(|rule| matches(rule, dep));
+        if !permitted {
+            return Err(ValidationError::DisallowedRelationship {
+                artifact_id: artifact.artifact_id.clone(),
+                dependency_id: dep.artifact_id.clone(),
+                relationship: dep.relationship,
+            });
+        }
+    }
+
+    Ok(())
+}
+
 /// Detect circular dependencies using DFS
 pub fn detect_circular_dependency(
     artifact_id: &str,
@@ -286,16 +839,123 @@ pub fn validate_uniqueness(
     Ok(())
 }
 
-/// Check if artifact type is immutable
+/// Critical Path artifact types; always immutable whatever the configuration
+pub const CRITICAL_PATH_TYPES: [&str; 4] = ["Intent_Anchor", "Charter", "Baseline", "Core_Thesis"];
+
+/// Normalize a type name so "IntentAnchor", "Intent_Anchor" and "intent_anchor" match
+fn type_key(name: &str) -> String {
+    let key: String = name
+        .chars()
+        .filter(|c| *c != '_')
+        .flat_map(char::to_lowercase)
+        .collect();
+    match key.as_str() {
+        "baselinereport" => "baseline".to_string(),
+        _ => key,
+    }
+}
+
+/// Artifact types treated as immutable once locked
+///
+/// Names may use either the artifact type ("IntentAnchor") or spine ("Intent_Anchor")
+/// spelling, and need not be a known `ArtifactType`, so methodology variants can
+/// lock component artifacts such as "Operating_Principles". The Critical Path
+/// types must always be present.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ImmutableArtifactTypes {
+    types: Vec<String>,
+}
+
+impl Default for ImmutableArtifactTypes {
+    fn default() -> Self {
+        let mut types: Vec<String> = CRITICAL_PATH_TYPES.iter().map(|t| t.to_string()).collect();
+        types.push("Architecture_Map".to_string());
+        ImmutableArtifactTypes { types }
+    }
+}
+
+impl ImmutableArtifactTypes {
+    /// Build a set from type names, rejecting one that omits a Critical Path type
+    pub fn new(types: Vec<String>) -> Result<Self, ValidationError> {
+        let set = ImmutableArtifactTypes { types };
+        set.validate()?;
+        Ok(set)
+    }
+
+    /// Add a type to the immutable set
+    pub fn with_type(mut self, type_name: &str) -> Self {
+        if !self.contains(type_name) {
+            self.types.push(type_name.to_string());
+        }
+        self
+    }
+
+    /// Check that every Critical Path type is immutable
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        let missing: Vec<&str> = CRITICAL_PATH_TYPES
+            .iter()
+            .copied()
+            .filter(|t| !self.contains(t))
+            .collect();
+        if !missing.is_empty() {
+            return Err(ValidationError::InvalidFieldValue {
+                field: "immutable_artifact_types".to_string(),
+                reason: format!("Critical Path types must be immutable, missing: {}", missing.join(", ")),
+            });
+        }
+        Ok(())
+    }
+
+    /// Whether the named type is immutable
+    pub fn contains(&self, type_name: &str) -> bool {
+        let key = type_key(type_name);
+        self.types.iter().any(|t| type_key(t) == key)
+    }
+
+    /// Whether an artifact type is immutable
+    pub fn is_immutable(&self, artifact_type: &ArtifactType) -> bool {
+        self.contains(&format!("{:?}", artifact_type))
+    }
+
+    /// Type names in the set
+    pub fn types(&self) -> &[String] {
+        &self.types
+    }
+}
+
+/// Check if artifact type is immutable under the default immutable set
 /// From specs/Method-VI_Artifact_Templates.md (line 50-68)
 pub fn is_immutable_type(artifact_type: &ArtifactType) -> bool {
-    matches!(
-        artifact_type,
+    ImmutableArtifactTypes::default().is_immutable(artifact_type)
+}
+
+/// Reject an update to an artifact whose type is locked
+pub fn validate_update(
+    artifact_id: &str,
+    type_name: &str,
+    immutable_types: &ImmutableArtifactTypes,
+) -> Result<(), ValidationError> {
+    if immutable_types.contains(type_name) {
+        return Err(ValidationError::ImmutableModification(artifact_id.to_string()));
+    }
+    Ok(())
+}
+
+/// Step in which the orchestrator produces an artifact type
+pub fn step_origin_for_type(artifact_type: &ArtifactType) -> i32 {
+    match artifact_type {
+        ArtifactType::IntentSummary | ArtifactType::PatternSuggestions => 0,
         ArtifactType::IntentAnchor
-            | ArtifactType::Charter
-            | ArtifactType::BaselineReport
-            | ArtifactType::ArchitectureMap
-    )
+        | ArtifactType::Charter
+        | ArtifactType::BaselineReport
+        | ArtifactType::ArchitectureMap => 1,
+        ArtifactType::GovernanceSummary | ArtifactType::DomainSnapshots => 2,
+        ArtifactType::DiagnosticSummary | ArtifactType::LensEfficacyReport => 3,
+        ArtifactType::CoreThesis | ArtifactType::CausalSpineDraft | ArtifactType::Glossary => 4,
+        ArtifactType::FrameworkDraft | ArtifactType::InnovationNotes => 5,
+        ArtifactType::ValidationReport | ArtifactType::FinalOutput | ArtifactType::PatternCard => 6,
+    }
 }
 
 /// Validate immutability constraints
@@ -303,6 +963,15 @@ pub fn is_immutable_type(artifact_type: &ArtifactType) -> bool {
 pub fn validate_immutability(
     artifact: &ArtifactFrontmatter,
     existing_immutable_ids: &HashSet<String>,
+) -> Result<(), ValidationError> {
+    validate_immutability_with(artifact, existing_immutable_ids, &ImmutableArtifactTypes::default())
+}
+
+/// Validate immutability constraints against a configured immutable set
+pub fn validate_immutability_with(
+    artifact: &ArtifactFrontmatter,
+    existing_immutable_ids: &HashSet<String>,
+    immutable_types: &ImmutableArtifactTypes,
 ) -> Result<(), ValidationError> {
     // Check if trying to modify an immutable artifact
     if existing_immutable_ids.contains(&artifact.artifact_id) {
@@ -312,7 +981,7 @@ pub fn validate_immutability(
     }
 
     // Verify is_immutable flag matches artifact type
-    let should_be_immutable = is_immutable_type(&artifact.artifact_type);
+    let should_be_immutable = immutable_types.is_immutable(&artifact.artifact_type);
     if artifact.is_immutable != should_be_immutable {
         return Err(ValidationError::InvalidFieldValue {
             field: "is_immutable".to_string(),
@@ -332,7 +1001,8 @@ pub fn validate_artifact(
     existing_artifact_ids: &HashSet<String>,
     existing_artifact_hashes: &HashSet<String>,
     existing_immutable_ids: &HashSet<String>,
-    dependency_graph: &std::collections::HashMap<String, Vec<String>>,
+    existing_artifact_types: &HashMap<String, ArtifactType>,
+    dependency_graph: &HashMap<String, Vec<String>>,
 ) -> Result<(), Vec<ValidationError>> {
     let mut errors = Vec::new();
 
@@ -361,12 +1031,17 @@ pub fn validate_artifact(
         errors.push(e);
     }
 
-    // 6. Validate immutability
+    // 6. Validate dependency relationships
+    if let Err(e) = validate_relationships(&artifact.frontmatter, existing_artifact_types) {
+        errors.push(e);
+    }
+
+    // 7. Validate immutability
     if let Err(e) = validate_immutability(&artifact.frontmatter, existing_immutable_ids) {
         errors.push(e);
     }
 
-    // 7. Detect circular dependencies
+    // 8. Detect circular dependencies
     let mut visited = HashSet::new();
     let mut path = Vec::new();
     if let Some(cycle) =
@@ -386,6 +1061,58 @@ pub fn validate_artifact(
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_governance_role_frontmatter() {
+        let markdown = |role: &str| {
+            format!(
+                "---\nartifact_id: \"test-001\"\nartifact_type: IntentSummary\nrun_id: \"run-001\"\n\
+                step_origin: 0\ncreated_at: \"2025-01-01T00:00:00Z\"\nhash: \"{}\"\nparent_hash: null\n\
+                dependencies: []\nintent_anchor_link: null\nis_immutable: false\nauthor: \"tester\"\n\
+                governance_role: \"{}\"\n---\n\nBody",
+                calculate_content_hash("Body"),
+                role
+            )
+        };
+
+        let artifact = parse_artifact(&markdown("Conductor")).unwrap();
+        assert_eq!(artifact.frontmatter.governance_role, GovernanceRole::Conductor);
+        assert!(validate_frontmatter(&artifact.frontmatter).is_ok());
+        assert_eq!(GovernanceRole::Conductor.to_string(), "Conductor");
+
+        let err = parse_artifact(&markdown("Conducter")).unwrap_err();
+        match err.downcast_ref::<ValidationError>() {
+            Some(ValidationError::InvalidFieldValue { field, reason }) => {
+                assert_eq!(field, "governance_role");
+                assert!(reason.contains("'Conducter'"));
+            }
+            other => panic!("expected an invalid governance_role, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_render_display() {
+        let body = "# Charter\n\n## Objectives\n- Shorten onboarding";
+        let charter = format!(
+            "---\nartifact_id: \"run-001-charter\"\nartifact_type: Charter\nrun_id: \"run-001\"\n\
+            step_origin: 1\ncreated_at: \"2025-01-01T00:00:00Z\"\nhash: \"{}\"\nparent_hash: null\n\
+            dependencies: []\nintent_anchor_link: null\nis_immutable: true\nauthor: \"tester\"\n\
+            governance_role: \"Conductor\"\n---\n\n{}",
+            calculate_content_hash(body),
+            body
+        );
+
+        let display = render_display(&charter);
+        assert_eq!(
+            display.lines().next(),
+            Some("> **Charter** · Created 2025-01-01T00:00:00Z · 🔒 Immutable")
+        );
+        assert!(display.ends_with(body));
+        assert!(!display.contains("artifact_id"));
+
+        let blob = "Notes without frontmatter\n\n- first point";
+        assert_eq!(render_display(blob), blob);
+    }
+
     #[test]
     fn test_calculate_content_hash() {
         let content = "Test content";
@@ -412,6 +1139,7 @@ mod tests {
                 is_immutable: false,
                 author: "test-agent".to_string(),
                 governance_role: GovernanceRole::Observer,
+                template_version: ARTIFACT_TEMPLATE_VERSION,
             },
             content,
         };
@@ -435,6 +1163,7 @@ mod tests {
                 is_immutable: false,
                 author: "test-agent".to_string(),
                 governance_role: GovernanceRole::Observer,
+                template_version: ARTIFACT_TEMPLATE_VERSION,
             },
             content: "Test content".to_string(),
         };
@@ -459,6 +1188,7 @@ mod tests {
             is_immutable: true,
             author: "scope-pattern-agent".to_string(),
             governance_role: GovernanceRole::Observer,
+            template_version: ARTIFACT_TEMPLATE_VERSION,
         };
 
         assert!(validate_parent(&frontmatter, &existing).is_ok());
@@ -481,6 +1211,7 @@ mod tests {
             is_immutable: true,
             author: "scope-pattern-agent".to_string(),
             governance_role: GovernanceRole::Observer,
+            template_version: ARTIFACT_TEMPLATE_VERSION,
         };
 
         assert!(matches!(
@@ -488,4 +1219,345 @@ mod tests {
             Err(ValidationError::OrphanArtifact(_))
         ));
     }
+
+    /// Baseline frontmatter following the Baseline_Report template
+    fn baseline_artifact(dependencies: Vec<ArtifactDependency>) -> Artifact {
+        let content = "# Baseline Report".to_string();
+        Artifact {
+            frontmatter: ArtifactFrontmatter {
+                artifact_id: "baseline-001".to_string(),
+                artifact_type: ArtifactType::BaselineReport,
+                run_id: "run-001".to_string(),
+                step_origin: 2,
+                created_at: "2025-01-01T00:00:00Z".to_string(),
+                hash: calculate_content_hash(&content),
+                parent_hash: Some("charter_hash".to_string()),
+                dependencies,
+                intent_anchor_link: Some("intent-anchor-001".to_string()),
+                is_immutable: true,
+                author: "governance-telemetry-agent".to_string(),
+                governance_role: GovernanceRole::Conductor,
+                template_version: ARTIFACT_TEMPLATE_VERSION,
+            },
+            content,
+        }
+    }
+
+    fn existing_types() -> HashMap<String, ArtifactType> {
+        HashMap::from([
+            ("intent-anchor-001".to_string(), ArtifactType::IntentAnchor),
+            ("charter-001".to_string(), ArtifactType::Charter),
+        ])
+    }
+
+    fn validate_against_existing(artifact: &Artifact) -> Result<(), Vec<ValidationError>> {
+        let types = existing_types();
+        let ids: HashSet<String> = types.keys().cloned().collect();
+        let hashes = HashSet::from(["charter_hash".to_string()]);
+        validate_artifact(artifact, &ids, &hashes, &HashSet::new(), &types, &HashMap::new())
+    }
+
+    #[test]
+    fn test_compliant_baseline_relationships() {
+        let yaml = r#"
+- artifact_id: "charter-001"
+  relationship: "derived_from"
+- artifact_id: "intent-anchor-001"
+  relationship: "constrained_by"
+"#;
+        let dependencies: Vec<ArtifactDependency> = serde_yaml::from_str(yaml).unwrap();
+        let artifact = baseline_artifact(dependencies);
+
+        assert!(validate_against_existing(&artifact).is_ok());
+    }
+
+    #[test]
+    fn test_baseline_missing_required_relationship() {
+        let artifact = baseline_artifact(vec![ArtifactDependency {
+            artifact_id: "charter-001".to_string(),
+            relationship: Relationship::DerivedFrom,
+        }]);
+
+        let errors = validate_against_existing(&artifact).unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(
+            &errors[0],
+            ValidationError::MissingRelationship {
+                relationship: Relationship::ConstrainedBy,
+                target_type: ArtifactType::IntentAnchor,
+                ..
+            }
+        ));
+
+        // Charter referencing the Intent_Anchor with the wrong relationship
+        let mut charter = artifact.frontmatter.clone();
+        charter.artifact_id = "charter-002".to_string();
+        charter.artifact_type = ArtifactType::Charter;
+        charter.dependencies = vec![ArtifactDependency {
+            artifact_id: "intent-anchor-001".to_string(),
+            relationship: Relationship::References,
+        }];
+        let err = validate_relationships(&charter, &existing_types()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Artifact charter-002 is missing required derived_from dependency on IntentAnchor"
+        );
+    }
+
+    /// Check one `DEPENDENCY_RULES` entry against its template's dependency list
+    ///
+    /// The template list must pass, dropping any required dependency must fail,
+    /// and an extra dependency the template doesn't name must be rejected.
+    fn assert_template_rule(artifact_type: ArtifactType, template: &[(Relationship, ArtifactType)]) {
+        let existing: HashMap<String, ArtifactType> = ArtifactType::ALL
+            .into_iter()
+            .map(|t| (format!("{}-001", t), t))
+            .collect();
+        let dependencies: Vec<ArtifactDependency> = template
+            .iter()
+            .map(|(relationship, target)| ArtifactDependency {
+                artifact_id: format!("{}-001", target),
+                relationship: *relationship,
+            })
+            .collect();
+        let mut frontmatter = baseline_artifact(dependencies.clone()).frontmatter;
+        frontmatter.artifact_id = format!("{}-new", artifact_type);
+        frontmatter.artifact_type = artifact_type.clone();
+
+        assert!(validate_relationships(&frontmatter, &existing).is_ok(), "{} template", artifact_type);
+
+        let rules = dependency_rules(&artifact_type).expect("template types have rules");
+        for (i, dep) in dependencies.iter().enumerate() {
+            let is_required = rules
+                .required
+                .iter()
+                .any(|rule| rule.relationship == dep.relationship && existing[&dep.artifact_id] == rule.target);
+            let mut missing = frontmatter.clone();
+            missing.dependencies.remove(i);
+            assert_eq!(
+                matches!(
+                    validate_relationships(&missing, &existing),
+                    Err(ValidationError::MissingRelationship { .. })
+                ),
+                is_required,
+                "{} without {}",
+                artifact_type,
+                dep.artifact_id
+            );
+        }
+
+        let mut extra = frontmatter.clone();
+        extra.dependencies.push(ArtifactDependency {
+            artifact_id: format!("{}-001", ArtifactType::DomainSnapshots),
+            relationship: Relationship::DerivedFrom,
+        });
+        assert!(matches!(
+            validate_relationships(&extra, &existing),
+            Err(ValidationError::DisallowedRelationship { .. })
+        ));
+    }
+
+    #[test]
+    fn test_intent_summary_dependency_rule() {
+        assert_template_rule(ArtifactType::IntentSummary, &[]);
+    }
+
+    #[test]
+    fn test_pattern_suggestions_dependency_rule() {
+        assert_template_rule(
+            ArtifactType::PatternSuggestions,
+            &[
+                (Relationship::DerivedFrom, ArtifactType::IntentSummary),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_intent_anchor_dependency_rule() {
+        assert_template_rule(
+            ArtifactType::IntentAnchor,
+            &[
+                (Relationship::DerivedFrom, ArtifactType::IntentSummary),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_charter_dependency_rule() {
+        assert_template_rule(
+            ArtifactType::Charter,
+            &[
+                (Relationship::DerivedFrom, ArtifactType::IntentAnchor),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_baseline_report_dependency_rule() {
+        assert_template_rule(
+            ArtifactType::BaselineReport,
+            &[
+                (Relationship::DerivedFrom, ArtifactType::Charter),
+                (Relationship::ConstrainedBy, ArtifactType::IntentAnchor),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_architecture_map_dependency_rule() {
+        assert_template_rule(
+            ArtifactType::ArchitectureMap,
+            &[
+                (Relationship::DerivedFrom, ArtifactType::Charter),
+                (Relationship::ConstrainedBy, ArtifactType::IntentAnchor),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_governance_summary_dependency_rule() {
+        assert_template_rule(
+            ArtifactType::GovernanceSummary,
+            &[
+                (Relationship::DerivedFrom, ArtifactType::BaselineReport),
+                (Relationship::ConstrainedBy, ArtifactType::ArchitectureMap),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_diagnostic_summary_dependency_rule() {
+        assert_template_rule(
+            ArtifactType::DiagnosticSummary,
+            &[
+                (Relationship::DerivedFrom, ArtifactType::GovernanceSummary),
+                (Relationship::ConstrainedBy, ArtifactType::Charter),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_lens_efficacy_report_dependency_rule() {
+        assert_template_rule(
+            ArtifactType::LensEfficacyReport,
+            &[
+                (Relationship::DerivedFrom, ArtifactType::DiagnosticSummary),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_core_thesis_dependency_rule() {
+        assert_template_rule(
+            ArtifactType::CoreThesis,
+            &[
+                (Relationship::DerivedFrom, ArtifactType::DiagnosticSummary),
+                (Relationship::ConstrainedBy, ArtifactType::Charter),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_causal_spine_draft_dependency_rule() {
+        assert_template_rule(
+            ArtifactType::CausalSpineDraft,
+            &[
+                (Relationship::DerivedFrom, ArtifactType::CoreThesis),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_glossary_dependency_rule() {
+        assert_template_rule(
+            ArtifactType::Glossary,
+            &[
+                (Relationship::DerivedFrom, ArtifactType::CoreThesis),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_framework_draft_dependency_rule() {
+        assert_template_rule(
+            ArtifactType::FrameworkDraft,
+            &[
+                (Relationship::DerivedFrom, ArtifactType::CoreThesis),
+                (Relationship::DerivedFrom, ArtifactType::CausalSpineDraft),
+                (Relationship::References, ArtifactType::Glossary),
+                (Relationship::ConstrainedBy, ArtifactType::Charter),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_innovation_notes_dependency_rule() {
+        assert_template_rule(
+            ArtifactType::InnovationNotes,
+            &[
+                (Relationship::DerivedFrom, ArtifactType::FrameworkDraft),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_validation_report_dependency_rule() {
+        assert_template_rule(
+            ArtifactType::ValidationReport,
+            &[
+                (Relationship::DerivedFrom, ArtifactType::FrameworkDraft),
+                (Relationship::ConstrainedBy, ArtifactType::Charter),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_final_output_dependency_rule() {
+        assert_template_rule(
+            ArtifactType::FinalOutput,
+            &[
+                (Relationship::DerivedFrom, ArtifactType::FrameworkDraft),
+                (Relationship::DerivedFrom, ArtifactType::ValidationReport),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_pattern_card_dependency_rule() {
+        assert_template_rule(
+            ArtifactType::PatternCard,
+            &[
+                (Relationship::DerivedFrom, ArtifactType::ValidationReport),
+                (Relationship::References, ArtifactType::LensEfficacyReport),
+                (Relationship::References, ArtifactType::InnovationNotes),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_configured_immutable_types_reject_updates() {
+        let defaults = ImmutableArtifactTypes::default();
+        assert!(defaults.is_immutable(&ArtifactType::BaselineReport));
+        assert!(defaults.is_immutable(&ArtifactType::CoreThesis));
+        assert!(!defaults.is_immutable(&ArtifactType::Glossary));
+        assert!(validate_update("principles-001", "Operating_Principles", &defaults).is_ok());
+
+        let locked = defaults.with_type("Operating_Principles");
+        assert!(matches!(
+            validate_update("principles-001", "OperatingPrinciples", &locked),
+            Err(ValidationError::ImmutableModification(id)) if id == "principles-001"
+        ));
+        let spine_type = crate::spine::ArtifactType::Other("Operating_Principles".to_string());
+        assert!(spine_type.is_immutable_in(&locked));
+        assert!(!spine_type.is_immutable_in(&ImmutableArtifactTypes::default()));
+
+        // Critical Path types cannot be dropped from the set
+        let err = ImmutableArtifactTypes::new(vec![
+            "Intent_Anchor".to_string(),
+            "Charter".to_string(),
+            "BaselineReport".to_string(),
+        ])
+        .unwrap_err();
+        assert!(err.to_string().contains("missing: Core_Thesis"));
+    }
 }
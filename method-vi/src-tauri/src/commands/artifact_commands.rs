@@ -0,0 +1,310 @@
+use anyhow::Context;
+use log::{info, warn};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use tauri::State;
+use tauri_plugin_opener::OpenerExt;
+
+use crate::agents::orchestrator::RunArtifact;
+use crate::artifacts::ArtifactType;
+use crate::commands::step0::OrchestratorState;
+use crate::config::AppConfig;
+use crate::database;
+use crate::database::artifacts::{HashMismatch, PrunedContent};
+
+/// Get every artifact produced so far in a run
+///
+/// Reads from the run's orchestrator when it is loaded in this session,
+/// otherwise falls back to the persisted artifacts table.
+#[tauri::command]
+pub fn get_run_artifacts(
+    run_id: String,
+    state: State<OrchestratorState>,
+    app: tauri::AppHandle,
+) -> Result<Vec<RunArtifact>, String> {
+    info!("=== GET_RUN_ARTIFACTS command called ===");
+    info!("Run ID: {}", run_id);
+
+    {
+        let orchestrator_lock = state.0.lock().map_err(|e| e.to_string())?;
+        if let Some(orchestrator) = orchestrator_lock.get(Some(&run_id)) {
+            let artifacts = orchestrator.collect_artifacts();
+            info!("✓ Returning {} artifacts from loaded run", artifacts.len());
+            return Ok(artifacts);
+        }
+    }
+
+    let conn = database::get_connection(&app).map_err(|e| e.to_string())?;
+    let records = database::artifacts::list_artifacts_by_run(&conn, &run_id)
+        .map_err(|e| e.to_string())?;
+
+    let mut artifacts = Vec::with_capacity(records.len());
+    for record in records {
+        let content = match record.content_path.as_deref() {
+            Some(path) => std::fs::read_to_string(path).unwrap_or_else(|e| {
+                warn!("Failed to read content for artifact {} at {}: {}", record.id, path, e);
+                String::new()
+            }),
+            None => String::new(),
+        };
+
+        match persisted_run_artifact(record, content) {
+            Ok(artifact) => artifacts.push(artifact),
+            Err(e) => warn!("Skipping artifact: {}", e),
+        }
+    }
+
+    info!("✓ Returning {} persisted artifacts", artifacts.len());
+    Ok(artifacts)
+}
+
+/// Build a `RunArtifact` from a stored record and the body read from its content file
+fn persisted_run_artifact(record: database::models::Artifact, content: String) -> anyhow::Result<RunArtifact> {
+    let artifact_type: ArtifactType = serde_json::from_value(serde_json::Value::String(record.r#type.clone()))
+        .map_err(|_| anyhow::anyhow!("Artifact {} has unknown type '{}'", record.id, record.r#type))?;
+
+    Ok(RunArtifact {
+        artifact_id: record.id,
+        artifact_type,
+        is_immutable: record.is_immutable,
+        display: crate::artifacts::render_display(&content),
+        content,
+        hash: record.hash,
+    })
+}
+
+/// Save a single artifact (frontmatter + body) to a markdown file
+///
+/// The artifact must have been produced by the given run. Without `dest_path` it
+/// is saved as `<artifact_id>.md` in the export directory. The destination gets a
+/// `.md` extension and an existing file is only replaced when `overwrite` is set.
+/// Returns the number of bytes written.
+#[tauri::command]
+#[allow(clippy::too_many_arguments)]
+pub fn save_artifact_to_file(
+    run_id: String,
+    artifact_id: String,
+    dest_path: Option<String>,
+    overwrite: bool,
+    reveal: bool,
+    state: State<OrchestratorState>,
+    config_state: State<'_, Mutex<AppConfig>>,
+    app: tauri::AppHandle,
+) -> Result<u64, String> {
+    info!("=== SAVE_ARTIFACT_TO_FILE command called ===");
+    info!("Run ID: {}, Artifact ID: {}", run_id, artifact_id);
+
+    let text = {
+        let orchestrator_lock = state.0.lock().map_err(|e| e.to_string())?;
+        match orchestrator_lock.get(Some(&run_id)) {
+            Some(orchestrator) => orchestrator
+                .artifact_text(&artifact_id)
+                .ok_or_else(|| format!("Artifact {} was not produced by run {}", artifact_id, run_id))?,
+            None => {
+                drop(orchestrator_lock);
+                read_persisted_artifact(&app, &run_id, &artifact_id).map_err(|e| e.to_string())?
+            }
+        }
+    };
+
+    let dest = match dest_path {
+        Some(dest_path) => PathBuf::from(dest_path),
+        None => {
+            let config = config_state.lock().map_err(|e| e.to_string())?.clone();
+            config.export_dir(&app).map_err(|e| e.to_string())?.join(&artifact_id)
+        }
+    };
+
+    let (path, bytes_written) = write_artifact_file(&text, &dest, overwrite)
+        .map_err(|e| e.to_string())?;
+    info!("✓ Wrote {} bytes to {}", bytes_written, path.display());
+
+    if reveal {
+        if let Err(e) = app.opener().reveal_item_in_dir(&path) {
+            warn!("Failed to reveal {}: {}", path.display(), e);
+        }
+    }
+
+    Ok(bytes_written)
+}
+
+/// Release stored content of a run's older mutable artifacts
+///
+/// Keeps the Critical Path, other immutable artifacts and the latest
+/// `keep_steps` steps (default from `artifact_retention_steps` in settings).
+/// Pruned artifacts keep their IDs and hashes.
+#[tauri::command]
+pub fn prune_run_artifacts(
+    run_id: String,
+    keep_steps: Option<usize>,
+    config_state: State<'_, Mutex<AppConfig>>,
+    app: tauri::AppHandle,
+) -> Result<PrunedContent, String> {
+    info!("=== PRUNE_RUN_ARTIFACTS command called ===");
+
+    let keep_steps = match keep_steps {
+        Some(keep_steps) => keep_steps,
+        None => config_state.lock().map_err(|e| e.to_string())?.artifact_retention_steps,
+    };
+    info!("Run ID: {}, keep_steps: {}", run_id, keep_steps);
+
+    let mut conn = database::get_connection(&app).map_err(|e| e.to_string())?;
+    let pruned = database::artifacts::prune_content(&mut conn, &run_id, keep_steps).map_err(|e| e.to_string())?;
+
+    for path in &pruned.content_paths {
+        if let Err(e) = std::fs::remove_file(path) {
+            warn!("Failed to remove artifact content {}: {}", path, e);
+        }
+    }
+
+    info!("✓ Pruned content of {} artifacts", pruned.artifact_ids.len());
+    Ok(pruned)
+}
+
+/// Recompute every stored artifact's content hash and list the mismatches
+///
+/// A maintenance check for content edited on disk or hashed by an older template.
+#[tauri::command]
+pub fn audit_artifact_hashes(app: tauri::AppHandle) -> Result<Vec<HashMismatch>, String> {
+    info!("=== AUDIT_ARTIFACT_HASHES command called ===");
+
+    let conn = database::get_connection(&app).map_err(|e| e.to_string())?;
+    let mismatches = database::artifacts::audit_hashes(&conn).map_err(|e| e.to_string())?;
+
+    info!("✓ Hash audit found {} mismatch(es)", mismatches.len());
+    Ok(mismatches)
+}
+
+/// Read a persisted artifact's full text, checking it belongs to the run
+fn read_persisted_artifact(app: &tauri::AppHandle, run_id: &str, artifact_id: &str) -> anyhow::Result<String> {
+    let conn = database::get_connection(app)?;
+    persisted_artifact_text(&conn, run_id, artifact_id)
+}
+
+/// Full text (frontmatter + body) of a persisted artifact
+///
+/// Content files hold only the body, so the frontmatter is rebuilt from the
+/// artifact's database record.
+pub(crate) fn persisted_artifact_text(
+    conn: &rusqlite::Connection,
+    run_id: &str,
+    artifact_id: &str,
+) -> anyhow::Result<String> {
+    let record = database::artifacts::get_artifact(conn, artifact_id)?
+        .ok_or_else(|| anyhow::anyhow!("Artifact {} not found", artifact_id))?;
+
+    if record.run_id != run_id {
+        anyhow::bail!("Artifact {} was not produced by run {}", artifact_id, run_id);
+    }
+
+    let content_path = record
+        .content_path
+        .clone()
+        .ok_or_else(|| anyhow::anyhow!("Artifact {} has no stored content (it may have been pruned)", artifact_id))?;
+
+    let content = std::fs::read_to_string(&content_path)
+        .with_context(|| format!("Failed to read artifact content at {}", content_path))?;
+    Ok(persisted_run_artifact(record, content)?.to_markdown(run_id))
+}
+
+/// Write artifact text to `dest` with a `.md` extension
+///
+/// Returns the final path and the number of bytes written.
+pub(crate) fn write_artifact_file(text: &str, dest: &Path, overwrite: bool) -> anyhow::Result<(PathBuf, u64)> {
+    let path = if dest.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("md")) {
+        dest.to_path_buf()
+    } else {
+        dest.with_extension("md")
+    };
+
+    if path.exists() && !overwrite {
+        anyhow::bail!("File already exists: {} (pass overwrite to replace it)", path.display());
+    }
+
+    std::fs::write(&path, text)
+        .with_context(|| format!("Failed to write artifact to {}", path.display()))?;
+
+    Ok((path, text.len() as u64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agents::orchestrator::Orchestrator;
+
+    #[tokio::test]
+    async fn test_save_stub_charter_round_trip() {
+        let mut orch = Orchestrator::new("save-artifact").with_stubs();
+        orch.execute_step_0("Design a customer onboarding framework").await.unwrap();
+        orch.approve_gate("Human Reviewer").unwrap();
+        orch.execute_step_1().await.unwrap();
+
+        let charter_id = format!("{}-charter", orch.run_id);
+        let text = orch.artifact_text(&charter_id).expect("charter should exist");
+        assert!(text.starts_with("---"));
+        assert!(orch.artifact_text("unknown-artifact").is_none());
+
+        let dest = std::env::temp_dir().join(format!("method-vi-{}", uuid::Uuid::new_v4()));
+        let (path, bytes) = write_artifact_file(&text, &dest, false).unwrap();
+        assert_eq!(path.extension().unwrap(), "md");
+        assert_eq!(bytes, text.len() as u64);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), text);
+
+        // Existing files are only replaced on request
+        assert!(write_artifact_file("changed", &path, false).is_err());
+        write_artifact_file("changed", &path, true).unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "changed");
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_save_persisted_artifact_round_trip() {
+        use crate::database::models::{Artifact, Run};
+
+        let conn = rusqlite::Connection::open_in_memory().unwrap();
+        database::schema::create_schema(&conn).unwrap();
+        database::runs::create_run(&conn, &Run {
+            id: "persisted-run".to_string(),
+            intent_anchor_hash: "hash".to_string(),
+            created_at: chrono::Utc::now(),
+            completed_at: None,
+            final_ci: None,
+            final_ev: None,
+            status: Some("completed".to_string()),
+        })
+        .unwrap();
+
+        // Content files hold the body only, as `write_step_artifacts` stores them
+        let body = "# Charter\n\n## Primary Goal\nOnboard customers";
+        let content_path = std::env::temp_dir().join(format!("method-vi-{}.md", uuid::Uuid::new_v4()));
+        std::fs::write(&content_path, body).unwrap();
+        let hash = crate::artifacts::calculate_content_hash(body);
+        database::artifacts::create_artifact(&conn, &Artifact {
+            id: "persisted-run-charter".to_string(),
+            run_id: "persisted-run".to_string(),
+            r#type: serde_json::to_value(ArtifactType::Charter).unwrap().as_str().unwrap().to_string(),
+            step_origin: 1,
+            hash: hash.clone(),
+            is_immutable: true,
+            content_path: Some(content_path.to_string_lossy().into_owned()),
+            created_at: chrono::Utc::now(),
+            parent_hash: None,
+        })
+        .unwrap();
+
+        let text = persisted_artifact_text(&conn, "persisted-run", "persisted-run-charter").unwrap();
+        assert!(text.starts_with("---\nartifact_id: \"persisted-run-charter\""));
+        assert!(text.contains("artifact_type: \"Charter\""));
+        assert!(text.contains(&format!("hash: \"{}\"", hash)));
+        assert_eq!(crate::content::strip_frontmatter(&text).trim(), body);
+        assert!(persisted_artifact_text(&conn, "other-run", "persisted-run-charter").is_err());
+
+        let dest = std::env::temp_dir().join(format!("method-vi-{}", uuid::Uuid::new_v4()));
+        let (path, _) = write_artifact_file(&text, &dest, false).unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), text);
+
+        std::fs::remove_file(&path).unwrap();
+        std::fs::remove_file(&content_path).unwrap();
+    }
+}
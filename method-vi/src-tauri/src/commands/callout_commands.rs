@@ -3,7 +3,7 @@ use serde::{Deserialize, Serialize};
 use tauri::State;
 
 use crate::agents::orchestrator::ArtifactSummary;
-use crate::governance::{Callout, CalloutSummary, AcknowledgmentRecord};
+use crate::governance::{Callout, CalloutSummary, AcknowledgmentRecord, GateCalloutSummary, Step};
 use crate::commands::step0::OrchestratorState;
 
 // Re-export types for external use
@@ -31,42 +31,62 @@ pub enum GateDecision {
 
 /// Get all callouts for the current run
 #[tauri::command]
-pub fn get_all_callouts(state: State<OrchestratorState>) -> Result<Vec<Callout>, String> {
+pub fn get_all_callouts(run_id: Option<String>, state: State<OrchestratorState>) -> Result<Vec<Callout>, String> {
     let orch_lock = state.0.lock().map_err(|e| e.to_string())?;
-    let orchestrator = orch_lock.as_ref()
+    let orchestrator = orch_lock.get(run_id.as_deref())
         .ok_or_else(|| "No active run".to_string())?;
     Ok(orchestrator.callout_manager.all().to_vec())
 }
 
 /// Get callouts requiring acknowledgment
 #[tauri::command]
-pub fn get_pending_callouts(state: State<OrchestratorState>) -> Result<Vec<Callout>, String> {
+pub fn get_pending_callouts(run_id: Option<String>, state: State<OrchestratorState>) -> Result<Vec<Callout>, String> {
     let orch_lock = state.0.lock().map_err(|e| e.to_string())?;
-    let orchestrator = orch_lock.as_ref()
+    let orchestrator = orch_lock.get(run_id.as_deref())
         .ok_or_else(|| "No active run".to_string())?;
     Ok(orchestrator.callout_manager.get_pending_acknowledgments().into_iter().cloned().collect())
 }
 
 /// Get callout summary (counts by tier, can_proceed status)
 #[tauri::command]
-pub fn get_callout_summary(state: State<OrchestratorState>) -> Result<CalloutSummary, String> {
+pub fn get_callout_summary(run_id: Option<String>, state: State<OrchestratorState>) -> Result<CalloutSummary, String> {
     let orch_lock = state.0.lock().map_err(|e| e.to_string())?;
 
     // If no active run, return empty summary (graceful degradation)
-    if let Some(orchestrator) = orch_lock.as_ref() {
+    if let Some(orchestrator) = orch_lock.get(run_id.as_deref()) {
         Ok(orchestrator.callout_manager.summary())
     } else {
         Ok(CalloutSummary::default())
     }
 }
 
+/// Get the callout summary for a gate decision
+///
+/// `step` defaults to the run's current step. Lists the callouts blocking
+/// approval alongside the step's counts by tier.
+#[tauri::command]
+pub fn get_gate_callout_summary(
+    run_id: Option<String>,
+    step: Option<u8>,
+    state: State<OrchestratorState>,
+) -> Result<GateCalloutSummary, String> {
+    let orch_lock = state.0.lock().map_err(|e| e.to_string())?;
+    let orchestrator = orch_lock.get(run_id.as_deref())
+        .ok_or_else(|| "No active run".to_string())?;
+
+    let step_number = step.unwrap_or_else(|| orchestrator.state.step_number());
+    let step = Step::from_u8(step_number)
+        .ok_or_else(|| format!("Step {} has no callouts", step_number))?;
+    Ok(orchestrator.callout_manager.gate_summary(step))
+}
+
 /// Check if we can proceed (no unacknowledged Critical callouts)
 #[tauri::command]
-pub fn can_proceed(state: State<OrchestratorState>) -> Result<bool, String> {
+pub fn can_proceed(run_id: Option<String>, state: State<OrchestratorState>) -> Result<bool, String> {
     let orch_lock = state.0.lock().map_err(|e| e.to_string())?;
 
     // If no active run, allow proceeding (no callouts to block)
-    if let Some(orchestrator) = orch_lock.as_ref() {
+    if let Some(orchestrator) = orch_lock.get(run_id.as_deref()) {
         Ok(orchestrator.callout_manager.can_proceed())
     } else {
         Ok(true)
@@ -76,12 +96,13 @@ pub fn can_proceed(state: State<OrchestratorState>) -> Result<bool, String> {
 /// Acknowledge a specific callout
 #[tauri::command]
 pub fn acknowledge_callout(
+    run_id: Option<String>,
     state: State<OrchestratorState>,
     callout_id: String,
     confirmation: String,
 ) -> Result<AcknowledgmentRecord, String> {
     let mut orch_lock = state.0.lock().map_err(|e| e.to_string())?;
-    let orchestrator = orch_lock.as_mut()
+    let orchestrator = orch_lock.get_mut(run_id.as_deref())
         .ok_or_else(|| "No active run".to_string())?;
     orchestrator.callout_manager.acknowledge_with_confirmation(&callout_id, confirmation)
     // TODO: Log to ledger in Session 1.4 integration
@@ -90,23 +111,55 @@ pub fn acknowledge_callout(
 /// Acknowledge all pending Critical callouts
 #[tauri::command]
 pub fn acknowledge_all_callouts(
+    run_id: Option<String>,
     state: State<OrchestratorState>,
     confirmation: String,
 ) -> Result<Vec<AcknowledgmentRecord>, String> {
     let mut orch_lock = state.0.lock().map_err(|e| e.to_string())?;
-    let orchestrator = orch_lock.as_mut()
+    let orchestrator = orch_lock.get_mut(run_id.as_deref())
         .ok_or_else(|| "No active run".to_string())?;
     Ok(orchestrator.callout_manager.acknowledge_all_pending(confirmation))
     // TODO: Log to ledger in Session 1.4 integration
 }
 
+/// Check on demand how far the current step's output has drifted from the Step 0 intent
+///
+/// Returns the drift score (0.0-1.0). Drift above the configured threshold also
+/// adds a Warning callout.
+#[tauri::command]
+pub async fn check_intent_drift(
+    run_id: Option<String>,
+    state: State<'_, OrchestratorState>,
+) -> Result<f64, String> {
+    info!("=== CHECK_INTENT_DRIFT command called ===");
+
+    // Take the orchestrator so the lock isn't held across the API call
+    let (run_key, mut orchestrator) = {
+        let mut orch_lock = state.0.lock().map_err(|e| e.to_string())?;
+        let run_key = orch_lock
+            .resolve(run_id.as_deref())
+            .ok_or_else(|| "No active run".to_string())?;
+        let orchestrator = orch_lock.take(&run_key).ok_or_else(|| "No active run".to_string())?;
+        (run_key, orchestrator)
+    };
+
+    let result = orchestrator.check_intent_drift().await;
+
+    {
+        let mut orch_lock = state.0.lock().map_err(|e| e.to_string())?;
+        orch_lock.insert(run_key, orchestrator);
+    }
+
+    result.map_err(|e| format!("Failed to check intent drift: {}", e))
+}
+
 /// Get gate preview - shows artifacts created and missing deliverables
 #[tauri::command]
-pub fn get_gate_preview(step: i32, state: State<OrchestratorState>) -> Result<GatePreview, String> {
+pub fn get_gate_preview(step: i32, run_id: Option<String>, state: State<OrchestratorState>) -> Result<GatePreview, String> {
     info!("=== GET_GATE_PREVIEW called for step {} ===", step);
 
     let orch_lock = state.0.lock().map_err(|e| e.to_string())?;
-    let orchestrator = orch_lock.as_ref()
+    let orchestrator = orch_lock.get(run_id.as_deref())
         .ok_or_else(|| "No active run".to_string())?;
 
     // Get artifacts created
@@ -134,9 +187,9 @@ pub fn get_gate_preview(step: i32, state: State<OrchestratorState>) -> Result<Ga
 
 /// Get all hard-block callouts (cannot be cleared by acknowledgment)
 #[tauri::command]
-pub fn get_hard_blocks(state: State<OrchestratorState>) -> Result<Vec<Callout>, String> {
+pub fn get_hard_blocks(run_id: Option<String>, state: State<OrchestratorState>) -> Result<Vec<Callout>, String> {
     let orch_lock = state.0.lock().map_err(|e| e.to_string())?;
-    let orchestrator = orch_lock.as_ref()
+    let orchestrator = orch_lock.get(run_id.as_deref())
         .ok_or_else(|| "No active run".to_string())?;
     Ok(orchestrator.callout_manager.get_hard_blocks().into_iter().cloned().collect())
 }
@@ -146,6 +199,7 @@ pub fn get_hard_blocks(state: State<OrchestratorState>) -> Result<Vec<Callout>,
 pub fn submit_gate_decision(
     decision: GateDecision,
     feedback: Option<String>,
+    run_id: Option<String>,
     state: State<OrchestratorState>,
 ) -> Result<(), String> {
     info!("=== SUBMIT_GATE_DECISION called ===");
@@ -155,7 +209,7 @@ pub fn submit_gate_decision(
     }
 
     let mut orch_lock = state.0.lock().map_err(|e| e.to_string())?;
-    let orchestrator = orch_lock.as_mut()
+    let orchestrator = orch_lock.get_mut(run_id.as_deref())
         .ok_or_else(|| "No active run".to_string())?;
 
     match decision {
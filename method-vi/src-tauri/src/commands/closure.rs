@@ -1,8 +1,13 @@
-use log::info;
+use anyhow::Context;
+use log::{info, warn};
 use serde::{Deserialize, Serialize};
-use tauri::State;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use tauri::{Manager, State};
 
 use crate::commands::step0::OrchestratorState;
+use crate::config::AppConfig;
+use crate::database;
 use crate::agents::orchestrator::{
     ClosureResult, ClosureStatus, AuditEntry, ArchivedArtifact, RunStatistics,
 };
@@ -59,13 +64,13 @@ pub async fn execute_closure(
     let mut orchestrator = {
         let mut orch_guard = state.0.lock().unwrap();
         info!("State lock acquired");
-        info!("State contains orchestrator: {}", orch_guard.is_some());
+        info!("State contains run {}: {}", run_id, orch_guard.contains(&run_id));
 
         // Take ownership of the orchestrator temporarily
         let orch = orch_guard
-            .take()
+            .take(&run_id)
             .ok_or_else(|| {
-                let err = "No active run found. Please complete Steps 0-6 first.".to_string();
+                let err = "No run found for this run_id. Please complete Steps 0-6 first.".to_string();
                 log::error!("[EXECUTE_CLOSURE] {}", err);
                 err
             })?;
@@ -95,7 +100,7 @@ pub async fn execute_closure(
     // Return orchestrator to state
     {
         let mut orch_guard = state.0.lock().unwrap();
-        *orch_guard = Some(orchestrator);
+        orch_guard.insert(run_id.clone(), orchestrator);
     }
 
     info!("Orchestrator returned to state");
@@ -120,7 +125,7 @@ pub async fn export_markdown(
     // Generate report data while holding the lock
     let (run_id_str, ledger, steps_completed, exceptional) = {
         let orch_guard = state.0.lock().unwrap();
-        let orchestrator = orch_guard.as_ref()
+        let orchestrator = orch_guard.get(Some(&run_id))
             .ok_or_else(|| "No active run found".to_string())?;
 
         (
@@ -148,6 +153,44 @@ pub async fn export_markdown(
     Ok(markdown)
 }
 
+/// Export the run report as a single self-contained HTML page
+///
+/// Writes to `dest_path` when given, otherwise to
+/// `<export_dir>/<run_id>-<timestamp>.html`. Returns the path written.
+#[tauri::command]
+pub fn export_run_html(
+    run_id: String,
+    dest_path: Option<String>,
+    state: State<'_, OrchestratorState>,
+    config_state: State<'_, Mutex<AppConfig>>,
+    app: tauri::AppHandle,
+) -> Result<String, String> {
+    info!("=== EXPORT_RUN_HTML command called ===");
+    info!("Run ID: {}", run_id);
+
+    let html = {
+        let orch_guard = state.0.lock().map_err(|e| e.to_string())?;
+        let orchestrator = orch_guard
+            .get(Some(&run_id))
+            .ok_or_else(|| format!("Run {} is not loaded", run_id))?;
+        orchestrator.to_html_report()
+    }; // Lock released here
+
+    let path = match dest_path {
+        Some(dest_path) => PathBuf::from(dest_path),
+        None => {
+            let config = config_state.lock().map_err(|e| e.to_string())?.clone();
+            let export_dir = config.export_dir(&app).map_err(|e| e.to_string())?;
+            export_dir.join(format!("{}-{}.html", run_id, chrono::Utc::now().format("%Y%m%dT%H%M%SZ")))
+        }
+    };
+    std::fs::write(&path, &html)
+        .map_err(|e| format!("Failed to write HTML report to {}: {}", path.display(), e))?;
+
+    info!("✓ HTML report exported to {} ({} bytes)", path.display(), html.len());
+    Ok(path.to_string_lossy().into_owned())
+}
+
 /// Export run artifacts as JSON bundle
 #[tauri::command]
 pub async fn export_json(
@@ -160,7 +203,7 @@ pub async fn export_json(
     // Generate export data while holding the lock
     let (run_id_str, state_str, exceptional, ledger) = {
         let orch_guard = state.0.lock().unwrap();
-        let orchestrator = orch_guard.as_ref()
+        let orchestrator = orch_guard.get(Some(&run_id))
             .ok_or_else(|| "No active run found".to_string())?;
 
         (
@@ -187,3 +230,193 @@ pub async fn export_json(
 
     Ok(json_string)
 }
+
+/// Write a run's export bundle to the app's archives directory
+fn write_run_archive(app: &tauri::AppHandle, run_id: &str, bundle: &serde_json::Value) -> anyhow::Result<PathBuf> {
+    let archive_dir = app
+        .path()
+        .app_data_dir()
+        .context("Failed to get app data directory")?
+        .join("archives");
+    std::fs::create_dir_all(&archive_dir).context("Failed to create archives directory")?;
+
+    write_bundle_file(&archive_dir, run_id, bundle)
+}
+
+/// Write a bundle as `<run_id>-<timestamp>.json` in `dir`
+fn write_bundle_file(dir: &Path, run_id: &str, bundle: &serde_json::Value) -> anyhow::Result<PathBuf> {
+    let file_name = format!("{}-{}.json", run_id, chrono::Utc::now().format("%Y%m%dT%H%M%SZ"));
+    let path = dir.join(file_name);
+    let json = serde_json::to_string_pretty(bundle).context("Failed to serialize run bundle")?;
+    std::fs::write(&path, json).with_context(|| format!("Failed to write run bundle to {}", path.display()))?;
+
+    Ok(path)
+}
+
+/// Get the directory exports are written to when no path is given
+///
+/// `export_dir` from settings, or `exports/` in the app data directory; created
+/// if missing.
+#[tauri::command]
+pub fn get_export_dir(
+    config_state: State<'_, Mutex<AppConfig>>,
+    app: tauri::AppHandle,
+) -> Result<String, String> {
+    info!("=== GET_EXPORT_DIR command called ===");
+
+    let config = config_state.lock().map_err(|e| e.to_string())?.clone();
+    let export_dir = config.export_dir(&app).map_err(|e| e.to_string())?;
+    Ok(export_dir.to_string_lossy().into_owned())
+}
+
+/// Export a persisted run's full bundle (every row plus artifact content) as JSON
+///
+/// Writes to `dest_path` when given, otherwise to the export directory. Returns
+/// the path written.
+#[tauri::command]
+pub fn export_run_bundle(
+    run_id: String,
+    dest_path: Option<String>,
+    config_state: State<'_, Mutex<AppConfig>>,
+    app: tauri::AppHandle,
+) -> Result<String, String> {
+    info!("=== EXPORT_RUN_BUNDLE command called ===");
+    info!("Run ID: {}", run_id);
+
+    let conn = database::get_connection(&app).map_err(|e| e.to_string())?;
+    if database::runs::get_run(&conn, &run_id).map_err(|e| e.to_string())?.is_none() {
+        return Err(format!("Run {} not found", run_id));
+    }
+    let bundle = database::archive::export_run_bundle(&conn, &run_id).map_err(|e| e.to_string())?;
+
+    let path = match dest_path {
+        Some(dest_path) => {
+            let path = PathBuf::from(dest_path);
+            let json = serde_json::to_string_pretty(&bundle).map_err(|e| e.to_string())?;
+            std::fs::write(&path, json)
+                .map_err(|e| format!("Failed to write run bundle to {}: {}", path.display(), e))?;
+            path
+        }
+        None => {
+            let config = config_state.lock().map_err(|e| e.to_string())?.clone();
+            let export_dir = config.export_dir(&app).map_err(|e| e.to_string())?;
+            write_bundle_file(&export_dir, &run_id, &bundle).map_err(|e| e.to_string())?
+        }
+    };
+
+    info!("✓ Run bundle exported to {}", path.display());
+    Ok(path.to_string_lossy().into_owned())
+}
+
+/// Delete a run and all of its data, optionally archiving it first
+///
+/// With `archive`, the full bundle (every row of the run plus artifact content and,
+/// if loaded, the steno ledger) is written to the archives directory before
+/// anything is removed, and its path is returned. Rows are deleted in a single
+/// transaction; the in-memory orchestrator is dropped too.
+#[tauri::command]
+pub fn delete_run(
+    run_id: String,
+    archive: bool,
+    state: State<'_, OrchestratorState>,
+    app: tauri::AppHandle,
+) -> Result<Option<String>, String> {
+    info!("=== DELETE_RUN command called ===");
+    info!("Run ID: {}, archive: {}", run_id, archive);
+
+    let mut conn = database::get_connection(&app).map_err(|e| e.to_string())?;
+    let loaded = state.0.lock().map_err(|e| e.to_string())?.contains(&run_id);
+    let stored = database::runs::get_run(&conn, &run_id).map_err(|e| e.to_string())?.is_some();
+    if !stored && !loaded {
+        return Err(format!("Run {} not found", run_id));
+    }
+
+    let archive_path = if archive {
+        let mut bundle = database::archive::export_run_bundle(&conn, &run_id).map_err(|e| e.to_string())?;
+        if let Some(orchestrator) = state.0.lock().map_err(|e| e.to_string())?.get(Some(&run_id)) {
+            bundle["final_ledger"] = serde_json::json!(orchestrator.generate_steno_ledger());
+        }
+        let path = write_run_archive(&app, &run_id, &bundle).map_err(|e| e.to_string())?;
+        info!("✓ Run archived to {}", path.display());
+        Some(path.to_string_lossy().into_owned())
+    } else {
+        None
+    };
+
+    let summary = database::archive::delete_run_data(&mut conn, &run_id).map_err(|e| e.to_string())?;
+    info!("✓ Deleted {} rows for run {}", summary.total_rows(), run_id);
+
+    for path in &summary.content_paths {
+        if let Err(e) = std::fs::remove_file(path) {
+            warn!("Failed to remove artifact content {}: {}", path, e);
+        }
+    }
+
+    if state.0.lock().map_err(|e| e.to_string())?.remove(&run_id).is_some() {
+        info!("✓ Dropped in-memory orchestrator for run {}", run_id);
+    }
+
+    Ok(archive_path)
+}
+
+/// Tag a persisted run with a free-form label for later searches
+#[tauri::command]
+pub fn tag_run(run_id: String, tag: String, app: tauri::AppHandle) -> Result<(), String> {
+    info!("=== TAG_RUN command called ===");
+    info!("Run ID: {}, tag: {}", run_id, tag);
+
+    let tag = tag.trim();
+    if tag.is_empty() {
+        return Err("Tag must not be empty".to_string());
+    }
+
+    let conn = database::get_connection(&app).map_err(|e| e.to_string())?;
+    if database::runs::get_run(&conn, &run_id).map_err(|e| e.to_string())?.is_none() {
+        return Err(format!("Run {} not found", run_id));
+    }
+    database::runs::add_tag(&conn, &run_id, tag).map_err(|e| e.to_string())?;
+
+    info!("✓ Run {} tagged '{}'", run_id, tag);
+    Ok(())
+}
+
+/// Remove a tag from a persisted run
+#[tauri::command]
+pub fn untag_run(run_id: String, tag: String, app: tauri::AppHandle) -> Result<(), String> {
+    info!("=== UNTAG_RUN command called ===");
+    info!("Run ID: {}, tag: {}", run_id, tag);
+
+    let conn = database::get_connection(&app).map_err(|e| e.to_string())?;
+    database::runs::remove_tag(&conn, &run_id, tag.trim()).map_err(|e| e.to_string())?;
+
+    info!("✓ Tag '{}' removed from run {}", tag, run_id);
+    Ok(())
+}
+
+/// Get the tags of a persisted run
+#[tauri::command]
+pub fn get_run_tags(run_id: String, app: tauri::AppHandle) -> Result<Vec<String>, String> {
+    info!("=== GET_RUN_TAGS command called ===");
+    info!("Run ID: {}", run_id);
+
+    let conn = database::get_connection(&app).map_err(|e| e.to_string())?;
+    database::runs::list_tags(&conn, &run_id).map_err(|e| e.to_string())
+}
+
+/// Find persisted runs by tag and/or a substring of the run label
+#[tauri::command]
+pub fn search_runs(
+    tag: Option<String>,
+    label_contains: Option<String>,
+    app: tauri::AppHandle,
+) -> Result<Vec<database::models::Run>, String> {
+    info!("=== SEARCH_RUNS command called ===");
+    info!("Tag: {:?}, label contains: {:?}", tag, label_contains);
+
+    let conn = database::get_connection(&app).map_err(|e| e.to_string())?;
+    let runs = database::runs::search_runs(&conn, tag.as_deref(), label_contains.as_deref())
+        .map_err(|e| e.to_string())?;
+
+    info!("✓ Found {} runs", runs.len());
+    Ok(runs)
+}
@@ -9,6 +9,10 @@ pub mod step6_5;
 pub mod closure;
 pub mod callout_commands;
 pub mod mode_commands;
+pub mod artifact_commands;
+pub mod signal_commands;
+pub mod spine_commands;
+pub mod model_commands;
 
 pub use step0::*;
 pub use step1::*;
@@ -21,3 +25,7 @@ pub use step6_5::*;
 pub use closure::*;
 pub use callout_commands::*;
 pub use mode_commands::*;
+pub use artifact_commands::*;
+pub use signal_commands::*;
+pub use spine_commands::*;
+pub use model_commands::*;
@@ -16,10 +16,10 @@ pub struct ModeInfo {
 
 /// Get current detected mode for the run
 #[tauri::command]
-pub fn get_current_mode(state: State<OrchestratorState>) -> Result<ModeInfo, String> {
+pub fn get_current_mode(run_id: Option<String>, state: State<OrchestratorState>) -> Result<ModeInfo, String> {
     let orchestrator_lock = state.0.lock().map_err(|e| e.to_string())?;
 
-    if let Some(orchestrator) = orchestrator_lock.as_ref() {
+    if let Some(orchestrator) = orchestrator_lock.get(run_id.as_deref()) {
         if let Some(mode_result) = &orchestrator.mode_detection_result {
             return Ok(ModeInfo {
                 mode: Some(format!("{:?}", mode_result.mode)),
@@ -57,6 +57,7 @@ pub fn detect_mode(ci_baseline: f64) -> ModeDetectionResult {
 #[tauri::command]
 pub fn set_user_posture(
     posture: String,
+    run_id: Option<String>,
     state: State<OrchestratorState>,
 ) -> Result<String, String> {
     let posture_enum = match posture.as_str() {
@@ -67,7 +68,7 @@ pub fn set_user_posture(
 
     let mut orchestrator_lock = state.0.lock().map_err(|e| e.to_string())?;
 
-    if let Some(orchestrator) = orchestrator_lock.as_mut() {
+    if let Some(orchestrator) = orchestrator_lock.get_mut(run_id.as_deref()) {
         orchestrator.set_user_posture(posture_enum);
         Ok(format!("Posture set to {:?}", posture_enum))
     } else {
@@ -77,10 +78,10 @@ pub fn set_user_posture(
 
 /// Get current user posture for the run
 #[tauri::command]
-pub fn get_user_posture(state: State<OrchestratorState>) -> Result<String, String> {
+pub fn get_user_posture(run_id: Option<String>, state: State<OrchestratorState>) -> Result<String, String> {
     let orchestrator_lock = state.0.lock().map_err(|e| e.to_string())?;
 
-    if let Some(orchestrator) = orchestrator_lock.as_ref() {
+    if let Some(orchestrator) = orchestrator_lock.get(run_id.as_deref()) {
         Ok(format!("{:?}", orchestrator.user_posture))
     } else {
         Ok("Unconfirmed".to_string())
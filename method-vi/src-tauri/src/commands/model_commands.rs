@@ -0,0 +1,118 @@
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::sync::Mutex;
+use tauri::State;
+
+use crate::api::{AnthropicClient, KNOWN_MODELS};
+use crate::config::AppConfig;
+
+/// Models the user can pick from, e.g. for `default_model`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ModelList {
+    pub models: Vec<String>,
+    /// False when the list is the built-in fallback (no API key, echo provider or fetch failed)
+    pub fetched_from_api: bool,
+}
+
+/// Model list fetched once per session
+#[derive(Default)]
+pub struct ModelListCache(pub Mutex<Option<ModelList>>);
+
+/// List the models available for the configured provider
+///
+/// Queries the Anthropic models endpoint once per session and caches the result;
+/// without an API key (or if the query fails) the built-in list is returned
+/// uncached. The configured `default_model` is always included.
+#[tauri::command]
+pub async fn list_models(
+    config_state: State<'_, Mutex<AppConfig>>,
+    cache: State<'_, ModelListCache>,
+) -> Result<ModelList, String> {
+    info!("=== LIST_MODELS command called ===");
+
+    if let Some(cached) = cache.0.lock().map_err(|e| e.to_string())?.clone() {
+        info!("✓ Returning {} cached models", cached.models.len());
+        return Ok(cached);
+    }
+
+    let (client, default_model) = {
+        let config = config_state.lock().map_err(|e| e.to_string())?;
+        let client = match config.api_client() {
+            Ok(client) => Some(client),
+            Err(e) => {
+                warn!("Using the built-in model list - no API client: {}", e);
+                None
+            }
+        };
+        (client, config.default_model.clone())
+    };
+
+    let list = fetch_model_list(client.as_ref(), &default_model).await;
+    if list.fetched_from_api {
+        *cache.0.lock().map_err(|e| e.to_string())? = Some(list.clone());
+    }
+
+    info!("✓ {} models (fetched from API: {})", list.models.len(), list.fetched_from_api);
+    Ok(list)
+}
+
+/// Models from the API, or the built-in list if there is no client or the query fails
+pub(crate) async fn fetch_model_list(client: Option<&AnthropicClient>, default_model: &str) -> ModelList {
+    let fetched = match client {
+        Some(client) => match client.list_models().await {
+            Ok(models) if !models.is_empty() => Some(models),
+            Ok(_) => {
+                warn!("Models endpoint returned no models - using the built-in list");
+                None
+            }
+            Err(e) => {
+                warn!("Failed to list models - using the built-in list: {:#}", e);
+                None
+            }
+        },
+        None => None,
+    };
+
+    let fetched_from_api = fetched.is_some();
+    let mut models = fetched.unwrap_or_else(|| KNOWN_MODELS.iter().map(|m| m.to_string()).collect());
+    if !models.iter().any(|m| m == default_model) {
+        models.push(default_model.to_string());
+    }
+
+    ModelList { models, fetched_from_api }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::test_server::TestServer;
+
+    #[tokio::test]
+    async fn test_model_list_from_mocked_endpoint() {
+        let server = TestServer::fixed(
+            r#"{"data": [
+                {"type": "model", "id": "claude-sonnet-4-20250514", "display_name": "Claude Sonnet 4"},
+                {"type": "model", "id": "claude-3-5-haiku-20241022", "display_name": "Claude Haiku 3.5"}
+            ], "has_more": false}"#,
+        )
+        .await;
+        let client = server.client();
+
+        let default_model = AppConfig::default().default_model;
+        let list = fetch_model_list(Some(&client), &default_model).await;
+        assert!(list.fetched_from_api);
+        assert_eq!(list.models, vec!["claude-sonnet-4-20250514", "claude-3-5-haiku-20241022"]);
+        assert!(list.models.contains(&default_model));
+    }
+
+    #[tokio::test]
+    async fn test_model_list_falls_back_without_client() {
+        let list = fetch_model_list(None, "claude-custom-model").await;
+        assert!(!list.fetched_from_api);
+        assert_eq!(list.models.len(), KNOWN_MODELS.len() + 1);
+        assert!(list.models.contains(&"claude-custom-model".to_string()));
+
+        let echo = AnthropicClient::echo().unwrap();
+        assert!(!fetch_model_list(Some(&echo), "claude-sonnet-4-20250514").await.fetched_from_api);
+    }
+}
@@ -0,0 +1,77 @@
+use log::info;
+use tauri::State;
+
+use crate::commands::step0::OrchestratorState;
+use crate::database;
+use crate::signals::{Signal, SignalRouter};
+use crate::timeline::{RunTiming, TimelineEvent};
+
+/// Get the signal chain (workflow timeline) for a run
+///
+/// Returns the in-memory chain when the run is loaded, otherwise loads the
+/// persisted chain from the signals table.
+#[tauri::command]
+pub fn get_signal_chain(
+    run_id: String,
+    state: State<OrchestratorState>,
+    app: tauri::AppHandle,
+) -> Result<Vec<Signal>, String> {
+    info!("=== GET_SIGNAL_CHAIN command called ===");
+    info!("Run ID: {}", run_id);
+
+    {
+        let orchestrator_lock = state.0.lock().map_err(|e| e.to_string())?;
+        if let Some(orchestrator) = orchestrator_lock.get(Some(&run_id)) {
+            return Ok(orchestrator.get_signal_router().get_signal_chain(&orchestrator.run_id));
+        }
+    }
+
+    let conn = database::get_connection(&app).map_err(|e| e.to_string())?;
+    let chain = SignalRouter::new()
+        .load_chain(&conn, &run_id)
+        .map_err(|e| e.to_string())?;
+
+    info!("✓ Loaded {} persisted signals", chain.len());
+    Ok(chain)
+}
+
+/// Get a run's ledger entries, signals and callouts as one time-ordered list
+///
+/// Each event carries a `kind` (ledger, signal or callout). Ledger entries and
+/// callouts are kept in memory only, so the run must be loaded.
+#[tauri::command]
+pub fn get_run_timeline(run_id: String, state: State<OrchestratorState>) -> Result<Vec<TimelineEvent>, String> {
+    info!("=== GET_RUN_TIMELINE command called ===");
+    info!("Run ID: {}", run_id);
+
+    let orchestrator_lock = state.0.lock().map_err(|e| e.to_string())?;
+    let orchestrator = orchestrator_lock.get(Some(&run_id)).ok_or_else(|| {
+        format!(
+            "Run {} is not loaded: its ledger entries and callouts are not persisted, so its timeline is only available while the run is loaded",
+            run_id
+        )
+    })?;
+
+    let timeline = orchestrator.timeline();
+    info!("✓ {} timeline events", timeline.len());
+    Ok(timeline)
+}
+
+/// Get a run's total elapsed time, per-step durations and gate waits
+///
+/// Computed from ledger timestamps, which are kept in memory only, so the run
+/// must be loaded.
+#[tauri::command]
+pub fn get_run_timing(run_id: String, state: State<OrchestratorState>) -> Result<RunTiming, String> {
+    info!("=== GET_RUN_TIMING command called ===");
+    info!("Run ID: {}", run_id);
+
+    let orchestrator_lock = state.0.lock().map_err(|e| e.to_string())?;
+    let orchestrator = orchestrator_lock
+        .get(Some(&run_id))
+        .ok_or_else(|| format!("Run {} is not loaded", run_id))?;
+
+    let timing = orchestrator.timing();
+    info!("✓ {} steps timed, {:.1}s total", timing.steps.len(), timing.total_secs);
+    Ok(timing)
+}
@@ -0,0 +1,210 @@
+use log::info;
+use rusqlite::Connection;
+
+use crate::database;
+use crate::spine::{SpineGraph, SpineHealthReport, SpineManager};
+
+/// Build a run's spine from the database and report its health
+fn spine_health_for_run(conn: &Connection, run_id: &str) -> anyhow::Result<SpineHealthReport> {
+    let manager = SpineManager::from_db(conn, run_id)?;
+    Ok(manager.health_report(run_id))
+}
+
+/// Get the Coherence Spine health for a run
+///
+/// Returns the integrity report (breaks, orphans, cycles) with a
+/// human-readable explanation for each issue.
+#[tauri::command]
+pub fn get_spine_health(run_id: String, app: tauri::AppHandle) -> Result<SpineHealthReport, String> {
+    info!("=== GET_SPINE_HEALTH command called ===");
+    info!("Run ID: {}", run_id);
+
+    let conn = database::get_connection(&app).map_err(|e| e.to_string())?;
+    let report = spine_health_for_run(&conn, &run_id).map_err(|e| e.to_string())?;
+
+    info!(
+        "✓ Spine health: {} artifacts, {} edges, {} issue(s)",
+        report.artifact_count,
+        report.edge_count,
+        report.issues.len()
+    );
+    Ok(report)
+}
+
+/// Get a run's persisted spine as node and edge lists
+///
+/// The machine-readable counterpart to the rendered diagrams, for custom UI
+/// renderers. Orphaned artifacts appear as nodes without edges.
+#[tauri::command]
+pub fn get_spine_graph(run_id: String, app: tauri::AppHandle) -> Result<SpineGraph, String> {
+    info!("=== GET_SPINE_GRAPH command called ===");
+    info!("Run ID: {}", run_id);
+
+    let conn = database::get_connection(&app).map_err(|e| e.to_string())?;
+    let graph = SpineManager::from_db(&conn, &run_id)
+        .map_err(|e| e.to_string())?
+        .graph();
+
+    info!("✓ Spine graph: {} nodes, {} edges", graph.nodes.len(), graph.edges.len());
+    Ok(graph)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::models::{Artifact, Run, SpineEdge};
+    use crate::database::{artifacts, runs, schema, spine};
+    use crate::spine::types::SpineIssueKind;
+    use chrono::Utc;
+
+    fn setup_test_db() -> Connection {
+        let conn = Connection::open_in_memory().expect("Failed to create in-memory database");
+        conn.execute("PRAGMA foreign_keys = ON", []).unwrap();
+        schema::create_schema(&conn).expect("Failed to create schema");
+        conn
+    }
+
+    fn create_run(conn: &Connection, run_id: &str) {
+        runs::create_run(
+            conn,
+            &Run {
+                id: run_id.to_string(),
+                intent_anchor_hash: "hash-intent".to_string(),
+                created_at: Utc::now(),
+                completed_at: None,
+                final_ci: None,
+                final_ev: None,
+                status: Some("active".to_string()),
+            },
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_spine_graph_lists_nodes_and_edges() {
+        let conn = setup_test_db();
+        let run_id = "2025-12-17-Spine-Graph";
+        create_run(&conn, run_id);
+
+        for (id, artifact_type, step) in [
+            ("Intent-001", "IntentAnchor", 0),
+            ("Charter-002", "Charter", 1),
+            ("Baseline-003", "BaselineReport", 1),
+            ("Section-004", "Section", 3),
+            ("Section-005", "Section", 3),
+        ] {
+            artifacts::create_artifact(
+                &conn,
+                &Artifact {
+                    id: id.to_string(),
+                    run_id: run_id.to_string(),
+                    r#type: artifact_type.to_string(),
+                    step_origin: step,
+                    hash: format!("hash-{}", id),
+                    is_immutable: step < 3,
+                    content_path: None,
+                    created_at: Utc::now(),
+                    parent_hash: None,
+                },
+            )
+            .unwrap();
+        }
+
+        // Baseline only references the Charter, so the linked Critical Path stops there
+        for (source, target, edge_type) in [
+            ("Charter-002", "Intent-001", "derived_from"),
+            ("Baseline-003", "Charter-002", "references"),
+            ("Section-004", "Charter-002", "constrained_by"),
+        ] {
+            spine::create_spine_edge(
+                &conn,
+                &SpineEdge {
+                    source_id: source.to_string(),
+                    target_id: target.to_string(),
+                    edge_type: edge_type.to_string(),
+                    created_at: Utc::now(),
+                },
+            )
+            .unwrap();
+        }
+
+        let graph = SpineManager::from_db(&conn, run_id).unwrap().graph();
+
+        assert_eq!(graph.nodes.len(), 5);
+        assert_eq!(graph.edges.len(), 3);
+        let critical: Vec<&str> = graph
+            .nodes
+            .iter()
+            .filter(|node| node.on_critical_path)
+            .map(|node| node.id.as_str())
+            .collect();
+        assert_eq!(critical, vec!["Intent-001", "Charter-002"]);
+
+        // The orphan is still listed, with no edges touching it
+        let orphan = graph.nodes.iter().find(|node| node.id == "Section-005").unwrap();
+        assert_eq!(orphan.artifact_type, "Section");
+        assert!(!orphan.immutable);
+        assert!(!graph.edges.iter().any(|e| e.from == "Section-005" || e.to == "Section-005"));
+
+        let json = serde_json::to_value(&graph).unwrap();
+        assert_eq!(json["nodes"][0]["type"], "Intent_Anchor");
+        assert_eq!(json["edges"][0], serde_json::json!({"from": "Charter-002", "to": "Intent-001", "type": "derived_from"}));
+    }
+
+    #[test]
+    fn test_spine_health_reports_orphan() {
+        let conn = setup_test_db();
+        let run_id = "2025-12-17-Spine-Health";
+        create_run(&conn, run_id);
+
+        for (id, artifact_type, step) in [
+            ("Intent-001", "IntentAnchor", 0),
+            ("Charter-002", "Charter", 1),
+            ("Section-003", "Section", 3),
+        ] {
+            artifacts::create_artifact(
+                &conn,
+                &Artifact {
+                    id: id.to_string(),
+                    run_id: run_id.to_string(),
+                    r#type: artifact_type.to_string(),
+                    step_origin: step,
+                    hash: format!("hash-{}", id),
+                    is_immutable: step < 3,
+                    content_path: None,
+                    created_at: Utc::now(),
+                    parent_hash: None,
+                },
+            )
+            .unwrap();
+        }
+
+        spine::create_spine_edge(
+            &conn,
+            &SpineEdge {
+                source_id: "Charter-002".to_string(),
+                target_id: "Intent-001".to_string(),
+                edge_type: "derived_from".to_string(),
+                created_at: Utc::now(),
+            },
+        )
+        .unwrap();
+
+        let report = spine_health_for_run(&conn, run_id).unwrap();
+
+        assert!(!report.integrity.valid);
+        assert_eq!(report.artifact_count, 3);
+        assert_eq!(report.edge_count, 1);
+        assert_eq!(report.integrity.orphans, vec!["Section-003"]);
+        assert_eq!(report.issues.len(), 1);
+        assert_eq!(report.issues[0].kind, SpineIssueKind::Orphan);
+        assert_eq!(
+            report.issues[0].explanation,
+            "Section-003 (Section) has no path to the Intent_Anchor"
+        );
+
+        // Serialized form carries the explanation for the UI
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["issues"][0]["kind"], "Orphan");
+    }
+}
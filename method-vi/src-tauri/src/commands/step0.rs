@@ -1,21 +1,190 @@
 use anyhow::Result;
-use log::info;
+use log::{info, warn};
 use serde::{Deserialize, Serialize};
 use tauri::State;
+use std::collections::HashMap;
 use std::sync::Mutex;
 
-use crate::agents::orchestrator::Orchestrator;
+use crate::agents::analysis_synthesis::AnalysisSynthesisAgent;
+use crate::agents::governance_telemetry::{GovernanceTelemetryAgent, MetricDelta, ScopeExpansionCount};
+use crate::agents::orchestrator::{HaltStatusReport, Orchestrator, PendingGate, PermittedActions, RunState, StepDescription};
 use crate::agents::scope_pattern::{IntentSummary, ScopePatternAgent};
-use crate::api::AnthropicClient;
-use crate::config::AppConfig;
+use crate::agents::structure_redesign::StructureRedesignAgent;
+use crate::agents::validation_learning::ValidationLearningAgent;
+use crate::api::{AnthropicClient, ApiUsage};
+use crate::config::{AppConfig, PromptTemplates, RunConfig};
+use crate::ledger::LedgerChainProof;
 
-/// Global orchestrator state
-/// In a real application, this would be a map of run_id -> Orchestrator
-pub struct OrchestratorState(pub Mutex<Option<Orchestrator>>);
+/// Global orchestrator state: every run opened in this session
+pub struct OrchestratorState(pub Mutex<RunRegistry>);
+
+/// Orchestrators keyed by run_id
+///
+/// Commands select a run by run_id; commands called without one act on the
+/// active run (the one most recently started or activated).
+#[derive(Default)]
+pub struct RunRegistry {
+    runs: HashMap<String, Orchestrator>,
+    active_run: Option<String>,
+}
+
+impl RunRegistry {
+    /// The given run_id, or the active run if None (only if the run is loaded)
+    pub fn resolve(&self, run_id: Option<&str>) -> Option<String> {
+        run_id
+            .or(self.active_run.as_deref())
+            .filter(|id| self.runs.contains_key(*id))
+            .map(str::to_string)
+    }
+
+    pub fn get(&self, run_id: Option<&str>) -> Option<&Orchestrator> {
+        let key = self.resolve(run_id)?;
+        self.runs.get(&key)
+    }
+
+    pub fn get_mut(&mut self, run_id: Option<&str>) -> Option<&mut Orchestrator> {
+        let key = self.resolve(run_id)?;
+        self.runs.get_mut(&key)
+    }
+
+    /// Take a run out so it can execute without holding the lock; put it back with `insert`
+    pub fn take(&mut self, run_id: &str) -> Option<Orchestrator> {
+        self.runs.remove(run_id)
+    }
+
+    /// Add (or put back) a run without changing the active run
+    pub fn insert(&mut self, run_id: impl Into<String>, orchestrator: Orchestrator) {
+        self.runs.insert(run_id.into(), orchestrator);
+    }
+
+    /// Drop a run for good, clearing the active run if it was this one
+    pub fn remove(&mut self, run_id: &str) -> Option<Orchestrator> {
+        if self.active_run.as_deref() == Some(run_id) {
+            self.active_run = None;
+        }
+        self.runs.remove(run_id)
+    }
+
+    /// Make a loaded run the active one; false if it is not loaded
+    pub fn activate(&mut self, run_id: &str) -> bool {
+        if !self.runs.contains_key(run_id) {
+            return false;
+        }
+        self.active_run = Some(run_id.to_string());
+        true
+    }
+
+    pub fn active_run_id(&self) -> Option<&str> {
+        self.active_run.as_deref()
+    }
+
+    pub fn contains(&self, run_id: &str) -> bool {
+        self.runs.contains_key(run_id)
+    }
+
+    /// Loaded run_ids, sorted
+    pub fn run_ids(&self) -> Vec<String> {
+        let mut ids: Vec<String> = self.runs.keys().cloned().collect();
+        ids.sort();
+        ids
+    }
+
+    /// Gates awaiting approval across all loaded runs, ordered by run_id
+    ///
+    /// Each entry carries the run_id the run is loaded under, so it can be
+    /// passed straight back to the gate commands.
+    pub fn pending_gates(&self) -> Vec<PendingGate> {
+        self.run_ids()
+            .into_iter()
+            .filter_map(|run_id| {
+                let gate = self.runs[&run_id].pending_gate()?;
+                Some(PendingGate { run_id, ..gate })
+            })
+            .collect()
+    }
+
+    /// Markdown table comparing loaded runs side by side (one column per run)
+    ///
+    /// Meant for repeated runs of the same intent. Rows cover the state and
+    /// step each run reached, its latest metrics, model geometry, number of
+    /// operating principles, cost and callout counts. Runs that are not loaded
+    /// are listed below the table.
+    pub fn generate_comparison_report(&self, run_ids: &[String]) -> String {
+        let (loaded, missing): (Vec<&String>, Vec<&String>) = run_ids
+            .iter()
+            .partition(|run_id| self.runs.contains_key(run_id.as_str()));
+        let runs: Vec<&Orchestrator> = loaded.iter().map(|run_id| &self.runs[run_id.as_str()]).collect();
+
+        let mut rows: Vec<(&str, Vec<String>)> = vec![
+            ("Intent category", runs.iter().map(|orch| {
+                orch.intent_summary
+                    .as_ref()
+                    .map_or("-".to_string(), |summary| summary.intent_category.clone())
+            }).collect()),
+            ("State", runs.iter().map(|orch| format!("{:?}", orch.state)).collect()),
+            ("Reached step", runs.iter().map(|orch| match orch.state {
+                RunState::Completed => "Completed".to_string(),
+                _ => orch.state.step_number().to_string(),
+            }).collect()),
+        ];
+
+        for (index, name) in ["CI", "EV", "IAS", "EFI", "SEC", "PCI"].into_iter().enumerate() {
+            rows.push((name, runs.iter().map(|orch| {
+                orch.latest_metrics
+                    .as_ref()
+                    .and_then(|m| [&m.ci, &m.ev, &m.ias, &m.efi, &m.sec, &m.pci][index].as_ref())
+                    .map_or("-".to_string(), |result| format!("{:.2}", result.value))
+            }).collect()));
+        }
+
+        rows.push(("Model geometry", runs.iter().map(|orch| {
+            orch.model_geometry
+                .as_deref()
+                .and_then(|geometry| geometry.split(':').next())
+                .map_or("-".to_string(), str::to_string)
+        }).collect()));
+        rows.push(("Operating principles", runs.iter().map(|orch| {
+            orch.operating_principles.as_deref().map_or("-".to_string(), |principles| {
+                principles.lines().filter(|line| !line.trim().is_empty()).count().to_string()
+            })
+        }).collect()));
+        rows.push(("Cost (USD)", runs.iter().map(|orch| format!("{:.4}", orch.api_usage().cost_usd)).collect()));
+        rows.push(("Callouts", runs.iter().map(|orch| orch.callout_manager.summary().total.to_string()).collect()));
+        rows.push(("Critical callouts", runs.iter().map(|orch| {
+            orch.callout_manager.summary().by_tier.critical.to_string()
+        }).collect()));
+
+        let mut report = String::from("# Method-VI Run Comparison\n\n");
+        report.push_str(&format!(
+            "| Metric | {} |\n|---|{}\n",
+            loaded.iter().map(|run_id| table_cell(run_id)).collect::<Vec<_>>().join(" | "),
+            "---|".repeat(runs.len())
+        ));
+        for (name, values) in rows {
+            let cells: Vec<String> = values.iter().map(|value| table_cell(value)).collect();
+            report.push_str(&format!("| {} | {} |\n", name, cells.join(" | ")));
+        }
+
+        if !missing.is_empty() {
+            report.push_str(&format!(
+                "\nNot loaded: {}\n",
+                missing.iter().map(|run_id| run_id.as_str()).collect::<Vec<_>>().join(", ")
+            ));
+        }
+        report
+    }
+}
+
+/// Text made safe for one markdown table cell: pipes escaped, line breaks as `<br>`
+fn table_cell(text: &str) -> String {
+    text.trim().replace('|', "\\|").replace("\r\n", "<br>").replace('\n', "<br>")
+}
 
 /// Response structure for Step 0 that matches the frontend expectations
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Step0Response {
+    /// Id the run is stored under; later commands must use it
+    pub run_id: String,
     pub intent_summary: IntentSummaryForFrontend,
     pub clarification_questions: Vec<ClarificationQuestion>,
     pub pattern_recommendations: Vec<PatternRecommendation>,
@@ -58,7 +227,7 @@ impl From<IntentSummary> for IntentSummaryForFrontend {
 }
 
 /// Convert questions_for_clarification to ClarificationQuestion list
-fn convert_questions(questions: &[String]) -> Vec<ClarificationQuestion> {
+pub(crate) fn convert_questions(questions: &[String]) -> Vec<ClarificationQuestion> {
     questions
         .iter()
         .filter(|q| !q.contains("None - intent is clear"))
@@ -73,43 +242,45 @@ fn convert_questions(questions: &[String]) -> Vec<ClarificationQuestion> {
 ///
 /// This command is called when the user submits their intent in the UI.
 /// It creates a new orchestrator (or reuses existing) and executes Step 0.
+/// The run is stored under the orchestrator's own dated run_id, which can
+/// differ from `run_id` when the date has changed since the frontend built it;
+/// the response carries the id to use from then on.
 #[tauri::command]
 pub async fn start_step_0(
     run_id: String,
     user_intent: String,
     state: State<'_, OrchestratorState>,
     config_state: State<'_, Mutex<AppConfig>>,
+    app: tauri::AppHandle,
 ) -> Result<Step0Response, String> {
     info!("=== START_STEP_0 command called ===");
     info!("Run ID: {}", run_id);
     info!("User Intent length: {} chars", user_intent.len());
 
-    // Get API key from config
-    let api_key = {
+    // Create the run with its settings frozen from config, plus the Scope & Pattern Agent
+    let mut orchestrator = {
         let config = config_state.lock().unwrap();
-        config
-            .get_api_key()
-            .map_err(|e| format!("API key not configured: {}. Please set it in Settings or via ANTHROPIC_API_KEY environment variable.", e))?
+        let orchestrator = new_run(&config, &run_id, config.seed);
+        let run_config = orchestrator.run_config.clone().unwrap_or_else(|| RunConfig::from(&*config));
+        let claude_client = config
+            .run_api_client(&run_config)
+            .map_err(|e| format!("API key not configured: {}. Please set it in Settings or via ANTHROPIC_API_KEY environment variable.", e))?;
+        orchestrator.with_scope_agent(ScopePatternAgent::new(claude_client))
     };
+    if orchestrator.run_id != run_id {
+        info!("Run {} is stored as {}", run_id, orchestrator.run_id);
+    }
+    let run_id = orchestrator.run_id.clone();
 
-    info!("API key found: {}...", &api_key[..15]);
-
-    // Create Anthropic client
-    let claude_client = AnthropicClient::new(api_key)
-        .map_err(|e| format!("Failed to create Anthropic client: {}", e))?;
-
-    // Create Scope & Pattern Agent
-    let scope_agent = ScopePatternAgent::new(claude_client);
-
-    // Create new orchestrator with the scope agent
-    let label = run_id
-        .split('-')
-        .skip(3) // Skip YYYY-MM-DD parts
-        .collect::<Vec<_>>()
-        .join("-");
-
-    info!("Creating new orchestrator with label: {}", label);
-    let mut orchestrator = Orchestrator::new(&label).with_scope_agent(scope_agent);
+    // Persist the signal chain and step artifacts so the run survives restart
+    match crate::database::get_db_path(&app) {
+        Ok(db_path) => {
+            orchestrator = orchestrator
+                .with_signal_persistence(db_path.clone())
+                .with_artifact_persistence(db_path)
+        }
+        Err(e) => warn!("Signal and artifact persistence disabled - could not resolve database path: {}", e),
+    }
 
     // Execute Step 0
     info!("Executing Step 0...");
@@ -117,6 +288,7 @@ pub async fn start_step_0(
         .execute_step_0(&user_intent)
         .await
         .map_err(|e| format!("Failed to execute Step 0: {}", e))?;
+    auto_approve_gate(&mut orchestrator);
 
     info!("Step 0 completed successfully");
     info!("Intent: {}", intent_summary.primary_goal);
@@ -130,27 +302,288 @@ pub async fn start_step_0(
     // For now, pattern recommendations are empty (would query Learning Plane in future)
     let pattern_recommendations = vec![];
 
-    // Store orchestrator in state for future gate approval
+    // Store orchestrator in state for future gate approval and make it the active run
     {
         let mut guard = state.0.lock().unwrap();
-        *guard = Some(orchestrator);
+        guard.insert(run_id.clone(), orchestrator);
+        guard.activate(&run_id);
         info!("Orchestrator stored in state successfully");
-        info!("State contains orchestrator: {}", guard.is_some());
+        info!("Loaded runs: {}", guard.run_ids().len());
     }
 
     Ok(Step0Response {
+        run_id,
         intent_summary: frontend_summary,
         clarification_questions,
         pattern_recommendations,
     })
 }
 
+/// Label part of a run_id of the form `YYYY-MM-DD-<label>`
+pub(crate) fn run_label(run_id: &str) -> String {
+    run_id
+        .split('-')
+        .skip(3) // Skip YYYY-MM-DD parts
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+/// Create the orchestrator for a new run, with its settings taken from config
+///
+/// Shared by the Tauri commands and the headless session so both start runs
+/// the same way: immutability, step text, baseline and signal naming policy,
+/// the frozen run settings and gate auto-approval. Agents and persistence are
+/// left to the caller.
+pub(crate) fn new_run(config: &AppConfig, run_id: &str, seed: Option<u64>) -> Orchestrator {
+    let label = run_label(run_id);
+    info!("Creating new orchestrator with label: {}", label);
+
+    let mut orchestrator = match seed {
+        Some(seed) => Orchestrator::new_with_seed(&label, seed),
+        None => Orchestrator::new(&label),
+    }
+    .with_immutable_types(config.immutable_artifact_types.clone())
+    .with_step_descriptions(config.step_descriptions.clone())
+    .with_unlocked_baseline_policy(config.unlocked_baseline_policy)
+    .with_signal_naming(config.signal_naming.clone())
+    .with_run_config(RunConfig::from(config));
+
+    if config.auto_approve_gates {
+        orchestrator = orchestrator.with_auto_approval(config.auto_approve_steps.clone());
+    }
+    orchestrator
+}
+
+/// Governance Agent using a run's frozen utility model, HALT schedule, thresholds
+/// and metric batching
+pub(crate) fn governance_agent(client: AnthropicClient, run_config: &RunConfig) -> GovernanceTelemetryAgent {
+    GovernanceTelemetryAgent::from_client(client)
+        .with_utility_model(run_config.utility_model.clone())
+        .with_enforcement_schedule(run_config.metric_enforcement.clone())
+        .with_thresholds(run_config.metric_thresholds.clone())
+        .with_batched_metrics(run_config.batched_metrics)
+}
+
+/// Analysis & Synthesis Agent using the app's prompt templates and a run's
+/// frozen lens output format and Step 4 options
+pub(crate) fn analysis_synthesis_agent(
+    client: AnthropicClient,
+    run_config: &RunConfig,
+    templates: PromptTemplates,
+) -> AnalysisSynthesisAgent {
+    AnalysisSynthesisAgent::from_client(client)
+        .with_prompt_templates(templates)
+        .with_lens_output_format(run_config.lens_output_format)
+        .with_step4_options(run_config.step4_options)
+}
+
+/// Attach every agent a run needs after Step 0, built from its frozen settings
+///
+/// Used where a run continues without the per-step commands attaching agents
+/// as they go: a fork, and the headless session.
+pub(crate) fn with_run_agents(
+    orchestrator: Orchestrator,
+    config: &AppConfig,
+    run_config: &RunConfig,
+    templates: PromptTemplates,
+) -> Result<Orchestrator> {
+    let client = || config.run_api_client(run_config);
+    Ok(orchestrator
+        .with_scope_agent(ScopePatternAgent::new(client()?))
+        .with_governance_agent(governance_agent(client()?, run_config))
+        .with_structure_agent(StructureRedesignAgent::from_client(client()?))
+        .with_analysis_synthesis_agent(analysis_synthesis_agent(client()?, run_config, templates))
+        .with_validation_agent(ValidationLearningAgent::from_client(client()?)))
+}
+
+/// Settle a run after a step command executed its step
+///
+/// A step that succeeded has its gate auto-approved if the policy allows it; one
+/// that failed because the circuit breaker is open halts the run. Returns true
+/// if the gate was auto-approved.
+pub(crate) fn finish_step<T>(orchestrator: &mut Orchestrator, result: &Result<T>) -> bool {
+    match result {
+        Ok(_) => auto_approve_gate(orchestrator),
+        Err(e) => {
+            orchestrator.halt_on_circuit_open(e);
+            false
+        }
+    }
+}
+
+/// Approve the gate a step just reached, if the run's auto-approval policy allows it
+///
+/// Called after a step succeeds, by the Tauri commands and the headless
+/// session alike. A gate the policy leaves to a human, or whose approval
+/// fails (logged, not returned), stays pending. Returns true if approved.
+pub(crate) fn auto_approve_gate(orchestrator: &mut Orchestrator) -> bool {
+    let step = orchestrator.state.step_number();
+    match orchestrator.try_auto_approve() {
+        Ok(true) => {
+            info!("✓ Step {} gate auto-approved", step);
+            true
+        }
+        Ok(false) => false,
+        Err(e) => {
+            warn!("Auto-approval of the Step {} gate failed: {:#}", step, e);
+            false
+        }
+    }
+}
+
+/// Run selected by activate_run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActiveRunInfo {
+    pub run_id: String,
+    pub state: String,
+    pub step: u8,
+    pub loaded_runs: Vec<String>,
+}
+
+/// Make a run opened in this session the active one
+///
+/// Commands called without a run_id act on the active run. A run's state,
+/// ledger and artifacts are only held in memory, so a run saved in an earlier
+/// session is rejected rather than resumed.
+#[tauri::command]
+pub fn activate_run(
+    run_id: String,
+    state: State<'_, OrchestratorState>,
+    app: tauri::AppHandle,
+) -> Result<ActiveRunInfo, String> {
+    info!("=== ACTIVATE_RUN command called ===");
+    info!("Run ID: {}", run_id);
+
+    let conn = crate::database::get_connection(&app).map_err(|e| e.to_string())?;
+    activate_loaded_run(&run_id, &state, &conn)
+}
+
+/// Body of `activate_run`, callable without a Tauri app
+pub(crate) fn activate_loaded_run(
+    run_id: &str,
+    state: &OrchestratorState,
+    conn: &rusqlite::Connection,
+) -> Result<ActiveRunInfo, String> {
+    let mut orch_guard = state.0.lock().map_err(|e| e.to_string())?;
+    if !orch_guard.activate(run_id) {
+        let persisted = crate::database::runs::get_run(conn, run_id).map_err(|e| e.to_string())?;
+        return Err(match persisted {
+            Some(_) => format!(
+                "Run {} was started in an earlier session and cannot be resumed: its state, ledger and \
+                artifacts are only kept while the app is open. Start a new run with Step 0",
+                run_id
+            ),
+            None => format!("Run {} not found", run_id),
+        });
+    }
+
+    let orchestrator = orch_guard
+        .get(Some(run_id))
+        .ok_or_else(|| format!("Run {} not found", run_id))?;
+
+    info!("✓ Active run: {} ({:?})", run_id, orchestrator.state);
+    Ok(ActiveRunInfo {
+        run_id: run_id.to_string(),
+        state: format!("{:?}", orchestrator.state),
+        step: orchestrator.state.step_number(),
+        loaded_runs: orch_guard.run_ids(),
+    })
+}
+
+/// Get the settings a run was started with
+///
+/// Uses the loaded run, falling back to the configuration stored with the run
+/// row; None for runs started before settings were frozen.
+#[tauri::command]
+pub fn get_run_config(
+    run_id: String,
+    state: State<'_, OrchestratorState>,
+    app: tauri::AppHandle,
+) -> Result<Option<RunConfig>, String> {
+    info!("=== GET_RUN_CONFIG command called ===");
+    info!("Run ID: {}", run_id);
+
+    let loaded = {
+        let orch_guard = state.0.lock().map_err(|e| e.to_string())?;
+        orch_guard.get(Some(&run_id)).map(|o| o.run_config.clone())
+    };
+    if let Some(run_config) = loaded {
+        return Ok(run_config);
+    }
+
+    let conn = crate::database::get_connection(&app).map_err(|e| e.to_string())?;
+    crate::database::runs::get_run_config(&conn, &run_id).map_err(|e| e.to_string())
+}
+
+/// Run created by fork_run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ForkedRunInfo {
+    pub run_id: String,
+    pub forked_from: String,
+    pub up_to_step: u8,
+    pub state: String,
+    pub step: u8,
+    pub artifacts: usize,
+}
+
+/// Start a new run from a loaded run's results up to a given step
+///
+/// Artifacts and ledger entries of Steps 0..=`up_to_step` are copied under a
+/// new run_id, and the new run becomes the active one, waiting at the next
+/// step. Agents are built from the source run's frozen settings.
+#[tauri::command]
+pub fn fork_run(
+    source_run_id: String,
+    up_to_step: u8,
+    state: State<'_, OrchestratorState>,
+    config_state: State<'_, Mutex<AppConfig>>,
+    app: tauri::AppHandle,
+) -> Result<ForkedRunInfo, String> {
+    info!("=== FORK_RUN command called ===");
+    info!("Source run ID: {}, up to Step {}", source_run_id, up_to_step);
+
+    let mut orch_guard = state.0.lock().map_err(|e| e.to_string())?;
+    let source = orch_guard
+        .get(Some(&source_run_id))
+        .ok_or_else(|| format!("Run {} is not loaded", source_run_id))?;
+
+    // Same label scheme as start_step_0, with a short suffix so forks never collide
+    let source_label = run_label(&source_run_id);
+    let label = format!("{}-fork-{}", source_label, &uuid::Uuid::new_v4().simple().to_string()[..8]);
+    let mut fork = source
+        .fork(&label, up_to_step)
+        .map_err(|e| format!("Failed to fork run: {}", e))?;
+
+    {
+        let config = config_state.lock().map_err(|e| e.to_string())?;
+        let run_config = fork.run_config.clone().unwrap_or_else(|| RunConfig::from(&*config));
+        fork = with_run_agents(fork, &config, &run_config, PromptTemplates::load(&app))
+            .map_err(|e| format!("API key not configured: {}", e))?;
+    }
+
+    let info = ForkedRunInfo {
+        run_id: fork.run_id.clone(),
+        forked_from: source_run_id,
+        up_to_step,
+        state: format!("{:?}", fork.state),
+        step: fork.state.step_number(),
+        artifacts: fork.collect_artifacts().len(),
+    };
+    orch_guard.insert(info.run_id.clone(), fork);
+    orch_guard.activate(&info.run_id);
+
+    info!("✓ Forked {} as {} ({} artifacts)", info.forked_from, info.run_id, info.artifacts);
+    Ok(info)
+}
+
 /// Approve the gate and proceed to Step 1
 ///
 /// This command is called when the user clicks "Approve & Continue" in the UI.
+/// `run_id` selects the run; the active run is used if it is omitted.
 #[tauri::command]
 pub async fn approve_gate(
     approver: String,
+    run_id: Option<String>,
     state: State<'_, OrchestratorState>,
 ) -> Result<(), String> {
     info!("=== APPROVE_GATE command called ===");
@@ -158,10 +591,9 @@ pub async fn approve_gate(
 
     let mut orch_guard = state.0.lock().unwrap();
     info!("State lock acquired");
-    info!("State contains orchestrator: {}", orch_guard.is_some());
 
     let orchestrator = orch_guard
-        .as_mut()
+        .get_mut(run_id.as_deref())
         .ok_or_else(|| {
             let err = "No active run found in approve_gate".to_string();
             log::error!("{}", err);
@@ -180,7 +612,6 @@ pub async fn approve_gate(
 
     info!("Gate approved successfully");
     info!("New orchestrator state: {:?}", orchestrator.state);
-    info!("State still contains orchestrator: {}", orch_guard.is_some());
 
     Ok(())
 }
@@ -192,6 +623,7 @@ pub async fn approve_gate(
 pub async fn reject_gate(
     rejector: String,
     reason: String,
+    run_id: Option<String>,
     state: State<'_, OrchestratorState>,
 ) -> Result<(), String> {
     info!("=== REJECT_GATE command called ===");
@@ -199,7 +631,7 @@ pub async fn reject_gate(
 
     let mut orch_guard = state.0.lock().unwrap();
     let orchestrator = orch_guard
-        .as_mut()
+        .get_mut(run_id.as_deref())
         .ok_or_else(|| "No active run found".to_string())?;
 
     orchestrator
@@ -219,6 +651,7 @@ pub async fn handle_halt_decision(
     decision: String,
     decider: String,
     rationale: String,
+    run_id: Option<String>,
     state: State<'_, OrchestratorState>,
 ) -> Result<String, String> {
     info!("=== HANDLE_HALT_DECISION command called ===");
@@ -228,7 +661,7 @@ pub async fn handle_halt_decision(
 
     let mut orch_guard = state.0.lock().unwrap();
     let orchestrator = orch_guard
-        .as_mut()
+        .get_mut(run_id.as_deref())
         .ok_or_else(|| "No active run found".to_string())?;
 
     let next_state = orchestrator
@@ -239,6 +672,254 @@ pub async fn handle_halt_decision(
     Ok(next_state)
 }
 
+/// Accept a metrics warning PAUSE and resume the paused step
+///
+/// Refused when the run is HALTed; those go through `handle_halt_decision`.
+#[tauri::command]
+pub fn acknowledge_pause(
+    run_id: Option<String>,
+    approver: String,
+    note: String,
+    state: State<OrchestratorState>,
+) -> Result<(), String> {
+    info!("=== ACKNOWLEDGE_PAUSE command called ===");
+    info!("Approver: {}", approver);
+
+    let mut orch_guard = state.0.lock().unwrap();
+    let orchestrator = orch_guard
+        .get_mut(run_id.as_deref())
+        .ok_or_else(|| "No active run found".to_string())?;
+
+    orchestrator
+        .acknowledge_pause(&approver, &note)
+        .map_err(|e| e.to_string())?;
+
+    info!("✓ Pause acknowledged - state: {:?}", orchestrator.state);
+    Ok(())
+}
+
+/// Reopen the step a metrics HALT stopped, at the approver's risk
+///
+/// Requires `allow_halt_override` in the run's config and a justification,
+/// which is recorded in the ledger. Gate rejections are final. To retry the
+/// step without its outputs, use `Orchestrator::rollback_to_last_gate` instead.
+#[tauri::command]
+pub fn override_halt(
+    run_id: String,
+    approver: String,
+    justification: String,
+    state: State<OrchestratorState>,
+) -> Result<(), String> {
+    info!("=== OVERRIDE_HALT command called ===");
+    info!("Run ID: {}", run_id);
+    info!("Approver: {}", approver);
+    info!("Justification: {}", justification);
+
+    let mut orch_guard = state.0.lock().unwrap();
+    let orchestrator = orch_guard
+        .get_mut(Some(&run_id))
+        .ok_or_else(|| "No active run found".to_string())?;
+
+    orchestrator
+        .override_halt(&approver, &justification)
+        .map_err(|e| e.to_string())?;
+
+    info!("✓ HALT overridden - state: {:?}", orchestrator.state);
+    Ok(())
+}
+
+/// Get the HALT/PAUSE status of a run and its cause
+///
+/// Lets the UI show a blocking banner with the reason and offending metrics.
+#[tauri::command]
+pub fn get_halt_status(
+    run_id: Option<String>,
+    state: State<'_, OrchestratorState>,
+) -> Result<HaltStatusReport, String> {
+    info!("=== GET_HALT_STATUS command called ===");
+
+    let orch_guard = state.0.lock().map_err(|e| e.to_string())?;
+    let orchestrator = orch_guard
+        .get(run_id.as_deref())
+        .ok_or_else(|| "No active run found".to_string())?;
+
+    let report = orchestrator.halt_status();
+    info!("✓ Halt status: {:?} ({:?})", report.status, report.cause);
+    Ok(report)
+}
+
+/// Get the active role and the actions the ledger currently allows
+///
+/// Lets the UI enable only the buttons for legal actions (e.g. approve and
+/// reject while a gate is pending).
+#[tauri::command]
+pub fn get_permitted_actions(
+    run_id: Option<String>,
+    state: State<'_, OrchestratorState>,
+) -> Result<PermittedActions, String> {
+    info!("=== GET_PERMITTED_ACTIONS command called ===");
+
+    let orch_guard = state.0.lock().map_err(|e| e.to_string())?;
+    let orchestrator = orch_guard
+        .get(run_id.as_deref())
+        .ok_or_else(|| "No active run found".to_string())?;
+
+    let actions = orchestrator.permitted_actions();
+    info!(
+        "✓ {:?} as {:?}: {} action(s) permitted",
+        actions.ledger_state,
+        actions.active_role,
+        actions.permitted.len()
+    );
+    Ok(actions)
+}
+
+/// Get a run's Claude API usage and estimated cost
+///
+/// Includes prompt cache reads/writes and the estimated savings versus
+/// uncached input (all zero when no call used the cache).
+#[tauri::command]
+pub fn get_run_cost(
+    run_id: Option<String>,
+    state: State<'_, OrchestratorState>,
+) -> Result<ApiUsage, String> {
+    info!("=== GET_RUN_COST command called ===");
+
+    let orch_guard = state.0.lock().map_err(|e| e.to_string())?;
+    let orchestrator = orch_guard
+        .get(run_id.as_deref())
+        .ok_or_else(|| "No active run found".to_string())?;
+
+    let usage = orchestrator.api_usage();
+    info!(
+        "✓ {} call(s), ${:.4} (cache saved ${:.4})",
+        usage.calls, usage.cost_usd, usage.cache_savings_usd
+    );
+    Ok(usage)
+}
+
+/// Describe the next step of a run without executing it
+///
+/// Names the artifacts it will produce, the gate it emits and the agents it
+/// needs, or why no step can start (pending gate, pause, halt, completion).
+#[tauri::command]
+pub fn describe_next_step(
+    run_id: Option<String>,
+    state: State<'_, OrchestratorState>,
+) -> Result<StepDescription, String> {
+    info!("=== DESCRIBE_NEXT_STEP command called ===");
+
+    let orch_guard = state.0.lock().map_err(|e| e.to_string())?;
+    let orchestrator = orch_guard
+        .get(run_id.as_deref())
+        .ok_or_else(|| "No active run found".to_string())?;
+
+    let description = orchestrator.describe_next_step();
+    info!("✓ Next step: {} (blocked: {:?})", description.name, description.blocked_reason);
+    Ok(description)
+}
+
+/// List every loaded run waiting at a gate, so a reviewer can work through them
+///
+/// Each entry names the run, step, gate and the artifacts up for review.
+/// An entry's run_id is the one the run is loaded under; pass it to
+/// `approve_gate` to approve that gate.
+#[tauri::command]
+pub fn list_pending_gates(state: State<'_, OrchestratorState>) -> Result<Vec<PendingGate>, String> {
+    info!("=== LIST_PENDING_GATES command called ===");
+
+    let orch_guard = state.0.lock().map_err(|e| e.to_string())?;
+    let gates = orch_guard.pending_gates();
+
+    info!("✓ {} gate(s) pending", gates.len());
+    Ok(gates)
+}
+
+/// Compare loaded runs side by side as a Markdown table
+#[tauri::command]
+pub fn compare_runs(
+    run_ids: Vec<String>,
+    state: State<'_, OrchestratorState>,
+) -> Result<String, String> {
+    info!("=== COMPARE_RUNS command called ===");
+    info!("Runs: {:?}", run_ids);
+
+    let orch_guard = state.0.lock().map_err(|e| e.to_string())?;
+    let report = orch_guard.generate_comparison_report(&run_ids);
+
+    info!("✓ Comparison report generated ({} bytes)", report.len());
+    Ok(report)
+}
+
+/// Get the latest metrics of a run in the Prometheus text format
+///
+/// Labels each gauge with the run and the step the metrics were calculated at.
+/// Returns an empty body until metrics have been calculated.
+#[tauri::command]
+pub fn get_metrics_prometheus(
+    run_id: Option<String>,
+    state: State<'_, OrchestratorState>,
+) -> Result<String, String> {
+    info!("=== GET_METRICS_PROMETHEUS command called ===");
+
+    let orch_guard = state.0.lock().map_err(|e| e.to_string())?;
+    let orchestrator = orch_guard
+        .get(run_id.as_deref())
+        .ok_or_else(|| "No active run found".to_string())?;
+
+    let output = orchestrator
+        .metrics_history
+        .last()
+        .map(|(step, metrics)| metrics.to_prometheus(&orchestrator.run_id, *step))
+        .unwrap_or_default();
+    info!("✓ Exported {} metric lines", output.lines().filter(|l| !l.starts_with('#')).count());
+    Ok(output)
+}
+
+/// Compare a run's metrics between two steps
+///
+/// Returns each metric's delta from `step_a` to `step_b`, whether it improved
+/// or regressed, and its status at both steps.
+#[tauri::command]
+pub fn diff_metrics(
+    run_id: Option<String>,
+    step_a: u8,
+    step_b: u8,
+    state: State<'_, OrchestratorState>,
+) -> Result<Vec<MetricDelta>, String> {
+    info!("=== DIFF_METRICS command called ===");
+    info!("Steps: {} → {}", step_a, step_b);
+
+    let orch_guard = state.0.lock().map_err(|e| e.to_string())?;
+    let orchestrator = orch_guard
+        .get(run_id.as_deref())
+        .ok_or_else(|| "No active run found".to_string())?;
+
+    let diff = orchestrator.diff_metrics(step_a, step_b).map_err(|e| e.to_string())?;
+    info!("✓ Compared {} metrics", diff.len());
+    Ok(diff)
+}
+
+/// Get the ledger hash chain of a run with each link recomputed
+///
+/// Shows auditors which entry, if any, no longer matches its hash.
+#[tauri::command]
+pub fn get_ledger_chain_proof(
+    run_id: Option<String>,
+    state: State<'_, OrchestratorState>,
+) -> Result<LedgerChainProof, String> {
+    info!("=== GET_LEDGER_CHAIN_PROOF command called ===");
+
+    let orch_guard = state.0.lock().map_err(|e| e.to_string())?;
+    let orchestrator = orch_guard
+        .get(run_id.as_deref())
+        .ok_or_else(|| "No active run found".to_string())?;
+
+    let proof = orchestrator.get_ledger().chain_proof(&orchestrator.run_id);
+    info!("✓ Chain proof: {} entries, tampered at {:?}", proof.links.len(), proof.tampered_at);
+    Ok(proof)
+}
+
 /// Submit clarification answers
 ///
 /// This command handles clarification questions if the agent asks for more details.
@@ -249,6 +930,7 @@ pub async fn submit_clarifications(
     answers: Vec<String>,
     state: State<'_, OrchestratorState>,
     config_state: State<'_, Mutex<AppConfig>>,
+    app: tauri::AppHandle,
 ) -> Result<Step0Response, String> {
     info!("=== SUBMIT_CLARIFICATIONS command called ===");
     info!("Run ID: {}", run_id);
@@ -258,8 +940,8 @@ pub async fn submit_clarifications(
     let original_intent = {
         let orch_guard = state.0.lock().unwrap();
         let orchestrator = orch_guard
-            .as_ref()
-            .ok_or_else(|| "No active run found".to_string())?;
+            .get(Some(&run_id))
+            .ok_or_else(|| format!("Run {} not found", run_id))?;
 
         // Get the original user request from the intent summary
         orchestrator
@@ -278,5 +960,208 @@ pub async fn submit_clarifications(
     );
 
     // Re-run Step 0 with the updated intent
-    start_step_0(run_id, updated_intent, state, config_state).await
+    start_step_0(run_id, updated_intent, state, config_state, app).await
+}
+
+/// Apply one clarification answer to the Intent Summary without re-running Step 0
+///
+/// Moves the scope items the answer refers to and removes the answered question.
+#[tauri::command]
+pub fn apply_clarification(
+    run_id: String,
+    question: String,
+    answer: String,
+    state: State<'_, OrchestratorState>,
+) -> Result<IntentSummary, String> {
+    info!("=== APPLY_CLARIFICATION command called ===");
+    info!("Run ID: {}", run_id);
+
+    let mut orch_guard = state.0.lock().map_err(|e| e.to_string())?;
+    let orchestrator = orch_guard
+        .get_mut(Some(&run_id))
+        .ok_or_else(|| format!("Run {} not found", run_id))?;
+    let summary = orchestrator
+        .intent_summary
+        .as_mut()
+        .ok_or_else(|| "No intent summary found".to_string())?;
+
+    summary.apply_clarification(&question, &answer).map_err(|e| e.to_string())?;
+    info!("✓ Clarification applied, {} questions remaining", summary.questions_for_clarification.len());
+    Ok(summary.clone())
+}
+
+/// Record an intentional scope change for a run
+///
+/// Approved expansions keep SEC at 100%; unapproved ones lower it.
+#[tauri::command]
+pub fn record_scope_expansion(
+    run_id: String,
+    description: String,
+    approved: bool,
+    state: State<'_, OrchestratorState>,
+) -> Result<ScopeExpansionCount, String> {
+    info!("=== RECORD_SCOPE_EXPANSION command called ===");
+    info!("Run ID: {}, approved: {}", run_id, approved);
+
+    let mut orch_guard = state.0.lock().map_err(|e| e.to_string())?;
+    let orchestrator = orch_guard
+        .get_mut(Some(&run_id))
+        .ok_or_else(|| format!("Run {} not found", run_id))?;
+
+    let count = orchestrator
+        .record_scope_expansion(&description, approved)
+        .map_err(|e| e.to_string())?;
+    info!("✓ Scope expansion recorded ({} approved, {} unapproved)", count.approved, count.unapproved);
+    Ok(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn stub_run_at_gate(label: &str) -> Orchestrator {
+        let mut orch = Orchestrator::new(label).with_stubs();
+        orch.execute_step_0("Design a customer onboarding framework").await.unwrap();
+        orch
+    }
+
+    #[tokio::test]
+    async fn test_run_registry_targets_selected_run() {
+        let mut registry = RunRegistry::default();
+        registry.insert("run-a", stub_run_at_gate("run-a").await);
+        registry.activate("run-a");
+        registry.insert("run-b", stub_run_at_gate("run-b").await);
+
+        // Inserting does not change the active run
+        assert_eq!(registry.active_run_id(), Some("run-a"));
+        assert_eq!(registry.run_ids(), vec!["run-a", "run-b"]);
+
+        registry
+            .get_mut(Some("run-b"))
+            .unwrap()
+            .approve_gate("Human Reviewer")
+            .unwrap();
+
+        assert!(matches!(registry.get(Some("run-b")).unwrap().state, RunState::Step1Active));
+        assert!(matches!(registry.get(Some("run-a")).unwrap().state, RunState::Step0GatePending));
+        assert!(matches!(registry.get(None).unwrap().state, RunState::Step0GatePending));
+
+        assert!(registry.activate("run-b"));
+        assert!(matches!(registry.get(None).unwrap().state, RunState::Step1Active));
+        assert!(!registry.activate("run-c"));
+        assert!(registry.get(Some("run-c")).is_none());
+
+        // Removing the active run leaves no active run
+        assert!(registry.remove("run-b").is_some());
+        assert_eq!(registry.active_run_id(), None);
+        assert!(registry.get(None).is_none());
+        assert_eq!(registry.run_ids(), vec!["run-a"]);
+    }
+
+    #[tokio::test]
+    async fn test_pending_gates_across_runs() {
+        let mut registry = RunRegistry::default();
+        registry.insert("run-a", stub_run_at_gate("run-a").await);
+
+        let mut run_b = stub_run_at_gate("run-b").await;
+        run_b.approve_gate("Human Reviewer").unwrap();
+        run_b.execute_step_1().await.unwrap();
+        registry.insert("run-b", run_b);
+
+        let mut run_c = Orchestrator::new("run-c").with_stubs();
+        run_c.execute_step_0("Design a customer onboarding framework").await.unwrap();
+        run_c.approve_gate("Human Reviewer").unwrap();
+        registry.insert("run-c", run_c);
+
+        let gates = registry.pending_gates();
+        assert_eq!(gates.len(), 2);
+        assert_eq!((gates[0].step, gates[0].gate.as_str()), (0, "Ready_for_Step_1"));
+        assert_eq!((gates[1].step, gates[1].gate.as_str()), (1, "Baseline_Frozen"));
+        assert!(!gates[1].artifacts.is_empty());
+        assert!(gates.iter().all(|gate| gate.requested_at.is_some()));
+
+        // Entries name runs by the id they are loaded under
+        assert_eq!(gates[0].run_id, "run-a");
+        assert_ne!(gates[0].run_id, registry.get(Some("run-a")).unwrap().run_id);
+
+        // Approving one gate by its entry's run_id, as approve_gate looks it up, leaves the other queued
+        registry.get_mut(Some(&gates[0].run_id)).unwrap().approve_gate("Human Reviewer").unwrap();
+        assert!(matches!(registry.get(Some("run-a")).unwrap().state, RunState::Step1Active));
+        let gates = registry.pending_gates();
+        assert_eq!(gates.len(), 1);
+        assert_eq!(gates[0].run_id, "run-b");
+        registry.get_mut(Some(&gates[0].run_id)).unwrap().approve_gate("Human Reviewer").unwrap();
+        assert!(registry.pending_gates().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_activate_run_rejects_runs_from_earlier_sessions() {
+        let conn = rusqlite::Connection::open_in_memory().unwrap();
+        crate::database::schema::create_schema(&conn).unwrap();
+        let saved = crate::database::models::Run {
+            id: "saved-run".to_string(),
+            intent_anchor_hash: "hash".to_string(),
+            created_at: chrono::Utc::now(),
+            completed_at: None,
+            final_ci: None,
+            final_ev: None,
+            status: Some("active".to_string()),
+        };
+        crate::database::runs::create_run(&conn, &saved).unwrap();
+
+        let run_a = stub_run_at_gate("run-a").await;
+        let state = OrchestratorState(Mutex::new(RunRegistry::default()));
+        state.0.lock().unwrap().insert("run-a", run_a);
+
+        let info = activate_loaded_run("run-a", &state, &conn).unwrap();
+        assert_eq!(info.step, 0);
+        assert_eq!(info.loaded_runs, vec!["run-a"]);
+
+        // Persisted but not loaded: explicit rejection, active run unchanged
+        let err = activate_loaded_run("saved-run", &state, &conn).unwrap_err();
+        assert!(err.contains("earlier session and cannot be resumed"), "{}", err);
+        assert_eq!(state.0.lock().unwrap().active_run_id(), Some("run-a"));
+
+        let err = activate_loaded_run("missing-run", &state, &conn).unwrap_err();
+        assert_eq!(err, "Run missing-run not found");
+    }
+
+    #[tokio::test]
+    async fn test_comparison_report_has_column_per_run_and_row_per_metric() {
+        let mut registry = RunRegistry::default();
+        for label in ["compare-a", "compare-b"] {
+            let mut orch = Orchestrator::new(label).with_stubs().with_auto_approval(None);
+            orch.run_to_completion("Design a customer onboarding framework").await.unwrap();
+            registry.insert(orch.run_id.clone(), orch);
+        }
+        let mut run_ids = registry.run_ids();
+        let mut at_gate = stub_run_at_gate("at-gate").await;
+        at_gate.intent_summary.as_mut().unwrap().intent_category = "Design | Ops\nreview".to_string();
+        registry.insert("at-gate", at_gate);
+        run_ids.push("at-gate".to_string());
+        run_ids.push("not-loaded".to_string());
+
+        let report = registry.generate_comparison_report(&run_ids);
+        let lines: Vec<&str> = report.lines().filter(|line| line.starts_with('|')).collect();
+
+        // Header, separator, then one row per field
+        assert_eq!(lines[0], format!("| Metric | {} | {} | at-gate |", run_ids[0], run_ids[1]));
+        assert_eq!(lines[1], "|---|---|---|---|");
+        for metric in ["CI", "EV", "IAS", "EFI", "SEC", "PCI"] {
+            let row = lines
+                .iter()
+                .find(|line| line.starts_with(&format!("| {} |", metric)))
+                .unwrap_or_else(|| panic!("missing {} row", metric));
+            assert_eq!(row.matches('|').count(), 5, "{} row", metric);
+        }
+        // Pipes and line breaks inside a cell don't split the row
+        assert!(lines.iter().all(|line| line.replace("\\|", "").matches('|').count() == 5));
+        assert!(lines.iter().any(|line| line.ends_with("| Design \\| Ops<br>review |")));
+
+        // Finished runs read as completed; the unfinished one shows the step it
+        // reached and no Step 4 outputs
+        assert!(lines.contains(&"| Reached step | Completed | Completed | 0 |"));
+        assert!(lines.iter().any(|line| line.starts_with("| Operating principles |") && line.ends_with("| - |")));
+        assert!(report.contains("Not loaded: not-loaded"));
+    }
 }
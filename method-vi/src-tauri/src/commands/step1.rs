@@ -4,9 +4,10 @@ use serde::{Deserialize, Serialize};
 use tauri::State;
 use std::sync::Mutex;
 
-use crate::agents::{GovernanceTelemetryAgent, StructureRedesignAgent};
-use crate::commands::step0::OrchestratorState;
-use crate::config::AppConfig;
+use crate::agents::StructureRedesignAgent;
+use crate::commands::step0::{finish_step, governance_agent, OrchestratorState};
+use crate::config::{AppConfig, RunConfig};
+use crate::governance::parse_charter_objectives;
 
 /// Response structure for Step 1 that matches the frontend expectations
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -84,23 +85,26 @@ pub async fn execute_step_1(
     info!("Run ID: {}", run_id);
     info!("Timestamp: {}", chrono::Utc::now().to_rfc3339());
 
-    // Get API key from config
-    let api_key = {
+    // Use the settings frozen at Step 0 (current config for runs started without them)
+    let frozen_config = state.0.lock().unwrap().get(Some(&run_id)).and_then(|o| o.run_config.clone());
+
+    // Get API clients
+    let (governance_client, structure_client, run_config) = {
         let config = config_state.lock().unwrap();
-        config
-            .get_api_key()
-            .map_err(|e| format!("API key not configured: {}", e))?
+        let run_config = frozen_config.unwrap_or_else(|| RunConfig::from(&*config));
+        let api_client = || config
+            .run_api_client(&run_config)
+            .map_err(|e| format!("API key not configured: {}", e));
+        (api_client()?, api_client()?, run_config)
     };
-    info!("API key retrieved: {}...", &api_key[..15]);
+    info!("API clients created");
 
     // Create agents
     info!("Creating agents...");
-    let governance_agent = GovernanceTelemetryAgent::new(api_key.clone())
-        .map_err(|e| format!("Failed to create Governance Agent: {}", e))?;
+    let governance_agent = governance_agent(governance_client, &run_config);
     info!("Governance agent created");
 
-    let structure_agent = StructureRedesignAgent::new(api_key.clone())
-        .map_err(|e| format!("Failed to create Structure Agent: {}", e))?;
+    let structure_agent = StructureRedesignAgent::from_client(structure_client);
     info!("Structure agent created");
 
     // Get the orchestrator from state and add agents
@@ -108,13 +112,13 @@ pub async fn execute_step_1(
     let mut orchestrator = {
         let mut orch_guard = state.0.lock().unwrap();
         info!("State lock acquired");
-        info!("State contains orchestrator: {}", orch_guard.is_some());
+        info!("State contains run {}: {}", run_id, orch_guard.contains(&run_id));
 
         // Take ownership of the orchestrator temporarily
         let mut orch = orch_guard
-            .take()
+            .take(&run_id)
             .ok_or_else(|| {
-                let err = "No active run found. Please complete Step 0 first.".to_string();
+                let err = "No run found for this run_id. Please complete Step 0 first.".to_string();
                 log::error!("[EXECUTE_STEP_1] {}", err);
                 log::error!("[EXECUTE_STEP_1] State was empty when trying to take orchestrator");
                 err
@@ -123,7 +127,6 @@ pub async fn execute_step_1(
         info!("Orchestrator taken from state");
         info!("Orchestrator run_id: {}", orch.run_id);
         info!("Orchestrator state: {:?}", orch.state);
-        info!("State contains orchestrator after take: {}", orch_guard.is_some());
 
         // Add agents if not already present
         info!("Adding governance and structure agents...");
@@ -140,12 +143,13 @@ pub async fn execute_step_1(
 
     // Execute and ensure orchestrator is always put back, even on error
     let step1_result = orchestrator.execute_step_1().await;
+    finish_step(&mut orchestrator, &step1_result);
 
     // Always put orchestrator back into state, even if there was an error
     info!("Putting orchestrator back into state...");
     {
         let mut orch_guard = state.0.lock().unwrap();
-        *orch_guard = Some(orchestrator);
+        orch_guard.insert(run_id.clone(), orchestrator);
         info!("Orchestrator restored to state");
     }
 
@@ -161,7 +165,7 @@ pub async fn execute_step_1(
 
     // Get orchestrator back to extract artifact info
     let orch_guard = state.0.lock().unwrap();
-    let orchestrator = orch_guard.as_ref()
+    let orchestrator = orch_guard.get(Some(&run_id))
         .ok_or_else(|| "Orchestrator not found after Step 1 execution".to_string())?;
 
     // Extract artifact information for frontend
@@ -197,3 +201,31 @@ pub async fn execute_step_1(
 
     Ok(response)
 }
+
+/// Get the Charter objectives of a run as a list
+///
+/// Uses the same parser as the Step 4 relevance check and IAS, so the UI shows
+/// exactly what governance compares against.
+#[tauri::command]
+pub fn get_charter_objectives(
+    run_id: String,
+    state: State<'_, OrchestratorState>,
+) -> Result<Vec<String>, String> {
+    info!("=== GET_CHARTER_OBJECTIVES command called ===");
+    info!("Run ID: {}", run_id);
+
+    let orch_guard = state.0.lock().map_err(|e| e.to_string())?;
+    let orchestrator = orch_guard
+        .get(Some(&run_id))
+        .ok_or_else(|| format!("Run {} not found", run_id))?;
+    let charter = orchestrator
+        .charter
+        .as_ref()
+        .ok_or_else(|| "Charter not created - complete Step 1 first".to_string())?;
+
+    let objectives = parse_charter_objectives(&charter.to_display_markdown());
+    if objectives.is_empty() {
+        return Ok(charter.objectives.clone());
+    }
+    Ok(objectives)
+}
@@ -2,7 +2,9 @@ use log::info;
 use serde::{Deserialize, Serialize};
 use tauri::State;
 
-use crate::commands::step0::OrchestratorState;
+use crate::agents::governance_calibration::GovernanceCalibration;
+use crate::artifacts::ArtifactType;
+use crate::commands::step0::{finish_step, OrchestratorState};
 
 /// Response from execute_step_2 command
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -11,6 +13,8 @@ pub struct Step2Response {
     pub domain_snapshots_id: String,
     pub governance_summary: String,
     pub domain_snapshots: String,
+    /// Per-domain calibration parsed from the governance response
+    pub governance_calibration: Option<GovernanceCalibration>,
     pub metrics: Option<MetricsSnapshot>,
 }
 
@@ -47,13 +51,13 @@ pub async fn execute_step_2(
     let mut orchestrator = {
         let mut orch_guard = state.0.lock().unwrap();
         info!("State lock acquired");
-        info!("State contains orchestrator: {}", orch_guard.is_some());
+        info!("State contains run {}: {}", run_id, orch_guard.contains(&run_id));
 
         // Take ownership of the orchestrator temporarily
         let orch = orch_guard
-            .take()
+            .take(&run_id)
             .ok_or_else(|| {
-                let err = "No active run found. Please complete Steps 0 and 1 first.".to_string();
+                let err = "No run found for this run_id. Please complete Steps 0 and 1 first.".to_string();
                 log::error!("[EXECUTE_STEP_2] {}", err);
                 log::error!("[EXECUTE_STEP_2] State was empty when trying to take orchestrator");
                 err
@@ -62,35 +66,39 @@ pub async fn execute_step_2(
         info!("Orchestrator taken from state");
         info!("Orchestrator run_id: {}", orch.run_id);
         info!("Orchestrator state: {:?}", orch.state);
-        info!("State contains orchestrator after take: {}", orch_guard.is_some());
 
         orch
     }; // Lock is released here
 
     // Execute Step 2 (now without holding the lock)
     info!("Executing Step 2 workflow...");
-    let (governance_summary_id, domain_snapshots_id) = orchestrator
-        .execute_step_2()
-        .await
-        .map_err(|e| {
-            let err = format!("Failed to execute Step 2: {}", e);
-            log::error!("[EXECUTE_STEP_2] {}", err);
-            err
-        })?;
 
-    info!("Step 2 completed successfully");
+    // Execute and ensure orchestrator is always put back, even on error
+    let step2_result = orchestrator.execute_step_2().await;
+    finish_step(&mut orchestrator, &step2_result);
 
-    // Put orchestrator back into state
+    // Always put orchestrator back into state, even if there was an error
+    // (e.g. a rejected baseline halts the run, which must stay loaded)
     info!("Putting orchestrator back into state...");
     {
         let mut orch_guard = state.0.lock().unwrap();
-        *orch_guard = Some(orchestrator);
+        orch_guard.insert(run_id.clone(), orchestrator);
         info!("Orchestrator restored to state");
     }
 
+    // Now check if step2 succeeded
+    let (governance_summary_id, domain_snapshots_id) = step2_result
+        .map_err(|e| {
+            let err = format!("Failed to execute Step 2: {}", e);
+            log::error!("[EXECUTE_STEP_2] {}", err);
+            err
+        })?;
+
+    info!("Step 2 completed successfully");
+
     // Get orchestrator back to extract artifact info
     let orch_guard = state.0.lock().unwrap();
-    let orchestrator = orch_guard.as_ref()
+    let orchestrator = orch_guard.get(Some(&run_id))
         .ok_or_else(|| "Orchestrator not found after Step 2 execution".to_string())?;
 
     // Extract artifacts
@@ -106,6 +114,8 @@ pub async fn execute_step_2(
         .ok_or_else(|| "Domain Snapshots not generated".to_string())?
         .clone();
 
+    let governance_calibration = orchestrator.governance_calibration.clone();
+
     // Extract metrics snapshot
     let metrics = orchestrator.latest_metrics.as_ref().map(|m| {
         MetricsSnapshot {
@@ -125,10 +135,40 @@ pub async fn execute_step_2(
         domain_snapshots_id,
         governance_summary,
         domain_snapshots,
+        governance_calibration,
         metrics,
     })
 }
 
+/// Regenerate a mutable artifact (e.g. Governance_Summary) without rerunning its step
+///
+/// Returns the artifact's new content hash. Immutable types are refused.
+#[tauri::command]
+pub async fn regenerate_artifact(
+    run_id: String,
+    artifact_type: ArtifactType,
+    state: State<'_, OrchestratorState>,
+) -> Result<String, String> {
+    info!("=== REGENERATE_ARTIFACT command called ===");
+    info!("Run ID: {}, type: {:?}", run_id, artifact_type);
+
+    let mut orchestrator = {
+        let mut orch_guard = state.0.lock().map_err(|e| e.to_string())?;
+        orch_guard
+            .take(&run_id)
+            .ok_or_else(|| format!("Run {} not found", run_id))?
+    };
+
+    let result = orchestrator.regenerate_artifact(artifact_type).await;
+
+    // Put the run back whether or not regeneration succeeded
+    state.0.lock().map_err(|e| e.to_string())?.insert(run_id, orchestrator);
+
+    let new_hash = result.map_err(|e| format!("Failed to regenerate artifact: {}", e))?;
+    info!("✓ Artifact regenerated, new hash: {}", new_hash);
+    Ok(new_hash)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -142,6 +182,7 @@ mod tests {
             domain_snapshots_id: "test-domain-snapshots".to_string(),
             governance_summary: "test content".to_string(),
             domain_snapshots: "test content".to_string(),
+            governance_calibration: None,
             metrics: Some(MetricsSnapshot {
                 ci: Some(0.85),
                 ev: Some(5.0),
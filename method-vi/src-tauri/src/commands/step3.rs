@@ -3,9 +3,9 @@ use serde::{Deserialize, Serialize};
 use tauri::State;
 use std::sync::Mutex;
 
-use crate::commands::step0::OrchestratorState;
-use crate::config::AppConfig;
-use crate::agents::AnalysisSynthesisAgent;
+use crate::commands::step0::{analysis_synthesis_agent, finish_step, OrchestratorState};
+use crate::config::{AppConfig, PromptTemplates, RunConfig};
+use crate::agents::{LensEfficacyReport, Orchestrator};
 
 /// Response from execute_step_3 command
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -40,42 +40,51 @@ pub struct MetricsSnapshot {
 /// 7. Presents gate to user for approval
 ///
 /// CRITICAL: The Analysis agent created here is STATEFUL and will be REUSED in Step 4.
+///
+/// Content shorter than `min_analysis_chars` is only analyzed with `force`.
 #[tauri::command]
 pub async fn execute_step_3(
     run_id: String,
+    force: Option<bool>,
     state: State<'_, OrchestratorState>,
     config_state: State<'_, Mutex<AppConfig>>,
+    app: tauri::AppHandle,
 ) -> Result<Step3Response, String> {
     info!("=== EXECUTE_STEP_3 command called ===");
     info!("Run ID: {}", run_id);
     info!("Timestamp: {}", chrono::Utc::now().to_rfc3339());
 
-    // Get API key from config (checks env var first, then config file)
-    info!("Retrieving API key from config...");
-    let api_key = {
+    // Use the settings frozen at Step 0 (current config for runs started without them)
+    let frozen_config = state.0.lock().unwrap().get(Some(&run_id)).and_then(|o| o.run_config.clone());
+
+    // Get API client from config (checks env var first, then config file)
+    info!("Retrieving API client from config...");
+    let (api_client, run_config) = {
         let config = config_state.lock().unwrap();
-        config
-            .get_api_key()
+        let run_config = frozen_config.unwrap_or_else(|| RunConfig::from(&*config));
+        let api_client = config
+            .run_api_client(&run_config)
             .map_err(|e| {
                 let err = format!("API key not configured: {}. Please set it in Settings or via ANTHROPIC_API_KEY environment variable.", e);
                 log::error!("[EXECUTE_STEP_3] {}", err);
                 err
-            })?
+            })?;
+        (api_client, run_config)
     };
-    info!("API key retrieved successfully");
+    info!("API client created successfully");
 
     // Get the orchestrator from state
     info!("Acquiring state lock...");
     let mut orchestrator = {
         let mut orch_guard = state.0.lock().unwrap();
         info!("State lock acquired");
-        info!("State contains orchestrator: {}", orch_guard.is_some());
+        info!("State contains run {}: {}", run_id, orch_guard.contains(&run_id));
 
         // Take ownership of the orchestrator temporarily
         let mut orch = orch_guard
-            .take()
+            .take(&run_id)
             .ok_or_else(|| {
-                let err = "No active run found. Please complete Steps 0, 1, and 2 first.".to_string();
+                let err = "No run found for this run_id. Please complete Steps 0, 1, and 2 first.".to_string();
                 log::error!("[EXECUTE_STEP_3] {}", err);
                 log::error!("[EXECUTE_STEP_3] State was empty when trying to take orchestrator");
                 err
@@ -84,20 +93,16 @@ pub async fn execute_step_3(
         info!("Orchestrator taken from state");
         info!("Orchestrator run_id: {}", orch.run_id);
         info!("Orchestrator state: {:?}", orch.state);
-        info!("State contains orchestrator after take: {}", orch_guard.is_some());
 
         // CREATE and ATTACH Analysis & Synthesis Agent (OBSERVER role)
         info!("Creating Analysis & Synthesis Agent...");
-        let analysis_agent = AnalysisSynthesisAgent::new(api_key)
-            .map_err(|e| {
-                let err = format!("Failed to create Analysis & Synthesis Agent: {}", e);
-                log::error!("[EXECUTE_STEP_3] {}", err);
-                err
-            })?;
+        let analysis_agent = analysis_synthesis_agent(api_client, &run_config, PromptTemplates::load(&app));
         info!("Analysis & Synthesis Agent created successfully");
 
         info!("Attaching Analysis & Synthesis Agent to Orchestrator...");
-        orch = orch.with_analysis_synthesis_agent(analysis_agent);
+        orch = orch
+            .with_analysis_synthesis_agent(analysis_agent)
+            .with_min_analysis_chars(run_config.min_analysis_chars);
         info!("Analysis & Synthesis Agent attached - will be REUSED in Step 4");
 
         orch
@@ -105,28 +110,28 @@ pub async fn execute_step_3(
 
     // Execute Step 3 (now without holding the lock)
     info!("Executing Step 3 workflow...");
-    let (integrated_diagnostic_id, lens_efficacy_report_id) = orchestrator
-        .execute_step_3()
-        .await
-        .map_err(|e| {
-            let err = format!("Failed to execute Step 3: {}", e);
-            log::error!("[EXECUTE_STEP_3] {}", err);
-            err
-        })?;
-
-    info!("Step 3 completed successfully");
+    let result = orchestrator.execute_step_3(force.unwrap_or(false)).await;
+    finish_step(&mut orchestrator, &result);
 
-    // Put orchestrator back into state
+    // Put orchestrator back into state, also on failure so the run can be retried
     info!("Putting orchestrator back into state...");
     {
         let mut orch_guard = state.0.lock().unwrap();
-        *orch_guard = Some(orchestrator);
+        orch_guard.insert(run_id.clone(), orchestrator);
         info!("Orchestrator restored to state");
     }
 
+    let (integrated_diagnostic_id, lens_efficacy_report_id) = result.map_err(|e| {
+        let err = format!("Failed to execute Step 3: {}", e);
+        log::error!("[EXECUTE_STEP_3] {}", err);
+        err
+    })?;
+
+    info!("Step 3 completed successfully");
+
     // Get orchestrator back to extract artifact info
     let orch_guard = state.0.lock().unwrap();
-    let orchestrator = orch_guard.as_ref()
+    let orchestrator = orch_guard.get(Some(&run_id))
         .ok_or_else(|| "Orchestrator not found after Step 3 execution".to_string())?;
 
     // Extract artifacts
@@ -165,6 +170,184 @@ pub async fn execute_step_3(
     })
 }
 
+/// Efficacy of a single lens for the UI
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LensScore {
+    pub lens_name: String,
+    pub efficacy_score: f64,
+    pub key_findings: usize,
+    /// False when findings were taken from the last paragraphs (no findings section)
+    pub findings_parsed_cleanly: bool,
+    pub tokens_used: u32,
+}
+
+/// Typed Lens Efficacy Report for the UI
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LensEfficacyResponse {
+    pub lenses: Vec<LensScore>,
+    pub total_insights: usize,
+    pub high_value_count: usize,
+    pub estimated_cost: f64,
+    pub actual_cost: f64,
+    /// Points where lenses disagree (also recorded as Contradiction flaws)
+    pub contradictions: Vec<String>,
+}
+
+impl From<LensEfficacyReport> for LensEfficacyResponse {
+    fn from(report: LensEfficacyReport) -> Self {
+        LensEfficacyResponse {
+            lenses: report
+                .lens_results
+                .into_iter()
+                .map(|lens| LensScore {
+                    lens_name: lens.lens_name,
+                    efficacy_score: lens.efficacy_score,
+                    key_findings: lens.key_findings.len(),
+                    findings_parsed_cleanly: lens.findings_parsed_cleanly,
+                    tokens_used: lens.tokens_used,
+                })
+                .collect(),
+            total_insights: report.total_insights,
+            high_value_count: report.high_value_combinations,
+            estimated_cost: report.estimated_cost,
+            actual_cost: report.actual_cost,
+            contradictions: report.contradictions,
+        }
+    }
+}
+
+/// Full analysis of a single lens for the UI
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LensAnalysis {
+    pub lens_name: String,
+    pub analysis: String,
+    pub key_findings: Vec<String>,
+}
+
+/// Deserialize the stored Lens Efficacy Report of the given run
+fn lens_report_for_run(orchestrator: &Orchestrator, run_id: &str) -> Result<LensEfficacyReport, String> {
+    let report = orchestrator
+        .lens_efficacy_report
+        .as_ref()
+        .ok_or_else(|| format!("Lens efficacy report not available - Step 3 has not run for {}", run_id))?;
+
+    parse_lens_report(report)
+}
+
+fn parse_lens_report(report: &str) -> Result<LensEfficacyReport, String> {
+    serde_json::from_str(report).map_err(|e| format!("Failed to parse Lens Efficacy Report: {}", e))
+}
+
+fn lens_efficacy_for_run(orchestrator: &Orchestrator, run_id: &str) -> Result<LensEfficacyResponse, String> {
+    lens_report_for_run(orchestrator, run_id).map(Into::into)
+}
+
+fn lens_analyses(report: LensEfficacyReport) -> Vec<LensAnalysis> {
+    report
+        .lens_results
+        .into_iter()
+        .map(|lens| LensAnalysis {
+            lens_name: lens.lens_name,
+            analysis: lens.analysis,
+            key_findings: lens.key_findings,
+        })
+        .collect()
+}
+
+/// Get the Step 3 Lens Efficacy Report as typed per-lens scores
+#[tauri::command]
+pub fn get_lens_efficacy(
+    run_id: String,
+    state: State<'_, OrchestratorState>,
+) -> Result<LensEfficacyResponse, String> {
+    info!("=== GET_LENS_EFFICACY command called ===");
+    info!("Run ID: {}", run_id);
+
+    let orch_guard = state.0.lock().map_err(|e| e.to_string())?;
+    let orchestrator = orch_guard
+        .get(Some(&run_id))
+        .ok_or_else(|| format!("Run {} not found", run_id))?;
+
+    lens_efficacy_for_run(orchestrator, &run_id)
+}
+
+/// Get the full text and findings of each Step 3 lens
+///
+/// Reads from the run's orchestrator when it is loaded in this session,
+/// otherwise from the persisted Lens Efficacy Report artifact.
+#[tauri::command]
+pub fn get_lens_analyses(
+    run_id: String,
+    state: State<'_, OrchestratorState>,
+    app: tauri::AppHandle,
+) -> Result<Vec<LensAnalysis>, String> {
+    info!("=== GET_LENS_ANALYSES command called ===");
+    info!("Run ID: {}", run_id);
+
+    {
+        let orch_guard = state.0.lock().map_err(|e| e.to_string())?;
+        if let Some(orchestrator) = orch_guard.get(Some(&run_id)) {
+            let analyses = lens_analyses(lens_report_for_run(orchestrator, &run_id)?);
+            info!("✓ Returning {} lens analyses from loaded run", analyses.len());
+            return Ok(analyses);
+        }
+    }
+
+    let conn = crate::database::get_connection(&app).map_err(|e| e.to_string())?;
+    let record = crate::database::artifacts::list_artifacts_by_run(&conn, &run_id)
+        .map_err(|e| e.to_string())?
+        .into_iter()
+        .find(|artifact| artifact.r#type == "LensEfficacyReport")
+        .ok_or_else(|| format!("Lens efficacy report not available - Step 3 has not run for {}", run_id))?;
+    let path = record
+        .content_path
+        .ok_or_else(|| format!("Lens efficacy report content for {} was pruned", run_id))?;
+    let report = std::fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read Lens Efficacy Report at {}: {}", path, e))?;
+
+    let analyses = lens_analyses(parse_lens_report(&report)?);
+    info!("✓ Returning {} persisted lens analyses", analyses.len());
+    Ok(analyses)
+}
+
+/// The Step 3 Integrated Diagnostic for review before the analysis gate
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IntegratedDiagnosticResponse {
+    /// Full diagnostic text, as stored
+    pub diagnostic: String,
+    /// Points where lenses disagree (empty when the Lens Efficacy Report has none)
+    pub contradictions: Vec<String>,
+}
+
+fn integrated_diagnostic_for_run(orchestrator: &Orchestrator, run_id: &str) -> Result<IntegratedDiagnosticResponse, String> {
+    let diagnostic = orchestrator
+        .integrated_diagnostic
+        .clone()
+        .ok_or_else(|| format!("Integrated diagnostic not available - Step 3 has not completed for {}", run_id))?;
+    let contradictions = lens_report_for_run(orchestrator, run_id)
+        .map(|report| report.contradictions)
+        .unwrap_or_default();
+
+    Ok(IntegratedDiagnosticResponse { diagnostic, contradictions })
+}
+
+/// Get the full Step 3 Integrated Diagnostic text
+#[tauri::command]
+pub fn get_integrated_diagnostic(
+    run_id: String,
+    state: State<'_, OrchestratorState>,
+) -> Result<IntegratedDiagnosticResponse, String> {
+    info!("=== GET_INTEGRATED_DIAGNOSTIC command called ===");
+    info!("Run ID: {}", run_id);
+
+    let orch_guard = state.0.lock().map_err(|e| e.to_string())?;
+    let orchestrator = orch_guard
+        .get(Some(&run_id))
+        .ok_or_else(|| format!("Run {} not found", run_id))?;
+
+    integrated_diagnostic_for_run(orchestrator, &run_id)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -191,4 +374,108 @@ mod tests {
         assert_eq!(response.integrated_diagnostic_id, "test-integrated-diagnostic");
         assert!(response.metrics.is_some());
     }
+
+    #[test]
+    fn test_lens_efficacy_round_trip() {
+        use crate::agents::LensResult;
+
+        let mut orch = Orchestrator::new("lens-efficacy");
+        let run_id = orch.run_id.clone();
+
+        let err = lens_efficacy_for_run(&orch, &run_id).unwrap_err();
+        assert!(err.contains("not available"));
+
+        let report = LensEfficacyReport {
+            lens_results: vec![
+                LensResult {
+                    lens_name: "Structural".to_string(),
+                    analysis: "Sections follow the onboarding flow".to_string(),
+                    key_findings: vec!["Clear phases".to_string(), "Missing exit criteria".to_string()],
+                    findings_parsed_cleanly: true,
+                    efficacy_score: 0.8,
+                    tokens_used: 1200,
+                },
+                LensResult {
+                    lens_name: "Evidence".to_string(),
+                    analysis: "Few sources".to_string(),
+                    key_findings: vec![],
+                    findings_parsed_cleanly: false,
+                    efficacy_score: 0.3,
+                    tokens_used: 900,
+                },
+            ],
+            total_insights: 2,
+            high_value_combinations: 1,
+            estimated_cost: 0.12,
+            actual_cost: 0.09,
+            contradictions: vec![],
+        };
+        orch.lens_efficacy_report = Some(serde_json::to_string_pretty(&report).unwrap());
+
+        let response = lens_efficacy_for_run(&orch, &run_id).unwrap();
+        assert_eq!(response.lenses.len(), 2);
+        assert_eq!(response.lenses[0].lens_name, "Structural");
+        assert_eq!(response.lenses[0].efficacy_score, 0.8);
+        assert_eq!(response.lenses[0].key_findings, 2);
+        assert_eq!(response.lenses[1].tokens_used, 900);
+        assert!(response.lenses[0].findings_parsed_cleanly);
+        assert!(!response.lenses[1].findings_parsed_cleanly);
+        assert_eq!(response.total_insights, 2);
+        assert_eq!(response.high_value_count, 1);
+        assert_eq!(response.estimated_cost, 0.12);
+        assert_eq!(response.actual_cost, 0.09);
+    }
+
+    #[test]
+    fn test_lens_analyses_return_each_lens() {
+        use crate::agents::LensResult;
+
+        let mut orch = Orchestrator::new("lens-analyses");
+        let run_id = orch.run_id.clone();
+        let names = ["Structural", "Thematic", "Logic", "Evidence", "Expression", "Intent"];
+
+        let report = LensEfficacyReport {
+            lens_results: names
+                .iter()
+                .map(|name| LensResult {
+                    lens_name: name.to_string(),
+                    analysis: format!("{} lens analysis text", name),
+                    key_findings: vec![format!("{} finding", name)],
+                    findings_parsed_cleanly: true,
+                    efficacy_score: 0.7,
+                    tokens_used: 800,
+                })
+                .collect(),
+            total_insights: 6,
+            high_value_combinations: 0,
+            estimated_cost: 0.12,
+            actual_cost: 0.1,
+            contradictions: vec![],
+        };
+        orch.lens_efficacy_report = Some(serde_json::to_string_pretty(&report).unwrap());
+
+        let analyses = lens_analyses(lens_report_for_run(&orch, &run_id).unwrap());
+        assert_eq!(analyses.len(), 6);
+        for (analysis, name) in analyses.iter().zip(names) {
+            assert_eq!(analysis.lens_name, name);
+            assert_eq!(analysis.analysis, format!("{} lens analysis text", name));
+            assert_eq!(analysis.key_findings, vec![format!("{} finding", name)]);
+        }
+    }
+
+    #[test]
+    fn test_integrated_diagnostic_returned_verbatim() {
+        let mut orch = Orchestrator::new("integrated-diagnostic");
+        let run_id = orch.run_id.clone();
+
+        let err = integrated_diagnostic_for_run(&orch, &run_id).unwrap_err();
+        assert!(err.contains("not available"));
+
+        let diagnostic = "---\nartifact_id: \"x\"\n---\n\n# Integrated Diagnostic\n\n  Indented line kept as-is.\n";
+        orch.integrated_diagnostic = Some(diagnostic.to_string());
+
+        let response = integrated_diagnostic_for_run(&orch, &run_id).unwrap();
+        assert_eq!(response.diagnostic, diagnostic);
+        assert!(response.contradictions.is_empty());
+    }
 }
@@ -2,7 +2,8 @@ use log::info;
 use serde::{Deserialize, Serialize};
 use tauri::State;
 
-use crate::commands::step0::OrchestratorState;
+use crate::agents::analysis_synthesis::{parse_causal_spine, CausalEdge, FeedbackLoop, GlossaryEntry};
+use crate::commands::step0::{finish_step, OrchestratorState};
 
 /// Response from execute_step_4 command
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -12,7 +13,12 @@ pub struct Step4Response {
     pub core_thesis: String,
     pub operating_principles: String,
     pub model_geometry: String,
+    /// Text diagram of the model structure, if Step 4 produced one
+    pub geometry_diagram: Option<String>,
     pub causal_spine: String,
+    /// `causal_spine` parsed into edges for graph rendering
+    pub causal_edges: Vec<CausalEdge>,
+    pub feedback_loops: Vec<FeedbackLoop>,
     pub north_star_narrative: String,
     pub glossary: String,
     pub limitations: String,
@@ -48,6 +54,11 @@ pub async fn execute_step_4(
     run_id: String,
     state: State<'_, OrchestratorState>,
 ) -> Result<Step4Response, String> {
+    run_step_4(&run_id, &state).await
+}
+
+/// Body of `execute_step_4`, callable without a Tauri app
+pub(crate) async fn run_step_4(run_id: &str, state: &OrchestratorState) -> Result<Step4Response, String> {
     info!("=== EXECUTE_STEP_4 command called ===");
     info!("Run ID: {}", run_id);
     info!("Timestamp: {}", chrono::Utc::now().to_rfc3339());
@@ -57,13 +68,13 @@ pub async fn execute_step_4(
     let mut orchestrator = {
         let mut orch_guard = state.0.lock().unwrap();
         info!("State lock acquired");
-        info!("State contains orchestrator: {}", orch_guard.is_some());
+        info!("State contains run {}: {}", run_id, orch_guard.contains(run_id));
 
         // Take ownership of the orchestrator temporarily
         let orch = orch_guard
-            .take()
+            .take(run_id)
             .ok_or_else(|| {
-                let err = "No active run found. Please complete Steps 0, 1, 2, and 3 first.".to_string();
+                let err = "No run found for this run_id. Please complete Steps 0, 1, 2, and 3 first.".to_string();
                 log::error!("[EXECUTE_STEP_4] {}", err);
                 log::error!("[EXECUTE_STEP_4] State was empty when trying to take orchestrator");
                 err
@@ -72,35 +83,38 @@ pub async fn execute_step_4(
         info!("Orchestrator taken from state");
         info!("Orchestrator run_id: {}", orch.run_id);
         info!("Orchestrator state: {:?}", orch.state);
-        info!("State contains orchestrator after take: {}", orch_guard.is_some());
 
         orch
     }; // Lock is released here
 
     // Execute Step 4 (now without holding the lock)
     info!("Executing Step 4 workflow...");
-    let (core_thesis_id, north_star_narrative_id) = orchestrator
-        .execute_step_4()
-        .await
-        .map_err(|e| {
-            let err = format!("Failed to execute Step 4: {}", e);
-            log::error!("[EXECUTE_STEP_4] {}", err);
-            err
-        })?;
 
-    info!("Step 4 completed successfully");
+    // Execute and ensure orchestrator is always put back, even on error
+    let step4_result = orchestrator.execute_step_4().await;
+    finish_step(&mut orchestrator, &step4_result);
 
-    // Put orchestrator back into state
+    // Always put orchestrator back into state, even if there was an error
     info!("Putting orchestrator back into state...");
     {
         let mut orch_guard = state.0.lock().unwrap();
-        *orch_guard = Some(orchestrator);
+        orch_guard.insert(run_id.to_string(), orchestrator);
         info!("Orchestrator restored to state");
     }
 
+    // Now check if step4 succeeded
+    let (core_thesis_id, north_star_narrative_id) = step4_result
+        .map_err(|e| {
+            let err = format!("Failed to execute Step 4: {}", e);
+            log::error!("[EXECUTE_STEP_4] {}", err);
+            err
+        })?;
+
+    info!("Step 4 completed successfully");
+
     // Get orchestrator back to extract artifact info
     let orch_guard = state.0.lock().unwrap();
-    let orchestrator = orch_guard.as_ref()
+    let orchestrator = orch_guard.get(Some(run_id))
         .ok_or_else(|| "Orchestrator not found after Step 4 execution".to_string())?;
 
     // Extract artifacts
@@ -122,11 +136,14 @@ pub async fn execute_step_4(
         .ok_or_else(|| "Model Geometry not generated".to_string())?
         .clone();
 
+    let geometry_diagram = orchestrator.geometry_diagram.clone();
+
     let causal_spine = orchestrator
         .causal_spine
         .as_ref()
         .ok_or_else(|| "Causal Spine not generated".to_string())?
         .clone();
+    let (causal_edges, feedback_loops) = parse_causal_spine(&causal_spine);
 
     let north_star_narrative = orchestrator
         .north_star_narrative
@@ -166,7 +183,10 @@ pub async fn execute_step_4(
         core_thesis,
         operating_principles,
         model_geometry,
+        geometry_diagram,
         causal_spine,
+        causal_edges,
+        feedback_loops,
         north_star_narrative,
         glossary,
         limitations,
@@ -174,6 +194,22 @@ pub async fn execute_step_4(
     })
 }
 
+/// Find glossary terms defined by earlier runs that match `term`
+///
+/// Matching ignores case, punctuation and a plural ending, so a new run can
+/// reuse the established definition.
+#[tauri::command]
+pub fn find_similar_glossary_terms(term: String, app: tauri::AppHandle) -> Result<Vec<GlossaryEntry>, String> {
+    info!("=== FIND_SIMILAR_GLOSSARY_TERMS command called ===");
+    info!("Term: {}", term);
+
+    let conn = crate::database::get_connection(&app).map_err(|e| e.to_string())?;
+    let entries = crate::database::glossary::find_similar_terms(&conn, &term).map_err(|e| e.to_string())?;
+
+    info!("✓ Found {} similar terms", entries.len());
+    Ok(entries)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -188,7 +224,10 @@ mod tests {
             core_thesis: "test thesis content".to_string(),
             operating_principles: "test principles".to_string(),
             model_geometry: "Linear: Sequential flow".to_string(),
+            geometry_diagram: None,
             causal_spine: "test causal spine".to_string(),
+            causal_edges: vec![],
+            feedback_loops: vec![],
             north_star_narrative: "test narrative".to_string(),
             glossary: "test glossary".to_string(),
             limitations: "test limitations".to_string(),
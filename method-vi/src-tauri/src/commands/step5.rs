@@ -2,7 +2,7 @@ use log::info;
 use serde::{Deserialize, Serialize};
 use tauri::State;
 
-use crate::commands::step0::OrchestratorState;
+use crate::commands::step0::{finish_step, OrchestratorState};
 
 /// Response from execute_step_5 command
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -38,6 +38,11 @@ pub async fn execute_step_5(
     run_id: String,
     state: State<'_, OrchestratorState>,
 ) -> Result<Step5Response, String> {
+    run_step_5(&run_id, &state).await
+}
+
+/// Body of `execute_step_5`, callable without a Tauri app
+pub(crate) async fn run_step_5(run_id: &str, state: &OrchestratorState) -> Result<Step5Response, String> {
     info!("=== EXECUTE_STEP_5 command called ===");
     info!("Run ID: {}", run_id);
     info!("Timestamp: {}", chrono::Utc::now().to_rfc3339());
@@ -47,13 +52,13 @@ pub async fn execute_step_5(
     let mut orchestrator = {
         let mut orch_guard = state.0.lock().unwrap();
         info!("State lock acquired");
-        info!("State contains orchestrator: {}", orch_guard.is_some());
+        info!("State contains run {}: {}", run_id, orch_guard.contains(run_id));
 
         // Take ownership of the orchestrator temporarily
         let orch = orch_guard
-            .take()
+            .take(run_id)
             .ok_or_else(|| {
-                let err = "No active run found. Please complete Steps 0-4 first.".to_string();
+                let err = "No run found for this run_id. Please complete Steps 0-4 first.".to_string();
                 log::error!("[EXECUTE_STEP_5] {}", err);
                 log::error!("[EXECUTE_STEP_5] State was empty when trying to take orchestrator");
                 err
@@ -62,35 +67,38 @@ pub async fn execute_step_5(
         info!("Orchestrator taken from state");
         info!("Orchestrator run_id: {}", orch.run_id);
         info!("Orchestrator state: {:?}", orch.state);
-        info!("State contains orchestrator after take: {}", orch_guard.is_some());
 
         orch
     }; // Lock is released here
 
     // Execute Step 5 (now without holding the lock)
     info!("Executing Step 5 workflow...");
-    let framework_architecture_id = orchestrator
-        .execute_step_5()
-        .await
-        .map_err(|e| {
-            let err = format!("Failed to execute Step 5: {}", e);
-            log::error!("[EXECUTE_STEP_5] {}", err);
-            err
-        })?;
 
-    info!("Step 5 completed successfully");
+    // Execute and ensure orchestrator is always put back, even on error
+    let step5_result = orchestrator.execute_step_5().await;
+    finish_step(&mut orchestrator, &step5_result);
 
-    // Put orchestrator back into state
+    // Always put orchestrator back into state, even if there was an error
     info!("Putting orchestrator back into state...");
     {
         let mut orch_guard = state.0.lock().unwrap();
-        *orch_guard = Some(orchestrator);
+        orch_guard.insert(run_id.to_string(), orchestrator);
         info!("Orchestrator restored to state");
     }
 
+    // Now check if step5 succeeded
+    let framework_architecture_id = step5_result
+        .map_err(|e| {
+            let err = format!("Failed to execute Step 5: {}", e);
+            log::error!("[EXECUTE_STEP_5] {}", err);
+            err
+        })?;
+
+    info!("Step 5 completed successfully");
+
     // Get orchestrator back to extract artifact info
     let orch_guard = state.0.lock().unwrap();
-    let orchestrator = orch_guard.as_ref()
+    let orchestrator = orch_guard.get(Some(run_id))
         .ok_or_else(|| "Orchestrator not found after Step 5 execution".to_string())?;
 
     // Extract artifacts
@@ -145,4 +153,53 @@ mod tests {
         assert_eq!(response.framework_architecture_id, "test-framework-architecture");
         assert!(response.metrics.is_some());
     }
+
+    #[tokio::test]
+    async fn test_missing_prerequisites_keep_run_registered() {
+        use crate::agents::orchestrator::{Orchestrator, RunState};
+        use crate::commands::step0::RunRegistry;
+
+        // A run resumed at Step 5 without the Step 4 artifacts it needs
+        let mut orch = Orchestrator::new("resumed").with_stubs();
+        orch.state = RunState::Step5Active;
+        let mut registry = RunRegistry::default();
+        registry.insert("resumed", orch);
+        let state = OrchestratorState(std::sync::Mutex::new(registry));
+
+        let err = run_step_5("resumed", &state).await.unwrap_err();
+        assert!(err.contains("Cannot execute Step 5 - missing prerequisite artifacts"), "{}", err);
+
+        let registry = state.0.lock().unwrap();
+        let orch = registry.get(Some("resumed")).expect("run stays registered");
+        assert!(matches!(orch.state, RunState::Step5Active));
+    }
+
+    #[tokio::test]
+    async fn test_command_auto_approves_allowed_gate() {
+        use crate::agents::orchestrator::{Orchestrator, RunState};
+        use crate::commands::step0::RunRegistry;
+
+        let mut orch = Orchestrator::new("auto-gate").with_stubs().with_auto_approval(Some(vec![5]));
+        orch.execute_step_0("Design a customer onboarding framework").await.unwrap();
+        // Gates outside the allowlist stay with the human
+        assert!(!orch.try_auto_approve().unwrap());
+        orch.approve_gate("Human").unwrap();
+        for _ in 1..=4 {
+            orch.execute_active_step(true).await.unwrap();
+            orch.approve_gate("Human").unwrap();
+        }
+        assert!(matches!(orch.state, RunState::Step5Active));
+
+        let mut registry = RunRegistry::default();
+        registry.insert("auto-gate", orch);
+        let state = OrchestratorState(std::sync::Mutex::new(registry));
+
+        run_step_5("auto-gate", &state).await.unwrap();
+
+        let registry = state.0.lock().unwrap();
+        let orch = registry.get(Some("auto-gate")).unwrap();
+        assert!(matches!(orch.state, RunState::Step6Active));
+        let entries = orch.get_ledger().get_entries(&orch.run_id);
+        assert!(entries.iter().any(|e| e.payload.action == "gate_auto_approved" && e.step == Some(5)));
+    }
 }
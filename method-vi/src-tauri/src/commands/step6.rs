@@ -3,8 +3,8 @@ use serde::{Deserialize, Serialize};
 use tauri::State;
 use std::sync::Mutex;
 
-use crate::commands::step0::OrchestratorState;
-use crate::config::AppConfig;
+use crate::commands::step0::{finish_step, OrchestratorState};
+use crate::config::{AppConfig, RunConfig};
 use crate::agents::validation_learning::ValidationLearningAgent;
 
 /// Response from execute_step_6 command
@@ -49,15 +49,28 @@ pub async fn execute_step_6(
     run_id: String,
     state: State<'_, OrchestratorState>,
     config_state: State<'_, Mutex<AppConfig>>,
+) -> Result<Step6Response, String> {
+    run_step_6(&run_id, &state, &config_state).await
+}
+
+/// Body of `execute_step_6`, callable without a Tauri app
+pub(crate) async fn run_step_6(
+    run_id: &str,
+    state: &OrchestratorState,
+    config_state: &Mutex<AppConfig>,
 ) -> Result<Step6Response, String> {
     info!("=== EXECUTE_STEP_6 command called ===");
     info!("Run ID: {}", run_id);
     info!("Timestamp: {}", chrono::Utc::now().to_rfc3339());
 
-    // Get API key from config
-    let api_key = {
+    // Use the settings frozen at Step 0 (current config for runs started without them)
+    let frozen_config = state.0.lock().unwrap().get(Some(run_id)).and_then(|o| o.run_config.clone());
+
+    // Get API client from config
+    let api_client = {
         let config = config_state.lock().unwrap();
-        config.get_api_key()
+        let run_config = frozen_config.unwrap_or_else(|| RunConfig::from(&*config));
+        config.run_api_client(&run_config)
             .map_err(|e| format!("Failed to get API key: {}", e))?
     };
 
@@ -66,13 +79,13 @@ pub async fn execute_step_6(
     let mut orchestrator = {
         let mut orch_guard = state.0.lock().unwrap();
         info!("State lock acquired");
-        info!("State contains orchestrator: {}", orch_guard.is_some());
+        info!("State contains run {}: {}", run_id, orch_guard.contains(run_id));
 
         // Take ownership of the orchestrator temporarily
         let mut orch = orch_guard
-            .take()
+            .take(run_id)
             .ok_or_else(|| {
-                let err = "No active run found. Please complete Steps 0-5 first.".to_string();
+                let err = "No run found for this run_id. Please complete Steps 0-5 first.".to_string();
                 log::error!("[EXECUTE_STEP_6] {}", err);
                 log::error!("[EXECUTE_STEP_6] State was empty when trying to take orchestrator");
                 err
@@ -84,8 +97,7 @@ pub async fn execute_step_6(
 
         // CREATE and ATTACH Validation & Learning Agent
         info!("Creating Validation & Learning Agent...");
-        let validation_agent = ValidationLearningAgent::new(api_key)
-            .map_err(|e| format!("Failed to create Validation Agent: {}", e))?;
+        let validation_agent = ValidationLearningAgent::from_client(api_client);
 
         info!("Attaching Validation & Learning Agent to orchestrator...");
         orch = orch.with_validation_agent(validation_agent);
@@ -96,9 +108,21 @@ pub async fn execute_step_6(
 
     // Execute Step 6 (now without holding the lock)
     info!("Executing Step 6 workflow...");
-    let validation_outcome = orchestrator
-        .execute_step_6()
-        .await
+
+    // Execute and ensure orchestrator is always put back, even on error
+    let step6_result = orchestrator.execute_step_6().await;
+    finish_step(&mut orchestrator, &step6_result);
+
+    // Always put orchestrator back into state, even if there was an error
+    info!("Putting orchestrator back into state...");
+    {
+        let mut orch_guard = state.0.lock().unwrap();
+        orch_guard.insert(run_id.to_string(), orchestrator);
+        info!("Orchestrator restored to state");
+    }
+
+    // Now check if step6 succeeded
+    let validation_outcome = step6_result
         .map_err(|e| {
             let err = format!("Failed to execute Step 6: {}", e);
             log::error!("[EXECUTE_STEP_6] {}", err);
@@ -108,17 +132,9 @@ pub async fn execute_step_6(
     info!("Step 6 completed successfully");
     info!("Validation outcome: {}", validation_outcome);
 
-    // Put orchestrator back into state
-    info!("Putting orchestrator back into state...");
-    {
-        let mut orch_guard = state.0.lock().unwrap();
-        *orch_guard = Some(orchestrator);
-        info!("Orchestrator restored to state");
-    }
-
     // Get orchestrator back to extract artifact info
     let orch_guard = state.0.lock().unwrap();
-    let orchestrator = orch_guard.as_ref()
+    let orchestrator = orch_guard.get(Some(run_id))
         .ok_or_else(|| "Orchestrator not found after Step 6 execution".to_string())?;
 
     // Extract validation artifacts
@@ -1,8 +1,11 @@
+use std::collections::{BTreeSet, HashMap};
+
 use log::info;
+use rusqlite::Connection;
 use serde::{Deserialize, Serialize};
 use tauri::State;
 
-use crate::commands::step0::OrchestratorState;
+use crate::commands::step0::{finish_step, OrchestratorState};
 use crate::agents::validation_learning::PatternCard;
 
 /// Response from execute_step_6_5 command
@@ -53,6 +56,11 @@ pub async fn execute_step_6_5(
     state: State<'_, OrchestratorState>,
     // NO config_state - agent already attached in Step 6
 ) -> Result<Step6_5Response, String> {
+    run_step_6_5(&run_id, &state).await
+}
+
+/// Body of `execute_step_6_5`, callable without a Tauri app
+pub(crate) async fn run_step_6_5(run_id: &str, state: &OrchestratorState) -> Result<Step6_5Response, String> {
     info!("=== EXECUTE_STEP_6_5 command called ===");
     info!("Run ID: {}", run_id);
     info!("Timestamp: {}", chrono::Utc::now().to_rfc3339());
@@ -62,13 +70,13 @@ pub async fn execute_step_6_5(
     let mut orchestrator = {
         let mut orch_guard = state.0.lock().unwrap();
         info!("State lock acquired");
-        info!("State contains orchestrator: {}", orch_guard.is_some());
+        info!("State contains run {}: {}", run_id, orch_guard.contains(run_id));
 
         // Take ownership of the orchestrator temporarily
         let orch = orch_guard
-            .take()
+            .take(run_id)
             .ok_or_else(|| {
-                let err = "No active run found. Please complete Steps 0-6 first.".to_string();
+                let err = "No run found for this run_id. Please complete Steps 0-6 first.".to_string();
                 log::error!("[EXECUTE_STEP_6_5] {}", err);
                 err
             })?;
@@ -83,9 +91,20 @@ pub async fn execute_step_6_5(
 
     // Execute Step 6.5 (now without holding the lock)
     info!("Executing Step 6.5 workflow...");
-    let harvest_result = orchestrator
-        .execute_step_6_5()
-        .await
+
+    // Execute and ensure orchestrator is always put back, even on error
+    let harvest_result = orchestrator.execute_step_6_5().await;
+    finish_step(&mut orchestrator, &harvest_result);
+
+    // Return orchestrator to state
+    {
+        let mut orch_guard = state.0.lock().unwrap();
+        orch_guard.insert(run_id.to_string(), orchestrator);
+    }
+
+    info!("Orchestrator returned to state");
+
+    let harvest_result = harvest_result
         .map_err(|e| {
             let err_msg = format!("Failed to execute Step 6.5: {}", e);
             log::error!("[EXECUTE_STEP_6_5] {}", err_msg);
@@ -103,14 +122,6 @@ pub async fn execute_step_6_5(
 
     info!("Extracted {} pattern cards for frontend", pattern_cards.len());
 
-    // Return orchestrator to state
-    {
-        let mut orch_guard = state.0.lock().unwrap();
-        *orch_guard = Some(orchestrator);
-    }
-
-    info!("Orchestrator returned to state");
-
     // Build response
     let response = Step6_5Response {
         knowledge_update: harvest_result.knowledge_update,
@@ -124,3 +135,154 @@ pub async fn execute_step_6_5(
 
     Ok(response)
 }
+
+/// A pattern card stored by an earlier run's learning harvest
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LearnedPattern {
+    pub card: PatternCardForFrontend,
+    pub intent_category: String,
+    /// Model geometry of the run that produced the card
+    pub geometry: String,
+    pub geometry_rationale: String,
+    pub source_run_id: Option<String>,
+    /// Number of runs whose pattern cards share this geometry
+    pub run_count: usize,
+}
+
+/// Stored pattern cards, most frequent geometry first
+///
+/// Rows without a stored card (e.g. starter patterns) are skipped.
+pub fn list_learned_patterns(conn: &Connection, intent_category: Option<&str>) -> anyhow::Result<Vec<LearnedPattern>> {
+    let rows = match intent_category {
+        Some(category) => crate::database::patterns::list_patterns_by_category(conn, category)?,
+        None => crate::database::patterns::list_patterns(conn)?,
+    };
+
+    let mut patterns = Vec::new();
+    for row in rows {
+        let Some(card) = row.validation_pattern.as_deref().and_then(|json| serde_json::from_str::<PatternCard>(json).ok()) else {
+            continue;
+        };
+        let architecture: serde_json::Value = row
+            .architecture_pattern
+            .as_deref()
+            .and_then(|json| serde_json::from_str(json).ok())
+            .unwrap_or_default();
+        patterns.push(LearnedPattern {
+            card: card.into(),
+            intent_category: row.intent_category,
+            geometry: architecture["geometry"].as_str().unwrap_or_default().to_string(),
+            geometry_rationale: architecture["rationale"].as_str().unwrap_or_default().to_string(),
+            source_run_id: row.source_run_id,
+            run_count: 0,
+        });
+    }
+
+    let mut runs_by_geometry: HashMap<String, BTreeSet<String>> = HashMap::new();
+    for pattern in &patterns {
+        if let Some(ref run_id) = pattern.source_run_id {
+            runs_by_geometry.entry(pattern.geometry.clone()).or_default().insert(run_id.clone());
+        }
+    }
+    for pattern in &mut patterns {
+        pattern.run_count = runs_by_geometry.get(&pattern.geometry).map_or(0, BTreeSet::len);
+    }
+    patterns.sort_by(|a, b| b.run_count.cmp(&a.run_count).then_with(|| a.card.pattern_name.cmp(&b.card.pattern_name)));
+
+    Ok(patterns)
+}
+
+/// List pattern cards learned across runs, optionally for one intent category
+#[tauri::command]
+pub async fn list_patterns(
+    intent_category: Option<String>,
+    app: tauri::AppHandle,
+) -> Result<Vec<LearnedPattern>, String> {
+    info!("=== LIST_PATTERNS command called ===");
+
+    let conn = crate::database::get_connection(&app).map_err(|e| e.to_string())?;
+    let patterns = list_learned_patterns(&conn, intent_category.as_deref()).map_err(|e| e.to_string())?;
+
+    info!("✓ Found {} learned patterns", patterns.len());
+    Ok(patterns)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::models::{Pattern, Run};
+    use crate::database::{patterns, runs, schema};
+    use chrono::Utc;
+
+    fn seed(conn: &Connection, run_id: &str, category: &str, geometry: &str, name: &str) {
+        runs::create_run(conn, &Run {
+            id: run_id.to_string(),
+            intent_anchor_hash: "hash".to_string(),
+            created_at: Utc::now(),
+            completed_at: None,
+            final_ci: None,
+            final_ev: None,
+            status: Some("completed".to_string()),
+        })
+        .unwrap();
+        let card = PatternCard {
+            pattern_id: "pattern-1".to_string(),
+            pattern_name: name.to_string(),
+            category: "Success".to_string(),
+            context: String::new(),
+            mechanics: String::new(),
+            efficacy: 0.9,
+            reusability: "High".to_string(),
+            recommendation: String::new(),
+        };
+        patterns::create_pattern(conn, &Pattern {
+            id: format!("{}-pattern-1", run_id),
+            intent_category: category.to_string(),
+            ci_achievement: None,
+            ev_stability: None,
+            architecture_pattern: Some(serde_json::json!({ "geometry": geometry, "rationale": "fits" }).to_string()),
+            analysis_pattern: None,
+            synthesis_pattern: None,
+            structure_pattern: None,
+            validation_pattern: Some(serde_json::to_string(&card).unwrap()),
+            applicability: None,
+            vitality_freshness: 1.0,
+            vitality_relevance: 1.0,
+            application_count: 0,
+            success_count: 0,
+            created_at: Utc::now(),
+            last_applied: None,
+            source_run_id: Some(run_id.to_string()),
+            is_starter: false,
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn test_learned_patterns_aggregate_runs_by_geometry() {
+        let conn = Connection::open_in_memory().unwrap();
+        schema::create_schema(&conn).unwrap();
+        seed(&conn, "run-a", "Analytical", "Cyclic", "Feedback loop");
+        seed(&conn, "run-b", "Operational", "Linear", "Staged rollout");
+        seed(&conn, "run-c", "Analytical", "Cyclic", "Review cadence");
+
+        let all = list_learned_patterns(&conn, None).unwrap();
+        let summary: Vec<(&str, &str, usize)> = all
+            .iter()
+            .map(|p| (p.card.pattern_name.as_str(), p.geometry.as_str(), p.run_count))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("Feedback loop", "Cyclic", 2),
+                ("Review cadence", "Cyclic", 2),
+                ("Staged rollout", "Linear", 1),
+            ]
+        );
+        assert_eq!(all[0].geometry_rationale, "fits");
+
+        let operational = list_learned_patterns(&conn, Some("Operational")).unwrap();
+        assert_eq!(operational.len(), 1);
+        assert_eq!(operational[0].card.pattern_name, "Staged rollout");
+    }
+}
@@ -1,12 +1,28 @@
+pub mod prompts;
+pub mod run_config;
+pub mod step_descriptions;
 pub mod thresholds;
 
 use anyhow::{Context, Result};
 use base64::Engine;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 use tauri::Manager;
 
+use crate::agents::governance_telemetry::ThresholdsConfig;
+use crate::agents::orchestrator::UnlockedBaselinePolicy;
+use crate::agents::{LensOutputFormat, Step4Options};
+use crate::api::{AnthropicClient, CircuitBreaker, RateLimiter, SharedSlot};
+use crate::artifacts::ImmutableArtifactTypes;
+use crate::governance::MetricEnforcementSchedule;
+use crate::signals::SignalNaming;
+
+pub use prompts::PromptTemplates;
+pub use run_config::RunConfig;
+pub use step_descriptions::{StepDescriptions, StepLabel, StepRationale};
 pub use thresholds::{ThresholdConfig, MetricThreshold, Critical6Thresholds};
 
 /// Application configuration settings
@@ -16,10 +32,18 @@ pub struct AppConfig {
     #[serde(default)]
     pub anthropic_api_key: Option<String>,
 
+    /// LLM provider: "anthropic" (default) or "echo" for offline development
+    #[serde(default = "default_provider")]
+    pub provider: String,
+
     /// Default Claude model to use
     #[serde(default = "default_model")]
     pub default_model: String,
 
+    /// Model for short judgment micro-calls (relevance checks, classification)
+    #[serde(default = "default_utility_model")]
+    pub utility_model: String,
+
     /// Default max tokens for API calls
     #[serde(default = "default_max_tokens")]
     pub default_max_tokens: u32,
@@ -27,16 +51,169 @@ pub struct AppConfig {
     /// Enable API call logging for cost tracking
     #[serde(default = "default_true")]
     pub enable_api_logging: bool,
+
+    /// Default sampling temperature for calls that don't set one (None = API default)
+    #[serde(default)]
+    pub default_temperature: Option<f32>,
+
+    /// Default top_p for calls that don't set one (None = API default)
+    #[serde(default)]
+    pub default_top_p: Option<f32>,
+
+    /// Auto-approve gates after each step for non-interactive runs
+    /// (Critical callouts still block)
+    #[serde(default)]
+    pub auto_approve_gates: bool,
+
+    /// Steps whose gates may be auto-approved (None = all steps)
+    #[serde(default)]
+    pub auto_approve_steps: Option<Vec<u8>>,
+
+    /// Intent drift above which a callout is raised (None = built-in default)
+    #[serde(default)]
+    pub intent_drift_threshold: Option<f64>,
+
+    /// Steps at which each metric can HALT (default reproduces FIX-008)
+    #[serde(default)]
+    pub metric_enforcement: MetricEnforcementSchedule,
+
+    /// Pass, warning and HALT thresholds per metric (unset = built-in defaults)
+    #[serde(default)]
+    pub metric_thresholds: ThresholdsConfig,
+
+    /// Let the Step 2 governance calibration override EV, IAS and PCI thresholds
+    #[serde(default)]
+    pub calibrated_thresholds: bool,
+
+    /// Score CI, IAS and EFI with one combined Claude call per metrics pass
+    #[serde(default)]
+    pub batched_metrics: bool,
+
+    /// Artifact types locked once created (must include the Critical Path types)
+    #[serde(default)]
+    pub immutable_artifact_types: ImmutableArtifactTypes,
+
+    /// Most recent steps whose mutable artifacts keep full content when a run is
+    /// pruned; older ones keep only hashes and metadata
+    #[serde(default = "default_artifact_retention_steps")]
+    pub artifact_retention_steps: usize,
+
+    /// Default destination for exports (None = `exports/` in the app data directory)
+    #[serde(default)]
+    pub export_dir: Option<PathBuf>,
+
+    /// Seconds a single Claude call may take before it fails with a timeout
+    #[serde(default = "default_request_timeout_secs")]
+    pub request_timeout_secs: u64,
+
+    /// Claude calls started per minute, across all runs (0 = no pacing)
+    #[serde(default = "default_requests_per_minute")]
+    pub requests_per_minute: u32,
+
+    /// Claude calls in flight at once, across all runs
+    #[serde(default = "default_max_concurrent_requests")]
+    pub max_concurrent_requests: usize,
+
+    /// Rate limiter shared by every client built from this config (not persisted)
+    #[serde(skip)]
+    pub rate_limiter: SharedSlot<RateLimiter>,
+
+    /// Consecutive failed Claude calls before a run's circuit breaker opens
+    #[serde(default = "default_circuit_failure_threshold")]
+    pub circuit_failure_threshold: u32,
+
+    /// Seconds an open circuit breaker waits before allowing a trial call
+    #[serde(default = "default_circuit_cooldown_secs")]
+    pub circuit_cooldown_secs: u64,
+
+    /// Step names and ledger rationales by step number (unset = English defaults)
+    #[serde(default)]
+    pub step_descriptions: StepDescriptions,
+
+    /// Seed for the echo provider's canned variation (None = fixed responses)
+    #[serde(default)]
+    pub seed: Option<u64>,
+
+    /// Whether Step 2 locks ("Lock") or rejects ("Reject") an unlocked E_baseline
+    #[serde(default)]
+    pub unlocked_baseline_policy: UnlockedBaselinePolicy,
+
+    /// Analysis targets shorter than this many characters need confirmation at Step 3
+    #[serde(default = "default_min_analysis_chars")]
+    pub min_analysis_chars: usize,
+
+    /// Whether Step 3 lenses answer in prose ("prose") or JSON ("structured")
+    #[serde(default)]
+    pub lens_output_format: LensOutputFormat,
+
+    /// Optional Step 4 sub-steps (glossary, limitations, causal spine) to run
+    #[serde(default)]
+    pub step4_options: Step4Options,
+
+    /// Treat Warning metrics as blocking: their callouts need acknowledgment
+    #[serde(default)]
+    pub strict_metrics: bool,
+
+    /// Let a human reopen a step after a metrics HALT (`override_halt`)
+    #[serde(default)]
+    pub allow_halt_override: bool,
+
+    /// External aliases for signal types in the signal chain (unset = internal names)
+    #[serde(default)]
+    pub signal_naming: SignalNaming,
+
+    /// Log level per module, e.g. {"agents": "debug"} (empty = RUST_LOG, default info)
+    ///
+    /// Keys are "orchestrator", "agents", "ledger", "api", "default" or any other
+    /// module path within the crate.
+    #[serde(default)]
+    pub log_levels: HashMap<String, String>,
+}
+
+fn default_provider() -> String {
+    "anthropic".to_string()
 }
 
 fn default_model() -> String {
     "claude-sonnet-4-20250514".to_string()
 }
 
+fn default_utility_model() -> String {
+    crate::api::DEFAULT_UTILITY_MODEL.to_string()
+}
+
 fn default_max_tokens() -> u32 {
     4096
 }
 
+fn default_artifact_retention_steps() -> usize {
+    1
+}
+
+fn default_request_timeout_secs() -> u64 {
+    crate::api::DEFAULT_REQUEST_TIMEOUT_SECS
+}
+
+fn default_requests_per_minute() -> u32 {
+    crate::api::rate_limiter::DEFAULT_REQUESTS_PER_MINUTE
+}
+
+fn default_max_concurrent_requests() -> usize {
+    crate::api::rate_limiter::DEFAULT_MAX_CONCURRENT
+}
+
+fn default_circuit_failure_threshold() -> u32 {
+    crate::api::circuit_breaker::DEFAULT_FAILURE_THRESHOLD
+}
+
+fn default_circuit_cooldown_secs() -> u64 {
+    crate::api::circuit_breaker::DEFAULT_COOLDOWN_SECS
+}
+
+fn default_min_analysis_chars() -> usize {
+    crate::agents::orchestrator::DEFAULT_MIN_ANALYSIS_CHARS
+}
+
 fn default_true() -> bool {
     true
 }
@@ -45,9 +222,39 @@ impl Default for AppConfig {
     fn default() -> Self {
         AppConfig {
             anthropic_api_key: None,
+            provider: default_provider(),
             default_model: default_model(),
+            utility_model: default_utility_model(),
             default_max_tokens: default_max_tokens(),
             enable_api_logging: true,
+            default_temperature: None,
+            default_top_p: None,
+            auto_approve_gates: false,
+            auto_approve_steps: None,
+            intent_drift_threshold: None,
+            metric_enforcement: MetricEnforcementSchedule::default(),
+            metric_thresholds: ThresholdsConfig::default(),
+            calibrated_thresholds: false,
+            batched_metrics: false,
+            immutable_artifact_types: ImmutableArtifactTypes::default(),
+            artifact_retention_steps: default_artifact_retention_steps(),
+            export_dir: None,
+            request_timeout_secs: default_request_timeout_secs(),
+            requests_per_minute: default_requests_per_minute(),
+            max_concurrent_requests: default_max_concurrent_requests(),
+            rate_limiter: SharedSlot::default(),
+            circuit_failure_threshold: default_circuit_failure_threshold(),
+            circuit_cooldown_secs: default_circuit_cooldown_secs(),
+            step_descriptions: StepDescriptions::default(),
+            seed: None,
+            unlocked_baseline_policy: UnlockedBaselinePolicy::default(),
+            min_analysis_chars: default_min_analysis_chars(),
+            lens_output_format: LensOutputFormat::default(),
+            step4_options: Step4Options::default(),
+            strict_metrics: false,
+            allow_halt_override: false,
+            signal_naming: SignalNaming::default(),
+            log_levels: HashMap::new(),
         }
     }
 }
@@ -82,6 +289,13 @@ impl AppConfig {
 
             let config: AppConfig = serde_json::from_str(&contents)
                 .context("Failed to parse settings file")?;
+            config
+                .immutable_artifact_types
+                .validate()
+                .context("Invalid immutable_artifact_types in settings file")?;
+            if !matches!(config.provider.as_str(), "anthropic" | "echo") {
+                anyhow::bail!("Unknown provider in settings file: {}", config.provider);
+            }
 
             Ok(config)
         } else {
@@ -129,6 +343,97 @@ impl AppConfig {
         anyhow::bail!("ANTHROPIC_API_KEY not found in environment or config file")
     }
 
+    /// Create a client for the configured provider
+    ///
+    /// The echo provider needs no API key and makes no network calls. Every
+    /// client built from this config shares one rate limiter, so all runs draw
+    /// on the same request budget.
+    pub fn api_client(&self) -> Result<AnthropicClient> {
+        let client = match self.provider.as_str() {
+            "echo" => match self.seed {
+                Some(seed) => AnthropicClient::echo_with_seed(seed)?,
+                None => AnthropicClient::echo()?,
+            },
+            "anthropic" => AnthropicClient::new(self.get_api_key()?)?
+                .with_request_timeout(Duration::from_secs(self.request_timeout_secs)),
+            other => anyhow::bail!("Unknown provider: {}", other),
+        };
+        let rate_limiter = self
+            .rate_limiter
+            .get_or_init(|| RateLimiter::new(self.requests_per_minute, self.max_concurrent_requests));
+        Ok(client.with_rate_limiter(rate_limiter))
+    }
+
+    /// Client for the configured provider using a run's frozen settings
+    ///
+    /// The provider and API key come from this (global) config; model, token
+    /// limit, sampling defaults and timeout come from `run_config`. Every client
+    /// built for the run shares one circuit breaker, created on first use with
+    /// the run's thresholds.
+    pub fn run_api_client(&self, run_config: &RunConfig) -> Result<AnthropicClient> {
+        let circuit_breaker = run_config.circuit_breaker.get_or_init(|| {
+            CircuitBreaker::new(
+                run_config.circuit_failure_threshold,
+                Duration::from_secs(run_config.circuit_cooldown_secs),
+            )
+        });
+        Ok(self
+            .api_client()?
+            .with_model_defaults(&run_config.model, run_config.max_tokens)
+            .with_sampling_defaults(run_config.temperature, run_config.top_p)
+            .with_request_timeout(Duration::from_secs(run_config.request_timeout_secs))
+            .with_circuit_breaker(circuit_breaker))
+    }
+
+    /// Export directory: the configured one, or `exports/` under `app_data_dir`
+    pub fn resolve_export_dir(&self, app_data_dir: &Path) -> PathBuf {
+        self.export_dir
+            .clone()
+            .unwrap_or_else(|| app_data_dir.join("exports"))
+    }
+
+    /// Resolve the export directory for this app, creating it if missing
+    pub fn export_dir(&self, app_handle: &tauri::AppHandle) -> Result<PathBuf> {
+        let app_data_dir = app_handle
+            .path()
+            .app_data_dir()
+            .context("Failed to get app data directory")?;
+
+        let export_dir = self.resolve_export_dir(&app_data_dir);
+        fs::create_dir_all(&export_dir)
+            .with_context(|| format!("Failed to create export directory {}", export_dir.display()))?;
+
+        Ok(export_dir)
+    }
+
+    /// `env_logger` filter built from `log_levels` (None = use RUST_LOG)
+    ///
+    /// Modules left out log at the "default" level, or info.
+    pub fn log_filter(&self) -> Option<String> {
+        if self.log_levels.is_empty() {
+            return None;
+        }
+
+        let crate_name = env!("CARGO_CRATE_NAME");
+        let mut directives: Vec<String> = self
+            .log_levels
+            .iter()
+            .filter(|(module, _)| module.as_str() != "default")
+            .map(|(module, level)| {
+                let path = match module.as_str() {
+                    "orchestrator" => "agents::orchestrator",
+                    other => other,
+                };
+                format!("{}::{}={}", crate_name, path, level)
+            })
+            .collect();
+        directives.sort();
+
+        let default_level = self.log_levels.get("default").map(String::as_str).unwrap_or("info");
+        directives.insert(0, default_level.to_string());
+        Some(directives.join(","))
+    }
+
     /// Set the API key in config (stores as base64)
     pub fn set_api_key(&mut self, api_key: &str) {
         let encoded = base64::prelude::BASE64_STANDARD.encode(api_key.as_bytes());
@@ -144,9 +449,70 @@ mod tests {
     fn test_default_config() {
         let config = AppConfig::default();
         assert_eq!(config.default_model, "claude-sonnet-4-20250514");
+        assert_eq!(config.utility_model, crate::api::DEFAULT_UTILITY_MODEL);
         assert_eq!(config.default_max_tokens, 4096);
         assert!(config.enable_api_logging);
         assert!(config.anthropic_api_key.is_none());
+        assert_eq!(config.provider, "anthropic");
+        assert_eq!(config.request_timeout_secs, 120);
+    }
+
+    #[test]
+    fn test_provider_selects_client() {
+        let config: AppConfig = serde_json::from_str(r#"{"provider": "echo"}"#).unwrap();
+        assert!(config.api_client().is_ok());
+
+        let config = AppConfig { provider: "other".to_string(), ..AppConfig::default() };
+        assert!(config.api_client().err().unwrap().to_string().contains("Unknown provider"));
+    }
+
+    #[test]
+    fn test_run_clients_share_one_circuit_breaker() {
+        let config = AppConfig {
+            provider: "echo".to_string(),
+            circuit_failure_threshold: 2,
+            ..AppConfig::default()
+        };
+        let run_config = RunConfig::from(&config);
+        let governance = config.run_api_client(&run_config).unwrap();
+        let analysis = config.run_api_client(&run_config.clone()).unwrap();
+        assert!(std::sync::Arc::ptr_eq(&governance.circuit_breaker(), &analysis.circuit_breaker()));
+
+        // Opens at the configured threshold, for every client of the run
+        governance.circuit_breaker().record_failure();
+        assert!(!analysis.circuit_breaker().is_open());
+        governance.circuit_breaker().record_failure();
+        assert!(analysis.circuit_breaker().is_open());
+
+        // Another run has its own breaker
+        let other_run = config.run_api_client(&RunConfig::from(&config)).unwrap();
+        assert!(!other_run.circuit_breaker().is_open());
+    }
+
+    #[test]
+    fn test_clients_share_one_configured_rate_limiter() {
+        let config: AppConfig =
+            serde_json::from_str(r#"{"provider": "echo", "requests_per_minute": 10, "max_concurrent_requests": 2}"#).unwrap();
+        let first_run = config.run_api_client(&RunConfig::from(&config)).unwrap();
+        let second_run = config.run_api_client(&RunConfig::from(&config)).unwrap();
+        let model_list = config.clone().api_client().unwrap();
+
+        let limiter = first_run.rate_limiter();
+        assert!(std::sync::Arc::ptr_eq(&limiter, &second_run.rate_limiter()));
+        assert!(std::sync::Arc::ptr_eq(&limiter, &model_list.rate_limiter()));
+        assert_eq!(limiter.requests_per_minute(), 10);
+        assert_eq!(limiter.max_concurrent(), 2);
+    }
+
+    #[test]
+    fn test_export_dir_default_and_override() {
+        let app_data_dir = Path::new("/data/method-vi");
+
+        let config = AppConfig::default();
+        assert_eq!(config.resolve_export_dir(app_data_dir), app_data_dir.join("exports"));
+
+        let config: AppConfig = serde_json::from_str(r#"{"export_dir": "/home/user/reports"}"#).unwrap();
+        assert_eq!(config.resolve_export_dir(app_data_dir), PathBuf::from("/home/user/reports"));
     }
 
     #[test]
@@ -175,4 +541,25 @@ mod tests {
         assert_eq!(config.default_model, deserialized.default_model);
         assert_eq!(config.anthropic_api_key, deserialized.anthropic_api_key);
     }
+
+    #[test]
+    fn test_log_filter_from_module_levels() {
+        assert_eq!(AppConfig::default().log_filter(), None);
+
+        let config = AppConfig {
+            log_levels: HashMap::from([
+                ("agents".to_string(), "debug".to_string()),
+                ("orchestrator".to_string(), "info".to_string()),
+                ("ledger".to_string(), "warn".to_string()),
+                ("api".to_string(), "trace".to_string()),
+            ]),
+            ..AppConfig::default()
+        };
+
+        assert_eq!(
+            config.log_filter().unwrap(),
+            "info,method_vi_lib::agents::orchestrator=info,method_vi_lib::agents=debug,\
+             method_vi_lib::api=trace,method_vi_lib::ledger=warn"
+        );
+    }
 }
@@ -0,0 +1,88 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use tauri::Manager;
+
+/// User-tunable additions to the agent prompts
+///
+/// Stored in `config/prompt_templates.json`. Lens focus entries are keyed by
+/// lens name, then intent category, e.g.
+/// `{"lens_focus": {"Thematic": {"Exploratory": "Probe for emerging themes."}}}`.
+/// Combinations without an entry use the base lens prompt unchanged.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PromptTemplates {
+    #[serde(default)]
+    pub lens_focus: HashMap<String, HashMap<String, String>>,
+}
+
+impl PromptTemplates {
+    /// Get path to prompt_templates.json config file
+    pub fn get_config_path(app_handle: &tauri::AppHandle) -> Result<PathBuf> {
+        let app_data_dir = app_handle
+            .path()
+            .app_data_dir()
+            .context("Failed to get app data directory")?;
+
+        let config_dir = app_data_dir.join("config");
+        std::fs::create_dir_all(&config_dir)
+            .context("Failed to create config directory")?;
+
+        Ok(config_dir.join("prompt_templates.json"))
+    }
+
+    /// Load prompt templates, falling back to none if the file is missing or invalid
+    pub fn load(app_handle: &tauri::AppHandle) -> Self {
+        match Self::get_config_path(app_handle) {
+            Ok(path) => Self::load_from(&path),
+            Err(e) => {
+                log::warn!("Failed to get prompt templates path: {}", e);
+                Self::default()
+            }
+        }
+    }
+
+    /// Load prompt templates from a file, for callers without an app handle
+    pub fn load_from(config_path: &Path) -> Self {
+        match std::fs::read_to_string(config_path) {
+            Ok(contents) => match serde_json::from_str::<PromptTemplates>(&contents) {
+                Ok(templates) => {
+                    log::info!("Loaded prompt templates from config file: {:?}", config_path);
+                    templates
+                }
+                Err(e) => {
+                    log::error!("Failed to parse prompt templates: {}", e);
+                    log::info!("Using base prompts (prompt templates corrupted)");
+                    Self::default()
+                }
+            },
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Self::default(),
+            Err(e) => {
+                log::error!("Failed to read prompt templates: {}", e);
+                Self::default()
+            }
+        }
+    }
+
+    /// Configured focus for a lens under an intent category (names match case-insensitively)
+    pub fn lens_focus(&self, lens: &str, intent_category: &str) -> Option<&str> {
+        self.lens_focus
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(lens))?
+            .1
+            .iter()
+            .find(|(category, _)| category.eq_ignore_ascii_case(intent_category))
+            .map(|(_, focus)| focus.as_str())
+    }
+
+    /// Base lens prompt with the configured focus for this intent category appended
+    pub fn lens_prompt(&self, base_prompt: &str, lens: &str, intent_category: &str) -> String {
+        match self.lens_focus(lens, intent_category) {
+            Some(focus) => format!(
+                "{}\n\nADDITIONAL FOCUS ({} intent):\n{}",
+                base_prompt, intent_category, focus
+            ),
+            None => base_prompt.to_string(),
+        }
+    }
+}
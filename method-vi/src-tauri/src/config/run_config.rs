@@ -0,0 +1,119 @@
+use serde::{Deserialize, Serialize};
+
+use super::AppConfig;
+use crate::agents::governance_telemetry::ThresholdsConfig;
+use crate::agents::{LensOutputFormat, Step4Options};
+use crate::api::circuit_breaker::{DEFAULT_COOLDOWN_SECS, DEFAULT_FAILURE_THRESHOLD};
+use crate::api::{CircuitBreaker, SharedSlot};
+use crate::governance::MetricEnforcementSchedule;
+
+/// Settings frozen for one run when it starts
+///
+/// Captured from the global `AppConfig` at Step 0 and persisted with the run,
+/// so changing settings later does not alter a run already in flight. The
+/// provider and API key are not frozen: they stay global.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RunConfig {
+    /// Claude model for the run's agent calls
+    pub model: String,
+
+    /// Model for short judgment micro-calls
+    pub utility_model: String,
+
+    /// Max tokens for calls that don't set their own limit
+    pub max_tokens: u32,
+
+    /// Sampling defaults for calls that don't set their own (None = API default)
+    #[serde(default)]
+    pub temperature: Option<f32>,
+    #[serde(default)]
+    pub top_p: Option<f32>,
+
+    /// Seconds a single Claude call may take
+    pub request_timeout_secs: u64,
+
+    /// Consecutive failed calls before the run's circuit breaker opens
+    #[serde(default = "default_circuit_failure_threshold")]
+    pub circuit_failure_threshold: u32,
+
+    /// Seconds the open breaker waits before allowing a trial call
+    #[serde(default = "default_circuit_cooldown_secs")]
+    pub circuit_cooldown_secs: u64,
+
+    /// Breaker shared by the run's API clients (not persisted)
+    #[serde(skip)]
+    pub circuit_breaker: SharedSlot<CircuitBreaker>,
+
+    /// Intent drift above which a callout is raised (None = built-in default)
+    #[serde(default)]
+    pub intent_drift_threshold: Option<f64>,
+
+    /// Steps at which each metric can HALT
+    #[serde(default)]
+    pub metric_enforcement: MetricEnforcementSchedule,
+
+    /// Pass, warning and HALT thresholds per metric
+    #[serde(default)]
+    pub metric_thresholds: ThresholdsConfig,
+
+    /// Step 2 calibration overrides EV, IAS and PCI thresholds
+    #[serde(default)]
+    pub calibrated_thresholds: bool,
+
+    /// CI, IAS and EFI are scored with one combined call
+    #[serde(default)]
+    pub batched_metrics: bool,
+
+    /// Analysis targets shorter than this many characters need confirmation at Step 3
+    pub min_analysis_chars: usize,
+
+    /// Whether Step 3 lenses answer in prose or structured JSON
+    #[serde(default)]
+    pub lens_output_format: LensOutputFormat,
+
+    /// Which optional Step 4 sub-steps run
+    #[serde(default)]
+    pub step4_options: Step4Options,
+
+    /// Warning metrics block the gate until acknowledged, like Critical ones
+    #[serde(default)]
+    pub strict_metrics: bool,
+
+    /// A metrics HALT can be overridden with a recorded justification
+    #[serde(default)]
+    pub allow_halt_override: bool,
+}
+
+fn default_circuit_failure_threshold() -> u32 {
+    DEFAULT_FAILURE_THRESHOLD
+}
+
+fn default_circuit_cooldown_secs() -> u64 {
+    DEFAULT_COOLDOWN_SECS
+}
+
+impl From<&AppConfig> for RunConfig {
+    fn from(config: &AppConfig) -> Self {
+        RunConfig {
+            model: config.default_model.clone(),
+            utility_model: config.utility_model.clone(),
+            max_tokens: config.default_max_tokens,
+            temperature: config.default_temperature,
+            top_p: config.default_top_p,
+            request_timeout_secs: config.request_timeout_secs,
+            circuit_failure_threshold: config.circuit_failure_threshold,
+            circuit_cooldown_secs: config.circuit_cooldown_secs,
+            circuit_breaker: SharedSlot::default(),
+            intent_drift_threshold: config.intent_drift_threshold,
+            metric_enforcement: config.metric_enforcement.clone(),
+            metric_thresholds: config.metric_thresholds.clone(),
+            calibrated_thresholds: config.calibrated_thresholds,
+            batched_metrics: config.batched_metrics,
+            min_analysis_chars: config.min_analysis_chars,
+            lens_output_format: config.lens_output_format,
+            step4_options: config.step4_options,
+            strict_metrics: config.strict_metrics,
+            allow_halt_override: config.allow_halt_override,
+        }
+    }
+}
@@ -0,0 +1,102 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Which of a step's ledger rationales to look up
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StepRationale {
+    /// The step's work is done (`step_N_complete`)
+    Completed,
+    /// The gate signal was emitted and the run waits for approval
+    GateRequested,
+    /// A human approved the step's gate
+    GateApproved,
+}
+
+/// Text overrides for one step; unset fields keep the English default
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct StepLabel {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub completed: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gate_requested: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gate_approved: Option<String>,
+}
+
+/// Step names and ledger rationales, keyed by step number (0-6)
+///
+/// Stored in settings as e.g.
+/// `{"step_descriptions": {"2": {"gate_approved": "Kalibrierung freigegeben"}}}`
+/// for localization or house wording. Steps and fields without an entry use
+/// the built-in English text.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct StepDescriptions(HashMap<u8, StepLabel>);
+
+impl StepDescriptions {
+    /// Replace the overrides for one step
+    pub fn with_step(mut self, step: u8, label: StepLabel) -> Self {
+        self.0.insert(step, label);
+        self
+    }
+
+    /// Display name of a step, e.g. "Step 2: Governance Calibration"
+    pub fn name(&self, step: u8) -> String {
+        self.0
+            .get(&step)
+            .and_then(|label| label.name.clone())
+            .unwrap_or_else(|| default_name(step).to_string())
+    }
+
+    /// Ledger rationale for a step event
+    pub fn rationale(&self, step: u8, kind: StepRationale) -> String {
+        let custom = self.0.get(&step).and_then(|label| match kind {
+            StepRationale::Completed => label.completed.clone(),
+            StepRationale::GateRequested => label.gate_requested.clone(),
+            StepRationale::GateApproved => label.gate_approved.clone(),
+        });
+        custom.unwrap_or_else(|| default_rationale(step, kind).to_string())
+    }
+}
+
+fn default_name(step: u8) -> &'static str {
+    match step {
+        0 => "Step 0: Intent Capture",
+        1 => "Step 1: Baseline Establishment",
+        2 => "Step 2: Governance Calibration",
+        3 => "Step 3: Multi-Angle Analysis",
+        4 => "Step 4: Synthesis Lock-In",
+        5 => "Step 5: Structure & Redesign",
+        _ => "Step 6: Validation & Assurance",
+    }
+}
+
+fn default_rationale(step: u8, kind: StepRationale) -> &'static str {
+    use StepRationale::*;
+
+    match (step, kind) {
+        (0, Completed) => "Scope & Pattern Agent completed intent analysis",
+        (0, GateRequested) => "Step 0 complete, awaiting human approval to proceed",
+        (0, GateApproved) => "Human approved progression to Step 1",
+        (1, Completed) => "4 immutable baseline artifacts created and locked",
+        (1, GateRequested) => "Step 1 complete, baseline frozen, awaiting human approval to proceed",
+        (1, GateApproved) => "Human approved baseline freeze - ready to proceed to Step 2",
+        (2, Completed) => "Governance calibration complete, five control domains configured",
+        (2, GateRequested) => "Step 2 complete, governance calibrated, awaiting human approval to proceed",
+        (2, GateApproved) => "Human approved governance calibration - ready to proceed to Step 3",
+        (3, Completed) => "Six-lens analysis complete, integrated diagnostic created",
+        (3, GateRequested) => "Step 3 complete, six-lens analysis complete, awaiting human approval to proceed",
+        (3, GateApproved) => "Human approved multi-angle analysis - ready to proceed to Step 4",
+        (4, Completed) => "Synthesis complete, model locked, ready for redesign",
+        (4, GateRequested) => "Step 4 complete, synthesis locked, awaiting human approval to proceed",
+        (4, GateApproved) => "Human approved synthesis lock-in - ready to proceed to Step 5",
+        (5, Completed) => "Framework architecture complete, ready for implementation",
+        (5, GateRequested) => "Step 5 complete, framework ready, awaiting human approval to proceed to validation",
+        (5, GateApproved) => "Human approved framework architecture - ready to proceed to Step 6",
+        (_, Completed) => "Validation complete",
+        (_, GateRequested) => "Step 6 complete, validation results ready, awaiting human approval",
+        (_, GateApproved) => "Validation complete - run finished (no Step 6.5)",
+    }
+}
@@ -0,0 +1,114 @@
+//! Content normalization shared by metrics, baseline and artifact handling
+//!
+//! Baseline and current sizes must be measured the same way, so frontmatter
+//! splitting and word counting live here rather than in each caller.
+
+/// Return the body of an artifact, without its YAML frontmatter
+///
+/// Frontmatter is a leading `---` line up to the next `---` line. Text without
+/// frontmatter is returned unchanged; unterminated frontmatter yields "".
+pub fn strip_frontmatter(text: &str) -> &str {
+    let trimmed = text.trim_start();
+    let Some(rest) = trimmed.strip_prefix("---") else {
+        return text;
+    };
+    if !(rest.starts_with('\n') || rest.starts_with("\r\n")) {
+        return text;
+    }
+
+    let mut offset = 0;
+    for line in rest.split_inclusive('\n') {
+        offset += line.len();
+        if offset > line.len() && line.trim_end() == "---" {
+            return &rest[offset..];
+        }
+    }
+
+    ""
+}
+
+/// Normalize text for size measurement
+///
+/// Strips frontmatter and code fence delimiters, drops tokens that are pure
+/// markup (`#`, `-`, `|`, `>`...), and collapses whitespace to single spaces.
+/// Code inside fences is kept so it counts like any other content.
+pub fn normalize_for_counting(text: &str) -> String {
+    strip_frontmatter(text)
+        .lines()
+        .filter(|line| !line.trim_start().starts_with("```"))
+        .flat_map(str::split_whitespace)
+        .filter(|token| token.chars().any(char::is_alphanumeric))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Count words in normalized text
+pub fn word_count(text: &str) -> usize {
+    normalize_for_counting(text).split_whitespace().count()
+}
+
+/// First `max_chars` characters of `text`
+///
+/// Slicing by byte length (`&s[..s.len().min(n)]`) panics when byte `n` falls
+/// inside a multi-byte character; this always cuts on a character boundary.
+pub fn truncate_chars(text: &str, max_chars: usize) -> &str {
+    match text.char_indices().nth(max_chars) {
+        Some((end, _)) => &text[..end],
+        None => text,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strip_frontmatter_with_body() {
+        let artifact = "---\nartifact_id: \"a-1\"\nhash: \"abc\"\n---\n\n# Title\nBody text";
+        assert_eq!(strip_frontmatter(artifact), "\n# Title\nBody text");
+    }
+
+    #[test]
+    fn test_strip_frontmatter_without_trailing_body() {
+        assert_eq!(strip_frontmatter("---\nartifact_id: \"a-1\"\n---"), "");
+        assert_eq!(strip_frontmatter("---\nartifact_id: \"a-1\"\n---\n"), "");
+        // Unterminated frontmatter has no body
+        assert_eq!(strip_frontmatter("---\nartifact_id: \"a-1\"\n"), "");
+    }
+
+    #[test]
+    fn test_strip_frontmatter_passthrough() {
+        assert_eq!(strip_frontmatter("# Title\n---\nBody"), "# Title\n---\nBody");
+        assert_eq!(strip_frontmatter("----\nnot frontmatter"), "----\nnot frontmatter");
+    }
+
+    #[test]
+    fn test_word_count_with_code_fences() {
+        let text = "---\nhash: \"abc\"\n---\n# Setup Guide\n\n- Install the tool\n\n```bash\ncargo build --release\n```\n\n| Step | Done |\n|---|---|\n";
+        assert_eq!(
+            normalize_for_counting(text),
+            "Setup Guide Install the tool cargo build --release Step Done"
+        );
+        assert_eq!(word_count(text), 10);
+    }
+
+    #[test]
+    fn test_word_count_collapses_whitespace() {
+        assert_eq!(word_count("  one\ttwo\n\n three  "), 3);
+        assert_eq!(word_count(""), 0);
+    }
+
+    #[test]
+    fn test_truncate_chars_on_multibyte_boundaries() {
+        // 'é' is 2 bytes and straddles byte 200
+        let text = format!("{}é and more", "a".repeat(199));
+        assert_eq!(truncate_chars(&text, 200), format!("{}é", "a".repeat(199)));
+
+        let text = format!("{}🙂🙂 tail", "b".repeat(198));
+        assert_eq!(truncate_chars(&text, 199), format!("{}🙂", "b".repeat(198)));
+
+        assert_eq!(truncate_chars("naïve ✓", 200), "naïve ✓");
+        assert_eq!(truncate_chars("naïve", 3), "naï");
+        assert_eq!(truncate_chars("", 5), "");
+    }
+}
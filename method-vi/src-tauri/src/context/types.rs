@@ -28,6 +28,21 @@ pub enum Role {
     Archivist,
 }
 
+impl Role {
+    /// Check a change of the active role at the gate into `step`
+    ///
+    /// Per the spec the Orchestrator moves Observer → Conductor entering Step 1
+    /// and Conductor → Observer entering Step 3; every other gate keeps the
+    /// active role.
+    pub fn valid_transition(from: &Role, to: &Role, step: u8) -> bool {
+        match step {
+            1 => *from == Role::Observer && *to == Role::Conductor,
+            3 => *from == Role::Conductor && *to == Role::Observer,
+            _ => from == to,
+        }
+    }
+}
+
 /// Operational modes in Method-VI
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Mode {
@@ -90,3 +105,25 @@ pub struct RunContext {
     /// Current signal state
     pub signal: Signal,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_role_transitions() {
+        assert!(Role::valid_transition(&Role::Observer, &Role::Conductor, 1));
+        assert!(Role::valid_transition(&Role::Conductor, &Role::Conductor, 2));
+        assert!(Role::valid_transition(&Role::Conductor, &Role::Observer, 3));
+        for step in 4..=6 {
+            assert!(Role::valid_transition(&Role::Observer, &Role::Observer, step));
+        }
+    }
+
+    #[test]
+    fn test_invalid_role_transition() {
+        // Step 3 hands back to the Observer; staying Conductor is a broken state machine
+        assert!(!Role::valid_transition(&Role::Conductor, &Role::Conductor, 3));
+        assert!(!Role::valid_transition(&Role::Observer, &Role::Conductor, 4));
+    }
+}
@@ -0,0 +1,371 @@
+use anyhow::{Context, Result};
+use rusqlite::types::ValueRef;
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+
+/// Artifact ids belonging to a run, for spine edge lookups
+const RUN_ARTIFACT_IDS: &str = "SELECT id FROM artifacts WHERE run_id = ?1";
+
+/// Rows removed by `delete_run_data`
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RunDeletionSummary {
+    pub runs: usize,
+    pub artifacts: usize,
+    pub spine_edges: usize,
+    pub ledger_entries: usize,
+    pub signals: usize,
+    pub patterns: usize,
+    pub run_tags: usize,
+    /// Glossary terms only this run defined
+    pub glossary_terms: usize,
+    /// Flaws deleted because this run was the only one affected
+    pub flaws_deleted: usize,
+    /// Flaws kept with this run removed from `affected_runs`
+    pub flaws_updated: usize,
+    /// Artifact content files the rows pointed to (not removed here)
+    pub content_paths: Vec<String>,
+}
+
+impl RunDeletionSummary {
+    /// Total number of rows deleted or updated
+    pub fn total_rows(&self) -> usize {
+        self.runs
+            + self.artifacts
+            + self.spine_edges
+            + self.ledger_entries
+            + self.signals
+            + self.patterns
+            + self.run_tags
+            + self.glossary_terms
+            + self.flaws_deleted
+            + self.flaws_updated
+    }
+}
+
+/// Converts a row into a JSON object keyed by column name
+fn row_to_json(row: &rusqlite::Row, columns: &[String]) -> rusqlite::Result<Value> {
+    let mut object = Map::new();
+    for (i, column) in columns.iter().enumerate() {
+        let value = match row.get_ref(i)? {
+            ValueRef::Null => Value::Null,
+            ValueRef::Integer(n) => json!(n),
+            ValueRef::Real(f) => json!(f),
+            ValueRef::Text(text) => json!(String::from_utf8_lossy(text)),
+            ValueRef::Blob(bytes) => json!(bytes),
+        };
+        object.insert(column.clone(), value);
+    }
+    Ok(Value::Object(object))
+}
+
+/// Runs a query taking the run_id as ?1 and returns every row as JSON
+fn query_json(conn: &Connection, sql: &str, run_id: &str) -> Result<Vec<Value>> {
+    let mut stmt = conn.prepare(sql).context("Failed to prepare query")?;
+    let columns: Vec<String> = stmt.column_names().into_iter().map(str::to_string).collect();
+
+    let rows = stmt
+        .query_map([run_id], |row| row_to_json(row, &columns))
+        .context("Failed to query run rows")?
+        .collect::<Result<Vec<_>, _>>()
+        .context("Failed to collect run rows")?;
+
+    Ok(rows)
+}
+
+/// Flaws whose `affected_runs` JSON array lists the run, as (id, remaining runs)
+fn flaws_affecting_run(conn: &Connection, run_id: &str) -> Result<Vec<(i64, Vec<String>)>> {
+    let mut stmt = conn
+        .prepare("SELECT id, affected_runs FROM persistent_flaws WHERE affected_runs IS NOT NULL")
+        .context("Failed to prepare query")?;
+
+    let rows = stmt
+        .query_map([], |row| Ok((row.get::<_, i64>(0)?, row.get::<_, String>(1)?)))
+        .context("Failed to query persistent flaws")?
+        .collect::<Result<Vec<_>, _>>()
+        .context("Failed to collect persistent flaws")?;
+
+    let mut affected = Vec::new();
+    for (id, runs_json) in rows {
+        let runs: Vec<String> = serde_json::from_str(&runs_json).unwrap_or_default();
+        if runs.iter().any(|r| r == run_id) {
+            let remaining = runs.into_iter().filter(|r| r != run_id).collect();
+            affected.push((id, remaining));
+        }
+    }
+
+    Ok(affected)
+}
+
+/// Exports every row belonging to a run as one JSON bundle
+///
+/// Artifact rows include their stored content when the content file can be read.
+pub fn export_run_bundle(conn: &Connection, run_id: &str) -> Result<Value> {
+    let run = query_json(conn, "SELECT * FROM runs WHERE id = ?1", run_id)?
+        .into_iter()
+        .next()
+        .unwrap_or(Value::Null);
+
+    let mut artifacts = query_json(
+        conn,
+        "SELECT * FROM artifacts WHERE run_id = ?1 ORDER BY created_at ASC",
+        run_id,
+    )?;
+    for artifact in &mut artifacts {
+        let content = artifact["content_path"]
+            .as_str()
+            .and_then(|path| std::fs::read_to_string(path).ok());
+        if let Some(content) = content {
+            artifact["content"] = json!(content);
+        }
+    }
+
+    let spine_edges = query_json(
+        conn,
+        &format!(
+            "SELECT * FROM spine_edges WHERE source_id IN ({0}) OR target_id IN ({0}) ORDER BY created_at ASC",
+            RUN_ARTIFACT_IDS
+        ),
+        run_id,
+    )?;
+    let ledger_entries = query_json(conn, "SELECT * FROM ledger_entries WHERE run_id = ?1 ORDER BY id ASC", run_id)?;
+    let signals = query_json(conn, "SELECT * FROM signals WHERE run_id = ?1 ORDER BY id ASC", run_id)?;
+    let patterns = query_json(conn, "SELECT * FROM patterns WHERE source_run_id = ?1", run_id)?;
+    let run_tags = query_json(conn, "SELECT * FROM run_tags WHERE run_id = ?1 ORDER BY tag ASC", run_id)?;
+    let glossary_terms = query_json(conn, "SELECT * FROM glossary_terms WHERE source_run_id = ?1 ORDER BY id ASC", run_id)?;
+
+    let flaw_ids: Vec<i64> = flaws_affecting_run(conn, run_id)?.into_iter().map(|(id, _)| id).collect();
+    let mut flaws = Vec::new();
+    for id in flaw_ids {
+        flaws.extend(query_json(conn, "SELECT * FROM persistent_flaws WHERE id = ?1", &id.to_string())?);
+    }
+
+    Ok(json!({
+        "run_id": run_id,
+        "exported_at": chrono::Utc::now().to_rfc3339(),
+        "run": run,
+        "artifacts": artifacts,
+        "spine_edges": spine_edges,
+        "ledger_entries": ledger_entries,
+        "signals": signals,
+        "patterns": patterns,
+        "run_tags": run_tags,
+        "glossary_terms": glossary_terms,
+        "persistent_flaws": flaws,
+    }))
+}
+
+/// Deletes all rows belonging to a run in a single transaction
+///
+/// Children are removed before the rows they reference so foreign keys hold
+/// throughout. Flaws and glossary terms shared with other runs are kept with
+/// this run removed from `affected_runs` / `defining_runs`. Artifact content
+/// files are left to the caller.
+pub fn delete_run_data(conn: &mut Connection, run_id: &str) -> Result<RunDeletionSummary> {
+    let tx = conn.transaction().context("Failed to start transaction")?;
+    let mut summary = RunDeletionSummary::default();
+
+    {
+        let mut stmt = tx
+            .prepare("SELECT content_path FROM artifacts WHERE run_id = ?1 AND content_path IS NOT NULL")
+            .context("Failed to prepare query")?;
+        summary.content_paths = stmt
+            .query_map([run_id], |row| row.get(0))
+            .context("Failed to query artifact content paths")?
+            .collect::<Result<Vec<String>, _>>()
+            .context("Failed to collect artifact content paths")?;
+    }
+
+    summary.spine_edges = tx
+        .execute(
+            &format!(
+                "DELETE FROM spine_edges WHERE source_id IN ({0}) OR target_id IN ({0})",
+                RUN_ARTIFACT_IDS
+            ),
+            [run_id],
+        )
+        .context("Failed to delete spine edges")?;
+    summary.artifacts = tx
+        .execute("DELETE FROM artifacts WHERE run_id = ?1", [run_id])
+        .context("Failed to delete artifacts")?;
+    summary.ledger_entries = tx
+        .execute("DELETE FROM ledger_entries WHERE run_id = ?1", [run_id])
+        .context("Failed to delete ledger entries")?;
+    summary.signals = tx
+        .execute("DELETE FROM signals WHERE run_id = ?1", [run_id])
+        .context("Failed to delete signals")?;
+    summary.patterns = tx
+        .execute("DELETE FROM patterns WHERE source_run_id = ?1", [run_id])
+        .context("Failed to delete patterns")?;
+    summary.run_tags = tx
+        .execute("DELETE FROM run_tags WHERE run_id = ?1", [run_id])
+        .context("Failed to delete run tags")?;
+    summary.glossary_terms = super::glossary::release_run_terms(&tx, run_id)?;
+
+    for (id, remaining) in flaws_affecting_run(&tx, run_id)? {
+        if remaining.is_empty() {
+            tx.execute("DELETE FROM persistent_flaws WHERE id = ?1", [id])
+                .context("Failed to delete persistent flaw")?;
+            summary.flaws_deleted += 1;
+        } else {
+            tx.execute(
+                "UPDATE persistent_flaws SET affected_runs = ?2 WHERE id = ?1",
+                rusqlite::params![id, serde_json::to_string(&remaining)?],
+            )
+            .context("Failed to update persistent flaw")?;
+            summary.flaws_updated += 1;
+        }
+    }
+
+    summary.runs = tx
+        .execute("DELETE FROM runs WHERE id = ?1", [run_id])
+        .context("Failed to delete run")?;
+
+    tx.commit().context("Failed to commit run deletion")?;
+    Ok(summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::models::{Artifact, Run, SpineEdge};
+    use crate::database::{artifacts, glossary, runs, schema, spine};
+    use chrono::Utc;
+
+    fn setup_test_db() -> Connection {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute("PRAGMA foreign_keys = ON", []).unwrap();
+        schema::create_schema(&conn).unwrap();
+        conn
+    }
+
+    /// Insert a run with one row in every run-scoped table
+    fn insert_run_rows(conn: &Connection, run_id: &str, flaw_runs: &str) {
+        runs::create_run(conn, &Run {
+            id: run_id.to_string(),
+            intent_anchor_hash: "hash".to_string(),
+            created_at: Utc::now(),
+            completed_at: None,
+            final_ci: None,
+            final_ev: None,
+            status: Some("active".to_string()),
+        })
+        .unwrap();
+        runs::add_tag(conn, run_id, "experiment").unwrap();
+
+        for suffix in ["charter", "baseline"] {
+            artifacts::create_artifact(conn, &Artifact {
+                id: format!("{}-{}", run_id, suffix),
+                run_id: run_id.to_string(),
+                r#type: suffix.to_string(),
+                step_origin: 1,
+                hash: "hash".to_string(),
+                is_immutable: true,
+                content_path: Some(format!("/tmp/{}-{}.md", run_id, suffix)),
+                created_at: Utc::now(),
+                parent_hash: None,
+            })
+            .unwrap();
+        }
+        spine::create_spine_edge(conn, &SpineEdge {
+            source_id: format!("{}-baseline", run_id),
+            target_id: format!("{}-charter", run_id),
+            edge_type: "derived_from".to_string(),
+            created_at: Utc::now(),
+        })
+        .unwrap();
+
+        let now = Utc::now().to_rfc3339();
+        conn.execute(
+            "INSERT INTO ledger_entries (run_id, entry_type, hash, created_at) VALUES (?1, 'gate', 'h1', ?2)",
+            [run_id, now.as_str()],
+        )
+        .unwrap();
+        conn.execute(
+            "INSERT INTO signals (run_id, signal_type, timestamp, hash, payload) VALUES (?1, 'Ready_for_Step_1', ?2, 'h2', '{}')",
+            [run_id, now.as_str()],
+        )
+        .unwrap();
+        conn.execute(
+            "INSERT INTO patterns (id, intent_category, created_at, source_run_id) VALUES (?1, 'Analytical', ?2, ?3)",
+            [format!("{}-pattern", run_id).as_str(), now.as_str(), run_id],
+        )
+        .unwrap();
+        let term = |term: &str| crate::agents::GlossaryEntry {
+            term: term.to_string(),
+            definition: "Defined here".to_string(),
+        };
+        glossary::record_glossary(conn, run_id, &[term(&format!("{} term", run_id)), term("Shared term")]).unwrap();
+        conn.execute(
+            "INSERT INTO persistent_flaws (flaw_description, first_seen, last_seen, affected_runs) VALUES ('Weak evidence', ?1, ?1, ?2)",
+            [now.as_str(), flaw_runs],
+        )
+        .unwrap();
+    }
+
+    fn count(conn: &Connection, sql: &str, run_id: &str) -> i64 {
+        conn.query_row(sql, [run_id], |row| row.get(0)).unwrap()
+    }
+
+    #[test]
+    fn test_delete_run_data_removes_all_rows() {
+        let mut conn = setup_test_db();
+        insert_run_rows(&conn, "run-a", r#"["run-a"]"#);
+        insert_run_rows(&conn, "run-b", r#"["run-a", "run-b"]"#);
+
+        let bundle = export_run_bundle(&conn, "run-a").unwrap();
+        assert_eq!(bundle["run"]["id"], "run-a");
+        assert_eq!(bundle["artifacts"].as_array().unwrap().len(), 2);
+        assert_eq!(bundle["spine_edges"].as_array().unwrap().len(), 1);
+        assert_eq!(bundle["persistent_flaws"].as_array().unwrap().len(), 2);
+        assert_eq!(bundle["run_tags"].as_array().unwrap().len(), 1);
+        assert_eq!(bundle["glossary_terms"].as_array().unwrap().len(), 2);
+
+        let summary = delete_run_data(&mut conn, "run-a").unwrap();
+        assert_eq!(summary.runs, 1);
+        assert_eq!(summary.artifacts, 2);
+        assert_eq!(summary.spine_edges, 1);
+        assert_eq!(summary.ledger_entries, 1);
+        assert_eq!(summary.signals, 1);
+        assert_eq!(summary.patterns, 1);
+        assert_eq!(summary.run_tags, 1);
+        assert_eq!(summary.glossary_terms, 1);
+        assert_eq!(summary.flaws_deleted, 1);
+        assert_eq!(summary.flaws_updated, 1);
+        assert_eq!(summary.content_paths.len(), 2);
+
+        for sql in [
+            "SELECT COUNT(*) FROM runs WHERE id = ?1",
+            "SELECT COUNT(*) FROM artifacts WHERE run_id = ?1",
+            "SELECT COUNT(*) FROM ledger_entries WHERE run_id = ?1",
+            "SELECT COUNT(*) FROM signals WHERE run_id = ?1",
+            "SELECT COUNT(*) FROM patterns WHERE source_run_id = ?1",
+            "SELECT COUNT(*) FROM run_tags WHERE run_id = ?1",
+            "SELECT COUNT(*) FROM glossary_terms WHERE source_run_id = ?1",
+            "SELECT COUNT(*) FROM spine_edges WHERE source_id LIKE ?1 || '-%'",
+            "SELECT COUNT(*) FROM persistent_flaws WHERE affected_runs LIKE '%\"' || ?1 || '\"%'",
+        ] {
+            assert_eq!(count(&conn, sql, "run-a"), 0, "{}", sql);
+        }
+
+        // The other run is untouched, its shared flaw no longer lists run-a and
+        // the glossary term both runs defined now belongs to run-b
+        assert_eq!(count(&conn, "SELECT COUNT(*) FROM artifacts WHERE run_id = ?1", "run-b"), 2);
+        assert_eq!(count(&conn, "SELECT COUNT(*) FROM spine_edges WHERE source_id LIKE ?1 || '-%'", "run-b"), 1);
+        let remaining: String = conn
+            .query_row("SELECT affected_runs FROM persistent_flaws", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(remaining, r#"["run-b"]"#);
+        let (source, defining): (String, String) = conn
+            .query_row(
+                "SELECT source_run_id, defining_runs FROM glossary_terms WHERE normalized_term = 'shared term'",
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .unwrap();
+        assert_eq!((source.as_str(), defining.as_str()), ("run-b", r#"["run-b"]"#));
+
+        // Nothing left to delete the second time
+        assert_eq!(delete_run_data(&mut conn, "run-a").unwrap().total_rows(), 0);
+    }
+}
@@ -1,18 +1,692 @@
-// TODO: Implement CRUD operations for artifacts table
-// Reference runs.rs for implementation pattern
-
 use super::models::Artifact;
-use anyhow::Result;
-use rusqlite::Connection;
+use crate::artifacts::{calculate_content_hash, Artifact as ParsedArtifact, ValidationError};
+use anyhow::{Context, Result};
+use rusqlite::{Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+
+/// Maps a row from the artifacts table into an Artifact model
+fn row_to_artifact(row: &rusqlite::Row) -> rusqlite::Result<Artifact> {
+    Ok(Artifact {
+        id: row.get(0)?,
+        run_id: row.get(1)?,
+        r#type: row.get(2)?,
+        step_origin: row.get(3)?,
+        hash: row.get(4)?,
+        is_immutable: row.get(5)?,
+        content_path: row.get(6)?,
+        created_at: row.get::<_, String>(7)?.parse().unwrap(),
+        parent_hash: row.get(8)?,
+    })
+}
+
+/// Checks that no artifact with this ID is stored, in any run
+///
+/// Fails with `ValidationError::DuplicateId` if one is.
+pub fn validate_uniqueness_db(conn: &Connection, artifact_id: &str) -> Result<()> {
+    let exists: bool = conn
+        .query_row("SELECT EXISTS(SELECT 1 FROM artifacts WHERE id = ?1)", [artifact_id], |row| row.get(0))
+        .context("Failed to check artifact ID uniqueness")?;
+
+    if exists {
+        return Err(ValidationError::DuplicateId(artifact_id.to_string()).into());
+    }
+    Ok(())
+}
+
+/// True if a write failed on a UNIQUE or PRIMARY KEY constraint
+fn is_unique_violation(error: &rusqlite::Error) -> bool {
+    matches!(
+        error,
+        rusqlite::Error::SqliteFailure(failure, _)
+            if failure.extended_code == rusqlite::ffi::SQLITE_CONSTRAINT_PRIMARYKEY
+                || failure.extended_code == rusqlite::ffi::SQLITE_CONSTRAINT_UNIQUE
+    )
+}
+
+/// Checks that every dependency in an artifact's frontmatter is stored for its run
+///
+/// Call before persisting a parsed artifact so a broken spine is caught at
+/// write time. Fails with `ValidationError::MissingDependencies` listing the
+/// missing IDs in frontmatter order.
+pub fn validate_dependencies_exist(conn: &Connection, artifact: &ParsedArtifact) -> Result<()> {
+    let frontmatter = &artifact.frontmatter;
+    let mut missing = Vec::new();
+    for dependency in &frontmatter.dependencies {
+        let exists: bool = conn
+            .query_row(
+                "SELECT EXISTS(SELECT 1 FROM artifacts WHERE id = ?1 AND run_id = ?2)",
+                [&dependency.artifact_id, &frontmatter.run_id],
+                |row| row.get(0),
+            )
+            .context("Failed to check artifact dependency")?;
+        if !exists && !missing.contains(&dependency.artifact_id) {
+            missing.push(dependency.artifact_id.clone());
+        }
+    }
+
+    if !missing.is_empty() {
+        return Err(ValidationError::MissingDependencies {
+            artifact_id: frontmatter.artifact_id.clone(),
+            missing,
+        }
+        .into());
+    }
+    Ok(())
+}
+
+/// Creates a new artifact in the database
+///
+/// Artifact IDs are unique across the whole database; re-inserting an ID
+/// fails with `ValidationError::DuplicateId`. The ID is checked up front, and a
+/// constraint failure on the insert maps to the same error, so a concurrent
+/// writer that passes the check at the same time is still caught.
+pub fn create_artifact(conn: &Connection, artifact: &Artifact) -> Result<()> {
+    validate_uniqueness_db(conn, &artifact.id)?;
+
+    let inserted = conn.execute(
+        r#"
+        INSERT INTO artifacts (id, run_id, type, step_origin, hash, is_immutable, content_path, created_at, parent_hash)
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
+        "#,
+        rusqlite::params![
+            artifact.id,
+            artifact.run_id,
+            artifact.r#type,
+            artifact.step_origin,
+            artifact.hash,
+            artifact.is_immutable,
+            artifact.content_path,
+            artifact.created_at.to_rfc3339(),
+            artifact.parent_hash,
+        ],
+    );
+
+    match inserted {
+        Ok(_) => Ok(()),
+        Err(e) if is_unique_violation(&e) => Err(ValidationError::DuplicateId(artifact.id.clone()).into()),
+        Err(e) => Err(anyhow::Error::new(e).context("Failed to create artifact")),
+    }
+}
+
+/// Inserts a batch of artifacts in a single transaction
+///
+/// Either every artifact is committed or, if any insert fails, none are.
+pub fn insert_artifacts_tx(conn: &mut Connection, artifacts: &[Artifact]) -> Result<()> {
+    let tx = conn.transaction().context("Failed to begin artifact transaction")?;
+    insert_artifacts(&tx, artifacts)?;
+    tx.commit().context("Failed to commit artifact transaction")?;
+
+    Ok(())
+}
+
+/// Inserts each artifact within the caller's transaction
+fn insert_artifacts(tx: &rusqlite::Transaction, artifacts: &[Artifact]) -> Result<()> {
+    for artifact in artifacts {
+        create_artifact(tx, artifact)
+            .with_context(|| format!("Failed to insert artifact {}", artifact.id))?;
+    }
+    Ok(())
+}
+
+/// Deletes the rows matching `condition` for a run, with their spine edges
+///
+/// Returns the content paths of the deleted artifacts.
+fn delete_run_artifacts_where(
+    tx: &rusqlite::Transaction,
+    run_id: &str,
+    condition: &str,
+    step: i32,
+) -> Result<Vec<String>> {
+    let selected = format!("SELECT id FROM artifacts WHERE run_id = ?1 AND {}", condition);
+
+    let content_paths = {
+        let mut stmt = tx
+            .prepare(&format!("SELECT content_path FROM artifacts WHERE run_id = ?1 AND {}", condition))
+            .context("Failed to prepare query")?;
+        let paths = stmt
+            .query_map(rusqlite::params![run_id, step], |row| row.get::<_, Option<String>>(0))
+            .context("Failed to query artifacts")?
+            .collect::<Result<Vec<_>, _>>()
+            .context("Failed to collect artifacts")?;
+        paths.into_iter().flatten().collect()
+    };
+
+    tx.execute(
+        &format!("DELETE FROM spine_edges WHERE source_id IN ({0}) OR target_id IN ({0})", selected),
+        rusqlite::params![run_id, step],
+    )
+    .context("Failed to delete spine edges")?;
+    tx.execute(
+        &format!("DELETE FROM artifacts WHERE run_id = ?1 AND {}", condition),
+        rusqlite::params![run_id, step],
+    )
+    .context("Failed to delete artifacts")?;
+
+    Ok(content_paths)
+}
+
+/// Deletes a run's artifacts from `step` onward in a single transaction
+///
+/// Used when a run rolls back to an earlier gate. Returns the content paths of
+/// the deleted rows; the caller removes the files once the delete commits.
+pub fn delete_artifacts_from_step(conn: &mut Connection, run_id: &str, step: i32) -> Result<Vec<String>> {
+    let tx = conn.transaction().context("Failed to begin artifact transaction")?;
+    let content_paths = delete_run_artifacts_where(&tx, run_id, "step_origin >= ?2", step)?;
+    tx.commit().context("Failed to commit artifact deletion")?;
+
+    Ok(content_paths)
+}
+
+/// Replaces a run's stored artifacts for one step in a single transaction
+///
+/// Rows left by an earlier execution of the step (a re-run after a HALT
+/// override, say) are deleted before the new batch is inserted, so either the
+/// whole replacement commits or nothing changes. Returns the content paths of
+/// the replaced rows.
+pub fn replace_step_artifacts_tx(
+    conn: &mut Connection,
+    run_id: &str,
+    step: i32,
+    artifacts: &[Artifact],
+) -> Result<Vec<String>> {
+    let tx = conn.transaction().context("Failed to begin artifact transaction")?;
+
+    let replaced = delete_run_artifacts_where(&tx, run_id, "step_origin = ?2", step)?;
+    insert_artifacts(&tx, artifacts)?;
+
+    tx.commit().context("Failed to commit artifact transaction")?;
+
+    Ok(replaced)
+}
+
+/// Gets an artifact by ID
+pub fn get_artifact(conn: &Connection, id: &str) -> Result<Option<Artifact>> {
+    let mut stmt = conn
+        .prepare("SELECT id, run_id, type, step_origin, hash, is_immutable, content_path, created_at, parent_hash FROM artifacts WHERE id = ?1")
+        .context("Failed to prepare query")?;
+
+    let artifact = stmt
+        .query_row([id], row_to_artifact)
+        .optional()
+        .context("Failed to query artifact")?;
+
+    Ok(artifact)
+}
+
+/// Lists all artifacts for a run, in the order they were produced
+pub fn list_artifacts_by_run(conn: &Connection, run_id: &str) -> Result<Vec<Artifact>> {
+    let mut stmt = conn
+        .prepare("SELECT id, run_id, type, step_origin, hash, is_immutable, content_path, created_at, parent_hash FROM artifacts WHERE run_id = ?1 ORDER BY step_origin ASC, created_at ASC")
+        .context("Failed to prepare query")?;
+
+    let artifacts = stmt
+        .query_map([run_id], row_to_artifact)
+        .context("Failed to query artifacts")?
+        .collect::<Result<Vec<_>, _>>()
+        .context("Failed to collect artifacts")?;
+
+    Ok(artifacts)
+}
+
+/// A stored artifact whose content no longer hashes to its recorded hash
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HashMismatch {
+    pub artifact_id: String,
+    pub run_id: String,
+    pub content_path: String,
+    pub stored_hash: String,
+    /// None when the content file could not be read
+    pub computed_hash: Option<String>,
+}
+
+/// Recomputes the content hash of every stored artifact and reports mismatches
+///
+/// Flags artifacts whose content changed on disk, or whose hash was produced by
+/// an older template. Content is stored with surrounding whitespace trimmed, so
+/// a hash of either the stored or the trimmed text counts as a match. Pruned
+/// artifacts (no `content_path`) have nothing to recompute and are skipped.
+pub fn audit_hashes(conn: &Connection) -> Result<Vec<HashMismatch>> {
+    let mut stmt = conn
+        .prepare("SELECT id, run_id, content_path, hash FROM artifacts WHERE content_path IS NOT NULL ORDER BY run_id ASC, step_origin ASC, id ASC")
+        .context("Failed to prepare query")?;
+    let rows = stmt
+        .query_map([], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?, row.get::<_, String>(2)?, row.get::<_, String>(3)?))
+        })
+        .context("Failed to query artifacts")?
+        .collect::<Result<Vec<_>, _>>()
+        .context("Failed to collect artifacts")?;
+
+    let mut mismatches = Vec::new();
+    for (artifact_id, run_id, content_path, stored_hash) in rows {
+        let computed_hash = match std::fs::read_to_string(&content_path) {
+            Ok(content) => {
+                let hash = calculate_content_hash(&content);
+                if hash == stored_hash || calculate_content_hash(content.trim()) == stored_hash {
+                    continue;
+                }
+                Some(hash)
+            }
+            Err(e) => {
+                log::warn!("Cannot read content of artifact {} at {}: {}", artifact_id, content_path, e);
+                None
+            }
+        };
+        mismatches.push(HashMismatch { artifact_id, run_id, content_path, stored_hash, computed_hash });
+    }
+
+    Ok(mismatches)
+}
 
-pub fn create_artifact(_conn: &Connection, _artifact: &Artifact) -> Result<()> {
-    todo!("Implement create_artifact")
+/// Artifacts whose stored content was released by `prune_content`
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PrunedContent {
+    pub artifact_ids: Vec<String>,
+    /// Content files the rows pointed to (not removed here)
+    pub content_paths: Vec<String>,
 }
 
-pub fn get_artifact(_conn: &Connection, _id: &str) -> Result<Option<Artifact>> {
-    todo!("Implement get_artifact")
+/// Drops stored content of older mutable artifacts, keeping hashes and metadata
+///
+/// Immutable artifacts (the Critical Path and any configured extras) and every
+/// artifact from the run's latest `keep_steps` steps keep their content; at
+/// least the latest step is always kept. Pruned rows stay in place with their
+/// hash and a `pruned_at` time, so exported content can still be verified
+/// against them. Content files are left to the caller.
+pub fn prune_content(conn: &mut Connection, run_id: &str, keep_steps: usize) -> Result<PrunedContent> {
+    let tx = conn.transaction().context("Failed to start transaction")?;
+    let mut pruned = PrunedContent::default();
+
+    let oldest_kept_step: Option<i32> = tx
+        .query_row(
+            "SELECT MIN(step_origin) FROM (SELECT DISTINCT step_origin FROM artifacts WHERE run_id = ?1 ORDER BY step_origin DESC LIMIT ?2)",
+            rusqlite::params![run_id, keep_steps.max(1) as i64],
+            |row| row.get(0),
+        )
+        .context("Failed to find retained steps")?;
+    let Some(oldest_kept_step) = oldest_kept_step else {
+        return Ok(pruned);
+    };
+
+    {
+        let mut stmt = tx
+            .prepare(
+                "SELECT id, content_path FROM artifacts \
+                 WHERE run_id = ?1 AND is_immutable = 0 AND step_origin < ?2 AND content_path IS NOT NULL",
+            )
+            .context("Failed to prepare query")?;
+        let rows = stmt
+            .query_map(rusqlite::params![run_id, oldest_kept_step], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+            })
+            .context("Failed to query prunable artifacts")?
+            .collect::<Result<Vec<_>, _>>()
+            .context("Failed to collect prunable artifacts")?;
+        for (id, path) in rows {
+            pruned.artifact_ids.push(id);
+            pruned.content_paths.push(path);
+        }
+    }
+
+    for id in &pruned.artifact_ids {
+        tx.execute("UPDATE artifacts SET content_path = NULL, pruned_at = datetime('now') WHERE id = ?1", [id])
+            .with_context(|| format!("Failed to prune content of artifact {}", id))?;
+    }
+
+    tx.commit().context("Failed to commit pruning")?;
+
+    Ok(pruned)
 }
 
-pub fn list_artifacts_by_run(_conn: &Connection, _run_id: &str) -> Result<Vec<Artifact>> {
-    todo!("Implement list_artifacts_by_run")
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::models::Run;
+    use crate::database::{runs, schema};
+    use chrono::Utc;
+
+    /// Helper function to create an in-memory test database
+    fn setup_test_db() -> Result<Connection> {
+        let conn = Connection::open_in_memory()
+            .context("Failed to create in-memory database")?;
+
+        conn.execute("PRAGMA foreign_keys = ON", [])
+            .context("Failed to enable foreign keys")?;
+
+        schema::create_schema(&conn)
+            .context("Failed to create schema")?;
+
+        Ok(conn)
+    }
+
+    #[test]
+    fn test_artifact_crud_by_run() {
+        let conn = setup_test_db().expect("Failed to initialize test database");
+
+        let run = Run {
+            id: "2025-12-17-Artifact-Run".to_string(),
+            intent_anchor_hash: "abc123".to_string(),
+            created_at: Utc::now(),
+            completed_at: None,
+            final_ci: None,
+            final_ev: None,
+            status: Some("active".to_string()),
+        };
+        runs::create_run(&conn, &run).expect("Failed to create run");
+
+        for (step, (suffix, artifact_type, immutable)) in [
+            ("intent-anchor", "IntentAnchor", true),
+            ("governance-summary", "GovernanceSummary", false),
+        ]
+        .iter()
+        .enumerate()
+        {
+            let artifact = Artifact {
+                id: format!("{}-{}", run.id, suffix),
+                run_id: run.id.clone(),
+                r#type: artifact_type.to_string(),
+                step_origin: (step * 2 + 1) as i32,
+                hash: format!("hash-{}", suffix),
+                is_immutable: *immutable,
+                content_path: None,
+                created_at: Utc::now(),
+                parent_hash: None,
+            };
+            create_artifact(&conn, &artifact).expect("Failed to create artifact");
+        }
+
+        let anchor = get_artifact(&conn, "2025-12-17-Artifact-Run-intent-anchor")
+            .expect("Failed to get artifact")
+            .expect("Artifact not found");
+        assert_eq!(anchor.r#type, "IntentAnchor");
+        assert!(anchor.is_immutable);
+
+        let listed = list_artifacts_by_run(&conn, &run.id).expect("Failed to list artifacts");
+        assert_eq!(listed.len(), 2);
+        assert_eq!(listed[0].step_origin, 1);
+        assert_eq!(listed[1].r#type, "GovernanceSummary");
+
+        assert!(list_artifacts_by_run(&conn, "missing-run").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_duplicate_artifact_id_is_rejected() {
+        let mut conn = setup_test_db().expect("Failed to initialize test database");
+
+        for run_id in ["2025-12-17-Run-A", "2025-12-17-Run-B"] {
+            runs::create_run(&conn, &Run {
+                id: run_id.to_string(),
+                intent_anchor_hash: "abc123".to_string(),
+                created_at: Utc::now(),
+                completed_at: None,
+                final_ci: None,
+                final_ev: None,
+                status: Some("active".to_string()),
+            })
+            .expect("Failed to create run");
+        }
+
+        let artifact = |run_id: &str| Artifact {
+            id: "shared-charter".to_string(),
+            run_id: run_id.to_string(),
+            r#type: "Charter".to_string(),
+            step_origin: 1,
+            hash: "hash-charter".to_string(),
+            is_immutable: true,
+            content_path: None,
+            created_at: Utc::now(),
+            parent_hash: None,
+        };
+
+        validate_uniqueness_db(&conn, "shared-charter").expect("ID should be free");
+        create_artifact(&conn, &artifact("2025-12-17-Run-A")).expect("Failed to create artifact");
+        let err = validate_uniqueness_db(&conn, "shared-charter").unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<ValidationError>(),
+            Some(ValidationError::DuplicateId(id)) if id == "shared-charter"
+        ));
+
+        // Re-insert within the run, and the same ID from another run
+        for run_id in ["2025-12-17-Run-A", "2025-12-17-Run-B"] {
+            let err = create_artifact(&conn, &artifact(run_id)).unwrap_err();
+            assert!(matches!(
+                err.downcast_ref::<ValidationError>(),
+                Some(ValidationError::DuplicateId(id)) if id == "shared-charter"
+            ));
+        }
+
+        let err = insert_artifacts_tx(&mut conn, &[artifact("2025-12-17-Run-B")]).unwrap_err();
+        assert!(matches!(err.downcast_ref::<ValidationError>(), Some(ValidationError::DuplicateId(_))));
+        assert!(list_artifacts_by_run(&conn, "2025-12-17-Run-B").unwrap().is_empty());
+
+        // A write that skipped the check still hits the constraint
+        let raw = conn
+            .execute(
+                "INSERT INTO artifacts (id, run_id, type, step_origin, hash, is_immutable, created_at) VALUES (?1, ?2, 'Charter', 1, 'hash', 1, ?3)",
+                rusqlite::params!["shared-charter", "2025-12-17-Run-B", Utc::now().to_rfc3339()],
+            )
+            .unwrap_err();
+        assert!(is_unique_violation(&raw));
+    }
+
+    #[test]
+    fn test_validate_dependencies_exist_reports_missing_ids() {
+        use crate::artifacts::{ArtifactDependency, ArtifactFrontmatter, ArtifactType, GovernanceRole, Relationship, ARTIFACT_TEMPLATE_VERSION};
+
+        let conn = setup_test_db().expect("Failed to initialize test database");
+        let run_id = "2025-12-17-Dependency-Run";
+        runs::create_run(&conn, &Run {
+            id: run_id.to_string(),
+            intent_anchor_hash: "abc123".to_string(),
+            created_at: Utc::now(),
+            completed_at: None,
+            final_ci: None,
+            final_ev: None,
+            status: Some("active".to_string()),
+        })
+        .expect("Failed to create run");
+        create_artifact(&conn, &Artifact {
+            id: "intent-anchor-001".to_string(),
+            run_id: run_id.to_string(),
+            r#type: "IntentAnchor".to_string(),
+            step_origin: 1,
+            hash: "hash-anchor".to_string(),
+            is_immutable: true,
+            content_path: None,
+            created_at: Utc::now(),
+            parent_hash: None,
+        })
+        .expect("Failed to create artifact");
+
+        let dependency = |artifact_id: &str| ArtifactDependency {
+            artifact_id: artifact_id.to_string(),
+            relationship: Relationship::DerivedFrom,
+        };
+        let mut charter = ParsedArtifact {
+            frontmatter: ArtifactFrontmatter {
+                artifact_id: "charter-001".to_string(),
+                artifact_type: ArtifactType::Charter,
+                run_id: run_id.to_string(),
+                step_origin: 1,
+                created_at: "2025-12-17T00:00:00Z".to_string(),
+                hash: "hash-charter".to_string(),
+                parent_hash: Some("hash-anchor".to_string()),
+                dependencies: vec![dependency("intent-anchor-001")],
+                intent_anchor_link: Some("intent-anchor-001".to_string()),
+                is_immutable: true,
+                author: "tester".to_string(),
+                governance_role: GovernanceRole::Conductor,
+                template_version: ARTIFACT_TEMPLATE_VERSION,
+            },
+            content: "# Charter".to_string(),
+        };
+        validate_dependencies_exist(&conn, &charter).expect("Stored dependency should pass");
+
+        charter.frontmatter.dependencies.push(dependency("baseline-report-404"));
+        let err = validate_dependencies_exist(&conn, &charter).unwrap_err();
+        match err.downcast_ref::<ValidationError>() {
+            Some(ValidationError::MissingDependencies { artifact_id, missing }) => {
+                assert_eq!(artifact_id, "charter-001");
+                assert_eq!(missing, &vec!["baseline-report-404".to_string()]);
+            }
+            other => panic!("expected missing dependencies, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_insert_artifacts_tx_rolls_back_on_failure() {
+        let mut conn = setup_test_db().expect("Failed to initialize test database");
+
+        let run = Run {
+            id: "2025-12-17-Batch-Run".to_string(),
+            intent_anchor_hash: "abc123".to_string(),
+            created_at: Utc::now(),
+            completed_at: None,
+            final_ci: None,
+            final_ev: None,
+            status: Some("active".to_string()),
+        };
+        runs::create_run(&conn, &run).expect("Failed to create run");
+
+        let artifact = |id: &str, run_id: &str| Artifact {
+            id: id.to_string(),
+            run_id: run_id.to_string(),
+            r#type: "IntentAnchor".to_string(),
+            step_origin: 1,
+            hash: format!("hash-{}", id),
+            is_immutable: true,
+            content_path: None,
+            created_at: Utc::now(),
+            parent_hash: None,
+        };
+
+        // The last artifact references a run that does not exist
+        let batch = vec![
+            artifact("batch-intent-anchor", &run.id),
+            artifact("batch-charter", &run.id),
+            artifact("batch-orphan", "missing-run"),
+        ];
+        assert!(insert_artifacts_tx(&mut conn, &batch).is_err());
+        assert!(list_artifacts_by_run(&conn, &run.id).unwrap().is_empty());
+        assert!(get_artifact(&conn, "batch-intent-anchor").unwrap().is_none());
+
+        insert_artifacts_tx(&mut conn, &batch[..2]).expect("Failed to insert batch");
+        assert_eq!(list_artifacts_by_run(&conn, &run.id).unwrap().len(), 2);
+
+        // Re-running the step replaces its rows; a failed replacement keeps the old ones
+        assert!(replace_step_artifacts_tx(&mut conn, &run.id, 1, &batch[1..]).is_err());
+        assert_eq!(list_artifacts_by_run(&conn, &run.id).unwrap().len(), 2);
+        replace_step_artifacts_tx(&mut conn, &run.id, 1, &batch[1..2]).expect("Failed to replace batch");
+        let listed = list_artifacts_by_run(&conn, &run.id).unwrap();
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].id, "batch-charter");
+
+        let deleted = delete_artifacts_from_step(&mut conn, &run.id, 1).expect("Failed to delete");
+        assert!(deleted.is_empty());
+        assert!(list_artifacts_by_run(&conn, &run.id).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_audit_hashes_flags_corrupted_hash() {
+        let conn = setup_test_db().expect("Failed to initialize test database");
+        let run = Run {
+            id: "2025-12-17-Hash-Audit".to_string(),
+            intent_anchor_hash: "abc123".to_string(),
+            created_at: Utc::now(),
+            completed_at: None,
+            final_ci: None,
+            final_ev: None,
+            status: Some("active".to_string()),
+        };
+        runs::create_run(&conn, &run).expect("Failed to create run");
+
+        let dir = std::env::temp_dir().join(format!("method-vi-hash-audit-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let content = "# Charter\n\nShorten onboarding to two weeks.";
+        let path = dir.join("charter.md");
+        std::fs::write(&path, format!("{}\n", content)).unwrap();
+
+        let artifact = Artifact {
+            id: format!("{}-charter", run.id),
+            run_id: run.id.clone(),
+            r#type: "Charter".to_string(),
+            step_origin: 1,
+            hash: calculate_content_hash(content),
+            is_immutable: true,
+            content_path: Some(path.to_string_lossy().to_string()),
+            created_at: Utc::now(),
+            parent_hash: None,
+        };
+        create_artifact(&conn, &artifact).expect("Failed to create artifact");
+        assert!(audit_hashes(&conn).unwrap().is_empty());
+
+        conn.execute("UPDATE artifacts SET hash = 'corrupted' WHERE id = ?1", [&artifact.id]).unwrap();
+        let mismatches = audit_hashes(&conn).unwrap();
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!(mismatches[0].artifact_id, artifact.id);
+        assert_eq!(mismatches[0].stored_hash, "corrupted");
+        assert_eq!(mismatches[0].computed_hash, Some(calculate_content_hash(&format!("{}\n", content))));
+
+        // Missing content is reported without a computed hash
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(audit_hashes(&conn).unwrap()[0].computed_hash, None);
+    }
+
+    #[test]
+    fn test_prune_content_keeps_hashes_and_ids() {
+        let mut conn = setup_test_db().expect("Failed to initialize test database");
+
+        let run = Run {
+            id: "2025-12-17-Prune-Run".to_string(),
+            intent_anchor_hash: "abc123".to_string(),
+            created_at: Utc::now(),
+            completed_at: None,
+            final_ci: None,
+            final_ev: None,
+            status: Some("active".to_string()),
+        };
+        runs::create_run(&conn, &run).expect("Failed to create run");
+
+        let batch: Vec<Artifact> = [
+            ("charter", "Charter", 1, true),
+            ("governance-summary", "GovernanceSummary", 2, false),
+            ("diagnostic-summary", "DiagnosticSummary", 3, false),
+            ("lens-efficacy-report", "LensEfficacyReport", 3, false),
+        ]
+        .iter()
+        .map(|(suffix, artifact_type, step, immutable)| Artifact {
+            id: format!("{}-{}", run.id, suffix),
+            run_id: run.id.clone(),
+            r#type: artifact_type.to_string(),
+            step_origin: *step,
+            hash: format!("hash-{}", suffix),
+            is_immutable: *immutable,
+            content_path: Some(format!("/tmp/{}.md", suffix)),
+            created_at: Utc::now(),
+            parent_hash: None,
+        })
+        .collect();
+        for step in 1..=3 {
+            let rows: Vec<Artifact> = batch.iter().filter(|a| a.step_origin == step).cloned().collect();
+            replace_step_artifacts_tx(&mut conn, &run.id, step, &rows).expect("Failed to insert batch");
+        }
+
+        let pruned = prune_content(&mut conn, &run.id, 1).expect("Failed to prune");
+        assert_eq!(pruned.artifact_ids, vec![format!("{}-governance-summary", run.id)]);
+        assert_eq!(pruned.content_paths, vec!["/tmp/governance-summary.md".to_string()]);
+
+        let summary = get_artifact(&conn, &pruned.artifact_ids[0]).unwrap().expect("Pruned artifact kept");
+        assert_eq!(summary.content_path, None);
+        assert_eq!(summary.hash, "hash-governance-summary");
+        assert_eq!(summary.step_origin, 2);
+
+        // Critical Path and latest-step content survive
+        let listed = list_artifacts_by_run(&conn, &run.id).unwrap();
+        assert_eq!(listed.len(), 4);
+        assert_eq!(listed.iter().filter(|a| a.content_path.is_some()).count(), 3);
+
+        // Pruning again finds nothing; an unknown run is a no-op
+        assert!(prune_content(&mut conn, &run.id, 1).unwrap().artifact_ids.is_empty());
+        assert_eq!(prune_content(&mut conn, "missing-run", 1).unwrap(), PrunedContent::default());
+    }
 }
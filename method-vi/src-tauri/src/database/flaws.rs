@@ -1,22 +1,198 @@
-// TODO: Implement CRUD operations for persistent_flaws table
-// Reference runs.rs for implementation pattern
-
 use super::models::PersistentFlaw;
-use anyhow::Result;
-use rusqlite::Connection;
+use anyhow::{Context, Result};
+use chrono::Utc;
+use rusqlite::{Connection, OptionalExtension};
+
+/// Flaw type for points where Step 3 lenses disagree
+pub const FLAW_TYPE_CONTRADICTION: &str = "Contradiction";
+
+const FLAW_COLUMNS: &str = "id, flaw_description, occurrence_count, first_seen, last_seen, \
+    affected_runs, resolution_status, policy_ticket, flaw_type";
+
+/// Maps a row from the persistent_flaws table into a PersistentFlaw model
+fn row_to_flaw(row: &rusqlite::Row) -> rusqlite::Result<PersistentFlaw> {
+    Ok(PersistentFlaw {
+        id: row.get(0)?,
+        flaw_description: row.get(1)?,
+        occurrence_count: row.get(2)?,
+        first_seen: row.get::<_, String>(3)?.parse().unwrap(),
+        last_seen: row.get::<_, String>(4)?.parse().unwrap(),
+        affected_runs: row.get(5)?,
+        resolution_status: row.get(6)?,
+        policy_ticket: row.get(7)?,
+        flaw_type: row.get(8)?,
+    })
+}
+
+/// Creates a new flaw in the database
+pub fn create_flaw(conn: &Connection, flaw: &PersistentFlaw) -> Result<()> {
+    conn.execute(
+        r#"
+        INSERT INTO persistent_flaws (flaw_description, occurrence_count, first_seen, last_seen, affected_runs, resolution_status, policy_ticket, flaw_type)
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+        "#,
+        rusqlite::params![
+            flaw.flaw_description,
+            flaw.occurrence_count,
+            flaw.first_seen.to_rfc3339(),
+            flaw.last_seen.to_rfc3339(),
+            flaw.affected_runs,
+            flaw.resolution_status,
+            flaw.policy_ticket,
+            flaw.flaw_type,
+        ],
+    )
+    .context("Failed to create flaw")?;
+
+    Ok(())
+}
+
+/// Gets a flaw by ID
+pub fn get_flaw(conn: &Connection, id: i64) -> Result<Option<PersistentFlaw>> {
+    let mut stmt = conn
+        .prepare(&format!("SELECT {} FROM persistent_flaws WHERE id = ?1", FLAW_COLUMNS))
+        .context("Failed to prepare query")?;
 
-pub fn create_flaw(_conn: &Connection, _flaw: &PersistentFlaw) -> Result<()> {
-    todo!("Implement create_flaw")
+    let flaw = stmt
+        .query_row([id], row_to_flaw)
+        .optional()
+        .context("Failed to query flaw")?;
+
+    Ok(flaw)
 }
 
-pub fn get_flaw(_conn: &Connection, _id: i64) -> Result<Option<PersistentFlaw>> {
-    todo!("Implement get_flaw")
+/// Lists flaws that are not resolved, most recently seen first
+pub fn list_open_flaws(conn: &Connection) -> Result<Vec<PersistentFlaw>> {
+    let mut stmt = conn
+        .prepare(&format!(
+            "SELECT {} FROM persistent_flaws WHERE resolution_status IS NULL OR resolution_status = 'open' \
+             ORDER BY last_seen DESC, id ASC",
+            FLAW_COLUMNS
+        ))
+        .context("Failed to prepare query")?;
+
+    let flaws = stmt
+        .query_map([], row_to_flaw)
+        .context("Failed to query flaws")?
+        .collect::<Result<Vec<_>, _>>()
+        .context("Failed to collect flaws")?;
+
+    Ok(flaws)
 }
 
-pub fn list_open_flaws(_conn: &Connection) -> Result<Vec<PersistentFlaw>> {
-    todo!("Implement list_open_flaws")
+/// Counts another occurrence of a flaw and refreshes its last_seen time
+pub fn update_flaw_occurrence(conn: &Connection, id: i64) -> Result<()> {
+    let rows_affected = conn
+        .execute(
+            "UPDATE persistent_flaws SET occurrence_count = occurrence_count + 1, last_seen = ?2 WHERE id = ?1",
+            rusqlite::params![id, Utc::now().to_rfc3339()],
+        )
+        .context("Failed to update flaw occurrence")?;
+
+    if rows_affected == 0 {
+        anyhow::bail!("Flaw not found: {}", id);
+    }
+
+    Ok(())
 }
 
-pub fn update_flaw_occurrence(_conn: &Connection, _id: i64) -> Result<()> {
-    todo!("Implement update_flaw_occurrence")
+/// New open Contradiction flaws for the contradictions found in one run
+pub fn contradiction_flaws(run_id: &str, contradictions: &[String]) -> Vec<PersistentFlaw> {
+    let now = Utc::now();
+    contradictions
+        .iter()
+        .map(|description| PersistentFlaw {
+            id: None,
+            flaw_description: description.clone(),
+            occurrence_count: 1,
+            first_seen: now,
+            last_seen: now,
+            affected_runs: Some(serde_json::json!([run_id]).to_string()),
+            resolution_status: Some("open".to_string()),
+            policy_ticket: None,
+            flaw_type: Some(FLAW_TYPE_CONTRADICTION.to_string()),
+        })
+        .collect()
+}
+
+/// Stores a run's contradictions as flaws, returning their IDs
+///
+/// A contradiction already recorded as an open flaw with the same description
+/// gets another occurrence (and the run added to its affected runs) instead of
+/// a duplicate row.
+pub fn record_contradictions(conn: &Connection, run_id: &str, contradictions: &[String]) -> Result<Vec<i64>> {
+    let mut ids = Vec::with_capacity(contradictions.len());
+
+    for flaw in contradiction_flaws(run_id, contradictions) {
+        let existing = list_open_flaws(conn)?.into_iter().find(|open| {
+            open.flaw_type.as_deref() == Some(FLAW_TYPE_CONTRADICTION) && open.flaw_description == flaw.flaw_description
+        });
+
+        match existing.and_then(|open| open.id.map(|id| (id, open.affected_runs))) {
+            Some((id, affected_runs)) => {
+                update_flaw_occurrence(conn, id)?;
+                let mut runs: Vec<String> = affected_runs
+                    .and_then(|runs| serde_json::from_str(&runs).ok())
+                    .unwrap_or_default();
+                if !runs.iter().any(|run| run == run_id) {
+                    runs.push(run_id.to_string());
+                    conn.execute(
+                        "UPDATE persistent_flaws SET affected_runs = ?2 WHERE id = ?1",
+                        rusqlite::params![id, serde_json::to_string(&runs)?],
+                    )
+                    .context("Failed to update affected runs")?;
+                }
+                ids.push(id);
+            }
+            None => {
+                create_flaw(conn, &flaw)?;
+                ids.push(conn.last_insert_rowid());
+            }
+        }
+    }
+
+    Ok(ids)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agents::analysis_synthesis::parse_contradictions;
+    use crate::database::schema;
+
+    #[test]
+    fn test_integration_contradictions_become_flaws() {
+        let conn = Connection::open_in_memory().unwrap();
+        schema::create_schema(&conn).unwrap();
+
+        let response = "**Integrated Insights:**\nThe lenses broadly agree.\n\n\
+            **Common Patterns:**\n- Onboarding is front-loaded\n\n\
+            **Contradictions to Resolve:**\n\
+            - Structural lens finds the phases clear, Expression lens finds them confusing\n\
+            - Evidence lens doubts the retention claim the Intent lens relies on\n\n\
+            **Priority Areas:**\n1. Clarify phase boundaries";
+        let contradictions = parse_contradictions(response);
+        assert_eq!(contradictions.len(), 2);
+
+        let ids = record_contradictions(&conn, "run-001", &contradictions).unwrap();
+        assert_eq!(ids.len(), 2);
+
+        let flaws = list_open_flaws(&conn).unwrap();
+        assert_eq!(flaws.len(), 2);
+        assert!(flaws.iter().all(|flaw| flaw.flaw_type.as_deref() == Some(FLAW_TYPE_CONTRADICTION)));
+        let first = get_flaw(&conn, ids[0]).unwrap().unwrap();
+        assert_eq!(first.flaw_description, contradictions[0]);
+        assert_eq!(first.affected_runs.as_deref(), Some(r#"["run-001"]"#));
+
+        // Seen again in another run: counted, not duplicated
+        record_contradictions(&conn, "run-002", &contradictions[..1]).unwrap();
+        let first = get_flaw(&conn, ids[0]).unwrap().unwrap();
+        assert_eq!(first.occurrence_count, 2);
+        assert_eq!(first.affected_runs.as_deref(), Some(r#"["run-001","run-002"]"#));
+        assert_eq!(list_open_flaws(&conn).unwrap().len(), 2);
+
+        // No contradictions section, or one that lists none
+        assert!(parse_contradictions("**Integrated Insights:**\nAll consistent.").is_empty());
+        assert!(parse_contradictions("**Contradictions to Resolve:**\n- None identified\n\n**Priority Areas:**").is_empty());
+    }
 }
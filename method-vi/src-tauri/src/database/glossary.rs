@@ -0,0 +1,182 @@
+use crate::agents::GlossaryEntry;
+use anyhow::{Context, Result};
+use chrono::Utc;
+use rusqlite::Connection;
+
+/// Comparison form of a glossary term
+///
+/// Lowercase, punctuation and hyphens as single spaces, and a plural last word
+/// made singular (roughly), so "Coherence-Indexes" style variants of one term
+/// collide.
+pub fn normalize_term(term: &str) -> String {
+    let lowered: String = term
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { ' ' })
+        .collect();
+    let mut words: Vec<&str> = lowered.split_whitespace().collect();
+
+    if let Some(last) = words.last_mut() {
+        let singular_s = ["ss", "is", "us"].iter().any(|ending| last.ends_with(ending));
+        if last.len() > 3 && last.ends_with('s') && !singular_s {
+            *last = last.strip_suffix("es").filter(|stem| stem.ends_with('x')).unwrap_or(&last[..last.len() - 1]);
+        }
+    }
+
+    words.join(" ")
+}
+
+/// Adds a run's glossary to the shared store, returning how many terms were new
+///
+/// A term that normalizes to one already stored is a near-duplicate: the
+/// established definition is kept and the new one is not stored, but the run
+/// is added to the term's `defining_runs` so the term outlives the run that
+/// stored it first.
+pub fn record_glossary(conn: &Connection, run_id: &str, entries: &[GlossaryEntry]) -> Result<usize> {
+    let now = Utc::now().to_rfc3339();
+    let mut added = 0;
+
+    for entry in entries {
+        let normalized = normalize_term(&entry.term);
+        if normalized.is_empty() {
+            continue;
+        }
+        let inserted = conn
+            .execute(
+                r#"
+                INSERT OR IGNORE INTO glossary_terms (term, normalized_term, definition, source_run_id, created_at, defining_runs)
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+                "#,
+                rusqlite::params![entry.term, normalized, entry.definition, run_id, now, serde_json::to_string(&[run_id])?],
+            )
+            .context("Failed to record glossary term")?;
+        if inserted == 0 {
+            let (id, runs_json): (i64, Option<String>) = conn
+                .query_row(
+                    "SELECT id, defining_runs FROM glossary_terms WHERE normalized_term = ?1",
+                    [&normalized],
+                    |row| Ok((row.get(0)?, row.get(1)?)),
+                )
+                .context("Failed to look up glossary term")?;
+            let mut runs = parse_runs(runs_json);
+            if !runs.iter().any(|r| r == run_id) {
+                runs.push(run_id.to_string());
+                set_defining_runs(conn, id, &runs)?;
+            }
+        }
+        added += inserted;
+    }
+
+    Ok(added)
+}
+
+/// Removes a run from the glossary, returning how many terms were deleted
+///
+/// Terms only this run defined are deleted. Terms other runs defined too are
+/// kept, with this run dropped from `defining_runs` and `source_run_id` moved
+/// to the next defining run.
+pub fn release_run_terms(conn: &Connection, run_id: &str) -> Result<usize> {
+    let mut stmt = conn
+        .prepare("SELECT id, defining_runs FROM glossary_terms WHERE defining_runs IS NOT NULL")
+        .context("Failed to prepare query")?;
+    let terms = stmt
+        .query_map([], |row| Ok((row.get::<_, i64>(0)?, row.get::<_, Option<String>>(1)?)))
+        .context("Failed to query glossary terms")?
+        .collect::<Result<Vec<_>, _>>()
+        .context("Failed to collect glossary terms")?;
+
+    let mut deleted = 0;
+    for (id, runs_json) in terms {
+        let runs = parse_runs(runs_json);
+        if !runs.iter().any(|r| r == run_id) {
+            continue;
+        }
+        let remaining: Vec<String> = runs.into_iter().filter(|r| r != run_id).collect();
+        if remaining.is_empty() {
+            deleted += conn
+                .execute("DELETE FROM glossary_terms WHERE id = ?1", [id])
+                .context("Failed to delete glossary term")?;
+        } else {
+            set_defining_runs(conn, id, &remaining)?;
+        }
+    }
+
+    Ok(deleted)
+}
+
+fn parse_runs(runs_json: Option<String>) -> Vec<String> {
+    runs_json.and_then(|json| serde_json::from_str(&json).ok()).unwrap_or_default()
+}
+
+fn set_defining_runs(conn: &Connection, id: i64, runs: &[String]) -> Result<()> {
+    conn.execute(
+        "UPDATE glossary_terms SET defining_runs = ?2, source_run_id = ?3 WHERE id = ?1",
+        rusqlite::params![id, serde_json::to_string(runs)?, runs.first()],
+    )
+    .context("Failed to update glossary term")?;
+    Ok(())
+}
+
+/// Previously defined terms that normalize to the same form as `term`
+pub fn find_similar_terms(conn: &Connection, term: &str) -> Result<Vec<GlossaryEntry>> {
+    let mut stmt = conn
+        .prepare("SELECT term, definition FROM glossary_terms WHERE normalized_term = ?1 ORDER BY id ASC")
+        .context("Failed to prepare query")?;
+
+    let entries = stmt
+        .query_map([normalize_term(term)], |row| {
+            Ok(GlossaryEntry {
+                term: row.get(0)?,
+                definition: row.get(1)?,
+            })
+        })
+        .context("Failed to query glossary terms")?
+        .collect::<Result<Vec<_>, _>>()
+        .context("Failed to collect glossary terms")?;
+
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::schema;
+
+    fn entry(term: &str, definition: &str) -> GlossaryEntry {
+        GlossaryEntry {
+            term: term.to_string(),
+            definition: definition.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_similar_terms_found_across_runs() {
+        let conn = Connection::open_in_memory().unwrap();
+        schema::create_schema(&conn).unwrap();
+
+        let added = record_glossary(&conn, "run-001", &[entry("Coherence Index", "How well the parts fit together")]).unwrap();
+        assert_eq!(added, 1);
+
+        let found = find_similar_terms(&conn, "coherence index").unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].term, "Coherence Index");
+        assert_eq!(found[0].definition, "How well the parts fit together");
+
+        // Near-duplicates from a later run merge into the established definition
+        let added = record_glossary(
+            &conn,
+            "run-002",
+            &[entry("coherence-indexes", "A different wording"), entry("Entry Gate", "Check before a step")],
+        )
+        .unwrap();
+        assert_eq!(added, 1);
+        let found = find_similar_terms(&conn, "Coherence  Index").unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].definition, "How well the parts fit together");
+
+        assert!(find_similar_terms(&conn, "Coherent Design").unwrap().is_empty());
+        assert_eq!(normalize_term("Entry Gates"), normalize_term("entry gate"));
+        assert_eq!(normalize_term("Process"), "process");
+        assert_eq!(normalize_term("Gap Analysis"), "gap analysis");
+    }
+}
@@ -6,15 +6,20 @@ pub mod patterns;
 pub mod ledger;
 pub mod spine;
 pub mod flaws;
+pub mod glossary;
+pub mod archive;
 
 use anyhow::{Context, Result};
 use rusqlite::Connection;
-use std::path::PathBuf;
-use std::sync::Mutex;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 use tauri::Manager;
 
-/// Global database connection
-static DB_CONNECTION: Mutex<Option<Connection>> = Mutex::new(None);
+/// How long a connection waits for another connection's write lock before failing
+///
+/// Every caller opens its own connection (see `open_connection`), so concurrent
+/// commands and background persistence contend through SQLite's file lock.
+pub const BUSY_TIMEOUT: Duration = Duration::from_secs(5);
 
 /// Gets the path to the database file using Tauri's app_data_dir
 pub fn get_db_path(app_handle: &tauri::AppHandle) -> Result<PathBuf> {
@@ -39,42 +44,85 @@ pub fn database_exists(app_handle: &tauri::AppHandle) -> Result<bool> {
 /// Initializes the database, creating tables and indexes if needed
 pub fn init_database(app_handle: &tauri::AppHandle) -> Result<()> {
     let db_path = get_db_path(app_handle)?;
+    init_database_at(&db_path)
+}
 
+/// Initializes the database file at `db_path`
+///
+/// Safe to call on an existing database: tables and indexes are only created
+/// if missing, so stored rows are kept.
+pub fn init_database_at(db_path: &Path) -> Result<()> {
     println!("Initializing database at: {:?}", db_path);
 
-    let conn = Connection::open(&db_path)
-        .context("Failed to open database connection")?;
+    let conn = open_connection(db_path)?;
 
-    // Enable foreign keys
-    conn.execute("PRAGMA foreign_keys = ON", [])
-        .context("Failed to enable foreign keys")?;
-
-    // Create schema (tables and indexes)
+    // Create tables and bring an existing database up to the latest schema
     schema::create_schema(&conn)
-        .context("Failed to create database schema")?;
-
-    println!("Database schema created successfully");
+        .context("Failed to migrate database schema")?;
 
-    // Store the connection globally
-    let mut db_conn = DB_CONNECTION.lock().unwrap();
-    *db_conn = Some(conn);
+    println!("Database schema at version {}", schema::get_schema_version(&conn)?);
 
     Ok(())
 }
 
-/// Gets a reference to the database connection
+/// Opens a new connection to the app database
 ///
-/// Note: This returns a new connection each time. For production use,
-/// consider using a connection pool (r2d2) for better concurrency.
+/// Each call gets its own connection; there is no shared global connection.
 pub fn get_connection(app_handle: &tauri::AppHandle) -> Result<Connection> {
     let db_path = get_db_path(app_handle)?;
+    open_connection(&db_path)
+}
 
-    let conn = Connection::open(&db_path)
+/// Opens a connection to the database at `db_path` with foreign keys enabled
+/// and `BUSY_TIMEOUT` applied
+pub fn open_connection(db_path: &Path) -> Result<Connection> {
+    let conn = Connection::open(db_path)
         .context("Failed to open database connection")?;
 
     // Enable foreign keys
     conn.execute("PRAGMA foreign_keys = ON", [])
         .context("Failed to enable foreign keys")?;
 
+    conn.busy_timeout(BUSY_TIMEOUT)
+        .context("Failed to set busy timeout")?;
+
     Ok(conn)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::models::Run;
+    use chrono::Utc;
+
+    #[test]
+    fn test_reinitializing_keeps_stored_rows() {
+        let db_path = std::env::temp_dir().join(format!("method-vi-init-{}.db", uuid::Uuid::new_v4()));
+        init_database_at(&db_path).expect("Failed to initialize database");
+
+        let conn = open_connection(&db_path).expect("Failed to open connection");
+        runs::create_run(&conn, &Run {
+            id: "2025-12-17-Init-Run".to_string(),
+            intent_anchor_hash: "abc123".to_string(),
+            created_at: Utc::now(),
+            completed_at: None,
+            final_ci: None,
+            final_ev: None,
+            status: Some("active".to_string()),
+        })
+        .expect("Failed to create run");
+        drop(conn);
+
+        // A second initialization (e.g. next app start) must not recreate tables
+        init_database_at(&db_path).expect("Failed to re-initialize database");
+
+        let conn = open_connection(&db_path).expect("Failed to open connection");
+        let run = runs::get_run(&conn, "2025-12-17-Init-Run").expect("Failed to query run");
+        assert!(run.is_some());
+        let foreign_keys: i64 = conn.query_row("PRAGMA foreign_keys", [], |row| row.get(0)).unwrap();
+        assert_eq!(foreign_keys, 1);
+
+        drop(conn);
+        let _ = std::fs::remove_file(&db_path);
+    }
+}
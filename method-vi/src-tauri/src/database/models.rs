@@ -84,4 +84,5 @@ pub struct PersistentFlaw {
     pub affected_runs: Option<String>, // JSON array of run IDs
     pub resolution_status: Option<String>, // open | resolved | escalated
     pub policy_ticket: Option<String>,
+    pub flaw_type: Option<String>, // Contradiction | None for untyped flaws
 }
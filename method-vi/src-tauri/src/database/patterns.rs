@@ -1,22 +1,124 @@
-// TODO: Implement CRUD operations for patterns table
-// Reference runs.rs for implementation pattern
-
 use super::models::Pattern;
-use anyhow::Result;
-use rusqlite::Connection;
+use anyhow::{Context, Result};
+use rusqlite::{Connection, OptionalExtension};
+
+const PATTERN_COLUMNS: &str = "id, intent_category, ci_achievement, ev_stability, architecture_pattern, \
+    analysis_pattern, synthesis_pattern, structure_pattern, validation_pattern, applicability, \
+    vitality_freshness, vitality_relevance, application_count, success_count, created_at, last_applied, \
+    source_run_id, is_starter";
+
+/// Maps a row from the patterns table into a Pattern model
+fn row_to_pattern(row: &rusqlite::Row) -> rusqlite::Result<Pattern> {
+    Ok(Pattern {
+        id: row.get(0)?,
+        intent_category: row.get(1)?,
+        ci_achievement: row.get(2)?,
+        ev_stability: row.get(3)?,
+        architecture_pattern: row.get(4)?,
+        analysis_pattern: row.get(5)?,
+        synthesis_pattern: row.get(6)?,
+        structure_pattern: row.get(7)?,
+        validation_pattern: row.get(8)?,
+        applicability: row.get(9)?,
+        vitality_freshness: row.get(10)?,
+        vitality_relevance: row.get(11)?,
+        application_count: row.get(12)?,
+        success_count: row.get(13)?,
+        created_at: row.get::<_, String>(14)?.parse().unwrap(),
+        last_applied: row
+            .get::<_, Option<String>>(15)?
+            .and_then(|s| s.parse().ok()),
+        source_run_id: row.get(16)?,
+        is_starter: row.get(17)?,
+    })
+}
+
+/// Runs a pattern query and collects the rows
+fn query_patterns(conn: &Connection, sql: &str, params: impl rusqlite::Params) -> Result<Vec<Pattern>> {
+    let mut stmt = conn.prepare(sql).context("Failed to prepare query")?;
+
+    let patterns = stmt
+        .query_map(params, row_to_pattern)
+        .context("Failed to query patterns")?
+        .collect::<Result<Vec<_>, _>>()
+        .context("Failed to collect patterns")?;
+
+    Ok(patterns)
+}
+
+/// Creates a new pattern in the database
+pub fn create_pattern(conn: &Connection, pattern: &Pattern) -> Result<()> {
+    conn.execute(
+        &format!(
+            "INSERT INTO patterns ({}) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18)",
+            PATTERN_COLUMNS
+        ),
+        rusqlite::params![
+            pattern.id,
+            pattern.intent_category,
+            pattern.ci_achievement,
+            pattern.ev_stability,
+            pattern.architecture_pattern,
+            pattern.analysis_pattern,
+            pattern.synthesis_pattern,
+            pattern.structure_pattern,
+            pattern.validation_pattern,
+            pattern.applicability,
+            pattern.vitality_freshness,
+            pattern.vitality_relevance,
+            pattern.application_count,
+            pattern.success_count,
+            pattern.created_at.to_rfc3339(),
+            pattern.last_applied.map(|t| t.to_rfc3339()),
+            pattern.source_run_id,
+            pattern.is_starter,
+        ],
+    )
+    .context("Failed to create pattern")?;
+
+    Ok(())
+}
+
+/// Gets a pattern by ID
+pub fn get_pattern(conn: &Connection, id: &str) -> Result<Option<Pattern>> {
+    let mut stmt = conn
+        .prepare(&format!("SELECT {} FROM patterns WHERE id = ?1", PATTERN_COLUMNS))
+        .context("Failed to prepare query")?;
+
+    let pattern = stmt
+        .query_row([id], row_to_pattern)
+        .optional()
+        .context("Failed to query pattern")?;
 
-pub fn create_pattern(_conn: &Connection, _pattern: &Pattern) -> Result<()> {
-    todo!("Implement create_pattern")
+    Ok(pattern)
 }
 
-pub fn get_pattern(_conn: &Connection, _id: &str) -> Result<Option<Pattern>> {
-    todo!("Implement get_pattern")
+/// Lists all patterns, oldest first
+pub fn list_patterns(conn: &Connection) -> Result<Vec<Pattern>> {
+    query_patterns(
+        conn,
+        &format!("SELECT {} FROM patterns ORDER BY created_at ASC, id ASC", PATTERN_COLUMNS),
+        [],
+    )
 }
 
-pub fn list_patterns_by_category(_conn: &Connection, _category: &str) -> Result<Vec<Pattern>> {
-    todo!("Implement list_patterns_by_category")
+/// Lists the patterns of one intent category, oldest first
+pub fn list_patterns_by_category(conn: &Connection, category: &str) -> Result<Vec<Pattern>> {
+    query_patterns(
+        conn,
+        &format!(
+            "SELECT {} FROM patterns WHERE intent_category = ?1 ORDER BY created_at ASC, id ASC",
+            PATTERN_COLUMNS
+        ),
+        [category],
+    )
 }
 
-pub fn get_starter_patterns(_conn: &Connection) -> Result<Vec<Pattern>> {
-    todo!("Implement get_starter_patterns")
+/// Lists the starter patterns shipped with the app
+pub fn get_starter_patterns(conn: &Connection) -> Result<Vec<Pattern>> {
+    query_patterns(
+        conn,
+        &format!("SELECT {} FROM patterns WHERE is_starter = 1 ORDER BY id ASC", PATTERN_COLUMNS),
+        [],
+    )
 }
@@ -1,4 +1,5 @@
 use super::models::Run;
+use crate::config::RunConfig;
 use anyhow::{Context, Result};
 use rusqlite::{Connection, OptionalExtension};
 
@@ -177,6 +178,128 @@ pub fn get_completed_runs(conn: &Connection) -> Result<Vec<Run>> {
     Ok(runs)
 }
 
+/// Maps a row of the standard run columns into a Run model
+fn row_to_run(row: &rusqlite::Row) -> rusqlite::Result<Run> {
+    Ok(Run {
+        id: row.get(0)?,
+        intent_anchor_hash: row.get(1)?,
+        created_at: row.get::<_, String>(2)?.parse().unwrap(),
+        completed_at: row
+            .get::<_, Option<String>>(3)?
+            .and_then(|s| s.parse().ok()),
+        final_ci: row.get(4)?,
+        final_ev: row.get(5)?,
+        status: row.get(6)?,
+    })
+}
+
+/// Stores the configuration a run was started with (see `RunConfig`)
+pub fn set_run_config(conn: &Connection, run_id: &str, run_config: &RunConfig) -> Result<()> {
+    let json = serde_json::to_string(run_config).context("Failed to serialize run config")?;
+    let rows_affected = conn
+        .execute("UPDATE runs SET run_config = ?2 WHERE id = ?1", [run_id, json.as_str()])
+        .context("Failed to store run config")?;
+
+    if rows_affected == 0 {
+        anyhow::bail!("Run with id '{}' not found", run_id);
+    }
+
+    Ok(())
+}
+
+/// Gets the configuration a run was started with (None if not stored)
+pub fn get_run_config(conn: &Connection, run_id: &str) -> Result<Option<RunConfig>> {
+    let json: Option<String> = conn
+        .query_row("SELECT run_config FROM runs WHERE id = ?1", [run_id], |row| row.get(0))
+        .optional()
+        .context("Failed to query run config")?
+        .flatten();
+
+    json.map(|json| serde_json::from_str(&json).context("Failed to parse stored run config"))
+        .transpose()
+}
+
+/// Tags a run (tagging twice with the same tag is a no-op)
+pub fn add_tag(conn: &Connection, run_id: &str, tag: &str) -> Result<()> {
+    conn.execute(
+        "INSERT OR IGNORE INTO run_tags (run_id, tag, created_at) VALUES (?1, ?2, ?3)",
+        rusqlite::params![run_id, tag, chrono::Utc::now().to_rfc3339()],
+    )
+    .with_context(|| format!("Failed to tag run '{}'", run_id))?;
+
+    Ok(())
+}
+
+/// Removes a tag from a run
+pub fn remove_tag(conn: &Connection, run_id: &str, tag: &str) -> Result<()> {
+    let rows_affected = conn
+        .execute("DELETE FROM run_tags WHERE run_id = ?1 AND tag = ?2", [run_id, tag])
+        .context("Failed to remove run tag")?;
+
+    if rows_affected == 0 {
+        anyhow::bail!("Run '{}' is not tagged '{}'", run_id, tag);
+    }
+
+    Ok(())
+}
+
+/// Lists the tags of a run, alphabetically
+pub fn list_tags(conn: &Connection, run_id: &str) -> Result<Vec<String>> {
+    let mut stmt = conn
+        .prepare("SELECT tag FROM run_tags WHERE run_id = ?1 ORDER BY tag ASC")
+        .context("Failed to prepare query")?;
+
+    let tags = stmt
+        .query_map([run_id], |row| row.get(0))
+        .context("Failed to query run tags")?
+        .collect::<Result<Vec<String>, _>>()
+        .context("Failed to collect run tags")?;
+
+    Ok(tags)
+}
+
+/// Gets all runs with a tag (empty if no run has it)
+pub fn find_by_tag(conn: &Connection, tag: &str) -> Result<Vec<Run>> {
+    let mut stmt = conn
+        .prepare(
+            r#"
+            SELECT r.id, r.intent_anchor_hash, r.created_at, r.completed_at, r.final_ci, r.final_ev, r.status
+            FROM runs r
+            JOIN run_tags t ON t.run_id = r.id
+            WHERE t.tag = ?1
+            ORDER BY r.created_at DESC
+            "#,
+        )
+        .context("Failed to prepare query")?;
+
+    let runs = stmt
+        .query_map([tag], row_to_run)
+        .context("Failed to query runs by tag")?
+        .collect::<Result<Vec<_>, _>>()
+        .context("Failed to collect runs")?;
+
+    Ok(runs)
+}
+
+/// Searches runs by tag and/or a case-insensitive substring of the run id
+/// (which carries the label)
+///
+/// Both filters are optional; with neither, every run is returned.
+pub fn search_runs(conn: &Connection, tag: Option<&str>, label_contains: Option<&str>) -> Result<Vec<Run>> {
+    let runs = match tag {
+        Some(tag) => find_by_tag(conn, tag)?,
+        None => list_runs(conn)?,
+    };
+
+    Ok(match label_contains {
+        Some(text) => {
+            let text = text.to_lowercase();
+            runs.into_iter().filter(|run| run.id.to_lowercase().contains(&text)).collect()
+        }
+        None => runs,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -324,4 +447,46 @@ mod tests {
 
         println!("\n=== Multiple Runs Test Passed ===\n");
     }
+
+    #[test]
+    fn test_run_tags() {
+        let conn = setup_test_db().expect("Failed to initialize test database");
+
+        for id in ["2025-12-17-Onboarding", "2025-12-18-Pricing"] {
+            create_run(&conn, &Run {
+                id: id.to_string(),
+                intent_anchor_hash: "hash".to_string(),
+                created_at: Utc::now(),
+                completed_at: None,
+                final_ci: None,
+                final_ev: None,
+                status: Some("active".to_string()),
+            })
+            .unwrap();
+            add_tag(&conn, id, "experiment-a").unwrap();
+        }
+        add_tag(&conn, "2025-12-18-Pricing", "baseline").unwrap();
+        add_tag(&conn, "2025-12-18-Pricing", "baseline").unwrap();
+
+        let ids = |runs: Vec<Run>| runs.into_iter().map(|r| r.id).collect::<Vec<_>>();
+
+        let mut shared = ids(find_by_tag(&conn, "experiment-a").unwrap());
+        shared.sort();
+        assert_eq!(shared, vec!["2025-12-17-Onboarding", "2025-12-18-Pricing"]);
+        assert_eq!(ids(find_by_tag(&conn, "baseline").unwrap()), vec!["2025-12-18-Pricing"]);
+        assert!(find_by_tag(&conn, "missing").unwrap().is_empty());
+
+        assert_eq!(
+            ids(search_runs(&conn, Some("experiment-a"), Some("onboard")).unwrap()),
+            vec!["2025-12-17-Onboarding"]
+        );
+        assert_eq!(list_tags(&conn, "2025-12-18-Pricing").unwrap(), vec!["baseline", "experiment-a"]);
+
+        remove_tag(&conn, "2025-12-18-Pricing", "baseline").unwrap();
+        assert!(find_by_tag(&conn, "baseline").unwrap().is_empty());
+        assert!(remove_tag(&conn, "2025-12-18-Pricing", "baseline").is_err());
+
+        // Tags need an existing run
+        assert!(add_tag(&conn, "no-such-run", "baseline").is_err());
+    }
 }
@@ -104,39 +104,245 @@ pub const SQL_CREATE_INDEXES: &[&str] = &[
     "CREATE INDEX IF NOT EXISTS idx_ledger_run ON ledger_entries(run_id)",
 ];
 
-/// Creates all tables and indexes in the database
+/// One step of the schema history
+pub struct Migration {
+    /// Schema version after this migration
+    pub version: i32,
+    pub description: &'static str,
+    pub apply: fn(&Connection) -> Result<()>,
+}
+
+/// Every migration in version order; append new ones, never edit applied ones
+pub const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        description: "Initial tables and indexes",
+        apply: create_tables,
+    },
+    Migration {
+        version: 2,
+        description: "Record when artifact content was pruned",
+        apply: add_artifact_pruned_at,
+    },
+    Migration {
+        version: 3,
+        description: "Persist signal chains and run tags",
+        apply: create_signals_and_run_tags,
+    },
+    Migration {
+        version: 4,
+        description: "Store each signal's external name",
+        apply: add_signal_external_name,
+    },
+    Migration {
+        version: 5,
+        description: "Classify persistent flaws by type",
+        apply: add_flaw_type,
+    },
+    Migration {
+        version: 6,
+        description: "Store each run's frozen configuration",
+        apply: add_run_config,
+    },
+    Migration {
+        version: 7,
+        description: "Accumulate glossary terms across runs",
+        apply: create_glossary_terms,
+    },
+    Migration {
+        version: 8,
+        description: "Record every run that defined a glossary term",
+        apply: add_glossary_defining_runs,
+    },
+];
+
+/// Creates all tables and indexes in the database, at the latest schema version
 pub fn create_schema(conn: &Connection) -> Result<()> {
-    // Create all tables
+    migrate(conn).map(|_| ())
+}
+
+/// Applies every migration newer than the database's schema version
+///
+/// Each migration runs in its own transaction together with its
+/// `schema_version` row, so an interrupted upgrade resumes where it stopped.
+/// A database without a `schema_version` table is treated as version 0.
+/// Returns the schema version afterwards.
+pub fn migrate(conn: &Connection) -> Result<i32> {
+    conn.execute(
+        r#"
+        CREATE TABLE IF NOT EXISTS schema_version (
+            version INTEGER PRIMARY KEY,
+            applied_at DATETIME NOT NULL
+        )
+        "#,
+        [],
+    )
+    .context("Failed to create schema_version table")?;
+
+    let current = get_schema_version(conn)?;
+    for migration in MIGRATIONS.iter().filter(|m| m.version > current) {
+        let tx = conn
+            .unchecked_transaction()
+            .context("Failed to start migration transaction")?;
+        (migration.apply)(&tx)
+            .with_context(|| format!("Migration {} ({}) failed", migration.version, migration.description))?;
+        tx.execute(
+            "INSERT INTO schema_version (version, applied_at) VALUES (?1, datetime('now'))",
+            [migration.version],
+        )
+        .context("Failed to record schema version")?;
+        tx.commit().context("Failed to commit migration")?;
+    }
+
+    get_schema_version(conn)
+}
+
+/// Whether `table` has a column named `column`
+fn has_column(conn: &Connection, table: &str, column: &str) -> Result<bool> {
+    let mut stmt = conn
+        .prepare(&format!("PRAGMA table_info({})", table))
+        .context("Failed to read table info")?;
+    let columns = stmt
+        .query_map([], |row| row.get::<_, String>(1))
+        .context("Failed to read table columns")?
+        .collect::<Result<Vec<_>, _>>()
+        .context("Failed to collect table columns")?;
+    Ok(columns.iter().any(|name| name == column))
+}
+
+/// Migration 1: the original tables and indexes
+fn create_tables(conn: &Connection) -> Result<()> {
     for sql in SQL_CREATE_TABLES {
         conn.execute(sql, [])
             .context("Failed to create table")?;
     }
 
-    // Create all indexes
     for sql in SQL_CREATE_INDEXES {
         conn.execute(sql, [])
             .context("Failed to create index")?;
     }
 
-    // Create schema version table for future migrations
+    Ok(())
+}
+
+/// Migration 2: `artifacts.pruned_at`, set when `prune_content` drops content
+fn add_artifact_pruned_at(conn: &Connection) -> Result<()> {
+    if !has_column(conn, "artifacts", "pruned_at")? {
+        conn.execute("ALTER TABLE artifacts ADD COLUMN pruned_at DATETIME", [])
+            .context("Failed to add artifacts.pruned_at")?;
+    }
+    Ok(())
+}
+
+/// Migration 3: `signals` (each run's signal chain) and `run_tags`
+fn create_signals_and_run_tags(conn: &Connection) -> Result<()> {
+    // No FK to runs: signals are persisted before the run row exists
     conn.execute(
         r#"
-        CREATE TABLE IF NOT EXISTS schema_version (
-            version INTEGER PRIMARY KEY,
-            applied_at DATETIME NOT NULL
+        CREATE TABLE IF NOT EXISTS signals (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            run_id TEXT NOT NULL,
+            signal_type TEXT NOT NULL,
+            timestamp DATETIME NOT NULL,
+            prior_signal_hash TEXT,
+            hash TEXT NOT NULL,
+            payload TEXT NOT NULL
         )
         "#,
         [],
     )
-    .context("Failed to create schema_version table")?;
+    .context("Failed to create signals")?;
+    // Free-form researcher tags
+    conn.execute(
+        r#"
+        CREATE TABLE IF NOT EXISTS run_tags (
+            run_id TEXT NOT NULL,
+            tag TEXT NOT NULL,
+            created_at DATETIME NOT NULL,
+            PRIMARY KEY (run_id, tag),
+            FOREIGN KEY (run_id) REFERENCES runs(id)
+        )
+        "#,
+        [],
+    )
+    .context("Failed to create run_tags")?;
+
+    conn.execute("CREATE INDEX IF NOT EXISTS idx_signals_run ON signals(run_id)", [])
+        .context("Failed to create index")?;
+    conn.execute("CREATE INDEX IF NOT EXISTS idx_run_tags_tag ON run_tags(tag)", [])
+        .context("Failed to create index")?;
+    Ok(())
+}
+
+/// Migration 4: `signals.external_name`, the `SignalNaming` alias at emission
+fn add_signal_external_name(conn: &Connection) -> Result<()> {
+    if !has_column(conn, "signals", "external_name")? {
+        conn.execute("ALTER TABLE signals ADD COLUMN external_name TEXT", [])
+            .context("Failed to add signals.external_name")?;
+    }
+    Ok(())
+}
+
+/// Migration 5: `persistent_flaws.flaw_type` (e.g. "Contradiction")
+fn add_flaw_type(conn: &Connection) -> Result<()> {
+    if !has_column(conn, "persistent_flaws", "flaw_type")? {
+        conn.execute("ALTER TABLE persistent_flaws ADD COLUMN flaw_type TEXT", [])
+            .context("Failed to add persistent_flaws.flaw_type")?;
+    }
+    Ok(())
+}
+
+/// Migration 6: `runs.run_config`, the run's settings frozen at Step 0 (JSON)
+fn add_run_config(conn: &Connection) -> Result<()> {
+    if !has_column(conn, "runs", "run_config")? {
+        conn.execute("ALTER TABLE runs ADD COLUMN run_config TEXT", [])
+            .context("Failed to add runs.run_config")?;
+    }
+    Ok(())
+}
 
-    // Record initial schema version
+/// Migration 7: `glossary_terms`, definitions shared across runs
+fn create_glossary_terms(conn: &Connection) -> Result<()> {
     conn.execute(
-        "INSERT OR IGNORE INTO schema_version (version, applied_at) VALUES (1, datetime('now'))",
+        r#"
+        CREATE TABLE IF NOT EXISTS glossary_terms (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            term TEXT NOT NULL,
+            normalized_term TEXT NOT NULL UNIQUE,
+            definition TEXT NOT NULL,
+            source_run_id TEXT,
+            created_at DATETIME NOT NULL
+        )
+        "#,
         [],
     )
-    .context("Failed to insert schema version")?;
+    .context("Failed to create glossary_terms")?;
+    Ok(())
+}
+
+/// Migration 8: `glossary_terms.defining_runs`, every run that defined the
+/// term (JSON list), seeded from `source_run_id`
+fn add_glossary_defining_runs(conn: &Connection) -> Result<()> {
+    if !has_column(conn, "glossary_terms", "defining_runs")? {
+        conn.execute("ALTER TABLE glossary_terms ADD COLUMN defining_runs TEXT", [])
+            .context("Failed to add glossary_terms.defining_runs")?;
+    }
 
+    let mut stmt = conn
+        .prepare("SELECT id, source_run_id FROM glossary_terms WHERE source_run_id IS NOT NULL AND defining_runs IS NULL")
+        .context("Failed to prepare query")?;
+    let sources = stmt
+        .query_map([], |row| Ok((row.get::<_, i64>(0)?, row.get::<_, String>(1)?)))
+        .context("Failed to query glossary terms")?
+        .collect::<Result<Vec<_>, _>>()
+        .context("Failed to collect glossary terms")?;
+    for (id, run_id) in sources {
+        conn.execute(
+            "UPDATE glossary_terms SET defining_runs = ?2 WHERE id = ?1",
+            rusqlite::params![id, serde_json::to_string(&[run_id])?],
+        )
+        .context("Failed to seed glossary_terms.defining_runs")?;
+    }
     Ok(())
 }
 
@@ -152,3 +358,60 @@ pub fn get_schema_version(conn: &Connection) -> Result<i32> {
 
     Ok(version)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_migrate_v0_database_to_latest() {
+        let conn = Connection::open_in_memory().unwrap();
+
+        // A database from before schema versioning: tables but no schema_version
+        conn.execute(SQL_CREATE_TABLES[0], []).unwrap();
+        conn.execute(SQL_CREATE_TABLES[1], []).unwrap();
+        conn.execute(
+            "INSERT INTO runs (id, intent_anchor_hash, created_at) VALUES ('old-run', 'abc', datetime('now'))",
+            [],
+        )
+        .unwrap();
+        assert_eq!(get_schema_version(&conn).unwrap(), 0);
+        assert!(!has_column(&conn, "artifacts", "pruned_at").unwrap());
+
+        let latest = MIGRATIONS.last().unwrap().version;
+        assert_eq!(latest, 8);
+        assert_eq!(migrate(&conn).unwrap(), latest);
+        assert!(has_column(&conn, "artifacts", "pruned_at").unwrap());
+        let versions: i64 = conn.query_row("SELECT COUNT(*) FROM schema_version", [], |row| row.get(0)).unwrap();
+        assert_eq!(versions, MIGRATIONS.len() as i64);
+        let runs: i64 = conn.query_row("SELECT COUNT(*) FROM runs", [], |row| row.get(0)).unwrap();
+        assert_eq!(runs, 1);
+
+        // Already up to date: nothing is re-applied
+        assert_eq!(migrate(&conn).unwrap(), latest);
+        let versions: i64 = conn.query_row("SELECT COUNT(*) FROM schema_version", [], |row| row.get(0)).unwrap();
+        assert_eq!(versions, MIGRATIONS.len() as i64);
+    }
+
+    #[test]
+    fn test_migrate_v1_database_to_latest() {
+        let conn = Connection::open_in_memory().unwrap();
+
+        // A database created before signals and run tags were persisted
+        create_tables(&conn).unwrap();
+        conn.execute(
+            "CREATE TABLE schema_version (version INTEGER PRIMARY KEY, applied_at DATETIME NOT NULL)",
+            [],
+        )
+        .unwrap();
+        conn.execute("INSERT INTO schema_version (version, applied_at) VALUES (1, datetime('now'))", [])
+            .unwrap();
+        assert!(!has_column(&conn, "signals", "id").unwrap());
+
+        assert_eq!(migrate(&conn).unwrap(), MIGRATIONS.last().unwrap().version);
+        assert!(has_column(&conn, "signals", "external_name").unwrap());
+        assert!(has_column(&conn, "run_tags", "tag").unwrap());
+        assert!(has_column(&conn, "glossary_terms", "normalized_term").unwrap());
+        assert!(has_column(&conn, "glossary_terms", "defining_runs").unwrap());
+    }
+}
@@ -1,18 +1,86 @@
-// TODO: Implement CRUD operations for spine_edges table
-// Reference runs.rs for implementation pattern
-
 use super::models::SpineEdge;
-use anyhow::Result;
+use anyhow::{Context, Result};
 use rusqlite::Connection;
 
-pub fn create_spine_edge(_conn: &Connection, _edge: &SpineEdge) -> Result<()> {
-    todo!("Implement create_spine_edge")
+/// Maps a row from the spine_edges table into a SpineEdge model
+fn row_to_edge(row: &rusqlite::Row) -> rusqlite::Result<SpineEdge> {
+    Ok(SpineEdge {
+        source_id: row.get(0)?,
+        target_id: row.get(1)?,
+        edge_type: row.get(2)?,
+        created_at: row.get::<_, String>(3)?.parse().unwrap(),
+    })
+}
+
+/// Creates a new spine edge in the database
+pub fn create_spine_edge(conn: &Connection, edge: &SpineEdge) -> Result<()> {
+    conn.execute(
+        r#"
+        INSERT INTO spine_edges (source_id, target_id, edge_type, created_at)
+        VALUES (?1, ?2, ?3, ?4)
+        "#,
+        rusqlite::params![
+            edge.source_id,
+            edge.target_id,
+            edge.edge_type,
+            edge.created_at.to_rfc3339(),
+        ],
+    )
+    .context("Failed to create spine edge")?;
+
+    Ok(())
+}
+
+/// Gets all edges originating from an artifact
+pub fn get_spine_edges_for_artifact(conn: &Connection, artifact_id: &str) -> Result<Vec<SpineEdge>> {
+    let mut stmt = conn
+        .prepare("SELECT source_id, target_id, edge_type, created_at FROM spine_edges WHERE source_id = ?1 ORDER BY created_at ASC")
+        .context("Failed to prepare query")?;
+
+    let edges = stmt
+        .query_map([artifact_id], row_to_edge)
+        .context("Failed to query spine edges")?
+        .collect::<Result<Vec<_>, _>>()
+        .context("Failed to collect spine edges")?;
+
+    Ok(edges)
 }
 
-pub fn get_spine_edges_for_artifact(_conn: &Connection, _artifact_id: &str) -> Result<Vec<SpineEdge>> {
-    todo!("Implement get_spine_edges_for_artifact")
+/// Lists all edges originating from artifacts of a run
+pub fn list_spine_edges_by_run(conn: &Connection, run_id: &str) -> Result<Vec<SpineEdge>> {
+    let mut stmt = conn
+        .prepare(
+            r#"
+            SELECT e.source_id, e.target_id, e.edge_type, e.created_at
+            FROM spine_edges e
+            JOIN artifacts a ON e.source_id = a.id
+            WHERE a.run_id = ?1
+            ORDER BY e.created_at ASC
+            "#,
+        )
+        .context("Failed to prepare query")?;
+
+    let edges = stmt
+        .query_map([run_id], row_to_edge)
+        .context("Failed to query spine edges")?
+        .collect::<Result<Vec<_>, _>>()
+        .context("Failed to collect spine edges")?;
+
+    Ok(edges)
 }
 
-pub fn delete_spine_edge(_conn: &Connection, _source_id: &str, _target_id: &str) -> Result<()> {
-    todo!("Implement delete_spine_edge")
+/// Deletes a spine edge
+pub fn delete_spine_edge(conn: &Connection, source_id: &str, target_id: &str) -> Result<()> {
+    let rows_affected = conn
+        .execute(
+            "DELETE FROM spine_edges WHERE source_id = ?1 AND target_id = ?2",
+            [source_id, target_id],
+        )
+        .context("Failed to delete spine edge")?;
+
+    if rows_affected == 0 {
+        anyhow::bail!("Spine edge not found: {} -> {}", source_id, target_id);
+    }
+
+    Ok(())
 }
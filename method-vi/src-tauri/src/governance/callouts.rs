@@ -2,7 +2,7 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use super::types::{Step, StructureMode, Thresholds, ThresholdResolver};
+use super::types::{MetricEnforcementSchedule, Step, StructureMode, Thresholds, ThresholdResolver};
 
 /// Callout severity tiers - replaces binary HALT/PASS with graduated responses
 /// Ordered by severity: Info < Attention < Warning < Critical
@@ -261,19 +261,49 @@ impl Default for CalloutSummary {
     }
 }
 
+/// Callouts relevant to approving one step's gate
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GateCalloutSummary {
+    pub step: Step,
+    /// Callouts raised at this step, by tier
+    pub by_tier: CalloutCountByTier,
+    /// Callouts from any step that block approval: unacknowledged callouts that
+    /// require acknowledgment (Warnings too in strict mode) and hard blocks
+    pub blocking: Vec<Callout>,
+    pub can_proceed: bool,
+}
+
 /// Manages callouts for a run
 #[derive(Debug, Default)]
 pub struct CalloutManager {
     callouts: Vec<Callout>,
+    /// Strict mode: Warning callouts also require acknowledgment
+    strict: bool,
 }
 
 impl CalloutManager {
     pub fn new() -> Self {
-        Self { callouts: Vec::new() }
+        Self { callouts: Vec::new(), strict: false }
+    }
+
+    /// Enable or disable strict mode for callouts added from now on
+    pub fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+    }
+
+    /// Whether Warning callouts block until acknowledged
+    pub fn is_strict(&self) -> bool {
+        self.strict
     }
 
     /// Add a callout to the manager
-    pub fn add(&mut self, callout: Callout) {
+    ///
+    /// In strict mode a Warning (before the noise filter) requires acknowledgment
+    /// like a Critical callout.
+    pub fn add(&mut self, mut callout: Callout) {
+        if self.strict && callout.original_tier >= CalloutTier::Warning {
+            callout.requires_acknowledgment = true;
+        }
         self.callouts.push(callout);
     }
 
@@ -320,6 +350,12 @@ impl CalloutManager {
         Ok(())
     }
 
+    /// Drop the callouts raised at `step` or later (e.g. when a rollback
+    /// discards those steps)
+    pub fn clear_from_step(&mut self, step: u8) {
+        self.callouts.retain(|c| c.step.as_u8() < step);
+    }
+
     /// Get all callouts for a specific step
     pub fn get_callouts_for_step(&self, step: Step) -> Vec<&Callout> {
         self.callouts
@@ -394,6 +430,35 @@ impl CalloutManager {
         records
     }
 
+    /// Summarize callouts for the gate decision at `step`
+    ///
+    /// Counts cover the step's own callouts; the blocking list covers the
+    /// whole run, since any pending acknowledgment or hard block holds the gate.
+    /// `can_proceed` matches `can_proceed()`, which the orchestrator checks.
+    pub fn gate_summary(&self, step: Step) -> GateCalloutSummary {
+        let step_callouts = self.get_callouts_for_step(step);
+        let count = |tier: CalloutTier| step_callouts.iter().filter(|c| c.tier == tier).count();
+
+        let mut blocking: Vec<Callout> = self.get_pending_acknowledgments().into_iter().cloned().collect();
+        for hard_block in self.get_hard_blocks() {
+            if !blocking.iter().any(|c| c.id == hard_block.id) {
+                blocking.push(hard_block.clone());
+            }
+        }
+
+        GateCalloutSummary {
+            step,
+            by_tier: CalloutCountByTier {
+                info: count(CalloutTier::Info),
+                attention: count(CalloutTier::Attention),
+                warning: count(CalloutTier::Warning),
+                critical: count(CalloutTier::Critical),
+            },
+            can_proceed: self.can_proceed(),
+            blocking,
+        }
+    }
+
     /// Get a summary of current callout state
     pub fn summary(&self) -> CalloutSummary {
         CalloutSummary {
@@ -451,30 +516,34 @@ impl CalloutTrigger {
     /// Determine tier for EFI (Evidence Fidelity Index) - STEP-AWARE
     /// EFI is informational before Step 4, enforced at Step 6 only
     pub fn efi_tier(value: f64, step: Step) -> CalloutTier {
-        // Steps 0-3: Always informational (diagnostic phase)
-        if step.as_u8() < 4 {
-            return CalloutTier::Info;
-        }
+        let enforced = MetricEnforcementSchedule::default().enforces("EFI", step.as_u8());
+        Self::efi_tier_scheduled(value, step, enforced)
+    }
 
-        // Step 4-5: Soft awareness
-        if step.as_u8() < 6 {
+    /// EFI tier when the enforcement schedule decides whether it can go Critical
+    pub fn efi_tier_scheduled(value: f64, step: Step, enforced: bool) -> CalloutTier {
+        if enforced {
+            // Full enforcement
             if value >= 0.80 {
                 CalloutTier::Info
             } else if value >= 0.60 {
                 CalloutTier::Attention
-            } else {
+            } else if value >= 0.50 {
                 CalloutTier::Warning
+            } else {
+                CalloutTier::Critical
             }
+        } else if step.as_u8() < 4 {
+            // Steps 0-3: Always informational (diagnostic phase)
+            CalloutTier::Info
         } else {
-            // Step 6: Full enforcement
+            // Soft awareness
             if value >= 0.80 {
                 CalloutTier::Info
             } else if value >= 0.60 {
                 CalloutTier::Attention
-            } else if value >= 0.50 {
-                CalloutTier::Warning
             } else {
-                CalloutTier::Critical
+                CalloutTier::Warning
             }
         }
     }
@@ -482,7 +551,13 @@ impl CalloutTrigger {
     /// Determine tier for PCI (Process Compliance Index) - STEP-AWARE
     /// PCI is monitored throughout, enforced strictly at Step 6
     pub fn pci_tier(value: f64, step: Step) -> CalloutTier {
-        if step.as_u8() < 6 {
+        let enforced = MetricEnforcementSchedule::default().enforces("PCI", step.as_u8());
+        Self::pci_tier_scheduled(value, enforced)
+    }
+
+    /// PCI tier when the enforcement schedule decides whether it can go Critical
+    pub fn pci_tier_scheduled(value: f64, enforced: bool) -> CalloutTier {
+        if !enforced {
             // Pre-validation: softer thresholds
             if value >= 0.85 {
                 CalloutTier::Info
@@ -492,7 +567,7 @@ impl CalloutTrigger {
                 CalloutTier::Warning
             }
         } else {
-            // Step 6: Strict validation
+            // Strict validation
             if value >= 0.95 {
                 CalloutTier::Info
             } else if value >= 0.85 {
@@ -544,18 +619,41 @@ impl CalloutTrigger {
         previous_value: Option<f64>,
         step: Step,
         mode: StructureMode,
+    ) -> CalloutTier {
+        Self::determine_tier_scheduled(
+            metric_name,
+            current_value,
+            previous_value,
+            step,
+            mode,
+            &MetricEnforcementSchedule::default(),
+        )
+    }
+
+    /// Determine tier, letting the schedule decide where each metric can HALT
+    ///
+    /// Metrics outside their scheduled steps are capped at Warning.
+    pub fn determine_tier_scheduled(
+        metric_name: &str,
+        current_value: f64,
+        previous_value: Option<f64>,
+        step: Step,
+        mode: StructureMode,
+        schedule: &MetricEnforcementSchedule,
     ) -> CalloutTier {
         let thresholds = ThresholdResolver::resolve(mode, step);
+        let enforced = schedule.enforces(metric_name, step.as_u8());
 
         let raw_tier = match metric_name {
             "CI" => Self::ci_tier(current_value, previous_value, &thresholds),
             "IAS" => Self::ias_tier(current_value, &thresholds),
-            "EFI" => Self::efi_tier(current_value, step),
-            "PCI" => Self::pci_tier(current_value, step),
+            "EFI" => Self::efi_tier_scheduled(current_value, step, enforced),
+            "PCI" => Self::pci_tier_scheduled(current_value, enforced),
             "EV" => Self::ev_tier(current_value),
             "SEC" => Self::sec_tier(current_value),
             _ => CalloutTier::Info, // Unknown metrics default to Info
         };
+        let raw_tier = if enforced { raw_tier } else { raw_tier.min(CalloutTier::Warning) };
 
         // Apply noise filter (Constraint 3)
         raw_tier.apply_noise_filter(mode)
@@ -711,6 +809,10 @@ mod tests {
 
         assert_eq!(manager.get_callouts_for_step(Step::Step3_Diagnostic).len(), 1);
         assert_eq!(manager.get_callouts_for_step(Step::Step4_Synthesis).len(), 2);
+
+        manager.clear_from_step(4);
+        assert_eq!(manager.all().len(), 1);
+        assert_eq!(manager.all()[0].step, Step::Step3_Diagnostic);
     }
 
     // =========================================================================
@@ -887,4 +989,77 @@ mod tests {
         assert_eq!(summary.pending_acknowledgments, 1);
         assert!(!summary.can_proceed);
     }
+
+    #[test]
+    fn test_gate_summary() {
+        let mut manager = CalloutManager::new();
+
+        // An unacknowledged Critical from an earlier step still blocks the gate
+        let earlier = Callout::new(
+            CalloutTier::Critical, "IAS", 0.20, None, "", "", "",
+            Step::Step3_Diagnostic, StructureMode::Builder,
+        );
+        let earlier_id = earlier.id.clone();
+        manager.add(earlier);
+
+        manager.add(Callout::new(
+            CalloutTier::Info, "CI", 0.80, None, "", "", "",
+            Step::Step4_Synthesis, StructureMode::Builder,
+        ));
+        manager.add(Callout::new(
+            CalloutTier::Warning, "PCI", 0.75, None, "", "", "",
+            Step::Step4_Synthesis, StructureMode::Builder,
+        ));
+        manager.add(Callout::new(
+            CalloutTier::Warning, "EFI", 0.60, None, "", "", "",
+            Step::Step4_Synthesis, StructureMode::Builder,
+        ));
+        let acknowledged = Callout::new(
+            CalloutTier::Critical, "CI", 0.15, None, "", "", "",
+            Step::Step4_Synthesis, StructureMode::Builder,
+        );
+        let acknowledged_id = acknowledged.id.clone();
+        manager.add(acknowledged);
+        manager.acknowledge(&acknowledged_id).unwrap();
+
+        let summary = manager.gate_summary(Step::Step4_Synthesis);
+        assert_eq!(summary.by_tier.info, 1);
+        assert_eq!(summary.by_tier.attention, 0);
+        assert_eq!(summary.by_tier.warning, 2);
+        assert_eq!(summary.by_tier.critical, 1);
+        assert_eq!(summary.blocking.len(), 1);
+        assert_eq!(summary.blocking[0].id, earlier_id);
+        assert!(!summary.can_proceed);
+        assert_eq!(summary.can_proceed, manager.can_proceed());
+
+        manager.acknowledge(&earlier_id).unwrap();
+        let summary = manager.gate_summary(Step::Step4_Synthesis);
+        assert!(summary.blocking.is_empty());
+        assert!(summary.can_proceed);
+    }
+
+    #[test]
+    fn test_gate_summary_strict_mode_blocks_on_warnings() {
+        let mut manager = CalloutManager::new();
+        manager.set_strict(true);
+
+        let warning = Callout::new(
+            CalloutTier::Warning, "PCI", 0.75, None, "", "", "",
+            Step::Step4_Synthesis, StructureMode::Builder,
+        );
+        let warning_id = warning.id.clone();
+        manager.add(warning);
+
+        let summary = manager.gate_summary(Step::Step4_Synthesis);
+        assert!(!manager.can_proceed());
+        assert!(!summary.can_proceed);
+        assert_eq!(summary.blocking.len(), 1);
+        assert_eq!(summary.blocking[0].id, warning_id);
+
+        manager.acknowledge(&warning_id).unwrap();
+        let summary = manager.gate_summary(Step::Step4_Synthesis);
+        assert!(summary.blocking.is_empty());
+        assert!(summary.can_proceed);
+        assert!(manager.can_proceed());
+    }
 }
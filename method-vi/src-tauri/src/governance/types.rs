@@ -133,6 +133,157 @@ impl CharterData {
     }
 }
 
+/// Parse the objectives listed in a Charter's "Objectives" section
+///
+/// The section starts at any heading mentioning "objective" and ends at the next
+/// heading of the same or a higher level that does not. Numbered and bulleted
+/// items are collected, as are `###` sub-headings ("### Objective 1: text" keeps
+/// the text after the colon) and bold "**Primary Objective:** text" lines.
+/// Single source for Step 4 relevance checks, IAS and `get_charter_objectives`.
+pub fn parse_charter_objectives(charter_content: &str) -> Vec<String> {
+    let mut objectives = Vec::new();
+    // Heading level of the objectives section, while inside it
+    let mut section_level: Option<usize> = None;
+
+    for line in charter_content.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+
+        let level = trimmed.chars().take_while(|c| *c == '#').count();
+        if level > 0 {
+            let heading = trimmed[level..].trim().replace("**", "");
+            let mentions_objective = heading.to_lowercase().contains("objective");
+
+            match section_level {
+                Some(current) if level > current => {
+                    // Sub-heading inside the section: one objective per heading
+                    if let Some(text) = objective_from_label(&heading) {
+                        objectives.push(text);
+                    }
+                }
+                _ if mentions_objective => {
+                    section_level = Some(level);
+                    if let Some((_, text)) = heading.split_once(':') {
+                        if !text.trim().is_empty() {
+                            objectives.push(text.trim().to_string());
+                        }
+                    }
+                }
+                _ => section_level = None,
+            }
+            continue;
+        }
+
+        if section_level.is_none() {
+            continue;
+        }
+
+        if let Some(item) = strip_list_marker(trimmed) {
+            let item = item.replace("**", "");
+            let item = match item.split_once(':') {
+                Some((label, text)) if label.to_lowercase().contains("objective") && !text.trim().is_empty() => {
+                    text.trim().to_string()
+                }
+                _ => item.trim().to_string(),
+            };
+            if !item.is_empty() {
+                objectives.push(item);
+            }
+        } else if trimmed.starts_with("**") {
+            if let Some(text) = objective_from_label(&trimmed.replace("**", "")) {
+                objectives.push(text);
+            }
+        }
+    }
+
+    objectives
+}
+
+/// Parse the success criteria listed in a Charter's "Success Criteria" section
+///
+/// The section starts at a heading mentioning "success criteria" (or
+/// "criterion") and ends at the next heading of the same or a higher level.
+/// Numbered and bulleted items and sub-headings each count as one criterion.
+pub fn parse_charter_success_criteria(charter_content: &str) -> Vec<String> {
+    let mut criteria = Vec::new();
+    let mut section_level: Option<usize> = None;
+
+    for line in charter_content.lines() {
+        let trimmed = line.trim();
+        let level = trimmed.chars().take_while(|c| *c == '#').count();
+        if level > 0 {
+            let heading = trimmed[level..].trim().replace("**", "");
+            let lower = heading.to_lowercase();
+            match section_level {
+                Some(current) if level > current => {
+                    if !heading.is_empty() {
+                        criteria.push(heading);
+                    }
+                }
+                _ if lower.contains("success criteri") || lower.contains("criterion") => {
+                    section_level = Some(level)
+                }
+                _ => section_level = None,
+            }
+            continue;
+        }
+
+        if section_level.is_some() {
+            if let Some(item) = strip_list_marker(trimmed) {
+                let item = item.replace("**", "");
+                if !item.trim().is_empty() {
+                    criteria.push(item.trim().to_string());
+                }
+            }
+        }
+    }
+
+    criteria
+}
+
+/// Objective text from a label such as "Objective 2: Reduce cost" or "Reduce cost"
+///
+/// Grouping labels ("Primary Objectives") carry no objective of their own.
+fn objective_from_label(label: &str) -> Option<String> {
+    if let Some((name, text)) = label.split_once(':') {
+        let text = text.trim();
+        return if text.is_empty() {
+            None
+        } else if name.to_lowercase().contains("objective") {
+            Some(text.to_string())
+        } else {
+            Some(label.trim().to_string())
+        };
+    }
+
+    let label = label.trim();
+    if label.is_empty() || label.to_lowercase().contains("objective") {
+        None
+    } else {
+        Some(label.to_string())
+    }
+}
+
+/// Strip a "- ", "* ", "+ ", "1. " or "1) " list marker, if the line has one
+fn strip_list_marker(line: &str) -> Option<&str> {
+    for marker in ["- ", "* ", "+ "] {
+        if let Some(rest) = line.strip_prefix(marker) {
+            return Some(rest.trim());
+        }
+    }
+
+    let digits = line.chars().take_while(|c| c.is_ascii_digit()).count();
+    if digits == 0 {
+        return None;
+    }
+    let rest = &line[digits..];
+    rest.strip_prefix(". ")
+        .or_else(|| rest.strip_prefix(") "))
+        .map(str::trim)
+}
+
 /// Auto-detected structure level of input content
 /// Mode is determined by CI baseline at Step 1 and remains fixed for the run
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -315,6 +466,67 @@ pub enum MetricEnforcement {
     Informational,
 }
 
+/// Metrics whose HALT enforcement can be scheduled (EV and SEC never HALT)
+pub const SCHEDULED_METRICS: [&str; 4] = ["CI", "IAS", "EFI", "PCI"];
+
+/// Steps at which each metric's HALT (Fail / Critical) is enforced
+///
+/// Outside its scheduled steps a metric can still warn but never HALTs. The
+/// default reproduces FIX-008: CI and IAS enforce at every step, EFI and PCI
+/// only at Step 6 (Validation).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MetricEnforcementSchedule {
+    pub ci: Vec<u8>,
+    pub ias: Vec<u8>,
+    pub efi: Vec<u8>,
+    pub pci: Vec<u8>,
+}
+
+impl Default for MetricEnforcementSchedule {
+    fn default() -> Self {
+        Self {
+            ci: (0..=6).collect(),
+            ias: (0..=6).collect(),
+            efi: vec![6],
+            pci: vec![6],
+        }
+    }
+}
+
+impl MetricEnforcementSchedule {
+    /// Steps at which a metric HALTs (empty for unscheduled metrics)
+    pub fn steps_for(&self, metric: &str) -> &[u8] {
+        match metric {
+            "CI" => &self.ci,
+            "IAS" => &self.ias,
+            "EFI" => &self.efi,
+            "PCI" => &self.pci,
+            _ => &[],
+        }
+    }
+
+    /// Whether a metric's HALT is enforced at a step
+    pub fn enforces(&self, metric: &str, step: u8) -> bool {
+        self.steps_for(metric).contains(&step)
+    }
+
+    /// Scheduled metrics that are not enforced at any step
+    pub fn unenforced_metrics(&self) -> Vec<&'static str> {
+        SCHEDULED_METRICS
+            .into_iter()
+            .filter(|metric| !self.steps_for(metric).iter().any(|step| *step <= 6))
+            .collect()
+    }
+
+    /// Log a warning for each metric the schedule never enforces
+    pub fn warn_unenforced(&self) {
+        for metric in self.unenforced_metrics() {
+            log::warn!("Metric enforcement schedule never enforces {} - it can no longer HALT a run", metric);
+        }
+    }
+}
+
 // =============================================================================
 // MODE DETECTION SERVICE (Constraint 1: Transparency Mandate)
 // =============================================================================
@@ -662,4 +874,61 @@ mod tests {
         let result = ModeDetector::detect(0.80, 0.40, UserPosture::Build, "Analytical");
         assert_ne!(result.mode, StructureMode::Transformation);
     }
+
+    #[test]
+    fn test_parse_charter_objectives_headed_sections() {
+        let charter = r#"# Charter
+
+## Objectives
+
+### Objective 1: Map the current process
+Details that are not an objective.
+
+### Objective 2: Find bottlenecks
+
+**Secondary Objective:** Keep the team informed
+
+## Success Criteria
+1. A process map exists
+"#;
+
+        let objectives = parse_charter_objectives(charter);
+        assert_eq!(
+            objectives,
+            vec!["Map the current process", "Find bottlenecks", "Keep the team informed"]
+        );
+
+        // No objectives section, no objectives
+        assert!(parse_charter_objectives("# Charter\n\n## Primary Goal\nGoal").is_empty());
+    }
+
+    #[test]
+    fn test_parse_charter_success_criteria() {
+        let charter = r#"# Charter
+
+## Success Criteria
+- Dashboard loads in < 2 seconds
+- 95% uptime
+
+### Criterion 3: Adoption
+Details that are not a criterion.
+
+## Constraints
+- Budget: $150,000
+"#;
+
+        assert_eq!(
+            parse_charter_success_criteria(charter),
+            vec!["Dashboard loads in < 2 seconds", "95% uptime", "Criterion 3: Adoption"]
+        );
+
+        // The Charter display markdown only records the criteria state
+        assert!(parse_charter_success_criteria("## Success Criteria State\nDefined").is_empty());
+
+        // A "criterion" heading opens the section too
+        assert_eq!(
+            parse_charter_success_criteria("## Completion Criterion\n1. Report signed off\n\n## Risks\n- Delay"),
+            vec!["Report signed off"]
+        );
+    }
 }
@@ -0,0 +1,48 @@
+//! Shared SHA-256 helpers
+//!
+//! Every content hash in artifacts, frontmatter and the governance reports is
+//! the lowercase hex SHA-256 of the exact content bytes, with no trimming or
+//! newline normalisation, so the same content always hashes the same wherever
+//! it is computed.
+
+use sha2::{Digest, Sha256};
+use std::io::{self, Read};
+
+/// Lowercase hex SHA-256 of some bytes
+pub fn sha256_hex(bytes: impl AsRef<[u8]>) -> String {
+    format!("{:x}", Sha256::digest(bytes.as_ref()))
+}
+
+/// Lowercase hex SHA-256 of a reader's contents, hashed in chunks
+///
+/// For large artifacts on disk; gives the same result as `sha256_hex` on the
+/// full contents without reading them into memory.
+pub fn sha256_hex_reader(mut reader: impl Read) -> io::Result<String> {
+    let mut hasher = Sha256::new();
+    let mut buffer = [0u8; 64 * 1024];
+    loop {
+        let read = reader.read(&mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sha256_hex() {
+        assert_eq!(
+            sha256_hex(""),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+        assert_ne!(sha256_hex("content"), sha256_hex("content\n"));
+
+        let large = "framework section\n".repeat(10_000);
+        assert_eq!(sha256_hex_reader(large.as_bytes()).unwrap(), sha256_hex(&large));
+    }
+}
@@ -0,0 +1,320 @@
+//! Headless command surface for scripting runs without the UI
+//!
+//! `HeadlessSession::handle_command` takes a command name and JSON arguments
+//! and returns a JSON result, driving the same orchestrator logic, agents and
+//! gate handling as the Tauri commands. Runs live in memory only; nothing is
+//! written to the database.
+//! `handle_line` wraps it for JSON-lines transports such as a CLI reading
+//! `{"id": 1, "method": "start_step_0", "params": {...}}` from stdin.
+
+use anyhow::{anyhow, Context, Result};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use crate::agents::orchestrator::Orchestrator;
+use crate::commands::step0::{auto_approve_gate, convert_questions, finish_step, new_run, with_run_agents};
+use crate::commands::{IntentSummaryForFrontend, RunRegistry};
+use crate::config::{AppConfig, PromptTemplates, RunConfig};
+
+/// Command names accepted by `handle_command`
+pub const COMMANDS: [&str; 6] = ["start_step_0", "execute_step", "approve_gate", "reject_gate", "status", "list_runs"];
+
+#[derive(Deserialize)]
+struct StartArgs {
+    run_id: String,
+    user_intent: String,
+    /// Stub absent agents instead of calling the configured provider
+    #[serde(default)]
+    stub: bool,
+    #[serde(default)]
+    seed: Option<u64>,
+}
+
+#[derive(Deserialize)]
+struct ExecuteArgs {
+    run_id: String,
+    /// Analyze a Step 3 target shorter than the configured minimum
+    #[serde(default)]
+    force: bool,
+}
+
+#[derive(Deserialize)]
+struct ApproveArgs {
+    run_id: String,
+    approver: String,
+}
+
+#[derive(Deserialize)]
+struct RejectArgs {
+    run_id: String,
+    rejector: String,
+    reason: String,
+}
+
+#[derive(Deserialize)]
+struct RunArgs {
+    run_id: String,
+}
+
+/// Runs driven through the headless command surface
+pub struct HeadlessSession {
+    config: AppConfig,
+    prompt_templates: PromptTemplates,
+    runs: RunRegistry,
+}
+
+impl HeadlessSession {
+    pub fn new(config: AppConfig) -> Self {
+        HeadlessSession {
+            config,
+            prompt_templates: PromptTemplates::default(),
+            runs: RunRegistry::default(),
+        }
+    }
+
+    /// Use the prompt templates the app loads (see `PromptTemplates::load_from`)
+    pub fn with_prompt_templates(mut self, prompt_templates: PromptTemplates) -> Self {
+        self.prompt_templates = prompt_templates;
+        self
+    }
+
+    /// Dispatch one command by name (see `COMMANDS`)
+    pub async fn handle_command(&mut self, name: &str, args: Value) -> Result<Value> {
+        match name {
+            "start_step_0" => self.start_step_0(parse_args(name, args)?).await,
+            "execute_step" => self.execute_step(parse_args(name, args)?).await,
+            "approve_gate" => {
+                let args: ApproveArgs = parse_args(name, args)?;
+                let orchestrator = self.run_mut(&args.run_id)?;
+                let approved = orchestrator.approve_gate(&args.approver)?;
+                Ok(json!({ "approved": approved, "status": status(orchestrator) }))
+            }
+            "reject_gate" => {
+                let args: RejectArgs = parse_args(name, args)?;
+                let orchestrator = self.run_mut(&args.run_id)?;
+                orchestrator.reject_gate(&args.rejector, &args.reason)?;
+                Ok(status(orchestrator))
+            }
+            "status" => {
+                let args: RunArgs = parse_args(name, args)?;
+                Ok(status(self.run_mut(&args.run_id)?))
+            }
+            "list_runs" => Ok(json!({
+                "runs": self.runs.run_ids(),
+                "active_run": self.runs.active_run_id(),
+            })),
+            _ => Err(anyhow!("Unknown command '{}' - expected one of: {}", name, COMMANDS.join(", "))),
+        }
+    }
+
+    /// Handle one JSON-RPC-style request line, returning the response line
+    ///
+    /// Errors (including malformed requests) are reported in the response
+    /// rather than returned, so a reader loop can keep going.
+    pub async fn handle_line(&mut self, line: &str) -> String {
+        let request: Value = match serde_json::from_str(line) {
+            Ok(request) => request,
+            Err(e) => return error_response(Value::Null, &format!("Invalid request: {}", e)),
+        };
+        let id = request.get("id").cloned().unwrap_or(Value::Null);
+        let Some(method) = request.get("method").and_then(Value::as_str) else {
+            return error_response(id, "Invalid request: missing 'method'");
+        };
+        let params = request.get("params").cloned().unwrap_or_else(|| json!({}));
+
+        match self.handle_command(method, params).await {
+            Ok(result) => json!({ "id": id, "result": result }).to_string(),
+            Err(e) => error_response(id, &format!("{:#}", e)),
+        }
+    }
+
+    async fn start_step_0(&mut self, args: StartArgs) -> Result<Value> {
+        let mut orchestrator = new_run(&self.config, &args.run_id, args.seed.or(self.config.seed));
+        // The orchestrator dates its run_id itself; the run is known by that id from here on
+        let run_id = orchestrator.run_id.clone();
+        if self.runs.contains(&run_id) {
+            anyhow::bail!("Run {} is already loaded", run_id);
+        }
+        let run_config = orchestrator.run_config.clone().unwrap_or_else(|| RunConfig::from(&self.config));
+
+        orchestrator = if args.stub {
+            orchestrator.with_stubs()
+        } else {
+            with_run_agents(orchestrator, &self.config, &run_config, self.prompt_templates.clone())
+                .context("API client not configured")?
+        }
+        .with_min_analysis_chars(run_config.min_analysis_chars);
+
+        let intent_summary = orchestrator
+            .execute_step_0(&args.user_intent)
+            .await
+            .context("Failed to execute Step 0")?;
+        let auto_approved = auto_approve_gate(&mut orchestrator);
+        let clarification_questions = convert_questions(&intent_summary.questions_for_clarification);
+
+        let response = json!({
+            "run_id": run_id,
+            "intent_summary": IntentSummaryForFrontend::from(intent_summary),
+            "clarification_questions": clarification_questions,
+            "auto_approved": auto_approved,
+            "status": status(&orchestrator),
+        });
+        self.runs.insert(run_id.clone(), orchestrator);
+        self.runs.activate(&run_id);
+        Ok(response)
+    }
+
+    async fn execute_step(&mut self, args: ExecuteArgs) -> Result<Value> {
+        let orchestrator = self.run_mut(&args.run_id)?;
+        let step = orchestrator.state.step_number();
+        let result = orchestrator.execute_active_step(args.force).await;
+        let auto_approved = finish_step(orchestrator, &result);
+        result?;
+        Ok(json!({ "executed_step": step, "auto_approved": auto_approved, "status": status(orchestrator) }))
+    }
+
+    fn run_mut(&mut self, run_id: &str) -> Result<&mut Orchestrator> {
+        self.runs
+            .get_mut(Some(run_id))
+            .ok_or_else(|| anyhow!("Run {} is not loaded - start it with start_step_0 first", run_id))
+    }
+}
+
+fn parse_args<T: DeserializeOwned>(command: &str, args: Value) -> Result<T> {
+    serde_json::from_value(args).with_context(|| format!("Invalid arguments for {}", command))
+}
+
+/// State, step, pending gate, next step and halt status of a run
+fn status(orchestrator: &Orchestrator) -> Value {
+    json!({
+        "run_id": orchestrator.run_id,
+        "state": format!("{:?}", orchestrator.state),
+        "step": orchestrator.state.step_number(),
+        "pending_gate": orchestrator.pending_gate(),
+        "next_step": orchestrator.describe_next_step(),
+        "halt_status": orchestrator.halt_status(),
+    })
+}
+
+fn error_response(id: Value, message: &str) -> String {
+    json!({ "id": id, "error": { "message": message } }).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_start_step_0_returns_structured_response() {
+        let mut session = HeadlessSession::new(AppConfig::default());
+
+        let response = session
+            .handle_command(
+                "start_step_0",
+                json!({
+                    "run_id": "2025-01-01-Headless",
+                    "user_intent": "Write a guide for onboarding new engineers",
+                    "stub": true
+                }),
+            )
+            .await
+            .unwrap();
+
+        // The run is keyed by the id the orchestrator dated itself, not the requested one
+        let run_id = response["run_id"].as_str().unwrap().to_string();
+        assert!(run_id.ends_with("-Headless"));
+        assert_eq!(response["status"]["run_id"], run_id.as_str());
+        assert_eq!(session.runs.run_ids(), vec![run_id.clone()]);
+        assert_eq!(response["status"]["state"], "Step0GatePending");
+        assert_eq!(response["status"]["pending_gate"]["step"], 0);
+        assert_eq!(response["intent_summary"]["user_intent"], "Write a guide for onboarding new engineers");
+        assert!(response["clarification_questions"].is_array());
+
+        let approved = session
+            .handle_command("approve_gate", json!({"run_id": run_id, "approver": "cli"}))
+            .await
+            .unwrap();
+        assert_eq!(approved["status"]["state"], "Step1Active");
+
+        let line = json!({"id": 7, "method": "execute_step", "params": {"run_id": run_id}}).to_string();
+        let executed = session.handle_line(&line).await;
+        let executed: Value = serde_json::from_str(&executed).unwrap();
+        assert_eq!(executed["id"], 7);
+        assert_eq!(executed["result"]["executed_step"], 1);
+        assert_eq!(executed["result"]["status"]["state"], "Step1GatePending");
+
+        let unknown: Value = serde_json::from_str(&session.handle_line(r#"{"id": 8, "method": "launch"}"#).await).unwrap();
+        assert!(unknown["error"]["message"].as_str().unwrap().starts_with("Unknown command 'launch'"));
+    }
+
+    #[tokio::test]
+    async fn test_execute_step_auto_approves_configured_gates() {
+        let mut session = HeadlessSession::new(AppConfig {
+            auto_approve_gates: true,
+            auto_approve_steps: Some(vec![1]),
+            ..AppConfig::default()
+        });
+        let args = json!({"run_id": "2025-01-01-Auto", "user_intent": "Write a guide for onboarding new engineers", "stub": true});
+
+        let started = session.handle_command("start_step_0", args).await.unwrap();
+        let run_id = started["run_id"].clone();
+        assert_eq!(started["auto_approved"], false);
+        assert_eq!(started["status"]["state"], "Step0GatePending");
+
+        session
+            .handle_command("approve_gate", json!({"run_id": run_id, "approver": "cli"}))
+            .await
+            .unwrap();
+        let executed = session
+            .handle_command("execute_step", json!({"run_id": run_id}))
+            .await
+            .unwrap();
+        assert_eq!(executed["executed_step"], 1);
+        assert_eq!(executed["auto_approved"], true);
+        assert_eq!(executed["status"]["state"], "Step2Active");
+    }
+
+    #[tokio::test]
+    async fn test_execute_step_halts_run_when_circuit_is_open() {
+        use crate::agents::analysis_synthesis::AnalysisSynthesisAgent;
+        use crate::api::test_server::TestServer;
+        use base64::Engine;
+
+        let config = AppConfig {
+            anthropic_api_key: Some(base64::prelude::BASE64_STANDARD.encode("test-key")),
+            circuit_failure_threshold: 1,
+            auto_approve_gates: true,
+            ..AppConfig::default()
+        };
+        let mut session = HeadlessSession::new(config.clone());
+        let args = json!({"run_id": "2025-01-01-Circuit", "user_intent": "Write a guide for onboarding new engineers", "stub": true});
+        let started = session.handle_command("start_step_0", args).await.unwrap();
+        let run_id = started["run_id"].as_str().unwrap().to_string();
+        for _ in 1..=2 {
+            session.handle_command("execute_step", json!({"run_id": run_id})).await.unwrap();
+        }
+
+        // The run's breaker opened on earlier failures
+        let orchestrator = session.runs.take(&run_id).unwrap();
+        let server = TestServer::echo(std::time::Duration::ZERO).await;
+        let client = config
+            .run_api_client(orchestrator.run_config.as_ref().unwrap())
+            .unwrap()
+            .with_api_url(server.messages_url());
+        client.circuit_breaker().record_failure();
+        session.runs.insert(
+            run_id.clone(),
+            orchestrator.with_analysis_synthesis_agent(AnalysisSynthesisAgent::from_client(client)),
+        );
+
+        let err = session
+            .handle_command("execute_step", json!({"run_id": run_id, "force": true}))
+            .await
+            .unwrap_err();
+        assert!(err.chain().any(|e| e.is::<crate::api::CircuitOpen>()), "unexpected error: {:#}", err);
+        let status = session.handle_command("status", json!({"run_id": run_id})).await.unwrap();
+        assert!(status["state"].as_str().unwrap().starts_with("Halted"));
+        assert_eq!(status["halt_status"]["status"], "Halt");
+    }
+}
@@ -3,6 +3,6 @@ pub mod manager;
 
 pub use types::{
     LedgerEntry, EntryType, LedgerState, HaltStatus, MetricsSnapshot,
-    LedgerPayload, ActionValidationResult,
+    LedgerPayload, ActionValidationResult, ChainLinkProof, LedgerChainProof,
 };
-pub use manager::LedgerManager;
+pub use manager::{LedgerManager, GOVERNED_ACTIONS};
@@ -118,6 +118,38 @@ pub struct LedgerEntry {
     pub created_at: DateTime<Utc>,
 }
 
+/// One link of a run's hash chain, as recomputed for an audit
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChainLinkProof {
+    /// 1-based position of the entry in the chain
+    pub position: usize,
+
+    /// Stored hash of the entry
+    pub hash: String,
+
+    /// Stored hash of the previous entry
+    pub prior_hash: Option<String>,
+
+    /// Hash recomputed from the entry's fields and payload
+    pub recomputed_hash: String,
+
+    /// Whether the stored hash matches the recomputed one
+    pub hash_valid: bool,
+
+    /// Whether prior_hash points at the previous entry's hash
+    pub link_valid: bool,
+}
+
+/// Hash chain of a run with the first broken link, if any
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LedgerChainProof {
+    pub run_id: String,
+    pub links: Vec<ChainLinkProof>,
+
+    /// 1-based position of the first entry whose hash or link does not verify
+    pub tampered_at: Option<usize>,
+}
+
 /// Result of action validation
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ActionValidationResult {
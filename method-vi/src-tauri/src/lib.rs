@@ -9,10 +9,14 @@ pub mod agents;
 pub mod governance;
 pub mod commands;
 pub mod artifacts;
+pub mod content;
+pub mod hash;
+pub mod headless;
+pub mod timeline;
 
 use std::sync::Mutex;
 use tauri::Manager;
-use commands::OrchestratorState;
+use commands::{ModelListCache, OrchestratorState, RunRegistry};
 use config::AppConfig;
 
 // Learn more about Tauri commands at https://tauri.app/develop/calling-rust/
@@ -23,10 +27,6 @@ fn greet(name: &str) -> String {
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
-    // Initialize logger
-    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info"))
-        .init();
-
     tauri::Builder::default()
         .plugin(tauri_plugin_opener::init())
         .setup(|app| {
@@ -47,9 +47,19 @@ pub fn run() {
 
             println!("Configuration loaded successfully");
 
+            // Initialize logger: per-module levels from config, else RUST_LOG
+            match config.log_filter() {
+                Some(filter) => env_logger::Builder::new().parse_filters(&filter).init(),
+                None => env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info")).init(),
+            }
+
+            // Settings warnings wait for the logger; anything logged before it is dropped
+            config.metric_enforcement.warn_unenforced();
+
             // Initialize orchestrator state
-            app.manage(OrchestratorState(Mutex::new(None)));
+            app.manage(OrchestratorState(Mutex::new(RunRegistry::default())));
             app.manage(Mutex::new(config));
+            app.manage(ModelListCache::default());
 
             Ok(())
         })
@@ -60,19 +70,25 @@ pub fn run() {
             commands::execute_step_2,
             commands::execute_step_3,
             commands::execute_step_4,
+            commands::find_similar_glossary_terms,
             commands::execute_step_5,
             commands::execute_step_6,
             commands::execute_step_6_5,
+            commands::list_patterns,
             commands::execute_closure,
             commands::export_markdown,
+            commands::export_run_html,
             commands::export_json,
             commands::approve_gate,
             commands::reject_gate,
             commands::handle_halt_decision,
+            commands::acknowledge_pause,
+            commands::override_halt,
             commands::submit_clarifications,
             commands::get_all_callouts,
             commands::get_pending_callouts,
             commands::get_callout_summary,
+            commands::get_gate_callout_summary,
             commands::can_proceed,
             commands::acknowledge_callout,
             commands::acknowledge_all_callouts,
@@ -83,6 +99,43 @@ pub fn run() {
             commands::get_gate_preview,
             commands::get_hard_blocks,
             commands::submit_gate_decision,
+            commands::get_run_artifacts,
+            commands::get_signal_chain,
+            commands::get_run_timeline,
+            commands::get_run_timing,
+            commands::save_artifact_to_file,
+            commands::get_spine_health,
+            commands::get_spine_graph,
+            commands::check_intent_drift,
+            commands::get_lens_efficacy,
+            commands::get_lens_analyses,
+            commands::get_integrated_diagnostic,
+            commands::activate_run,
+            commands::get_run_config,
+            commands::fork_run,
+            commands::list_models,
+            commands::get_charter_objectives,
+            commands::delete_run,
+            commands::get_halt_status,
+            commands::get_permitted_actions,
+            commands::get_run_cost,
+            commands::get_ledger_chain_proof,
+            commands::describe_next_step,
+            commands::list_pending_gates,
+            commands::get_metrics_prometheus,
+            commands::diff_metrics,
+            commands::compare_runs,
+            commands::prune_run_artifacts,
+            commands::audit_artifact_hashes,
+            commands::apply_clarification,
+            commands::record_scope_expansion,
+            commands::get_export_dir,
+            commands::export_run_bundle,
+            commands::regenerate_artifact,
+            commands::tag_run,
+            commands::untag_run,
+            commands::get_run_tags,
+            commands::search_runs,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
@@ -1,5 +1,5 @@
 pub mod types;
 pub mod router;
 
-pub use types::{Signal, SignalPayload, SignalType};
+pub use types::{Signal, SignalNaming, SignalPayload, SignalType};
 pub use router::SignalRouter;
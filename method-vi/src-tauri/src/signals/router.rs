@@ -1,7 +1,11 @@
-use super::types::{Signal, SignalPayload, SignalType};
+use super::types::{Signal, SignalNaming, SignalPayload, SignalType};
+use anyhow::{Context, Result};
 use chrono::Utc;
+use log::warn;
+use rusqlite::Connection;
 use sha2::{Digest, Sha256};
 use std::collections::HashMap;
+use std::path::PathBuf;
 
 /// Signal Router for managing signal emission, sequencing, and gate recognition
 ///
@@ -10,6 +14,12 @@ use std::collections::HashMap;
 pub struct SignalRouter {
     /// Signal chains organized by run_id
     signal_chains: HashMap<String, Vec<Signal>>,
+
+    /// Database file to persist signals into as they are emitted (None = in-memory only)
+    db_path: Option<PathBuf>,
+
+    /// External signal names for emitted and persisted signals
+    naming: SignalNaming,
 }
 
 impl SignalRouter {
@@ -17,9 +27,23 @@ impl SignalRouter {
     pub fn new() -> Self {
         SignalRouter {
             signal_chains: HashMap::new(),
+            db_path: None,
+            naming: SignalNaming::default(),
         }
     }
 
+    /// Record emitted signals under these external names
+    pub fn with_naming(mut self, naming: SignalNaming) -> Self {
+        self.naming = naming;
+        self
+    }
+
+    /// Persist every emitted signal to the `signals` table of the given database
+    pub fn with_persistence(mut self, db_path: PathBuf) -> Self {
+        self.db_path = Some(db_path);
+        self
+    }
+
     /// Emit a signal for a run
     ///
     /// Creates a new signal with hash chain integrity. Each signal's hash
@@ -66,6 +90,7 @@ impl SignalRouter {
             &payload,
         );
 
+        let external_name = self.naming.external_name(&signal_type);
         let signal = Signal {
             signal_type,
             run_id: run_id.to_string(),
@@ -73,6 +98,7 @@ impl SignalRouter {
             prior_signal_hash,
             hash,
             payload,
+            external_name,
         };
 
         // Add to signal chain
@@ -81,9 +107,87 @@ impl SignalRouter {
             .or_insert_with(Vec::new)
             .push(signal.clone());
 
+        // Persistence failures must not interrupt the workflow - the in-memory chain is authoritative
+        if let Some(ref db_path) = self.db_path {
+            let result = crate::database::open_connection(db_path)
+                .and_then(|conn| Self::persist_signal(&conn, &signal));
+            if let Err(e) = result {
+                warn!("Failed to persist signal {} for run {}: {}", signal.signal_type.as_str(), run_id, e);
+            }
+        }
+
         signal
     }
 
+    /// Write a single signal to the `signals` table
+    pub fn persist_signal(conn: &Connection, signal: &Signal) -> Result<()> {
+        let payload = serde_json::to_string(&signal.payload)
+            .context("Failed to serialize signal payload")?;
+
+        conn.execute(
+            r#"
+            INSERT INTO signals (run_id, signal_type, timestamp, prior_signal_hash, hash, payload, external_name)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+            "#,
+            rusqlite::params![
+                signal.run_id,
+                signal.signal_type.as_str(),
+                signal.timestamp.to_rfc3339(),
+                signal.prior_signal_hash,
+                signal.hash,
+                payload,
+                signal.external_name,
+            ],
+        )
+        .context("Failed to persist signal")?;
+
+        Ok(())
+    }
+
+    /// Load a run's signal chain from the `signals` table
+    ///
+    /// Replaces any in-memory chain for the run so subsequent emissions link
+    /// onto the persisted chain. Returns the loaded chain in emission order.
+    pub fn load_chain(&mut self, conn: &Connection, run_id: &str) -> Result<Vec<Signal>> {
+        let mut stmt = conn
+            .prepare("SELECT signal_type, timestamp, prior_signal_hash, hash, payload, external_name FROM signals WHERE run_id = ?1 ORDER BY id ASC")
+            .context("Failed to prepare query")?;
+
+        let rows = stmt
+            .query_map([run_id], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, Option<String>>(2)?,
+                    row.get::<_, String>(3)?,
+                    row.get::<_, String>(4)?,
+                    row.get::<_, Option<String>>(5)?,
+                ))
+            })
+            .context("Failed to query signals")?
+            .collect::<Result<Vec<_>, _>>()
+            .context("Failed to collect signals")?;
+
+        let mut chain = Vec::with_capacity(rows.len());
+        for (signal_type, timestamp, prior_signal_hash, hash, payload, external_name) in rows {
+            let external_name = external_name.unwrap_or_else(|| signal_type.clone());
+            chain.push(Signal {
+                signal_type: SignalType::parse(&signal_type)
+                    .with_context(|| format!("Unknown signal type: {}", signal_type))?,
+                run_id: run_id.to_string(),
+                timestamp: timestamp.parse().context("Invalid signal timestamp")?,
+                prior_signal_hash,
+                hash,
+                payload: serde_json::from_str(&payload).context("Invalid signal payload")?,
+                external_name,
+            });
+        }
+
+        self.signal_chains.insert(run_id.to_string(), chain.clone());
+
+        Ok(chain)
+    }
+
     /// Check if a signal type requires a gate (human approval)
     ///
     /// Gate signals:
@@ -122,6 +226,28 @@ impl SignalRouter {
             .unwrap_or_default()
     }
 
+    /// Most recent signal of the given type emitted for a run
+    pub fn last_signal_of_type(&self, run_id: &str, signal_type: &SignalType) -> Option<&Signal> {
+        self.signal_chains
+            .get(run_id)?
+            .iter()
+            .rev()
+            .find(|signal| &signal.signal_type == signal_type)
+    }
+
+    /// All signals of the given type emitted for a run, in chronological order
+    pub fn signals_of_type(&self, run_id: &str, signal_type: &SignalType) -> Vec<&Signal> {
+        self.signal_chains
+            .get(run_id)
+            .map(|chain| {
+                chain
+                    .iter()
+                    .filter(|signal| &signal.signal_type == signal_type)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
     /// Verify signal chain integrity for a run
     ///
     /// Checks that all signals in the chain have valid hash links.
@@ -210,6 +336,7 @@ impl Default for SignalRouter {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::signals::SignalNaming;
 
     /// TC-SR-001-A: Ready_for_Step_1 signal with required fields
     #[test]
@@ -490,6 +617,47 @@ mod tests {
         println!("✓ Test passed");
     }
 
+    /// Test last-of-type and all-of-type queries over a mixed chain
+    #[test]
+    fn test_signals_of_type() {
+        let mut router = SignalRouter::new();
+        let payload = |step_from: i32, step_to: i32| SignalPayload {
+            step_from,
+            step_to,
+            artifacts_produced: vec![],
+            metrics_snapshot: None,
+            gate_required: false,
+        };
+
+        router.emit_signal(SignalType::ReadyForStep1, "run-a", payload(0, 1));
+        router.emit_signal(SignalType::MetricsWarning, "run-a", payload(1, 1));
+        router.emit_signal(SignalType::BaselineFrozen, "run-a", payload(1, 2));
+        router.emit_signal(SignalType::MetricsWarning, "run-a", payload(2, 2));
+        router.emit_signal(SignalType::Halt, "run-a", payload(2, 2));
+        router.emit_signal(SignalType::MetricsWarning, "run-b", payload(3, 3));
+
+        let last = router.last_signal_of_type("run-a", &SignalType::MetricsWarning).unwrap();
+        assert_eq!(last.payload.step_from, 2);
+        let chain = router.get_signal_chain("run-a");
+        assert_eq!(last.hash, chain[3].hash);
+
+        let warnings = router.signals_of_type("run-a", &SignalType::MetricsWarning);
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(warnings[0].payload.step_from, 1);
+        assert!(warnings.iter().all(|signal| signal.run_id == "run-a"));
+
+        assert_eq!(
+            router.last_signal_of_type("run-a", &SignalType::Halt).unwrap().hash,
+            chain[4].hash
+        );
+        assert!(router.last_signal_of_type("run-a", &SignalType::ValidationComplete).is_none());
+
+        // Other runs' signals are never returned
+        assert!(router.last_signal_of_type("run-b", &SignalType::Halt).is_none());
+        assert_eq!(router.signals_of_type("run-b", &SignalType::MetricsWarning).len(), 1);
+        assert!(router.signals_of_type("run-c", &SignalType::MetricsWarning).is_empty());
+    }
+
     /// Test multiple isolated run chains
     #[test]
     fn test_multiple_run_isolation() {
@@ -586,4 +754,94 @@ mod tests {
 
         println!("✓ Comprehensive workflow test passed");
     }
+
+    #[test]
+    fn test_signal_chain_persist_and_reload() {
+        println!("\n=== Signal chain persistence round-trip ===");
+
+        let db_path = std::env::temp_dir().join(format!("method-vi-signals-{}.db", uuid::Uuid::new_v4()));
+        {
+            let conn = Connection::open(&db_path).expect("Failed to open database");
+            crate::database::schema::create_schema(&conn).expect("Failed to create schema");
+        }
+
+        let run_id = "run-persist";
+        let mut router = SignalRouter::new().with_persistence(db_path.clone());
+        let emitted = [
+            (SignalType::ReadyForStep1, 0, 1, true),
+            (SignalType::MetricUpdate, 1, 1, false),
+            (SignalType::BaselineFrozen, 1, 2, true),
+        ];
+        for (signal_type, step_from, step_to, gate_required) in emitted.iter() {
+            let payload = SignalPayload {
+                step_from: *step_from,
+                step_to: *step_to,
+                artifacts_produced: vec![format!("artifact-{}", step_to)],
+                metrics_snapshot: Some(serde_json::json!({"ci": 0.8})),
+                gate_required: *gate_required,
+            };
+            router.emit_signal(signal_type.clone(), run_id, payload);
+        }
+
+        let conn = Connection::open(&db_path).expect("Failed to reopen database");
+        let mut reloaded = SignalRouter::new();
+        let chain = reloaded.load_chain(&conn, run_id).expect("Failed to load chain");
+
+        println!("Reloaded {} signals", chain.len());
+        assert_eq!(chain.len(), 3);
+        let types: Vec<SignalType> = chain.iter().map(|s| s.signal_type.clone()).collect();
+        assert_eq!(
+            types,
+            vec![SignalType::ReadyForStep1, SignalType::MetricUpdate, SignalType::BaselineFrozen]
+        );
+
+        let original = router.get_signal_chain(run_id);
+        for (loaded, emitted) in chain.iter().zip(original.iter()) {
+            assert_eq!(loaded.hash, emitted.hash);
+            assert_eq!(loaded.prior_signal_hash, emitted.prior_signal_hash);
+        }
+        assert!(reloaded.verify_chain_integrity(run_id));
+
+        drop(conn);
+        let _ = std::fs::remove_file(&db_path);
+        println!("✓ Persisted chain matches emitted chain");
+    }
+
+    #[test]
+    fn test_signal_naming_alias_is_emitted_and_persisted() {
+        let db_path = std::env::temp_dir().join(format!("method-vi-signal-naming-{}.db", uuid::Uuid::new_v4()));
+        {
+            let conn = Connection::open(&db_path).expect("Failed to open database");
+            crate::database::schema::create_schema(&conn).expect("Failed to create schema");
+        }
+
+        let naming = SignalNaming::default().with_alias(SignalType::ReadyForAnalysis, "analysis.ready");
+        let mut router = SignalRouter::new().with_naming(naming).with_persistence(db_path.clone());
+        let payload = |step_from, step_to| SignalPayload {
+            step_from,
+            step_to,
+            artifacts_produced: vec![],
+            metrics_snapshot: None,
+            gate_required: true,
+        };
+        let aliased = router.emit_signal(SignalType::ReadyForAnalysis, "run-naming", payload(2, 3));
+        let plain = router.emit_signal(SignalType::ReadyForSynthesis, "run-naming", payload(3, 4));
+        assert_eq!(aliased.external_name, "analysis.ready");
+        assert_eq!(plain.external_name, "Ready_for_Synthesis");
+
+        let conn = Connection::open(&db_path).expect("Failed to reopen database");
+        let stored: String = conn
+            .query_row("SELECT external_name FROM signals WHERE signal_type = 'Ready_for_Analysis'", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(stored, "analysis.ready");
+
+        // Internal logic still keys on the enum
+        let chain = SignalRouter::new().load_chain(&conn, "run-naming").unwrap();
+        assert_eq!(chain[0].signal_type, SignalType::ReadyForAnalysis);
+        assert_eq!(chain[0].external_name, "analysis.ready");
+        assert!(SignalRouter::is_gate_signal(&chain[0].signal_type));
+
+        drop(conn);
+        let _ = std::fs::remove_file(&db_path);
+    }
 }
@@ -1,5 +1,6 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 /// Signal types in Method-VI workflow
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -41,6 +42,9 @@ pub enum SignalType {
 
     /// Metrics warning - requires attention but not a hard stop
     MetricsWarning,
+
+    /// Run reached Completed - payload carries the final scorecard
+    RunCompleted,
 }
 
 impl SignalType {
@@ -59,10 +63,57 @@ impl SignalType {
             SignalType::MetricUpdate => "Metric_Update",
             SignalType::Halt => "Halt",
             SignalType::MetricsWarning => "Metrics_Warning",
+            SignalType::RunCompleted => "Run_Completed",
+        }
+    }
+
+    /// Parse a signal type from its string representation (inverse of as_str)
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "Ready_for_Step_1" => Some(SignalType::ReadyForStep1),
+            "Baseline_Frozen" => Some(SignalType::BaselineFrozen),
+            "Ready_for_Analysis" => Some(SignalType::ReadyForAnalysis),
+            "Ready_for_Synthesis" => Some(SignalType::ReadyForSynthesis),
+            "Ready_for_Redesign" => Some(SignalType::ReadyForRedesign),
+            "Ready_for_Validation" => Some(SignalType::ReadyForValidation),
+            "Validation_Complete" => Some(SignalType::ValidationComplete),
+            "Learning_Harvested" => Some(SignalType::LearningHarvested),
+            "New_Run_Ready" => Some(SignalType::NewRunReady),
+            "Metric_Update" => Some(SignalType::MetricUpdate),
+            "Halt" => Some(SignalType::Halt),
+            "Metrics_Warning" => Some(SignalType::MetricsWarning),
+            "Run_Completed" => Some(SignalType::RunCompleted),
+            _ => None,
         }
     }
 }
 
+/// External aliases for signal types, for integrators mapping signals to other systems
+///
+/// Keyed by the internal name (`SignalType::as_str`), e.g.
+/// `{"signal_naming": {"Ready_for_Analysis": "analysis.ready"}}`. Only the
+/// external representation changes; workflow logic keys on `SignalType`.
+/// Signal types without an alias keep their internal name.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct SignalNaming(HashMap<String, String>);
+
+impl SignalNaming {
+    /// Set the external name of one signal type
+    pub fn with_alias(mut self, signal_type: SignalType, alias: &str) -> Self {
+        self.0.insert(signal_type.as_str().to_string(), alias.to_string());
+        self
+    }
+
+    /// Name to show outside the app for a signal type
+    pub fn external_name(&self, signal_type: &SignalType) -> String {
+        self.0
+            .get(signal_type.as_str())
+            .cloned()
+            .unwrap_or_else(|| signal_type.as_str().to_string())
+    }
+}
+
 /// Signal payload containing transition details
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SignalPayload {
@@ -104,4 +155,8 @@ pub struct Signal {
 
     /// Signal payload
     pub payload: SignalPayload,
+
+    /// Name under `SignalNaming` when emitted (not part of the hash)
+    #[serde(default)]
+    pub external_name: String,
 }
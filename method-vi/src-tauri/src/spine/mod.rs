@@ -1,5 +1,5 @@
 pub mod types;
 pub mod manager;
 
-pub use types::{Artifact, ArtifactType, Dependency, DependencyType};
+pub use types::{Artifact, ArtifactType, Dependency, DependencyType, SpineGraph, SpineHealthReport};
 pub use manager::SpineManager;
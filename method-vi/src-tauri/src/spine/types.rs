@@ -1,6 +1,14 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+/// Critical Path artifact types, in order
+pub const CRITICAL_PATH: [ArtifactType; 4] = [
+    ArtifactType::Intent_Anchor,
+    ArtifactType::Charter,
+    ArtifactType::Baseline,
+    ArtifactType::Core_Thesis,
+];
+
 /// Artifact types in the Method-VI workflow
 ///
 /// The Critical Path consists of: Intent_Anchor → Charter → Baseline → Core_Thesis
@@ -63,6 +71,58 @@ impl ArtifactType {
                 | ArtifactType::Core_Thesis
         )
     }
+
+    /// Returns true if this artifact type is locked under the given immutable set
+    ///
+    /// Critical Path types are always locked; configuration can lock others.
+    pub fn is_immutable_in(&self, immutable_types: &crate::artifacts::ImmutableArtifactTypes) -> bool {
+        self.is_on_critical_path() || immutable_types.contains(self.name())
+    }
+
+    /// Parses an artifact type as stored in the artifacts table
+    ///
+    /// Matching ignores case and underscores, so "IntentAnchor" and
+    /// "Intent_Anchor" are equivalent. Unknown types map to `Other`.
+    pub fn from_db_type(value: &str) -> Self {
+        let key: String = value
+            .chars()
+            .filter(|c| *c != '_')
+            .flat_map(char::to_lowercase)
+            .collect();
+
+        match key.as_str() {
+            "intentanchor" => ArtifactType::Intent_Anchor,
+            "charter" => ArtifactType::Charter,
+            "baseline" | "baselinereport" => ArtifactType::Baseline,
+            "corethesis" => ArtifactType::Core_Thesis,
+            "governancesummary" => ArtifactType::Governance_Summary,
+            "lensefficacyreport" => ArtifactType::Lens_Efficacy_Report,
+            "innovationnotes" => ArtifactType::Innovation_Notes,
+            "diagnosticsummary" => ArtifactType::Diagnostic_Summary,
+            "frameworkdraft" => ArtifactType::Framework_Draft,
+            "section" => ArtifactType::Section,
+            "patch" => ArtifactType::Patch,
+            _ => ArtifactType::Other(value.to_string()),
+        }
+    }
+
+    /// Display name used in reports (e.g., "Intent_Anchor")
+    pub fn name(&self) -> &str {
+        match self {
+            ArtifactType::Intent_Anchor => "Intent_Anchor",
+            ArtifactType::Charter => "Charter",
+            ArtifactType::Baseline => "Baseline",
+            ArtifactType::Core_Thesis => "Core_Thesis",
+            ArtifactType::Governance_Summary => "Governance_Summary",
+            ArtifactType::Lens_Efficacy_Report => "Lens_Efficacy_Report",
+            ArtifactType::Innovation_Notes => "Innovation_Notes",
+            ArtifactType::Diagnostic_Summary => "Diagnostic_Summary",
+            ArtifactType::Framework_Draft => "Framework_Draft",
+            ArtifactType::Section => "Section",
+            ArtifactType::Patch => "Patch",
+            ArtifactType::Other(name) => name,
+        }
+    }
 }
 
 /// Dependency edge types between artifacts
@@ -78,6 +138,27 @@ pub enum DependencyType {
     References,
 }
 
+impl DependencyType {
+    /// Edge type as stored in the spine_edges table
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DependencyType::DerivedFrom => "derived_from",
+            DependencyType::ConstrainedBy => "constrained_by",
+            DependencyType::References => "references",
+        }
+    }
+
+    /// Parses an edge type from the spine_edges table
+    pub fn from_db_type(value: &str) -> Option<Self> {
+        match value {
+            "derived_from" => Some(DependencyType::DerivedFrom),
+            "constrained_by" => Some(DependencyType::ConstrainedBy),
+            "references" => Some(DependencyType::References),
+            _ => None,
+        }
+    }
+}
+
 /// Represents an artifact node in the Coherence Spine DAG
 ///
 /// Each artifact represents a deliverable or checkpoint in the Method-VI workflow.
@@ -166,3 +247,97 @@ pub struct DependencyInfo {
     /// Type of dependency relationship
     pub dependency_type: DependencyType,
 }
+
+/// Kind of problem found by a spine health check
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SpineIssueKind {
+    Break,
+    Orphan,
+    Cycle,
+}
+
+/// A single spine problem with a human-readable explanation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpineIssue {
+    pub kind: SpineIssueKind,
+
+    /// Artifacts involved in the issue
+    pub artifact_ids: Vec<String>,
+
+    /// Explanation for display (e.g., "Section-003 (Section) has no path to the Intent_Anchor")
+    pub explanation: String,
+}
+
+/// Artifact node of a spine graph export
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SpineGraphNode {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub artifact_type: String,
+    pub step: i32,
+    pub immutable: bool,
+    /// True when the artifact is on the linked Critical Path (see `SpineManager::critical_path`)
+    pub on_critical_path: bool,
+}
+
+/// Dependency edge of a spine graph export, with the type as stored (e.g., "derived_from")
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SpineGraphEdge {
+    pub from: String,
+    pub to: String,
+    #[serde(rename = "type")]
+    pub edge_type: String,
+}
+
+/// The spine as plain node and edge lists, for custom renderers
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SpineGraph {
+    pub nodes: Vec<SpineGraphNode>,
+    pub edges: Vec<SpineGraphEdge>,
+}
+
+impl SpineGraph {
+    /// Render the graph as a Mermaid flowchart
+    ///
+    /// Nodes get positional IDs (`n0`, `n1`, ...) so artifact IDs never need
+    /// escaping; Critical Path nodes use the `critical` class. Edges point from
+    /// the dependent artifact to its dependency, labelled with the edge type.
+    /// Edges to artifacts outside the graph are skipped.
+    pub fn to_mermaid(&self) -> String {
+        let index: std::collections::HashMap<&str, usize> = self
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(i, node)| (node.id.as_str(), i))
+            .collect();
+
+        let mut output = String::from("flowchart TD\n");
+        for (i, node) in self.nodes.iter().enumerate() {
+            let class = if node.on_critical_path { ":::critical" } else { "" };
+            output.push_str(&format!(
+                "    n{}[\"{} (Step {})\"]{}\n",
+                i,
+                node.artifact_type.replace('"', "'"),
+                node.step,
+                class
+            ));
+        }
+        for edge in &self.edges {
+            if let (Some(from), Some(to)) = (index.get(edge.from.as_str()), index.get(edge.to.as_str())) {
+                output.push_str(&format!("    n{} -->|{}| n{}\n", from, edge.edge_type, to));
+            }
+        }
+        output.push_str("    classDef critical stroke-width:3px\n");
+        output
+    }
+}
+
+/// Integrity report plus one explained issue per break, orphan and cycle
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpineHealthReport {
+    pub run_id: String,
+    pub artifact_count: usize,
+    pub edge_count: usize,
+    pub integrity: SpineIntegrityReport,
+    pub issues: Vec<SpineIssue>,
+}
@@ -0,0 +1,286 @@
+//! Unified run timeline: ledger entries, signals and callouts in time order
+//!
+//! Also derives where a run's time went (per step, and waiting on gates) from
+//! its ledger timestamps.
+//!
+//! Each source keeps its own record of the run; the audit view wants one
+//! story. Events are merged by timestamp, and a ledger entry that only records
+//! a signal emission is folded into that signal's event.
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::governance::Callout;
+use crate::ledger::LedgerEntry;
+use crate::signals::Signal;
+
+/// Where a timeline event came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TimelineEventKind {
+    Ledger,
+    Signal,
+    Callout,
+}
+
+/// One event in a run's timeline
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimelineEvent {
+    pub kind: TimelineEventKind,
+    pub timestamp: DateTime<Utc>,
+    pub step: Option<u8>,
+    /// Ledger action, signal name or callout metric and tier
+    pub summary: String,
+    /// Ledger entry hash, signal hash or callout id
+    pub reference: String,
+    /// Hashes of ledger entries that recorded this same event
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ledger_refs: Vec<String>,
+}
+
+/// Merge a run's ledger entries, signals and callouts into one time-ordered list
+///
+/// Ledger entries carrying the `signal_hash` of an emitted signal describe the
+/// same action as the signal and are folded into its event. Events with equal
+/// timestamps keep the order ledger, signals, callouts.
+pub fn build_timeline(entries: &[LedgerEntry], signals: &[Signal], callouts: &[Callout]) -> Vec<TimelineEvent> {
+    let mut signal_events: Vec<TimelineEvent> = signals
+        .iter()
+        .map(|signal| TimelineEvent {
+            kind: TimelineEventKind::Signal,
+            timestamp: signal.timestamp,
+            step: u8::try_from(signal.payload.step_from).ok(),
+            summary: if signal.external_name.is_empty() {
+                signal.signal_type.as_str().to_string()
+            } else {
+                signal.external_name.clone()
+            },
+            reference: signal.hash.clone(),
+            ledger_refs: Vec::new(),
+        })
+        .collect();
+
+    let mut events = Vec::with_capacity(entries.len() + signals.len() + callouts.len());
+    for entry in entries {
+        let signal_hash = entry
+            .payload
+            .outputs
+            .as_ref()
+            .and_then(|outputs| outputs["signal_hash"].as_str());
+        if let Some(event) = signal_hash.and_then(|hash| signal_events.iter_mut().find(|e| e.reference == hash)) {
+            event.ledger_refs.push(entry.hash.clone());
+            continue;
+        }
+
+        events.push(TimelineEvent {
+            kind: TimelineEventKind::Ledger,
+            timestamp: entry.created_at,
+            step: entry.step.and_then(|step| u8::try_from(step).ok()),
+            summary: entry.payload.action.clone(),
+            reference: entry.hash.clone(),
+            ledger_refs: Vec::new(),
+        });
+    }
+    events.append(&mut signal_events);
+
+    events.extend(callouts.iter().map(|callout| TimelineEvent {
+        kind: TimelineEventKind::Callout,
+        timestamp: callout.created_at,
+        step: Some(callout.step.as_u8()),
+        summary: format!("{} {:?}", callout.metric_name, callout.tier),
+        reference: callout.id.clone(),
+        ledger_refs: Vec::new(),
+    }));
+
+    events.sort_by_key(|event| event.timestamp);
+    events
+}
+
+/// Time spent on one step, from its ledger entries
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StepTiming {
+    pub step: u8,
+    /// From the step's first entry to its completion (None while in progress)
+    pub duration_secs: Option<f64>,
+    /// Time its gate waited for a human decision
+    pub gate_wait_secs: f64,
+}
+
+/// Where a run's time went
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RunTiming {
+    /// From the first ledger entry to the latest
+    pub total_secs: f64,
+    /// Steps with at least one ledger entry, in step order
+    pub steps: Vec<StepTiming>,
+    /// Sum of all gate waits (human latency)
+    pub gate_wait_secs: f64,
+}
+
+fn secs_between(from: DateTime<Utc>, to: DateTime<Utc>) -> f64 {
+    (to - from).num_milliseconds() as f64 / 1000.0
+}
+
+/// Total, per-step and gate-wait durations from a run's ledger entries
+///
+/// A step is complete at its `step_N_complete` entry, or at its gate signal
+/// for steps without one. A gate waits from its `gate_signal_emitted` entry to
+/// the next approval or rejection.
+pub fn compute_run_timing(entries: &[LedgerEntry]) -> RunTiming {
+    let mut entries: Vec<&LedgerEntry> = entries.iter().collect();
+    entries.sort_by_key(|entry| entry.created_at);
+
+    let total_secs = match (entries.first(), entries.last()) {
+        (Some(first), Some(last)) => secs_between(first.created_at, last.created_at),
+        _ => 0.0,
+    };
+
+    // Step -> (first entry time, timing so far)
+    let mut steps: BTreeMap<u8, (DateTime<Utc>, StepTiming)> = BTreeMap::new();
+    for (index, entry) in entries.iter().enumerate() {
+        let Some(step) = entry.step.and_then(|step| u8::try_from(step).ok()) else {
+            continue;
+        };
+        let (start, timing) = steps
+            .entry(step)
+            .or_insert_with(|| (entry.created_at, StepTiming { step, duration_secs: None, gate_wait_secs: 0.0 }));
+
+        let action = entry.payload.action.as_str();
+        let completes = (action.starts_with("step_") && action.ends_with("_complete")) || action == "gate_signal_emitted";
+        if completes && timing.duration_secs.is_none() {
+            timing.duration_secs = Some(secs_between(*start, entry.created_at));
+        }
+
+        if action == "gate_signal_emitted" {
+            let decided = entries[index + 1..].iter().find(|later| {
+                matches!(later.payload.action.as_str(), "gate_approved" | "gate_auto_approved" | "gate_rejected")
+            });
+            if let Some(decision) = decided {
+                timing.gate_wait_secs += secs_between(entry.created_at, decision.created_at);
+            }
+        }
+    }
+    let steps: Vec<StepTiming> = steps.into_values().map(|(_, timing)| timing).collect();
+
+    let gate_wait_secs = steps.iter().map(|timing| timing.gate_wait_secs).sum();
+    RunTiming { total_secs, steps, gate_wait_secs }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::governance::{CalloutTier, Step, StructureMode};
+    use crate::ledger::{EntryType, LedgerPayload};
+    use crate::signals::{SignalPayload, SignalType};
+    use chrono::TimeZone;
+
+    fn at(seconds: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 1, 1, 12, 0, seconds).unwrap()
+    }
+
+    fn entry(action: &str, seconds: u32, outputs: Option<serde_json::Value>) -> LedgerEntry {
+        LedgerEntry {
+            id: None,
+            run_id: "run".to_string(),
+            entry_type: EntryType::Decision,
+            step: Some(1),
+            role: None,
+            payload: LedgerPayload {
+                action: action.to_string(),
+                inputs: None,
+                outputs,
+                rationale: None,
+            },
+            prior_hash: None,
+            hash: format!("ledger-{}", action),
+            created_at: at(seconds),
+        }
+    }
+
+    #[test]
+    fn test_timeline_interleaves_sources_by_time() {
+        let signal = Signal {
+            signal_type: SignalType::BaselineFrozen,
+            run_id: "run".to_string(),
+            timestamp: at(20),
+            prior_signal_hash: None,
+            hash: "signal-1".to_string(),
+            payload: SignalPayload {
+                step_from: 1,
+                step_to: 2,
+                artifacts_produced: Vec::new(),
+                metrics_snapshot: None,
+                gate_required: true,
+            },
+            external_name: String::new(),
+        };
+        let mut callout = Callout::new(
+            CalloutTier::Warning, "IAS", 0.55, None, "", "", "",
+            Step::Step1_Baseline, StructureMode::Builder,
+        );
+        callout.created_at = at(15);
+
+        let entries = vec![
+            entry("step_1_complete", 10, None),
+            // Records the signal's emission: folded into the signal event
+            entry("gate_signal_emitted", 20, Some(serde_json::json!({ "signal_hash": "signal-1" }))),
+            entry("gate_approved", 30, None),
+        ];
+
+        let timeline = build_timeline(&entries, &[signal], &[callout.clone()]);
+        let order: Vec<(TimelineEventKind, &str)> = timeline
+            .iter()
+            .map(|event| (event.kind, event.reference.as_str()))
+            .collect();
+        assert_eq!(
+            order,
+            vec![
+                (TimelineEventKind::Ledger, "ledger-step_1_complete"),
+                (TimelineEventKind::Callout, callout.id.as_str()),
+                (TimelineEventKind::Signal, "signal-1"),
+                (TimelineEventKind::Ledger, "ledger-gate_approved"),
+            ]
+        );
+        assert_eq!(timeline[2].summary, SignalType::BaselineFrozen.as_str());
+        assert_eq!(timeline[2].ledger_refs, vec!["ledger-gate_signal_emitted"]);
+        assert_eq!(timeline[1].summary, "IAS Warning");
+
+        let json = serde_json::to_value(&timeline[1]).unwrap();
+        assert_eq!(json["kind"], "callout");
+    }
+
+    #[test]
+    fn test_run_timing_from_ledger_timestamps() {
+        let mut entries = vec![
+            entry("run_start", 0, None),
+            entry("intent_captured", 5, None),
+            entry("gate_signal_emitted", 10, None),
+            entry("gate_approved", 40, None),
+            entry("baseline_drafted", 41, None),
+            entry("step_1_complete", 52, None),
+            entry("gate_signal_emitted", 55, None),
+            entry("gate_approved", 58, None),
+            entry("scope_expansion", 59, None),
+        ];
+        for (entry, step) in entries.iter_mut().zip([0, 0, 0, 0, 1, 1, 1, 1, 2]) {
+            entry.step = Some(step);
+        }
+
+        let timing = compute_run_timing(&entries);
+        assert_eq!(timing.total_secs, 59.0);
+        assert_eq!(
+            timing.steps,
+            vec![
+                StepTiming { step: 0, duration_secs: Some(10.0), gate_wait_secs: 30.0 },
+                StepTiming { step: 1, duration_secs: Some(11.0), gate_wait_secs: 3.0 },
+                StepTiming { step: 2, duration_secs: None, gate_wait_secs: 0.0 },
+            ]
+        );
+        assert_eq!(timing.gate_wait_secs, 33.0);
+
+        // Steps never run are omitted
+        assert!(timing.steps.iter().all(|step| step.step <= 2));
+    }
+}
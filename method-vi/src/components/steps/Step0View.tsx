@@ -1,5 +1,6 @@
 import { useState } from 'react';
 import { invoke } from '@tauri-apps/api/core';
+import { useNavigate } from 'react-router-dom';
 
 interface Step0ViewProps {
   runId: string;
@@ -27,6 +28,7 @@ interface PatternRecommendation {
 }
 
 interface Step0Result {
+  run_id: string;
   intent_summary: IntentSummary;
   clarification_questions: ClarificationQuestion[];
   pattern_recommendations: PatternRecommendation[];
@@ -40,6 +42,14 @@ export default function Step0View({ runId, onGateReached }: Step0ViewProps) {
   const [result, setResult] = useState<Step0Result | null>(null);
   const [error, setError] = useState<string | null>(null);
   const [answers, setAnswers] = useState<Record<number, string>>({});
+  const navigate = useNavigate();
+
+  // The backend stores the run under its own dated id; follow it if it differs
+  const followRunId = (response: Step0Result) => {
+    if (response.run_id !== runId) {
+      navigate(`/run/${response.run_id}`, { replace: true });
+    }
+  };
 
   const handleBeginAnalysis = async () => {
     if (!intentText.trim()) {
@@ -60,6 +70,7 @@ export default function Step0View({ runId, onGateReached }: Step0ViewProps) {
 
       console.log('Step 0 result:', response);
       setResult(response);
+      followRunId(response);
 
       // If there are clarification questions, go to clarifying state
       if (response.clarification_questions.length > 0) {
@@ -89,6 +100,7 @@ export default function Step0View({ runId, onGateReached }: Step0ViewProps) {
 
       console.log('Updated Step 0 result:', response);
       setResult(response);
+      followRunId(response);
       setViewState('review');
     } catch (err) {
       console.error('Error submitting clarifications:', err);